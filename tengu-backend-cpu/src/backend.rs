@@ -5,6 +5,7 @@
 
 use std::collections::HashSet;
 use std::rc::Rc;
+use std::time::Duration;
 
 use tengu_backend::{Error, Result};
 use tengu_backend_tensor::{IOType, StorageType};
@@ -27,6 +28,7 @@ impl tengu_backend::Backend for Backend {
     type Linker<'a> = Linker;
     type Readout<'a> = Readout;
     type Limits = Limits;
+    type Timer = ();
 
     /// Creates a new `Backend` instance asynchronously.
     ///
@@ -69,11 +71,58 @@ impl tengu_backend::Backend for Backend {
     /// # Parameters
     /// - `label`: A label for compute operations.
     /// - `call`: A function that takes a `Compute` and performs compute operations.
-    fn compute<F>(&self, _label: &str, call: F) -> Result<()>
+    ///
+    /// # Returns
+    /// The result of the compute function.
+    fn compute<F, E>(&self, _label: &str, call: F) -> std::result::Result<(), E>
     where
-        F: FnOnce(Self::Compute<'_>) -> anyhow::Result<()>,
+        F: FnOnce(Self::Compute<'_>) -> std::result::Result<(), E>,
+        E: From<Error>,
     {
-        call(Compute).map_err(Error::ComputeError)
+        call(Compute)
+    }
+
+    /// Creates a timer measuring the execution time of compute passes. Since all the work of this
+    /// CPU implementation is done by the processor rather than by the compute pass, there is
+    /// nothing to measure and the method always fails.
+    ///
+    /// # Parameters
+    /// - `label`: A label for the timer.
+    ///
+    /// # Returns
+    /// Always `Error::TimestampQueryUnsupported`.
+    fn timer(&self, _label: &str) -> Result<Self::Timer> {
+        Err(Error::TimestampQueryUnsupported)
+    }
+
+    /// Executes a compute pass measuring its execution time. Timers can't be created on this CPU
+    /// implementation, so the method always fails.
+    ///
+    /// # Parameters
+    /// - `label`: A label for compute operations.
+    /// - `timer`: The timer measuring the compute pass.
+    /// - `call`: A function that takes a `Compute` and performs compute operations.
+    ///
+    /// # Returns
+    /// Always the converted `Error::TimestampQueryUnsupported`.
+    fn compute_timed<F, E>(&self, _label: &str, _timer: &Self::Timer, _call: F) -> std::result::Result<(), E>
+    where
+        F: FnOnce(Self::Compute<'_>) -> std::result::Result<(), E>,
+        E: From<Error>,
+    {
+        Err(Error::TimestampQueryUnsupported.into())
+    }
+
+    /// Returns the total execution time measured by a timer. Timers can't be created on this CPU
+    /// implementation, so the method always fails.
+    ///
+    /// # Parameters
+    /// - `timer`: The timer measuring the compute passes.
+    ///
+    /// # Returns
+    /// Always `Error::TimestampQueryUnsupported`.
+    fn elapsed(&self, _timer: &Self::Timer) -> Result<Duration> {
+        Err(Error::TimestampQueryUnsupported)
     }
 
     /// Copies data to staging buffers using the provided staging function. In the case of this CPU
@@ -86,6 +135,10 @@ impl tengu_backend::Backend for Backend {
         call(Readout);
     }
 
+    /// Resolves pending retrieves. In the case of this CPU implementation, the data is always
+    /// available immediately, so this is a noop.
+    fn poll(&self) {}
+
     /// Creates a new tensor with the provided data.
     ///
     /// # Parameters
@@ -94,14 +147,15 @@ impl tengu_backend::Backend for Backend {
     /// - `data`: A slice of data to initialize the tensor with.
     ///
     /// # Returns
-    /// A new tensor initialized with the provided data.
-    fn tensor<T: IOType>(
+    /// A new tensor initialized with the provided data. Allocations go through the global
+    /// allocator, so they never fail with an error.
+    fn try_tensor<T: IOType>(
         self: &Rc<Self>,
         label: impl Into<String>,
         shape: impl Into<Vec<usize>>,
         data: &[T],
-    ) -> Self::Tensor<T> {
-        Tensor::new(label, shape, data)
+    ) -> Result<Self::Tensor<T>> {
+        Ok(Tensor::new(label, shape, data))
     }
 
     /// Creates a new zero-initialized tensor with the specified shape.
@@ -111,12 +165,13 @@ impl tengu_backend::Backend for Backend {
     /// - `shape`: The shape of the tensor.
     ///
     /// # Returns
-    /// A new zero-initialized tensor.
-    fn zero<T: StorageType>(
+    /// A new zero-initialized tensor. Allocations go through the global allocator, so they never
+    /// fail with an error.
+    fn try_zero<T: StorageType>(
         self: &Rc<Self>,
         label: impl Into<String>,
         shape: impl Into<Vec<usize>>,
-    ) -> Self::Tensor<T> {
-        Tensor::empty(label, shape)
+    ) -> Result<Self::Tensor<T>> {
+        Ok(Tensor::empty(label, shape))
     }
 }
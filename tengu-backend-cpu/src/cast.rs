@@ -1,4 +1,11 @@
-/// This module defines lossy type casts between various backend-supported types.
+//! This module defines lossy type casts between various backend-supported types.
+
+use tengu_backend_tensor::CastMode;
+
+/// The modulus of the wrapping casts from floats, which wraps the truncated value into the range of
+/// `u32`. The range of each narrower integer type divides it, so a further `as` cast wraps the value
+/// into that type.
+const WRAP_MODULUS: f64 = 4294967296.0;
 
 /// Trait for type casts.
 pub trait Cast<To> {
@@ -7,6 +14,15 @@ pub trait Cast<To> {
     /// # Returns
     /// The value casted to the target type.
     fn cast(self) -> To;
+
+    /// Casts the value to the target type, handling out-of-range values according to the mode.
+    ///
+    /// # Parameters
+    /// - `mode`: The way out-of-range values are cast.
+    ///
+    /// # Returns
+    /// The value casted to the target type.
+    fn cast_with(self, mode: CastMode) -> To;
 }
 
 impl<T> Cast<T> for T {
@@ -17,16 +33,30 @@ impl<T> Cast<T> for T {
     fn cast(self) -> T {
         self
     }
+
+    /// Casts the value to the same type, which is always in range.
+    ///
+    /// # Returns
+    /// The value passed in without any changes.
+    fn cast_with(self, _mode: CastMode) -> T {
+        self
+    }
 }
 
 /// Implementations of type casts between various types. The following conversions are supported:
-/// Conversiions to bool are based on the value being non-zero.
+/// Conversiions to bool are based on the value being non-zero. Casts from floats to integers and
+/// between integers are marked with `float` and `int` respectively, since only these can go out of
+/// range.
 macro_rules! impl_convert {
     ( $from:ty, bool ) => {
         impl Cast<bool> for $from {
             fn cast(self) -> bool {
                 self != (0 as $from)
             }
+
+            fn cast_with(self, _mode: CastMode) -> bool {
+                self.cast()
+            }
         }
     };
     ( bool, $to:ty ) => {
@@ -34,6 +64,38 @@ macro_rules! impl_convert {
             fn cast(self) -> $to {
                 self as u32 as $to
             }
+
+            fn cast_with(self, _mode: CastMode) -> $to {
+                self.cast()
+            }
+        }
+    };
+    ( float $from:ty, $to:ty ) => {
+        impl Cast<$to> for $from {
+            fn cast(self) -> $to {
+                self as $to
+            }
+
+            fn cast_with(self, mode: CastMode) -> $to {
+                match mode {
+                    CastMode::Saturate => self as $to,
+                    CastMode::Wrap => (self.trunc() as f64).rem_euclid(WRAP_MODULUS) as u32 as $to,
+                }
+            }
+        }
+    };
+    ( int $from:ty, $to:ty ) => {
+        impl Cast<$to> for $from {
+            fn cast(self) -> $to {
+                self as $to
+            }
+
+            fn cast_with(self, mode: CastMode) -> $to {
+                match mode {
+                    CastMode::Saturate => (self as i64).clamp(<$to>::MIN as i64, <$to>::MAX as i64) as $to,
+                    CastMode::Wrap => self as $to,
+                }
+            }
         }
     };
     ( $from:ty, $to:ty ) => {
@@ -41,19 +103,41 @@ macro_rules! impl_convert {
             fn cast(self) -> $to {
                 self as $to
             }
+
+            fn cast_with(self, _mode: CastMode) -> $to {
+                self.cast()
+            }
         }
     };
 }
 
-impl_convert!(u32, i32);
+impl_convert!(int u32, i32);
 impl_convert!(u32, f32);
+impl_convert!(int u32, u16);
+impl_convert!(int u32, i16);
 impl_convert!(u32, bool);
-impl_convert!(i32, u32);
+impl_convert!(int i32, u32);
 impl_convert!(i32, f32);
+impl_convert!(int i32, u16);
+impl_convert!(int i32, i16);
 impl_convert!(i32, bool);
-impl_convert!(f32, u32);
-impl_convert!(f32, i32);
+impl_convert!(float f32, u32);
+impl_convert!(float f32, i32);
+impl_convert!(float f32, u16);
+impl_convert!(float f32, i16);
 impl_convert!(f32, bool);
+impl_convert!(int u16, u32);
+impl_convert!(int u16, i32);
+impl_convert!(u16, f32);
+impl_convert!(int u16, i16);
+impl_convert!(u16, bool);
+impl_convert!(int i16, u32);
+impl_convert!(int i16, i32);
+impl_convert!(i16, f32);
+impl_convert!(int i16, u16);
+impl_convert!(i16, bool);
 impl_convert!(bool, u32);
 impl_convert!(bool, i32);
 impl_convert!(bool, f32);
+impl_convert!(bool, u16);
+impl_convert!(bool, i16);
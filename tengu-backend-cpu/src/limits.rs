@@ -1,6 +1,8 @@
 //! Module implementing the `Limits` struct for the CPU backend. It provides information about the
 //! limitations of the CPU backend, which is theoretically limitless in all respects.
 
+use tengu_backend_tensor::Type;
+
 pub struct Limits;
 
 impl tengu_backend::Limits for Limits {
@@ -13,4 +15,13 @@ impl tengu_backend::Limits for Limits {
     fn max_tensor_per_compute(&self) -> Option<usize> {
         None
     }
+
+    /// Returns the element types the backend can store and compute with. The CPU backend supports
+    /// all of them.
+    ///
+    /// # Returns
+    /// All element types.
+    fn supported_types(&self) -> Vec<Type> {
+        vec![Type::Bool, Type::U32, Type::I32, Type::F32, Type::U16, Type::I16]
+    }
 }
@@ -1,9 +1,10 @@
 //! This module defines the `Linker` struct which implements the `Linker` trait from the `tengu_backend` crate.
-//! The `Linker` is responsible for copying data between CPU buffers using the CPU backend.
+//! The `Linker` is responsible for copying and accumulating data between CPU buffers using the CPU backend.
 
 use tengu_backend::Linker as RawLinker;
 use tengu_backend_tensor::StorageType;
 
+use crate::processor::source::Source;
 use crate::tensor::Tensor;
 use crate::Backend as CPUBackend;
 
@@ -20,4 +21,19 @@ impl RawLinker<CPUBackend> for Linker {
     fn copy_link<T: StorageType>(&mut self, from: &Tensor<T>, to: &Tensor<T>) {
         to.copy_from(from)
     }
+
+    /// Adds data of one tensor buffer to another element-wise. This operation works only on typed
+    /// tensors, so the downcast conversion from sources should be performed elsewhere.
+    ///
+    /// # Parameters
+    /// - `from`: A reference to the source tensor.
+    /// - `to`: A reference to the destination tensor.
+    ///
+    /// # Panics
+    /// Panics if the tensors store booleans, which cannot be added.
+    fn add_link<T: StorageType>(&mut self, from: &Tensor<T>, to: &Tensor<T>) {
+        let to = Source::from(to);
+        let sum = &to + &Source::from(from);
+        to.copy_from(&sum);
+    }
 }
@@ -8,15 +8,17 @@
 //! handles AST of tensor operations to collect source tensors and produce declaration and shader body code.
 
 use std::collections::HashSet;
+use std::ops::Range;
 
 use tengu_backend::Processor as RawProcessor;
-use tengu_backend_tensor::{Function, Operator, StorageType, Type, UnaryFn};
+use tengu_backend::Result;
+use tengu_backend_tensor::{CastMode, Function, Operator, StorageType, Type, UnaryFn};
 
 use crate::tensor::Tensor;
 use crate::Backend as CPUBackend;
-use source::{Equality, Source};
+use source::{Equality, Ordering, Source};
 
-mod source;
+pub(crate) mod source;
 
 /// The `Processor` struct is used to manage and process tensor sources and perform tensor computations.
 pub struct Processor<'a> {
@@ -92,6 +94,7 @@ impl<'a> RawProcessor<'a, CPUBackend> for Processor<'a> {
         match function {
             Function::Exp => inner.exp(),
             Function::Log => inner.log(),
+            Function::Sign => inner.sign(),
         }
     }
 
@@ -112,26 +115,232 @@ impl<'a> RawProcessor<'a, CPUBackend> for Processor<'a> {
             Operator::Div => &lhs / &rhs,
             Operator::Eq => lhs.eq(&rhs),
             Operator::Neq => lhs.neq(&rhs),
+            Operator::Gt => lhs.gt(&rhs),
+            Operator::Lt => lhs.lt(&rhs),
+            Operator::Ge => lhs.ge(&rhs),
+            Operator::Le => lhs.le(&rhs),
+            Operator::Copysign => lhs.copysign(&rhs),
+            Operator::Max => lhs.max(&rhs),
+            Operator::Min => lhs.min(&rhs),
         }
     }
 
+    /// Generates the representation for the element-wise selection between two expressions.
+    ///
+    /// # Parameters
+    /// - `mask`: The boolean mask representation.
+    /// - `on_true`: The representation selected where the mask is true.
+    /// - `on_false`: The representation selected where the mask is false.
+    ///
+    /// # Returns
+    /// Processor representation of the selected elements.
+    fn select(&mut self, mask: Self::Repr, on_true: Self::Repr, on_false: Self::Repr) -> Self::Repr {
+        mask.select(&on_true, &on_false)
+    }
+
     /// Generates the representation for the type cast of the inner expression to a specified type.
     ///
     /// # Parameters
     /// - `inner`: The inner expression representation.
+    /// - `_from`: The type of the inner expression, which the source already knows.
     /// - `ty`: The target type to cast to.
+    /// - `mode`: The way out-of-range values are cast.
     ///
     /// # Returns
     /// Processor representation of the inner expression cast to the specified type.
-    fn cast(&mut self, inner: Self::Repr, ty: Type) -> Self::Repr {
+    fn cast(&mut self, inner: Self::Repr, _from: Type, ty: Type, mode: CastMode) -> Self::Repr {
         match ty {
-            Type::U32 => inner.cast::<u32>(),
-            Type::I32 => inner.cast::<i32>(),
-            Type::F32 => inner.cast::<f32>(),
-            Type::Bool => inner.cast::<bool>(),
+            Type::U32 => inner.cast::<u32>(mode),
+            Type::I32 => inner.cast::<i32>(mode),
+            Type::F32 => inner.cast::<f32>(mode),
+            Type::U16 => inner.cast::<u16>(mode),
+            Type::I16 => inner.cast::<i16>(mode),
+            Type::Bool => inner.cast::<bool>(mode),
         }
     }
 
+    /// Generates the representation for the histogram of the values of the inner expression.
+    ///
+    /// # Parameters
+    /// - `inner`: The inner expression representation.
+    /// - `bins`: The number of bins.
+    /// - `min`: The inclusive lower bound of the binned range.
+    /// - `max`: The exclusive upper bound of the binned range.
+    ///
+    /// # Returns
+    /// Processor representation of the `[bins]`-shaped tensor holding the bin counts.
+    fn bincount(&mut self, inner: Self::Repr, bins: usize, min: f32, max: f32) -> Self::Repr {
+        inner.bincount(bins, min, max)
+    }
+
+    /// Generates the representation for the mean of the inner expression along the specified axis.
+    ///
+    /// # Parameters
+    /// - `inner`: The inner expression representation.
+    /// - `shape`: The shape of the inner expression.
+    /// - `axis`: The axis along which the mean is computed.
+    ///
+    /// # Returns
+    /// Processor representation of the tensor holding the means.
+    fn mean(&mut self, inner: Self::Repr, shape: &[usize], axis: usize) -> Self::Repr {
+        inner.mean(shape, axis)
+    }
+
+    /// Generates the representation for the softmax of the inner expression along the specified axis.
+    ///
+    /// # Parameters
+    /// - `inner`: The inner expression representation.
+    /// - `shape`: The shape of the inner expression.
+    /// - `axis`: The axis along which the softmax is normalized.
+    ///
+    /// # Returns
+    /// Processor representation of the tensor holding the softmax values.
+    fn softmax(&mut self, inner: Self::Repr, shape: &[usize], axis: usize) -> Self::Repr {
+        inner.softmax(shape, axis)
+    }
+
+    /// Generates the representation for the one-hot encoding of the inner expression.
+    ///
+    /// # Parameters
+    /// - `inner`: The inner expression representation.
+    /// - `classes`: The number of classes.
+    ///
+    /// # Returns
+    /// Processor representation of the `[n, classes]`-shaped tensor holding the encoded values.
+    fn one_hot(&mut self, inner: Self::Repr, classes: usize) -> Self::Repr {
+        inner.one_hot(classes)
+    }
+
+    /// Generates the representation for the inner expression tiled along its axes.
+    ///
+    /// # Parameters
+    /// - `inner`: The inner expression representation.
+    /// - `shape`: The shape of the inner expression.
+    /// - `reps`: The number of repetitions along each axis.
+    /// - `_ty`: The element type of the inner expression, which the source already knows.
+    ///
+    /// # Returns
+    /// Processor representation of the tiled tensor.
+    fn tile(&mut self, inner: Self::Repr, shape: &[usize], reps: &[usize], _ty: Type) -> Self::Repr {
+        inner.tile(shape, reps)
+    }
+
+    /// Generates the representation for the inner expression padded with a constant value.
+    ///
+    /// # Parameters
+    /// - `inner`: The inner expression representation.
+    /// - `shape`: The shape of the inner expression.
+    /// - `padding`: The number of elements added before and after each axis.
+    /// - `value`: The representation of the scalar padding value.
+    /// - `_ty`: The element type of the inner expression, which the source already knows.
+    ///
+    /// # Returns
+    /// Processor representation of the padded tensor.
+    fn pad(
+        &mut self,
+        inner: Self::Repr,
+        shape: &[usize],
+        padding: &[(usize, usize)],
+        value: Self::Repr,
+        _ty: Type,
+    ) -> Self::Repr {
+        inner.pad(shape, padding, &value)
+    }
+
+    /// Generates the representation for the elements of the inner expression at the given flat
+    /// indices.
+    ///
+    /// # Parameters
+    /// - `inner`: The inner expression representation.
+    /// - `indices`: The flat indices of the taken elements.
+    /// - `_ty`: The element type of the inner expression, which the source already knows.
+    ///
+    /// # Returns
+    /// Processor representation of the 1-D tensor holding the taken elements.
+    fn take(&mut self, inner: Self::Repr, indices: &[usize], _ty: Type) -> Self::Repr {
+        inner.take(indices)
+    }
+
+    /// Generates the representation for a contiguous range of the inner expression along an axis.
+    ///
+    /// # Parameters
+    /// - `inner`: The inner expression representation.
+    /// - `shape`: The shape of the inner expression.
+    /// - `axis`: The axis along which the range is taken.
+    /// - `range`: The range of indices along the axis.
+    /// - `_ty`: The element type of the inner expression, which the source already knows.
+    ///
+    /// # Returns
+    /// Processor representation of the sliced tensor.
+    fn slice(&mut self, inner: Self::Repr, shape: &[usize], axis: usize, range: Range<usize>, _ty: Type) -> Self::Repr {
+        inner.slice(shape, axis, range)
+    }
+
+    /// Generates the representation for the inner expression reversed along the specified axes.
+    ///
+    /// # Parameters
+    /// - `inner`: The inner expression representation.
+    /// - `shape`: The shape of the inner expression.
+    /// - `axes`: The axes along which the elements are reversed.
+    /// - `_ty`: The element type of the inner expression, which the source already knows.
+    ///
+    /// # Returns
+    /// Processor representation of the flipped tensor.
+    fn flip(&mut self, inner: Self::Repr, shape: &[usize], axes: &[usize], _ty: Type) -> Self::Repr {
+        inner.flip(shape, axes)
+    }
+
+    /// Generates the representation for the inner expression shifted circularly along an axis.
+    ///
+    /// # Parameters
+    /// - `inner`: The inner expression representation.
+    /// - `shape`: The shape of the inner expression.
+    /// - `axis`: The axis along which the elements are shifted.
+    /// - `shift`: The number of positions the elements are shifted by.
+    /// - `_ty`: The element type of the inner expression, which the source already knows.
+    ///
+    /// # Returns
+    /// Processor representation of the rolled tensor.
+    fn roll(&mut self, inner: Self::Repr, shape: &[usize], axis: usize, shift: usize, _ty: Type) -> Self::Repr {
+        inner.roll(shape, axis, shift)
+    }
+
+    /// Generates the representation for the inner expressions joined along a new axis.
+    ///
+    /// # Parameters
+    /// - `inners`: The inner expression representations.
+    /// - `shape`: The shape shared by the inner expressions.
+    /// - `axis`: The position of the new axis.
+    /// - `_ty`: The element type of the inner expressions, which the sources already know.
+    ///
+    /// # Returns
+    /// Processor representation of the stacked tensor.
+    fn stack(&mut self, inners: Vec<Self::Repr>, shape: &[usize], axis: usize, _ty: Type) -> Self::Repr {
+        Source::stack(&inners, shape, axis)
+    }
+
+    /// Generates the representation for the elements of the inner expression where the mask is
+    /// true, and writes the number of the selected elements to the length tensor, if any.
+    ///
+    /// # Parameters
+    /// - `inner`: The inner expression representation.
+    /// - `mask`: The boolean mask representation.
+    /// - `length`: The representation of the tensor receiving the number of selected elements, or
+    ///   `None` if the selection is nested inside another expression.
+    /// - `_ty`: The element type of the inner expression, which the source already knows.
+    ///
+    /// # Returns
+    /// Processor representation of the selected elements padded with zeros.
+    fn mask_select(
+        &mut self,
+        inner: Self::Repr,
+        mask: Self::Repr,
+        length: Option<Self::Repr>,
+        _ty: Type,
+    ) -> Self::Repr {
+        inner.mask_select(&mask, length.as_ref())
+    }
+
     /// Copies the data from the `expr` expression to the `out` resulting source and outputs it as a
     /// method result.
     ///
@@ -151,5 +360,7 @@ impl<'a> RawProcessor<'a, CPUBackend> for Processor<'a> {
     ///
     /// # Parameters
     /// - `exprs`: An iterator over expression representations to be included in the block.
-    fn block(&mut self, _exprs: impl Iterator<Item = Self::Repr>) {}
+    fn block(&mut self, _exprs: impl Iterator<Item = Self::Repr>) -> Result<()> {
+        Ok(())
+    }
 }
@@ -3,18 +3,31 @@
 //! tensors as `dyn Any` objects and maintain the type information in enum variants.
 
 use std::any::TypeId;
-use tengu_backend_tensor::StorageType;
+use tengu_backend_tensor::{StorageType, Type};
 use tengu_utils::Cage;
 
 use crate::tensor::Tensor;
 
 mod arithmetic;
+mod bincount;
 mod cast;
 mod copy;
+mod flip;
+mod mask_select;
+mod mean;
+mod one_hot;
+mod pad;
 mod relational;
+mod roll;
+mod select;
+mod slice;
+mod softmax;
+mod stack;
+mod take;
+mod tile;
 mod unary_fn;
 
-pub use relational::Equality;
+pub use relational::{Equality, Ordering};
 
 /// The `Source` trait represents a "type-less" tensor. It is used by the `Processor`
 /// to handle all tensors in a uniform fashion.
@@ -25,6 +38,10 @@ pub enum Source<'a> {
     I32(Cage<'a>),
     /// A source variant storing a f32-based tensor.
     F32(Cage<'a>),
+    /// A source variant storing a u16-based tensor.
+    U16(Cage<'a>),
+    /// A source variant storing a i16-based tensor.
+    I16(Cage<'a>),
     /// A source variant storing a bool-based tensor.
     Bool(Cage<'a>),
 }
@@ -42,6 +59,8 @@ impl<'a> Source<'a> {
             Self::U32(cage) => cage.into_owned::<Tensor<T>>(),
             Self::I32(cage) => cage.into_owned::<Tensor<T>>(),
             Self::F32(cage) => cage.into_owned::<Tensor<T>>(),
+            Self::U16(cage) => cage.into_owned::<Tensor<T>>(),
+            Self::I16(cage) => cage.into_owned::<Tensor<T>>(),
             Self::Bool(cage) => cage.into_owned::<Tensor<T>>(),
         };
         tensor.expect("Source type mismatch")
@@ -59,21 +78,25 @@ impl<'a> Source<'a> {
             Self::U32(cage) => cage.as_ref::<Tensor<T>>(),
             Self::I32(cage) => cage.as_ref::<Tensor<T>>(),
             Self::F32(cage) => cage.as_ref::<Tensor<T>>(),
+            Self::U16(cage) => cage.as_ref::<Tensor<T>>(),
+            Self::I16(cage) => cage.as_ref::<Tensor<T>>(),
             Self::Bool(cage) => cage.as_ref::<Tensor<T>>(),
         };
         tensor.expect("Source type mismatch")
     }
 
-    /// Returns the string representation of the type variant of this `Source`.
+    /// Returns the element type of the tensor stored in this `Source`.
     ///
     /// # Returns
-    /// A string slice representing the type variant of this `Source`.
-    pub fn variant(&self) -> &'static str {
+    /// The `Type` variant corresponding to the type variant of this `Source`.
+    pub fn dtype(&self) -> Type {
         match self {
-            Self::U32(_) => "u32",
-            Self::I32(_) => "i32",
-            Self::F32(_) => "f32",
-            Self::Bool(_) => "bool",
+            Self::U32(_) => Type::U32,
+            Self::I32(_) => Type::I32,
+            Self::F32(_) => Type::F32,
+            Self::U16(_) => Type::U16,
+            Self::I16(_) => Type::I16,
+            Self::Bool(_) => Type::Bool,
         }
     }
 }
@@ -84,6 +107,8 @@ impl<'a> Clone for Source<'a> {
             Self::U32(cage) => Self::U32(cage.cloned::<Tensor<u32>>().expect("Source type mismatch")),
             Self::I32(cage) => Self::I32(cage.cloned::<Tensor<i32>>().expect("Source type mismatch")),
             Self::F32(cage) => Self::F32(cage.cloned::<Tensor<f32>>().expect("Source type mismatch")),
+            Self::U16(cage) => Self::U16(cage.cloned::<Tensor<u16>>().expect("Source type mismatch")),
+            Self::I16(cage) => Self::I16(cage.cloned::<Tensor<i16>>().expect("Source type mismatch")),
             Self::Bool(cage) => Self::Bool(cage.cloned::<Tensor<bool>>().expect("Source type mismatch")),
         }
     }
@@ -103,6 +128,12 @@ impl<'a, T: StorageType> From<Tensor<T>> for Source<'a> {
         if TypeId::of::<T>() == TypeId::of::<f32>() {
             return Source::F32(Cage::owned(value));
         }
+        if TypeId::of::<T>() == TypeId::of::<u16>() {
+            return Source::U16(Cage::owned(value));
+        }
+        if TypeId::of::<T>() == TypeId::of::<i16>() {
+            return Source::I16(Cage::owned(value));
+        }
         if TypeId::of::<T>() == TypeId::of::<bool>() {
             return Source::Bool(Cage::owned(value));
         }
@@ -124,6 +155,12 @@ impl<'a, T: StorageType> From<&'a Tensor<T>> for Source<'a> {
         if TypeId::of::<T>() == TypeId::of::<f32>() {
             return Source::F32(Cage::borrowed(value));
         }
+        if TypeId::of::<T>() == TypeId::of::<u16>() {
+            return Source::U16(Cage::borrowed(value));
+        }
+        if TypeId::of::<T>() == TypeId::of::<i16>() {
+            return Source::I16(Cage::borrowed(value));
+        }
         if TypeId::of::<T>() == TypeId::of::<bool>() {
             return Source::Bool(Cage::borrowed(value));
         }
@@ -12,11 +12,13 @@ macro_rules! impl_op_source {
                     (Source::U32(_), Source::U32(_)) => (self.as_ref::<u32>().$op(rhs.as_ref::<u32>())).into(),
                     (Source::I32(_), Source::I32(_)) => (self.as_ref::<i32>().$op(rhs.as_ref::<i32>())).into(),
                     (Source::F32(_), Source::F32(_)) => (self.as_ref::<f32>().$op(rhs.as_ref::<f32>())).into(),
+                    (Source::U16(_), Source::U16(_)) => (self.as_ref::<u16>().$op(rhs.as_ref::<u16>())).into(),
+                    (Source::I16(_), Source::I16(_)) => (self.as_ref::<i16>().$op(rhs.as_ref::<i16>())).into(),
                     (lhs, rhs) => panic!(
                         "{} operation not implemented for {} and {}",
                         stringify!($op),
-                        lhs.variant(),
-                        rhs.variant()
+                        lhs.dtype(),
+                        rhs.dtype()
                     ),
                 }
             }
@@ -28,3 +30,44 @@ impl_op_source!(add, Add);
 impl_op_source!(sub, Sub);
 impl_op_source!(div, Div);
 impl_op_source!(mul, Mul);
+
+macro_rules! impl_extremum_source {
+    ( $op:ident ) => {
+        pub fn $op(&self, other: &Self) -> Self {
+            match (self, other) {
+                (Source::U32(_), Source::U32(_)) => (self.as_ref::<u32>().$op(other.as_ref::<u32>())).into(),
+                (Source::I32(_), Source::I32(_)) => (self.as_ref::<i32>().$op(other.as_ref::<i32>())).into(),
+                (Source::F32(_), Source::F32(_)) => (self.as_ref::<f32>().$op(other.as_ref::<f32>())).into(),
+                (Source::U16(_), Source::U16(_)) => (self.as_ref::<u16>().$op(other.as_ref::<u16>())).into(),
+                (Source::I16(_), Source::I16(_)) => (self.as_ref::<i16>().$op(other.as_ref::<i16>())).into(),
+                (lhs, rhs) => panic!(
+                    "{} operation not implemented for {} and {}",
+                    stringify!($op),
+                    lhs.dtype(),
+                    rhs.dtype()
+                ),
+            }
+        }
+    };
+}
+
+impl<'a> Source<'a> {
+    impl_extremum_source!(max);
+    impl_extremum_source!(min);
+
+    pub fn copysign(&self, other: &Self) -> Self {
+        match (self, other) {
+            (Source::F32(_), Source::F32(_)) => (self.as_ref::<f32>().copysign(other.as_ref::<f32>())).into(),
+            (Source::I32(_), Source::I32(_)) => (self.as_ref::<i32>().copysign(other.as_ref::<i32>())).into(),
+            (Source::I16(_), Source::I16(_)) => (self.as_ref::<i16>().copysign(other.as_ref::<i16>())).into(),
+            (Source::U32(_), Source::U32(_)) => (self.as_ref::<u32>().copysign(other.as_ref::<u32>())).into(),
+            (Source::U16(_), Source::U16(_)) => (self.as_ref::<u16>().copysign(other.as_ref::<u16>())).into(),
+            (Source::Bool(_), Source::Bool(_)) => (self.as_ref::<bool>().copysign(other.as_ref::<bool>())).into(),
+            (lhs, rhs) => panic!(
+                "copysign operation not implemented for {} and {}",
+                lhs.dtype(),
+                rhs.dtype()
+            ),
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use super::Source;
+
+impl<'a> Source<'a> {
+    pub fn bincount(&self, bins: usize, min: f32, max: f32) -> Self {
+        match self {
+            Source::U32(_) => self.as_ref::<u32>().bincount(bins, min, max).into(),
+            Source::I32(_) => self.as_ref::<i32>().bincount(bins, min, max).into(),
+            Source::F32(_) => self.as_ref::<f32>().bincount(bins, min, max).into(),
+            Source::U16(_) => self.as_ref::<u16>().bincount(bins, min, max).into(),
+            Source::I16(_) => self.as_ref::<i16>().bincount(bins, min, max).into(),
+            Source::Bool(_) => self.as_ref::<bool>().bincount(bins, min, max).into(),
+        }
+    }
+}
@@ -1,22 +1,26 @@
-use tengu_backend_tensor::StorageType;
+use tengu_backend_tensor::{CastMode, StorageType};
 
 use super::Source;
-use crate::{cast::Cast, tensor::Tensor};
+use crate::cast::Cast;
 
 impl<'a> Source<'a> {
-    pub fn cast<T>(&self) -> Self
+    pub fn cast<T>(&self, mode: CastMode) -> Self
     where
         T: StorageType,
         u32: Cast<T>,
         i32: Cast<T>,
         f32: Cast<T>,
+        u16: Cast<T>,
+        i16: Cast<T>,
         bool: Cast<T>,
     {
         match self {
-            Source::U32(_) => Tensor::<T>::from(self.as_ref::<u32>()).into(),
-            Source::I32(_) => Tensor::<T>::from(self.as_ref::<i32>()).into(),
-            Source::F32(_) => Tensor::<T>::from(self.as_ref::<f32>()).into(),
-            Source::Bool(_) => Tensor::<T>::from(self.as_ref::<f32>()).into(),
+            Source::U32(_) => self.as_ref::<u32>().cast::<T>(mode).into(),
+            Source::I32(_) => self.as_ref::<i32>().cast::<T>(mode).into(),
+            Source::F32(_) => self.as_ref::<f32>().cast::<T>(mode).into(),
+            Source::U16(_) => self.as_ref::<u16>().cast::<T>(mode).into(),
+            Source::I16(_) => self.as_ref::<i16>().cast::<T>(mode).into(),
+            Source::Bool(_) => self.as_ref::<f32>().cast::<T>(mode).into(),
         }
     }
 }
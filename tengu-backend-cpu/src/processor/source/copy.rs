@@ -6,7 +6,9 @@ impl<'a> Source<'a> {
             (Source::U32(_), Source::U32(_)) => self.as_ref::<u32>().copy_from(other.as_ref::<u32>()),
             (Source::I32(_), Source::I32(_)) => self.as_ref::<i32>().copy_from(other.as_ref::<i32>()),
             (Source::F32(_), Source::F32(_)) => self.as_ref::<f32>().copy_from(other.as_ref::<f32>()),
-            (lhs, rhs) => panic!("Cannot copy from {} to {}", rhs.variant(), lhs.variant()),
+            (Source::U16(_), Source::U16(_)) => self.as_ref::<u16>().copy_from(other.as_ref::<u16>()),
+            (Source::I16(_), Source::I16(_)) => self.as_ref::<i16>().copy_from(other.as_ref::<i16>()),
+            (lhs, rhs) => panic!("Cannot copy from {} to {}", rhs.dtype(), lhs.dtype()),
         }
     }
 }
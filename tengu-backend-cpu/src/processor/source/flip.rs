@@ -0,0 +1,14 @@
+use super::Source;
+
+impl<'a> Source<'a> {
+    pub fn flip(&self, shape: &[usize], axes: &[usize]) -> Self {
+        match self {
+            Source::U32(_) => self.as_ref::<u32>().flip(shape, axes).into(),
+            Source::I32(_) => self.as_ref::<i32>().flip(shape, axes).into(),
+            Source::F32(_) => self.as_ref::<f32>().flip(shape, axes).into(),
+            Source::U16(_) => self.as_ref::<u16>().flip(shape, axes).into(),
+            Source::I16(_) => self.as_ref::<i16>().flip(shape, axes).into(),
+            Source::Bool(_) => self.as_ref::<bool>().flip(shape, axes).into(),
+        }
+    }
+}
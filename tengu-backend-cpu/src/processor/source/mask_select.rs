@@ -0,0 +1,19 @@
+use super::Source;
+use crate::tensor::Tensor;
+
+impl<'a> Source<'a> {
+    pub fn mask_select(&self, mask: &Self, length: Option<&Self>) -> Self {
+        let mask = mask.as_ref::<bool>();
+        if let Some(length) = length {
+            length.copy_from(&Tensor::new("", [1], [mask.count_true()]).into());
+        }
+        match self {
+            Source::U32(_) => self.as_ref::<u32>().mask_select(mask).into(),
+            Source::I32(_) => self.as_ref::<i32>().mask_select(mask).into(),
+            Source::F32(_) => self.as_ref::<f32>().mask_select(mask).into(),
+            Source::U16(_) => self.as_ref::<u16>().mask_select(mask).into(),
+            Source::I16(_) => self.as_ref::<i16>().mask_select(mask).into(),
+            Source::Bool(_) => self.as_ref::<bool>().mask_select(mask).into(),
+        }
+    }
+}
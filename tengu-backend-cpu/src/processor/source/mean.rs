@@ -0,0 +1,14 @@
+use super::Source;
+
+impl<'a> Source<'a> {
+    pub fn mean(&self, shape: &[usize], axis: usize) -> Self {
+        match self {
+            Source::U32(_) => self.as_ref::<u32>().mean(shape, axis).into(),
+            Source::I32(_) => self.as_ref::<i32>().mean(shape, axis).into(),
+            Source::F32(_) => self.as_ref::<f32>().mean(shape, axis).into(),
+            Source::U16(_) => self.as_ref::<u16>().mean(shape, axis).into(),
+            Source::I16(_) => self.as_ref::<i16>().mean(shape, axis).into(),
+            Source::Bool(_) => self.as_ref::<bool>().mean(shape, axis).into(),
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use super::Source;
+
+impl<'a> Source<'a> {
+    pub fn one_hot(&self, classes: usize) -> Self {
+        match self {
+            Source::U32(_) => self.as_ref::<u32>().one_hot(classes).into(),
+            Source::I32(_) => self.as_ref::<i32>().one_hot(classes).into(),
+            Source::F32(_) => self.as_ref::<f32>().one_hot(classes).into(),
+            Source::U16(_) => self.as_ref::<u16>().one_hot(classes).into(),
+            Source::I16(_) => self.as_ref::<i16>().one_hot(classes).into(),
+            Source::Bool(_) => self.as_ref::<bool>().one_hot(classes).into(),
+        }
+    }
+}
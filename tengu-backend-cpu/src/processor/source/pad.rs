@@ -0,0 +1,14 @@
+use super::Source;
+
+impl<'a> Source<'a> {
+    pub fn pad(&self, shape: &[usize], padding: &[(usize, usize)], value: &Self) -> Self {
+        match self {
+            Source::U32(_) => self.as_ref::<u32>().pad(shape, padding, value.as_ref()).into(),
+            Source::I32(_) => self.as_ref::<i32>().pad(shape, padding, value.as_ref()).into(),
+            Source::F32(_) => self.as_ref::<f32>().pad(shape, padding, value.as_ref()).into(),
+            Source::U16(_) => self.as_ref::<u16>().pad(shape, padding, value.as_ref()).into(),
+            Source::I16(_) => self.as_ref::<i16>().pad(shape, padding, value.as_ref()).into(),
+            Source::Bool(_) => self.as_ref::<bool>().pad(shape, padding, value.as_ref()).into(),
+        }
+    }
+}
@@ -16,10 +16,13 @@ impl<'a> Equality for Source<'a> {
             (Source::U32(_), Source::U32(_)) => (self.as_ref::<u32>().eq(other.as_ref::<u32>())).into(),
             (Source::I32(_), Source::I32(_)) => (self.as_ref::<i32>().eq(other.as_ref::<i32>())).into(),
             (Source::F32(_), Source::F32(_)) => (self.as_ref::<f32>().eq(other.as_ref::<f32>())).into(),
+            (Source::U16(_), Source::U16(_)) => (self.as_ref::<u16>().eq(other.as_ref::<u16>())).into(),
+            (Source::I16(_), Source::I16(_)) => (self.as_ref::<i16>().eq(other.as_ref::<i16>())).into(),
+            (Source::Bool(_), Source::Bool(_)) => (self.as_ref::<bool>().eq(other.as_ref::<bool>())).into(),
             (lhs, rhs) => panic!(
                 "Comparison operations are not implemented for {} and {}",
-                lhs.variant(),
-                rhs.variant()
+                lhs.dtype(),
+                rhs.dtype()
             ),
         }
     }
@@ -29,11 +32,55 @@ impl<'a> Equality for Source<'a> {
             (Source::U32(_), Source::U32(_)) => (self.as_ref::<u32>().neq(other.as_ref::<u32>())).into(),
             (Source::I32(_), Source::I32(_)) => (self.as_ref::<i32>().neq(other.as_ref::<i32>())).into(),
             (Source::F32(_), Source::F32(_)) => (self.as_ref::<f32>().neq(other.as_ref::<f32>())).into(),
+            (Source::U16(_), Source::U16(_)) => (self.as_ref::<u16>().neq(other.as_ref::<u16>())).into(),
+            (Source::I16(_), Source::I16(_)) => (self.as_ref::<i16>().neq(other.as_ref::<i16>())).into(),
+            (Source::Bool(_), Source::Bool(_)) => (self.as_ref::<bool>().neq(other.as_ref::<bool>())).into(),
             (lhs, rhs) => panic!(
                 "Comparison operations are not implemented for {} and {}",
-                lhs.variant(),
-                rhs.variant()
+                lhs.dtype(),
+                rhs.dtype()
             ),
         }
     }
 }
+
+pub trait Ordering<Rhs = Self> {
+    type Output;
+
+    fn gt(&self, other: &Rhs) -> Self::Output;
+
+    fn lt(&self, other: &Rhs) -> Self::Output;
+
+    fn ge(&self, other: &Rhs) -> Self::Output;
+
+    fn le(&self, other: &Rhs) -> Self::Output;
+}
+
+macro_rules! impl_ordering_source {
+    ( $( $op:ident ),* ) => {
+        impl<'a> Ordering for Source<'a> {
+            type Output = Self;
+
+            $(
+                fn $op(&self, other: &Self) -> Self::Output {
+                    match (self, other) {
+                        (Source::U32(_), Source::U32(_)) => (self.as_ref::<u32>().$op(other.as_ref::<u32>())).into(),
+                        (Source::I32(_), Source::I32(_)) => (self.as_ref::<i32>().$op(other.as_ref::<i32>())).into(),
+                        (Source::F32(_), Source::F32(_)) => (self.as_ref::<f32>().$op(other.as_ref::<f32>())).into(),
+                        (Source::U16(_), Source::U16(_)) => (self.as_ref::<u16>().$op(other.as_ref::<u16>())).into(),
+                        (Source::I16(_), Source::I16(_)) => (self.as_ref::<i16>().$op(other.as_ref::<i16>())).into(),
+                        // NOTE: Booleans are ordered with false before true.
+                        (Source::Bool(_), Source::Bool(_)) => (self.as_ref::<bool>().$op(other.as_ref::<bool>())).into(),
+                        (lhs, rhs) => panic!(
+                            "Comparison operations are not implemented for {} and {}",
+                            lhs.dtype(),
+                            rhs.dtype()
+                        ),
+                    }
+                }
+            )*
+        }
+    };
+}
+
+impl_ordering_source!(gt, lt, ge, le);
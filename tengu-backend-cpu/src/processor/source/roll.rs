@@ -0,0 +1,14 @@
+use super::Source;
+
+impl<'a> Source<'a> {
+    pub fn roll(&self, shape: &[usize], axis: usize, shift: usize) -> Self {
+        match self {
+            Source::U32(_) => self.as_ref::<u32>().roll(shape, axis, shift).into(),
+            Source::I32(_) => self.as_ref::<i32>().roll(shape, axis, shift).into(),
+            Source::F32(_) => self.as_ref::<f32>().roll(shape, axis, shift).into(),
+            Source::U16(_) => self.as_ref::<u16>().roll(shape, axis, shift).into(),
+            Source::I16(_) => self.as_ref::<i16>().roll(shape, axis, shift).into(),
+            Source::Bool(_) => self.as_ref::<bool>().roll(shape, axis, shift).into(),
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use super::Source;
+
+impl<'a> Source<'a> {
+    pub fn select(&self, on_true: &Self, on_false: &Self) -> Self {
+        let mask = self.as_ref::<bool>();
+        match (on_true, on_false) {
+            (Source::U32(_), Source::U32(_)) => mask.select(on_true.as_ref::<u32>(), on_false.as_ref::<u32>()).into(),
+            (Source::I32(_), Source::I32(_)) => mask.select(on_true.as_ref::<i32>(), on_false.as_ref::<i32>()).into(),
+            (Source::F32(_), Source::F32(_)) => mask.select(on_true.as_ref::<f32>(), on_false.as_ref::<f32>()).into(),
+            (Source::U16(_), Source::U16(_)) => mask.select(on_true.as_ref::<u16>(), on_false.as_ref::<u16>()).into(),
+            (Source::I16(_), Source::I16(_)) => mask.select(on_true.as_ref::<i16>(), on_false.as_ref::<i16>()).into(),
+            (Source::Bool(_), Source::Bool(_)) => {
+                mask.select(on_true.as_ref::<bool>(), on_false.as_ref::<bool>()).into()
+            }
+            (lhs, rhs) => panic!("Selection is not implemented for {} and {}", lhs.dtype(), rhs.dtype()),
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use std::ops::Range;
+
+use super::Source;
+
+impl<'a> Source<'a> {
+    pub fn slice(&self, shape: &[usize], axis: usize, range: Range<usize>) -> Self {
+        match self {
+            Source::U32(_) => self.as_ref::<u32>().slice(shape, axis, range).into(),
+            Source::I32(_) => self.as_ref::<i32>().slice(shape, axis, range).into(),
+            Source::F32(_) => self.as_ref::<f32>().slice(shape, axis, range).into(),
+            Source::U16(_) => self.as_ref::<u16>().slice(shape, axis, range).into(),
+            Source::I16(_) => self.as_ref::<i16>().slice(shape, axis, range).into(),
+            Source::Bool(_) => self.as_ref::<bool>().slice(shape, axis, range).into(),
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use super::Source;
+
+impl<'a> Source<'a> {
+    pub fn softmax(&self, shape: &[usize], axis: usize) -> Self {
+        match self {
+            Source::U32(_) => self.as_ref::<u32>().softmax(shape, axis).into(),
+            Source::I32(_) => self.as_ref::<i32>().softmax(shape, axis).into(),
+            Source::F32(_) => self.as_ref::<f32>().softmax(shape, axis).into(),
+            Source::U16(_) => self.as_ref::<u16>().softmax(shape, axis).into(),
+            Source::I16(_) => self.as_ref::<i16>().softmax(shape, axis).into(),
+            Source::Bool(_) => self.as_ref::<bool>().softmax(shape, axis).into(),
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use tengu_backend_tensor::StorageType;
+
+use super::Source;
+use crate::tensor::Tensor;
+
+impl<'a> Source<'a> {
+    pub fn stack(sources: &[Self], shape: &[usize], axis: usize) -> Self {
+        match &sources[0] {
+            Source::U32(_) => Self::stack_as::<u32>(sources, shape, axis),
+            Source::I32(_) => Self::stack_as::<i32>(sources, shape, axis),
+            Source::F32(_) => Self::stack_as::<f32>(sources, shape, axis),
+            Source::U16(_) => Self::stack_as::<u16>(sources, shape, axis),
+            Source::I16(_) => Self::stack_as::<i16>(sources, shape, axis),
+            Source::Bool(_) => Self::stack_as::<bool>(sources, shape, axis),
+        }
+    }
+
+    fn stack_as<T: StorageType>(sources: &[Self], shape: &[usize], axis: usize) -> Self {
+        let tensors: Vec<_> = sources.iter().map(|source| source.as_ref::<T>()).collect();
+        Tensor::stack(&tensors, shape, axis).into()
+    }
+}
@@ -0,0 +1,14 @@
+use super::Source;
+
+impl<'a> Source<'a> {
+    pub fn take(&self, indices: &[usize]) -> Self {
+        match self {
+            Source::U32(_) => self.as_ref::<u32>().take(indices).into(),
+            Source::I32(_) => self.as_ref::<i32>().take(indices).into(),
+            Source::F32(_) => self.as_ref::<f32>().take(indices).into(),
+            Source::U16(_) => self.as_ref::<u16>().take(indices).into(),
+            Source::I16(_) => self.as_ref::<i16>().take(indices).into(),
+            Source::Bool(_) => self.as_ref::<bool>().take(indices).into(),
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use super::Source;
+
+impl<'a> Source<'a> {
+    pub fn tile(&self, shape: &[usize], reps: &[usize]) -> Self {
+        match self {
+            Source::U32(_) => self.as_ref::<u32>().tile(shape, reps).into(),
+            Source::I32(_) => self.as_ref::<i32>().tile(shape, reps).into(),
+            Source::F32(_) => self.as_ref::<f32>().tile(shape, reps).into(),
+            Source::U16(_) => self.as_ref::<u16>().tile(shape, reps).into(),
+            Source::I16(_) => self.as_ref::<i16>().tile(shape, reps).into(),
+            Source::Bool(_) => self.as_ref::<bool>().tile(shape, reps).into(),
+        }
+    }
+}
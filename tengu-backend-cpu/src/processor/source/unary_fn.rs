@@ -6,8 +6,8 @@ macro_rules! impl_unary_fn {
     ( $fn:ident, $( [$variant:ident, $type:ty] )+ ) => {
         fn $fn(&self) -> Self {
             match self {
-                $(Self::$variant(_) => self.as_ref::<$type>().exp().into())+,
-                other => panic!("{} is not supported for {}", stringify!($fn), other.variant()),
+                $(Self::$variant(_) => self.as_ref::<$type>().$fn().into())+,
+                other => panic!("{} is not supported for {}", stringify!($fn), other.dtype()),
             }
         }
     };
@@ -16,4 +16,5 @@ macro_rules! impl_unary_fn {
 impl<'a> UnaryFn for Source<'a> {
     impl_unary_fn!(exp, [F32, f32]);
     impl_unary_fn!(log, [F32, f32]);
+    impl_unary_fn!(sign, [F32, f32] [I32, i32] [I16, i16] [U32, u32] [U16, u16] [Bool, bool]);
 }
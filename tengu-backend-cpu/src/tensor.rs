@@ -1,15 +1,33 @@
 //! This module provides the implementation of the `Tensor` struct, which represents a tensor on the CPU backend.
 //! It includes functionality for creating tensors, and for copying and extracting their data.
 
+use std::any::Any;
 use std::borrow::Cow;
 use std::cell::RefCell;
+use std::ops::Range;
+use std::rc::Rc;
 
+use anyhow::anyhow;
+use tengu_backend::Error;
 use tengu_backend_tensor::StorageType;
 use tengu_backend_tensor::Tensor as RawTensor;
 
 mod arithmetic;
+mod bincount;
 mod cast;
+mod flip;
+mod mask_select;
+mod mean;
+mod one_hot;
+mod pad;
 mod relational;
+mod roll;
+mod select;
+mod slice;
+mod softmax;
+mod stack;
+mod take;
+mod tile;
 mod unary_fn;
 
 /// Represents a tensor on the CPU backend.
@@ -17,7 +35,7 @@ pub struct Tensor<T> {
     label: String,
     count: usize,
     shape: Vec<usize>,
-    data: RefCell<Vec<T>>,
+    data: Rc<RefCell<Vec<T>>>,
 }
 
 impl<T: StorageType> Tensor<T> {
@@ -38,7 +56,7 @@ impl<T: StorageType> Tensor<T> {
             label: label.into(),
             count,
             shape,
-            data: data.into().into(),
+            data: Rc::new(data.into().into()),
         }
     }
 
@@ -58,7 +76,7 @@ impl<T: StorageType> Tensor<T> {
             label: label.into(),
             count,
             shape,
-            data: vec![elem; count].into(),
+            data: Rc::new(vec![elem; count].into()),
         }
     }
 
@@ -77,16 +95,84 @@ impl<T: StorageType> Tensor<T> {
             label: label.into(),
             count,
             shape,
-            data: vec![T::default(); count].into(),
+            data: Rc::new(vec![T::default(); count].into()),
         }
     }
 
-    /// Copies data from another tensor into this tensor.
+    /// Copies data from another tensor into this tensor. If both tensors have the same number of
+    /// elements, the data is copied in row-major order regardless of the shapes. Otherwise, the
+    /// other tensor is broadcast: its axes are aligned with the trailing axes of this tensor and
+    /// each of them must either match or have size 1, so e.g. a scalar is repeated into every
+    /// element.
     ///
     /// # Parameters
     /// - `other`: The tensor to copy data from.
+    ///
+    /// # Panics
+    /// Panics if the other tensor cannot be broadcast to the shape of this tensor.
     pub fn copy_from(&self, other: &Self) {
-        self.data.borrow_mut().copy_from_slice(&other.data.borrow());
+        if self.count == other.count {
+            self.data.borrow_mut().copy_from_slice(&other.data.borrow());
+            return;
+        }
+        assert!(
+            other.broadcasts_to(&self.shape),
+            "cannot broadcast shape {:?} to {:?}",
+            other.shape,
+            self.shape
+        );
+        let offset = self.shape.len() - other.shape.len();
+        let values = other.data.borrow();
+        for (i, value) in self.data.borrow_mut().iter_mut().enumerate() {
+            let (mut rest, mut idx, mut stride) = (i, 0, 1);
+            for (axis, dim) in self.shape.iter().enumerate().skip(offset).rev() {
+                let other_dim = other.shape[axis - offset];
+                if other_dim != 1 {
+                    idx += rest % dim * stride;
+                }
+                rest /= dim;
+                stride *= other_dim;
+            }
+            *value = values[idx];
+        }
+    }
+
+    /// Combines the elements of this tensor with the elements of another one. If one of the tensors
+    /// has a single element, e.g. because it is a scalar, the element is combined with every
+    /// element of the other tensor, and the result takes the shape of the other tensor.
+    ///
+    /// # Parameters
+    /// - `other`: The tensor providing the right-hand side elements.
+    /// - `op`: The function combining a pair of elements.
+    ///
+    /// # Returns
+    /// A new tensor holding the combined elements.
+    pub(crate) fn zip_with<S: StorageType>(&self, other: &Self, op: impl Fn(T, T) -> S) -> Tensor<S> {
+        let (lhs, rhs) = (self.data.borrow(), other.data.borrow());
+        let (shape, count) = match lhs.len() == 1 && rhs.len() > 1 {
+            true => (&other.shape, rhs.len()),
+            false => (&self.shape, lhs.len()),
+        };
+        let data: Vec<_> = (0..count).map(|i| op(lhs[i % lhs.len()], rhs[i % rhs.len()])).collect();
+        Tensor::new("", shape.clone(), data)
+    }
+
+    /// Checks whether the tensor can be broadcast to the given shape.
+    ///
+    /// # Parameters
+    /// - `shape`: The target shape.
+    ///
+    /// # Returns
+    /// `true` if the tensor has at most as many axes as the shape and each of its axes either
+    /// matches the corresponding trailing axis of the shape or has size 1, `false` otherwise.
+    fn broadcasts_to(&self, shape: &[usize]) -> bool {
+        self.shape.len() <= shape.len()
+            && self
+                .shape
+                .iter()
+                .rev()
+                .zip(shape.iter().rev())
+                .all(|(dim, target)| dim == target || *dim == 1)
     }
 }
 
@@ -117,19 +203,71 @@ impl<T: StorageType> RawTensor<T> for Tensor<T> {
         &self.shape
     }
 
-    /// Retrieves the data from the tensor.
+    /// Retrieves the data from the tensor. Storage types which are their own IO types (all of the
+    /// implemented ones, including `bool`) are copied in bulk without converting every element.
+    /// The data lives in a `RefCell` and can be overwritten by the next computation, so it cannot
+    /// be lent out and is always copied.
     ///
     /// # Returns
-    /// A `Cow` containing either a reference or owned buffer with the tensor data.
+    /// A `Cow` owning a copy of the tensor data.
     async fn retrieve(&self) -> anyhow::Result<Cow<'_, [T::IOType]>> {
-        let data = self
-            .data
-            .borrow()
-            .iter()
-            .map(|v| v.convert())
-            .collect::<Vec<_>>()
-            .into();
-        Ok(data)
+        let data = self.data.borrow();
+        if let Some(data) = (&*data as &dyn Any).downcast_ref::<Vec<T::IOType>>() {
+            return Ok(Cow::Owned(data.clone()));
+        }
+        Ok(data.iter().map(|v| v.convert()).collect::<Vec<_>>().into())
+    }
+
+    /// Retrieves a range of elements from the tensor, converting only the requested elements.
+    ///
+    /// # Parameters
+    /// - `range`: The range of elements to retrieve.
+    ///
+    /// # Returns
+    /// A result containing the data in the requested range.
+    async fn retrieve_range(&self, range: Range<usize>) -> anyhow::Result<Cow<'_, [T::IOType]>> {
+        let data = self.data.borrow();
+        Ok(data[range].iter().map(|v| v.convert()).collect::<Vec<_>>().into())
+    }
+
+    /// Overwrites the data of the tensor. Like with `retrieve`, storage types which are their own IO
+    /// types are copied in bulk, which covers all of the implemented ones.
+    ///
+    /// # Parameters
+    /// - `data`: The new data of the tensor.
+    ///
+    /// # Returns
+    /// A result indicating success, or `Error::WriteError` if the storage type cannot be written
+    /// from its IO type.
+    fn write(&self, data: &[T::IOType]) -> anyhow::Result<()> {
+        assert_eq!(
+            data.len(),
+            self.count,
+            "data length doesn't match the tensor '{}'",
+            self.label
+        );
+        let mut buffer = self.data.borrow_mut();
+        let buffer = (&mut *buffer as &mut dyn Any)
+            .downcast_mut::<Vec<T::IOType>>()
+            .ok_or_else(|| Error::WriteError(anyhow!("cannot write {} tensor '{}'", T::as_type(), self.label)))?;
+        buffer.copy_from_slice(data);
+        Ok(())
+    }
+
+    /// Returns a tensor with another label sharing the data of this one.
+    ///
+    /// # Parameters
+    /// - `label`: The label of the new tensor.
+    ///
+    /// # Returns
+    /// A new tensor with the specified label and the same data.
+    fn relabeled(&self, label: String) -> Self {
+        Self {
+            label,
+            count: self.count,
+            shape: self.shape.clone(),
+            data: Rc::clone(&self.data),
+        }
     }
 }
 
@@ -141,7 +279,7 @@ impl<T: StorageType> Clone for Tensor<T> {
             label: self.label.clone(),
             count: self.count,
             shape: self.shape.clone(),
-            data: self.data.clone(),
+            data: Rc::new(self.data.as_ref().clone()),
         }
     }
 }
@@ -153,7 +291,7 @@ mod tests {
     use std::rc::Rc;
 
     use tengu_backend::{Backend, Processor};
-    use tengu_backend_tensor::{Function, Operator, Type};
+    use tengu_backend_tensor::{CastMode, Function, Operator, Type};
 
     use crate::Backend as CPUBackend;
 
@@ -175,7 +313,7 @@ mod tests {
         let mut processor = backend.processor(&probes);
         let a = backend.tensor("a", [2, 2], &[1, 2, 3, 4]);
         let a = processor.var(&a);
-        let cast_a = processor.cast(a, Type::F32);
+        let cast_a = processor.cast(a, Type::I32, Type::F32, CastMode::Saturate);
         let cast_a = cast_a.as_ref::<f32>();
         assert_eq!(cast_a.shape, [2, 2]);
         assert_eq!(cast_a.data.borrow().len(), 4);
@@ -206,13 +344,232 @@ mod tests {
         let a = processor.var(&a);
         let b = processor.var(&b);
         let a_add_b = processor.binary(a, b, Operator::Mul);
-        println!("a_add_b is {}", a_add_b.variant());
         let a_add_b = a_add_b.as_ref::<i32>();
         assert_eq!(a_add_b.shape, [4]);
         assert_eq!(a_add_b.data.borrow().len(), 4);
         assert_eq!(a_add_b.data.borrow().to_vec(), [5, 12, 21, 32]);
     }
 
+    #[test]
+    fn bincount() {
+        let probes = HashSet::new();
+        let backend = Rc::new(CPUBackend);
+        let mut processor = backend.processor(&probes);
+        let a = backend.tensor("a", [8], &[0.0, 0.5, 1.0, 1.5, 2.5, 3.9, 4.0, -1.0]);
+        let a = processor.var(&a);
+        let counts = processor.bincount(a, 4, 0.0, 4.0);
+        let counts = counts.as_ref::<u32>();
+        assert_eq!(counts.shape, [4]);
+        assert_eq!(counts.data.borrow().to_vec(), [2, 2, 1, 1]);
+    }
+
+    #[test]
+    fn tensor_i16() {
+        let backend = Rc::new(CPUBackend);
+        let a = backend.tensor("a", [2, 2], &[-32768i16, -1, 1, 32767]);
+        assert_eq!(a.shape, [2, 2]);
+        assert_eq!(a.data.borrow().to_vec(), [-32768, -1, 1, 32767]);
+    }
+
+    #[test]
+    fn cast_modes() {
+        let probes = HashSet::new();
+        let backend = Rc::new(CPUBackend);
+        let mut processor = backend.processor(&probes);
+        let a = backend.tensor("a", [3], &[3e9f32, -5.0, 70000.0]);
+        let b = backend.tensor("b", [2], &[-1, 40000]);
+        let a = processor.var(&a);
+        let b = processor.var(&b);
+        let saturated = processor.cast(a.clone(), Type::F32, Type::I32, CastMode::Saturate);
+        assert_eq!(saturated.as_ref::<i32>().data.borrow().to_vec(), [i32::MAX, -5, 70000]);
+        let wrapped = processor.cast(a.clone(), Type::F32, Type::I32, CastMode::Wrap);
+        assert_eq!(wrapped.as_ref::<i32>().data.borrow().to_vec(), [-1294967296, -5, 70000]);
+        let saturated = processor.cast(a.clone(), Type::F32, Type::U16, CastMode::Saturate);
+        assert_eq!(saturated.as_ref::<u16>().data.borrow().to_vec(), [65535, 0, 65535]);
+        let wrapped = processor.cast(a, Type::F32, Type::U16, CastMode::Wrap);
+        assert_eq!(wrapped.as_ref::<u16>().data.borrow().to_vec(), [24064, 65531, 4464]);
+        let saturated = processor.cast(b.clone(), Type::I32, Type::I16, CastMode::Saturate);
+        assert_eq!(saturated.as_ref::<i16>().data.borrow().to_vec(), [-1, i16::MAX]);
+        let wrapped = processor.cast(b, Type::I32, Type::U32, CastMode::Wrap);
+        assert_eq!(wrapped.as_ref::<u32>().data.borrow().to_vec(), [u32::MAX, 40000]);
+    }
+
+    #[test]
+    fn binary_u16() {
+        let probes = HashSet::new();
+        let backend = Rc::new(CPUBackend);
+        let mut processor = backend.processor(&probes);
+        let a = backend.tensor("a", [3], &[1u16, 2, 3]);
+        let b = backend.tensor("b", [3], &[100u16, 200, 300]);
+        let a = processor.var(&a);
+        let b = processor.var(&b);
+        let a_add_b = processor.binary(a, b, Operator::Add);
+        let cast = processor.cast(a_add_b, Type::U16, Type::F32, CastMode::Saturate);
+        assert_eq!(cast.as_ref::<f32>().data.borrow().to_vec(), [101.0, 202.0, 303.0]);
+    }
+
+    #[test]
+    fn mean() {
+        let probes = HashSet::new();
+        let backend = Rc::new(CPUBackend);
+        let mut processor = backend.processor(&probes);
+        let a = backend.tensor("a", [2, 3], &[1, 2, 3, 4, 5, 6]);
+        let a = processor.var(&a);
+        let rows = processor.mean(a.clone(), &[2, 3], 1);
+        let rows = rows.as_ref::<f32>();
+        assert_eq!(rows.shape, [2]);
+        assert_eq!(rows.data.borrow().to_vec(), [2.0, 5.0]);
+        let cols = processor.mean(a, &[2, 3], 0);
+        let cols = cols.as_ref::<f32>();
+        assert_eq!(cols.shape, [3]);
+        assert_eq!(cols.data.borrow().to_vec(), [2.5, 3.5, 4.5]);
+    }
+
+    #[test]
+    fn softmax() {
+        let probes = HashSet::new();
+        let backend = Rc::new(CPUBackend);
+        let mut processor = backend.processor(&probes);
+        let a = backend.tensor("a", [2, 2], &[0.0, 0.0, 1000.0, 1000.0]);
+        let a = processor.var(&a);
+        let softmax = processor.softmax(a, &[2, 2], 1);
+        let softmax = softmax.as_ref::<f32>();
+        assert_eq!(softmax.shape, [2, 2]);
+        assert_eq!(softmax.data.borrow().to_vec(), [0.5, 0.5, 0.5, 0.5]);
+    }
+
+    #[test]
+    fn one_hot() {
+        let probes = HashSet::new();
+        let backend = Rc::new(CPUBackend);
+        let mut processor = backend.processor(&probes);
+        let a = backend.tensor("a", [3], &[0, 2, 5]);
+        let a = processor.var(&a);
+        let one_hot = processor.one_hot(a, 3);
+        let one_hot = one_hot.as_ref::<f32>();
+        assert_eq!(one_hot.shape, [3, 3]);
+        assert_eq!(
+            one_hot.data.borrow().to_vec(),
+            [1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0]
+        );
+    }
+
+    #[test]
+    fn tile() {
+        let probes = HashSet::new();
+        let backend = Rc::new(CPUBackend);
+        let mut processor = backend.processor(&probes);
+        let a = backend.tensor("a", [2, 2], &[1, 2, 3, 4]);
+        let a = processor.var(&a);
+        let tile = processor.tile(a, &[2, 2], &[2, 3], Type::I32);
+        let tile = tile.as_ref::<i32>();
+        assert_eq!(tile.shape, [4, 6]);
+        assert_eq!(
+            tile.data.borrow().to_vec(),
+            [1, 2, 1, 2, 1, 2, 3, 4, 3, 4, 3, 4, 1, 2, 1, 2, 1, 2, 3, 4, 3, 4, 3, 4]
+        );
+    }
+
+    #[test]
+    fn pad() {
+        let probes = HashSet::new();
+        let backend = Rc::new(CPUBackend);
+        let mut processor = backend.processor(&probes);
+        let a = backend.tensor("a", [2, 2], &[1, 2, 3, 4]);
+        let a = processor.var(&a);
+        let value = processor.scalar(9);
+        let pad = processor.pad(a, &[2, 2], &[(1, 0), (0, 1)], value, Type::I32);
+        let pad = pad.as_ref::<i32>();
+        assert_eq!(pad.shape, [3, 3]);
+        assert_eq!(pad.data.borrow().to_vec(), [9, 9, 9, 1, 2, 9, 3, 4, 9]);
+    }
+
+    #[test]
+    fn take() {
+        let probes = HashSet::new();
+        let backend = Rc::new(CPUBackend);
+        let mut processor = backend.processor(&probes);
+        let a = backend.tensor("a", [2, 2], &[1, 2, 3, 4]);
+        let a = processor.var(&a);
+        let take = processor.take(a, &[3, 1, 0, 3], Type::I32);
+        let take = take.as_ref::<i32>();
+        assert_eq!(take.shape, [4]);
+        assert_eq!(take.data.borrow().to_vec(), [4, 2, 1, 4]);
+    }
+
+    #[test]
+    fn slice() {
+        let probes = HashSet::new();
+        let backend = Rc::new(CPUBackend);
+        let mut processor = backend.processor(&probes);
+        let a = backend.tensor("a", [2, 3], &[1, 2, 3, 4, 5, 6]);
+        let a = processor.var(&a);
+        let slice = processor.slice(a, &[2, 3], 1, 1..3, Type::I32);
+        let slice = slice.as_ref::<i32>();
+        assert_eq!(slice.shape, [2, 2]);
+        assert_eq!(slice.data.borrow().to_vec(), [2, 3, 5, 6]);
+    }
+
+    #[test]
+    fn flip() {
+        let probes = HashSet::new();
+        let backend = Rc::new(CPUBackend);
+        let mut processor = backend.processor(&probes);
+        let a = backend.tensor("a", [2, 3], &[1, 2, 3, 4, 5, 6]);
+        let a = processor.var(&a);
+        let flip = processor.flip(a, &[2, 3], &[1], Type::I32);
+        let flip = flip.as_ref::<i32>();
+        assert_eq!(flip.shape, [2, 3]);
+        assert_eq!(flip.data.borrow().to_vec(), [3, 2, 1, 6, 5, 4]);
+    }
+
+    #[test]
+    fn roll() {
+        let probes = HashSet::new();
+        let backend = Rc::new(CPUBackend);
+        let mut processor = backend.processor(&probes);
+        let a = backend.tensor("a", [2, 3], &[1, 2, 3, 4, 5, 6]);
+        let a = processor.var(&a);
+        let roll = processor.roll(a, &[2, 3], 1, 2, Type::I32);
+        let roll = roll.as_ref::<i32>();
+        assert_eq!(roll.shape, [2, 3]);
+        assert_eq!(roll.data.borrow().to_vec(), [2, 3, 1, 5, 6, 4]);
+    }
+
+    #[test]
+    fn stack() {
+        let probes = HashSet::new();
+        let backend = Rc::new(CPUBackend);
+        let mut processor = backend.processor(&probes);
+        let a = backend.tensor("a", [2, 2], &[1, 2, 3, 4]);
+        let b = backend.tensor("b", [2, 2], &[5, 6, 7, 8]);
+        let inners = vec![processor.var(&a), processor.var(&b)];
+        let stack = processor.stack(inners.clone(), &[2, 2], 0, Type::I32);
+        let stack = stack.as_ref::<i32>();
+        assert_eq!(stack.shape, [2, 2, 2]);
+        assert_eq!(stack.data.borrow().to_vec(), [1, 2, 3, 4, 5, 6, 7, 8]);
+        let stack = processor.stack(inners, &[2, 2], 2, Type::I32);
+        let stack = stack.as_ref::<i32>();
+        assert_eq!(stack.shape, [2, 2, 2]);
+        assert_eq!(stack.data.borrow().to_vec(), [1, 5, 2, 6, 3, 7, 4, 8]);
+    }
+
+    #[test]
+    fn select() {
+        let probes = HashSet::new();
+        let backend = Rc::new(CPUBackend);
+        let mut processor = backend.processor(&probes);
+        let mask = backend.tensor("mask", [2, 2], &[true, false, false, true]);
+        let a = backend.tensor("a", [2, 2], &[1.0, 2.0, 3.0, 4.0]);
+        let mask = processor.var(&mask);
+        let a = processor.var(&a);
+        let fill = processor.scalar(-1.0f32);
+        let select = processor.select(mask, fill, a);
+        let select = select.as_ref::<f32>();
+        assert_eq!(select.shape, [2, 2]);
+        assert_eq!(select.data.borrow().to_vec(), [-1.0, 2.0, 3.0, -1.0]);
+    }
+
     #[test]
     fn statement() {
         let probes = HashSet::new();
@@ -231,4 +588,162 @@ mod tests {
         assert_eq!(statement.data.borrow().len(), 4);
         assert_eq!(statement.data.borrow().to_vec(), [6, 8, 10, 12]);
     }
+
+    #[test]
+    fn statement_broadcast() {
+        let probes = HashSet::new();
+        let backend = Rc::new(CPUBackend);
+        let mut processor = backend.processor(&probes);
+        let c = backend.zero::<i32>("c", [4]);
+        let c = processor.var(&c);
+        let scalar = processor.scalar(7);
+        let statement = processor.statement(c, scalar);
+        let statement = statement.as_ref::<i32>();
+        assert_eq!(statement.data.borrow().to_vec(), [7, 7, 7, 7]);
+    }
+
+    #[test]
+    fn copy_broadcast() {
+        let row = super::Tensor::new("row", [3], [1, 2, 3]);
+        let column = super::Tensor::new("column", [2, 1], [4, 5]);
+        let a = super::Tensor::<i32>::new("a", [2, 3], [0; 6]);
+        a.copy_from(&row);
+        assert_eq!(a.data.borrow().to_vec(), [1, 2, 3, 1, 2, 3]);
+        a.copy_from(&column);
+        assert_eq!(a.data.borrow().to_vec(), [4, 4, 4, 5, 5, 5]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn copy_incompatible() {
+        let a = super::Tensor::<i32>::new("a", [2, 3], [0; 6]);
+        a.copy_from(&super::Tensor::new("b", [2], [1, 2]));
+    }
+
+    #[test]
+    fn retrieve() {
+        use tengu_backend_tensor::Tensor as RawTensor;
+        let tensor = super::Tensor::new("a", [4], [1.5f32, 2.5, 3.5, 4.5]);
+        let data = pollster::block_on(tensor.retrieve()).unwrap();
+        assert_eq!(data.to_vec(), [1.5, 2.5, 3.5, 4.5]);
+    }
+
+    #[test]
+    fn retrieve_bool() {
+        use tengu_backend_tensor::Tensor as RawTensor;
+        let tensor = super::Tensor::new("a", [3], [true, false, true]);
+        let data = pollster::block_on(tensor.retrieve()).unwrap();
+        assert_eq!(data.to_vec(), [true, false, true]);
+    }
+
+    #[test]
+    fn retrieve_range() {
+        use tengu_backend_tensor::Tensor as RawTensor;
+        let tensor = super::Tensor::new("a", [5], [1, 2, 3, 4, 5]);
+        let data = pollster::block_on(tensor.retrieve_range(1..4)).unwrap();
+        assert_eq!(data.to_vec(), [2, 3, 4]);
+    }
+
+    #[test]
+    fn write() {
+        use tengu_backend_tensor::Tensor as RawTensor;
+        let tensor = super::Tensor::new("a", [3], [1, 2, 3]);
+        tensor.write(&[4, 5, 6]).unwrap();
+        let data = pollster::block_on(tensor.retrieve()).unwrap();
+        assert_eq!(data.to_vec(), [4, 5, 6]);
+    }
+
+    #[test]
+    fn sign() {
+        let probes = HashSet::new();
+        let backend = Rc::new(CPUBackend);
+        let mut processor = backend.processor(&probes);
+        let a = backend.tensor("a", [5], &[2.5f32, -0.5, 0.0, -0.0, 7.0]);
+        let a = processor.var(&a);
+        let sign = processor.unary_fn(a, Function::Sign);
+        assert_eq!(sign.as_ref::<f32>().data.borrow().to_vec(), [1.0, -1.0, 0.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn copysign() {
+        let probes = HashSet::new();
+        let backend = Rc::new(CPUBackend);
+        let mut processor = backend.processor(&probes);
+        let a = backend.tensor("a", [4], &[2.0f32, -3.0, 4.0, -5.0]);
+        let b = backend.tensor("b", [4], &[-1.0f32, 1.0, 0.0, -0.0]);
+        let a = processor.var(&a);
+        let b = processor.var(&b);
+        let copysign = processor.binary(a, b, Operator::Copysign);
+        assert_eq!(copysign.as_ref::<f32>().data.borrow().to_vec(), [-2.0, 3.0, 4.0, 5.0]);
+    }
+
+    #[test]
+    fn integer_sign_and_copysign() {
+        let probes = HashSet::new();
+        let backend = Rc::new(CPUBackend);
+        let mut processor = backend.processor(&probes);
+        let a = backend.tensor("a", [4], &[2i32, -3, 0, i32::MIN]);
+        let b = backend.tensor("b", [4], &[-1i32, 1, -5, 0]);
+        let c = backend.tensor("c", [3], &[0u32, 1, 9]);
+        let (a, b, c) = (processor.var(&a), processor.var(&b), processor.var(&c));
+        let sign = processor.unary_fn(a.clone(), Function::Sign);
+        assert_eq!(sign.as_ref::<i32>().data.borrow().to_vec(), [1, -1, 0, -1]);
+        let copysign = processor.binary(a, b, Operator::Copysign);
+        assert_eq!(copysign.as_ref::<i32>().data.borrow().to_vec(), [-2, 3, 0, i32::MIN]);
+        let sign = processor.unary_fn(c.clone(), Function::Sign);
+        assert_eq!(sign.as_ref::<u32>().data.borrow().to_vec(), [0, 1, 1]);
+        let copysign = processor.binary(c.clone(), c, Operator::Copysign);
+        assert_eq!(copysign.as_ref::<u32>().data.borrow().to_vec(), [0, 1, 9]);
+    }
+
+    #[test]
+    fn max_and_min() {
+        let probes = HashSet::new();
+        let backend = Rc::new(CPUBackend);
+        let mut processor = backend.processor(&probes);
+        let a = backend.tensor("a", [4], &[2, -3, 4, -5]);
+        let a = processor.var(&a);
+        let zero = processor.scalar(0);
+        let max = processor.binary(a.clone(), zero.clone(), Operator::Max);
+        assert_eq!(max.as_ref::<i32>().data.borrow().to_vec(), [2, 0, 4, 0]);
+        let min = processor.binary(a, zero, Operator::Min);
+        assert_eq!(min.as_ref::<i32>().data.borrow().to_vec(), [0, -3, 0, -5]);
+    }
+
+    #[test]
+    fn scalar_broadcast() {
+        let probes = HashSet::new();
+        let backend = Rc::new(CPUBackend);
+        let mut processor = backend.processor(&probes);
+        let a = backend.tensor("a", [4], &[1.0, 2.0, 3.0, 4.0]);
+        let a = processor.var(&a);
+        let two = processor.scalar(2.0);
+        let mul = processor.binary(a.clone(), two.clone(), Operator::Mul);
+        let mul = mul.as_ref::<f32>();
+        assert_eq!(mul.shape, [4]);
+        assert_eq!(mul.data.borrow().to_vec(), [2.0, 4.0, 6.0, 8.0]);
+        let sub = processor.binary(two.clone(), a.clone(), Operator::Sub);
+        let sub = sub.as_ref::<f32>();
+        assert_eq!(sub.shape, [4]);
+        assert_eq!(sub.data.borrow().to_vec(), [1.0, 0.0, -1.0, -2.0]);
+        let gt = processor.binary(a, two, Operator::Gt);
+        assert_eq!(gt.as_ref::<bool>().data.borrow().to_vec(), [false, false, true, true]);
+    }
+
+    #[test]
+    fn bool_comparison() {
+        let probes = HashSet::new();
+        let backend = Rc::new(CPUBackend);
+        let mut processor = backend.processor(&probes);
+        let a = backend.tensor("a", [4], &[false, false, true, true]);
+        let b = backend.tensor("b", [4], &[false, true, false, true]);
+        let a = processor.var(&a);
+        let b = processor.var(&b);
+        let eq = processor.binary(a.clone(), b.clone(), Operator::Eq);
+        assert_eq!(eq.as_ref::<bool>().data.borrow().to_vec(), [true, false, false, true]);
+        let gt = processor.binary(a.clone(), b.clone(), Operator::Gt);
+        assert_eq!(gt.as_ref::<bool>().data.borrow().to_vec(), [false, false, true, false]);
+        let le = processor.binary(a, b, Operator::Le);
+        assert_eq!(le.as_ref::<bool>().data.borrow().to_vec(), [true, true, false, true]);
+    }
 }
@@ -8,14 +8,7 @@ impl<T: StorageType + Add<Output = T>> Add for &Tensor<T> {
     type Output = Tensor<T>;
 
     fn add(self, rhs: Self) -> Self::Output {
-        let data: Vec<T> = self
-            .data
-            .borrow()
-            .iter()
-            .zip(rhs.data.borrow().iter())
-            .map(|(a, b)| *a + *b)
-            .collect();
-        Tensor::new("", self.shape.clone(), data)
+        self.zip_with(rhs, |a, b| a + b)
     }
 }
 
@@ -23,14 +16,7 @@ impl<T: StorageType + Sub<Output = T>> Sub for &Tensor<T> {
     type Output = Tensor<T>;
 
     fn sub(self, rhs: Self) -> Self::Output {
-        let data: Vec<T> = self
-            .data
-            .borrow()
-            .iter()
-            .zip(rhs.data.borrow().iter())
-            .map(|(a, b)| *a - *b)
-            .collect();
-        Tensor::new("", self.shape.clone(), data)
+        self.zip_with(rhs, |a, b| a - b)
     }
 }
 
@@ -38,14 +24,7 @@ impl<T: StorageType + Mul<Output = T>> Mul for &Tensor<T> {
     type Output = Tensor<T>;
 
     fn mul(self, rhs: Self) -> Self::Output {
-        let data: Vec<T> = self
-            .data
-            .borrow()
-            .iter()
-            .zip(rhs.data.borrow().iter())
-            .map(|(a, b)| *a * *b)
-            .collect();
-        Tensor::new("", self.shape.clone(), data)
+        self.zip_with(rhs, |a, b| a * b)
     }
 }
 
@@ -53,13 +32,46 @@ impl<T: StorageType + Div<Output = T>> Div for &Tensor<T> {
     type Output = Tensor<T>;
 
     fn div(self, rhs: Self) -> Self::Output {
-        let data: Vec<T> = self
-            .data
-            .borrow()
-            .iter()
-            .zip(rhs.data.borrow().iter())
-            .map(|(a, b)| *a / *b)
-            .collect();
-        Tensor::new("", self.shape.clone(), data)
+        self.zip_with(rhs, |a, b| a / b)
     }
 }
+
+impl<T: StorageType + PartialOrd> Tensor<T> {
+    pub fn max(&self, other: &Self) -> Tensor<T> {
+        self.zip_with(other, |lhs, rhs| if rhs > lhs { rhs } else { lhs })
+    }
+
+    pub fn min(&self, other: &Self) -> Tensor<T> {
+        self.zip_with(other, |lhs, rhs| if rhs < lhs { rhs } else { lhs })
+    }
+}
+
+impl Tensor<f32> {
+    pub fn copysign(&self, other: &Self) -> Tensor<f32> {
+        // NOTE: `f32::copysign` uses the sign bit, so `-0.0` would make the result negative. Zeros
+        // are treated as positive instead to match WGSL.
+        self.zip_with(other, |lhs, rhs| if rhs < 0.0 { -lhs.abs() } else { lhs.abs() })
+    }
+}
+
+macro_rules! impl_integer_copysign {
+    ( $( $type:ty => $copysign:expr ),* ) => {
+        $(
+            impl Tensor<$type> {
+                pub fn copysign(&self, other: &Self) -> Tensor<$type> {
+                    self.zip_with(other, $copysign)
+                }
+            }
+        )*
+    };
+}
+
+// NOTE: Wrapping matches WGSL, where the absolute value of the minimum is itself. Unsigned values
+// and booleans can't be negative, so they keep their magnitude.
+impl_integer_copysign!(
+    i32 => |lhs: i32, rhs: i32| if rhs < 0 { lhs.wrapping_abs().wrapping_neg() } else { lhs.wrapping_abs() },
+    i16 => |lhs: i16, rhs: i16| if rhs < 0 { lhs.wrapping_abs().wrapping_neg() } else { lhs.wrapping_abs() },
+    u32 => |lhs: u32, _| lhs,
+    u16 => |lhs: u16, _| lhs,
+    bool => |lhs: bool, _| lhs
+);
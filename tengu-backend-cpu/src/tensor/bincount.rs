@@ -0,0 +1,18 @@
+use tengu_backend_tensor::StorageType;
+
+use super::Tensor;
+use crate::cast::Cast;
+
+impl<T: StorageType + Cast<f32>> Tensor<T> {
+    pub fn bincount(&self, bins: usize, min: f32, max: f32) -> Tensor<u32> {
+        let mut data = vec![0; bins];
+        let scale = bins as f32 / (max - min);
+        for value in self.data.borrow().iter().map(|v| (*v).cast()) {
+            if value >= min && value < max {
+                let bin = (((value - min) * scale) as usize).min(bins - 1);
+                data[bin] += 1;
+            }
+        }
+        Tensor::new("", [bins], data)
+    }
+}
@@ -1,25 +1,16 @@
-use tengu_backend_tensor::StorageType;
+use tengu_backend_tensor::{CastMode, StorageType};
 
 use super::Tensor;
 use crate::cast::Cast;
 
 // NOTE: Tensor casting.
 
-macro_rules! impl_from {
-    ( $type:ty ) => {
-        impl<T: StorageType> From<&Tensor<$type>> for Tensor<T>
-        where
-            $type: Cast<T>,
-        {
-            fn from(other: &Tensor<$type>) -> Self {
-                let data: Vec<_> = other.data.borrow().iter().map(|v| (*v).cast()).collect();
-                Self::new("", other.shape.clone(), data)
-            }
-        }
-    };
+impl<S: StorageType> Tensor<S> {
+    pub fn cast<T: StorageType>(&self, mode: CastMode) -> Tensor<T>
+    where
+        S: Cast<T>,
+    {
+        let data: Vec<_> = self.data.borrow().iter().map(|v| (*v).cast_with(mode)).collect();
+        Tensor::new("", self.shape.clone(), data)
+    }
 }
-
-impl_from!(u32);
-impl_from!(i32);
-impl_from!(f32);
-impl_from!(bool);
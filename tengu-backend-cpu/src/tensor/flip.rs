@@ -0,0 +1,23 @@
+use tengu_backend_tensor::StorageType;
+
+use super::Tensor;
+
+impl<T: StorageType> Tensor<T> {
+    pub fn flip(&self, shape: &[usize], axes: &[usize]) -> Tensor<T> {
+        let values = self.data.borrow();
+        let data: Vec<_> = (0..values.len())
+            .map(|i| {
+                let (mut rest, mut idx, mut stride) = (i, 0, 1);
+                for (axis, dim) in shape.iter().enumerate().rev() {
+                    let coord = rest % dim;
+                    let coord = if axes.contains(&axis) { dim - 1 - coord } else { coord };
+                    idx += coord * stride;
+                    rest /= dim;
+                    stride *= dim;
+                }
+                values[idx]
+            })
+            .collect();
+        Tensor::new("", shape, data)
+    }
+}
@@ -0,0 +1,24 @@
+use tengu_backend_tensor::StorageType;
+
+use super::Tensor;
+
+impl<T: StorageType> Tensor<T> {
+    pub fn mask_select(&self, mask: &Tensor<bool>) -> Tensor<T> {
+        let values = self.data.borrow();
+        let mask = mask.data.borrow();
+        let mut data: Vec<_> = values
+            .iter()
+            .zip(mask.iter())
+            .filter(|(_, selected)| **selected)
+            .map(|(value, _)| *value)
+            .collect();
+        data.resize(self.count, T::default());
+        Tensor::new("", [self.count], data)
+    }
+}
+
+impl Tensor<bool> {
+    pub fn count_true(&self) -> u32 {
+        self.data.borrow().iter().filter(|&&v| v).count() as u32
+    }
+}
@@ -0,0 +1,22 @@
+use tengu_backend_tensor::StorageType;
+
+use super::Tensor;
+use crate::cast::Cast;
+
+impl<T: StorageType + Cast<f32>> Tensor<T> {
+    pub fn mean(&self, shape: &[usize], axis: usize) -> Tensor<f32> {
+        let len = shape[axis];
+        let inner = shape[axis + 1..].iter().product::<usize>();
+        let outer = shape[..axis].iter().product::<usize>();
+        let values = self.data.borrow();
+        let data: Vec<_> = (0..outer * inner)
+            .map(|i| {
+                let base = (i / inner) * len * inner + i % inner;
+                let sum: f32 = (0..len).map(|k| values[base + k * inner].cast()).sum();
+                sum / len as f32
+            })
+            .collect();
+        let shape: Vec<_> = shape.iter().enumerate().filter(|(i, _)| *i != axis).map(|(_, d)| *d).collect();
+        Tensor::new("", shape, data)
+    }
+}
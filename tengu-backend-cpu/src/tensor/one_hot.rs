@@ -0,0 +1,19 @@
+use tengu_backend_tensor::StorageType;
+
+use super::Tensor;
+use crate::cast::Cast;
+
+impl<T: StorageType + Cast<f32>> Tensor<T> {
+    pub fn one_hot(&self, classes: usize) -> Tensor<f32> {
+        let data = self
+            .data
+            .borrow()
+            .iter()
+            .flat_map(|v| {
+                let class: f32 = (*v).cast();
+                (0..classes).map(move |k| if class == k as f32 { 1.0 } else { 0.0 })
+            })
+            .collect();
+        Tensor::new("", [self.count, classes], data)
+    }
+}
@@ -0,0 +1,32 @@
+use tengu_backend_tensor::StorageType;
+
+use super::Tensor;
+
+impl<T: StorageType> Tensor<T> {
+    pub fn pad(&self, shape: &[usize], padding: &[(usize, usize)], value: &Tensor<T>) -> Tensor<T> {
+        let out_shape: Vec<_> = shape
+            .iter()
+            .zip(padding)
+            .map(|(dim, (before, after))| before + dim + after)
+            .collect();
+        let count = out_shape.iter().product::<usize>();
+        let value = value.data.borrow()[0];
+        let values = self.data.borrow();
+        let data: Vec<_> = (0..count)
+            .map(|i| {
+                let (mut rest, mut idx, mut stride) = (i, 0, 1);
+                for ((dim, out_dim), (before, _)) in shape.iter().zip(&out_shape).zip(padding).rev() {
+                    let coord = rest % out_dim;
+                    if coord < *before || coord >= before + dim {
+                        return value;
+                    }
+                    idx += (coord - before) * stride;
+                    rest /= out_dim;
+                    stride *= dim;
+                }
+                values[idx]
+            })
+            .collect();
+        Tensor::new("", out_shape, data)
+    }
+}
@@ -4,16 +4,28 @@ use super::Tensor;
 
 impl<T: StorageType + PartialEq> Tensor<T> {
     pub fn eq(&self, other: &Self) -> Tensor<bool> {
-        let lhs = self.data.borrow();
-        let rhs = other.data.borrow();
-        let data: Vec<_> = lhs.iter().zip(rhs.iter()).map(|(lhs, rhs)| *lhs == *rhs).collect();
-        Tensor::new("", self.shape.clone(), data)
+        self.zip_with(other, |lhs, rhs| lhs == rhs)
     }
 
     pub fn neq(&self, other: &Self) -> Tensor<bool> {
-        let lhs = self.data.borrow();
-        let rhs = other.data.borrow();
-        let data: Vec<_> = lhs.iter().zip(rhs.iter()).map(|(lhs, rhs)| *lhs != *rhs).collect();
-        Tensor::new("", self.shape.clone(), data)
+        self.zip_with(other, |lhs, rhs| lhs != rhs)
+    }
+}
+
+impl<T: StorageType + PartialOrd> Tensor<T> {
+    pub fn gt(&self, other: &Self) -> Tensor<bool> {
+        self.zip_with(other, |lhs, rhs| lhs > rhs)
+    }
+
+    pub fn lt(&self, other: &Self) -> Tensor<bool> {
+        self.zip_with(other, |lhs, rhs| lhs < rhs)
+    }
+
+    pub fn ge(&self, other: &Self) -> Tensor<bool> {
+        self.zip_with(other, |lhs, rhs| lhs >= rhs)
+    }
+
+    pub fn le(&self, other: &Self) -> Tensor<bool> {
+        self.zip_with(other, |lhs, rhs| lhs <= rhs)
     }
 }
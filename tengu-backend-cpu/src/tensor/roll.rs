@@ -0,0 +1,18 @@
+use tengu_backend_tensor::StorageType;
+
+use super::Tensor;
+
+impl<T: StorageType> Tensor<T> {
+    pub fn roll(&self, shape: &[usize], axis: usize, shift: usize) -> Tensor<T> {
+        let values = self.data.borrow();
+        let dim = shape[axis];
+        let stride = shape[axis + 1..].iter().product::<usize>();
+        let data: Vec<_> = (0..values.len())
+            .map(|i| {
+                let coord = (i / stride) % dim;
+                values[i - coord * stride + (coord + dim - shift) % dim * stride]
+            })
+            .collect();
+        Tensor::new("", shape, data)
+    }
+}
@@ -0,0 +1,22 @@
+use tengu_backend_tensor::StorageType;
+
+use super::Tensor;
+
+impl Tensor<bool> {
+    pub fn select<T: StorageType>(&self, on_true: &Tensor<T>, on_false: &Tensor<T>) -> Tensor<T> {
+        let mask = self.data.borrow();
+        let (lhs, rhs) = (on_true.data.borrow(), on_false.data.borrow());
+        let shape = [&self.shape, &on_true.shape, &on_false.shape]
+            .into_iter()
+            .max_by_key(|shape| shape.iter().product::<usize>())
+            .expect("there should be three operands");
+        let count = mask.len().max(lhs.len()).max(rhs.len());
+        let data: Vec<_> = (0..count)
+            .map(|i| match mask[i % mask.len()] {
+                true => lhs[i % lhs.len()],
+                false => rhs[i % rhs.len()],
+            })
+            .collect();
+        Tensor::new("", shape.clone(), data)
+    }
+}
@@ -0,0 +1,19 @@
+use std::ops::Range;
+
+use tengu_backend_tensor::StorageType;
+
+use super::Tensor;
+
+impl<T: StorageType> Tensor<T> {
+    pub fn slice(&self, shape: &[usize], axis: usize, range: Range<usize>) -> Tensor<T> {
+        let stride = shape[axis + 1..].iter().product::<usize>();
+        let values = self.data.borrow();
+        let data: Vec<_> = values
+            .chunks(shape[axis] * stride)
+            .flat_map(|chunk| chunk[range.start * stride..range.end * stride].iter().copied())
+            .collect();
+        let mut shape = shape.to_vec();
+        shape[axis] = range.len();
+        Tensor::new("", shape, data)
+    }
+}
@@ -0,0 +1,23 @@
+use tengu_backend_tensor::StorageType;
+
+use super::Tensor;
+use crate::cast::Cast;
+
+impl<T: StorageType + Cast<f32>> Tensor<T> {
+    pub fn softmax(&self, shape: &[usize], axis: usize) -> Tensor<f32> {
+        let len = shape[axis];
+        let inner = shape[axis + 1..].iter().product::<usize>();
+        let values: Vec<f32> = self.data.borrow().iter().map(|v| (*v).cast()).collect();
+        let mut data = vec![0.0; values.len()];
+        for i in 0..values.len() / len {
+            let base = (i / inner) * len * inner + i % inner;
+            let indices = (0..len).map(|k| base + k * inner);
+            let max = indices.clone().map(|idx| values[idx]).fold(f32::MIN, f32::max);
+            let sum: f32 = indices.clone().map(|idx| (values[idx] - max).exp()).sum();
+            for idx in indices {
+                data[idx] = (values[idx] - max).exp() / sum;
+            }
+        }
+        Tensor::new("", shape, data)
+    }
+}
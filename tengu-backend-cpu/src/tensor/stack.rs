@@ -0,0 +1,17 @@
+use tengu_backend_tensor::StorageType;
+
+use super::Tensor;
+
+impl<T: StorageType> Tensor<T> {
+    pub fn stack(tensors: &[&Tensor<T>], shape: &[usize], axis: usize) -> Tensor<T> {
+        let stride = shape[axis..].iter().product::<usize>();
+        let parts = tensors.len();
+        let mut out_shape = shape.to_vec();
+        out_shape.insert(axis, parts);
+        let values: Vec<_> = tensors.iter().map(|tensor| tensor.data.borrow()).collect();
+        let data: Vec<_> = (0..parts * shape.iter().product::<usize>())
+            .map(|i| values[(i / stride) % parts][i / (stride * parts) * stride + i % stride])
+            .collect();
+        Tensor::new("", out_shape, data)
+    }
+}
@@ -0,0 +1,11 @@
+use tengu_backend_tensor::StorageType;
+
+use super::Tensor;
+
+impl<T: StorageType> Tensor<T> {
+    pub fn take(&self, indices: &[usize]) -> Tensor<T> {
+        let values = self.data.borrow();
+        let data: Vec<_> = indices.iter().map(|&idx| values[idx]).collect();
+        Tensor::new("", [indices.len()], data)
+    }
+}
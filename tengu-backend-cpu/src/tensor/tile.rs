@@ -0,0 +1,23 @@
+use tengu_backend_tensor::StorageType;
+
+use super::Tensor;
+
+impl<T: StorageType> Tensor<T> {
+    pub fn tile(&self, shape: &[usize], reps: &[usize]) -> Tensor<T> {
+        let out_shape: Vec<_> = shape.iter().zip(reps).map(|(dim, rep)| dim * rep).collect();
+        let count = out_shape.iter().product::<usize>();
+        let values = self.data.borrow();
+        let data: Vec<_> = (0..count)
+            .map(|i| {
+                let (mut rest, mut idx, mut stride) = (i, 0, 1);
+                for (dim, out_dim) in shape.iter().zip(&out_shape).rev() {
+                    idx += (rest % out_dim) % dim * stride;
+                    rest /= out_dim;
+                    stride *= dim;
+                }
+                values[idx]
+            })
+            .collect();
+        Tensor::new("", out_shape, data)
+    }
+}
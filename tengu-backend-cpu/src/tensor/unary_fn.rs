@@ -12,4 +12,38 @@ impl UnaryFn for Tensor<f32> {
         let data = self.data.borrow().iter().map(|v| v.ln()).collect::<Vec<_>>();
         Self::new("", self.shape.clone(), data)
     }
+
+    fn sign(&self) -> Self {
+        // NOTE: `f32::signum` maps zeros to `1.0`, so zeros are handled explicitly to match WGSL.
+        let data = self
+            .data
+            .borrow()
+            .iter()
+            .map(|v| if *v == 0.0 { 0.0 } else { v.signum() })
+            .collect::<Vec<_>>();
+        Self::new("", self.shape.clone(), data)
+    }
 }
+
+macro_rules! impl_integer_sign {
+    ( $( $type:ty => $sign:expr ),* ) => {
+        $(
+            impl Tensor<$type> {
+                pub fn sign(&self) -> Self {
+                    let data = self.data.borrow().iter().map($sign).collect::<Vec<_>>();
+                    Self::new("", self.shape.clone(), data)
+                }
+            }
+        )*
+    };
+}
+
+// NOTE: Unsigned values are either zero or positive, so their sign is clamped to one, and
+// booleans are their own sign.
+impl_integer_sign!(
+    i32 => |v: &i32| v.signum(),
+    i16 => |v: &i16| v.signum(),
+    u32 => |v: &u32| (*v).min(1),
+    u16 => |v: &u16| (*v).min(1),
+    bool => |v: &bool| *v
+);
@@ -1,9 +1,11 @@
-/// This module contains the `Type` enumeration, which represents the possible types to which a
-/// cast can be done. This is used exclusively by the cast operations on tensors.
+//! This module contains the `Type` enumeration, which represents the possible types to which a
+//! cast can be done, and the `CastMode` enumeration, which controls how out-of-range values are
+//! cast. These are used exclusively by the cast operations on tensors.
 
 /// The type to which a cast is done. This is used exclusvely by the cast operations on tensors.
 /// This enumeration corresponds to `StorageType` implementors but is semantically different, as it
 /// represents possible cast operations on the backend, not possible types to be used by frontend.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Type {
     /// Boolean type.
     Bool,
@@ -13,4 +15,57 @@ pub enum Type {
     I32,
     /// f32 type.
     F32,
+    /// u16 type.
+    U16,
+    /// i16 type.
+    I16,
+}
+
+impl std::fmt::Display for Type {
+    /// Formats the type with the name of the corresponding Rust type.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Bool => "bool",
+            Self::U32 => "u32",
+            Self::I32 => "i32",
+            Self::F32 => "f32",
+            Self::U16 => "u16",
+            Self::I16 => "i16",
+        };
+        f.write_str(name)
+    }
+}
+
+/// The way a cast handles values which are out of the range of the target type. Casts between types
+/// where every value is in range, e.g. from integers to floats or from anything to booleans, are the
+/// same in both modes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CastMode {
+    /// Out-of-range values are clamped to the nearest value of the target type, and NaNs become
+    /// zero. This is the default mode for casts from floats.
+    Saturate,
+    /// Floats are truncated toward zero and, like integers, wrapped around modulo `2^bits` of the
+    /// target type, so that casts between integers keep their bits. Casting non-finite floats is
+    /// unspecified. This is the default mode for casts between integers.
+    Wrap,
+}
+
+impl CastMode {
+    /// Returns the mode of casts which don't specify one. It matches the native conversions of
+    /// WGSL and Rust `as`: casts between integers wrap, keeping the bits of the values (so `-1i32`
+    /// becomes `u32::MAX`), while all other casts saturate.
+    ///
+    /// # Parameters
+    /// - `from`: The type being cast from.
+    /// - `to`: The type being cast to.
+    ///
+    /// # Returns
+    /// The default cast mode for the pair of types.
+    pub fn default_for(from: Type, to: Type) -> Self {
+        let is_integer = |ty| matches!(ty, Type::U32 | Type::I32 | Type::U16 | Type::I16);
+        match is_integer(from) && is_integer(to) {
+            true => Self::Wrap,
+            false => Self::Saturate,
+        }
+    }
 }
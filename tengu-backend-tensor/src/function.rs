@@ -1,14 +1,16 @@
-/// This module defines the `Function` enumeration, which represents the supported unary functions
-/// that can be applied to tensors. It also defines the `UnaryFn` trait, which is to be implemented
-/// by backend tensors.
+//! This module defines the `Function` enumeration, which represents the supported unary functions
+//! that can be applied to tensors. It also defines the `UnaryFn` trait, which is to be implemented
+//! by backend tensors.
 
 /// Enum representing supported unary functions.
-#[derive(Copy, Clone)]
+#[derive(Debug, Copy, Clone)]
 pub enum Function {
     /// Logarithm function.
     Log,
     /// Exponentiation function.
     Exp,
+    /// Sign function, which is `-1` for negative values, `1` for positive values, and `0` for zeros.
+    Sign,
 }
 
 /// Trait for unary functions, to be implemented by backend tensors.
@@ -18,4 +20,7 @@ pub trait UnaryFn {
 
     /// Applies the natural logarithm function to the tensor.
     fn log(&self) -> Self;
+
+    /// Applies the sign function to the tensor. Zeros (of either sign) map to zero.
+    fn sign(&self) -> Self;
 }
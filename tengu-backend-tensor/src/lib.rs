@@ -8,7 +8,7 @@
 //!   with GPU operations and can be safely transferred between CPU and GPU memory.
 //! - Tensor Abstraction: Provides the Tensor trait, representing a tensor with essential methods for managing and retrieving
 //!   tensor data asynchronously.
-//! - Type Flexibility: Supports various types (f32, u32, i32) for tensor elements, enabling efficient computation and data management.
+//! - Type Flexibility: Supports various types (f32, u32, i32, u16, i16, bool) for tensor elements, enabling efficient computation and data management.
 //!
 //! ## Modules
 //!
@@ -33,9 +33,9 @@ mod tensor;
 mod types;
 mod utils;
 
-pub use cast::Type;
+pub use cast::{CastMode, Type};
 pub use function::{Function, UnaryFn};
 pub use operator::Operator;
 pub use tensor::Tensor;
-pub use types::{IOType, StorageType};
+pub use types::{IOType, Pod, StorageType};
 pub use utils::*;
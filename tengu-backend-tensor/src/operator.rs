@@ -1,7 +1,7 @@
-/// Operator module defines the `Operator` enum that represents binary operators that can be used in tensor expressions.
+//! Operator module defines the `Operator` enum that represents binary operators that can be used in tensor expressions.
 
 /// Operator enum defines possible binary operators that can be used in tensor expressions.
-#[derive(Clone, Copy)]
+#[derive(Debug, Clone, Copy)]
 pub enum Operator {
     /// Addition operator.
     Add,
@@ -15,4 +15,19 @@ pub enum Operator {
     Eq,
     /// Inequality operator.
     Neq,
+    /// Greater-than operator.
+    Gt,
+    /// Less-than operator.
+    Lt,
+    /// Greater-than-or-equal operator.
+    Ge,
+    /// Less-than-or-equal operator.
+    Le,
+    /// Operator taking the magnitude of the left-hand side and the sign of the right-hand side.
+    /// Zeros on the right-hand side (of either sign) are treated as positive.
+    Copysign,
+    /// Operator taking the larger of the two values.
+    Max,
+    /// Operator taking the smaller of the two values.
+    Min,
 }
@@ -6,6 +6,7 @@
 #![allow(async_fn_in_trait)]
 
 use std::borrow::Cow;
+use std::ops::Range;
 
 use crate::StorageType;
 
@@ -33,9 +34,49 @@ pub trait Tensor<T: StorageType> {
     /// The shape of the tensor as a slice of unsigned integers.
     fn shape(&self) -> &[usize];
 
-    /// Retrieves the data from the tensor.
+    /// Retrieves the data from the tensor. The backend might only resolve the returned future
+    /// after it has been polled with `Backend::poll`.
     ///
     /// # Returns
     /// A result containing a reference to the data stored in the tensor.
     async fn retrieve(&self) -> anyhow::Result<Cow<'_, [T::IOType]>>;
+
+    /// Retrieves a range of elements from the tensor. Backends that read the data back from device
+    /// memory only transfer the requested range instead of the whole tensor. As with `retrieve`,
+    /// the backend might only resolve the returned future after it has been polled with
+    /// `Backend::poll`.
+    ///
+    /// # Parameters
+    /// - `range`: The range of elements to retrieve.
+    ///
+    /// # Returns
+    /// A result containing the data in the requested range.
+    ///
+    /// # Panics
+    /// Panics if the range is out of bounds of the tensor.
+    async fn retrieve_range(&self, range: Range<usize>) -> anyhow::Result<Cow<'_, [T::IOType]>>;
+
+    /// Overwrites the data of the tensor, e.g. to restore it from a previously retrieved state. The
+    /// new data is visible to every computation run after the write.
+    ///
+    /// # Parameters
+    /// - `data`: The new data of the tensor in row-major order.
+    ///
+    /// # Returns
+    /// A result indicating success or failure.
+    ///
+    /// # Panics
+    /// Panics if the number of elements in the data doesn't match the tensor.
+    fn write(&self, data: &[T::IOType]) -> anyhow::Result<()>;
+
+    /// Returns a tensor with another label which shares the data of this one, e.g. to rename the
+    /// output of a computation without losing its data. Writes through either tensor are visible
+    /// through both.
+    ///
+    /// # Parameters
+    /// - `label`: The label of the new tensor.
+    ///
+    /// # Returns
+    /// A new tensor with the specified label and the same data.
+    fn relabeled(&self, label: String) -> Self;
 }
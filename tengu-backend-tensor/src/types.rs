@@ -15,51 +15,124 @@
 //! - `f32`
 //! - `u32`
 //! - `i32`
-//! - `bool` (only for `StorageType`, with `u32` as the associated `IOType`)
+//! - `u16`
+//! - `i16`
+//! - `bool` (transferred as `u32` on backends which cannot hold booleans in memory)
 
+use std::borrow::Cow;
 use std::fmt::{Debug, Display};
 
 use crate::Type;
 
 /// A type that can be used to transfer data between CPU and GPU.
 ///
-/// This trait combines the properties of `StorageType` and `bytemuck::Pod`
-/// to ensure that any type implementing `IOType` can be safely transferred and managed
-/// between CPU and GPU memory.
+/// Tensor data is handed to and received from the backends as values of this type. Since the GPU
+/// memory can only be filled with plain old data, every `IOType` has an associated `Pod` type used
+/// as its in-memory representation, which is the type itself for all numeric types. Booleans are
+/// represented as `u32`, so that `bool` tensors can be created and retrieved directly, while the
+/// backends are free to store them as 32-bit integers.
 ///
 /// # Implementors
 /// - `f32`
 /// - `u32`
 /// - `i32`
-///
-/// # Safety
-/// Implementors must ensure that the type is `Pod` (Plain Old Data) which means it
-/// can be safely transmuted to and from byte arrays without any undefined behavior.
-pub trait IOType: StorageType + bytemuck::Pod {}
+/// - `u16`
+/// - `i16`
+/// - `bool`
+pub trait IOType: StorageType {
+    /// The plain old data type representing the values in memory.
+    type Pod: IOType + bytemuck::Pod;
 
-impl IOType for f32 {}
+    /// Converts the value to its in-memory representation.
+    ///
+    /// # Returns
+    /// The plain old data representing the value.
+    fn to_pod(self) -> Self::Pod;
 
-impl IOType for u32 {}
+    /// Converts the in-memory representation back to the value.
+    ///
+    /// # Parameters
+    /// - `pod`: The plain old data representing the value.
+    ///
+    /// # Returns
+    /// The represented value.
+    fn from_pod(pod: Self::Pod) -> Self;
+
+    /// Converts a slice of values to their in-memory representation.
+    ///
+    /// # Parameters
+    /// - `values`: The values to convert.
+    ///
+    /// # Returns
+    /// The plain old data representing the values, borrowing the slice itself when the type is its
+    /// own representation.
+    fn to_pods(values: &[Self]) -> Cow<'_, [Self::Pod]> {
+        Cow::Owned(values.iter().map(|value| value.to_pod()).collect())
+    }
+}
 
-impl IOType for i32 {}
+/// The in-memory representation of the values of a storage type, e.g. `u32` for `bool`.
+pub type Pod<T> = <<T as StorageType>::IOType as IOType>::Pod;
+
+macro_rules! impl_io_type {
+    ($($type:ty),*) => {
+        $(
+            impl IOType for $type {
+                type Pod = $type;
+
+                fn to_pod(self) -> Self::Pod {
+                    self
+                }
+
+                fn from_pod(pod: Self::Pod) -> Self {
+                    pod
+                }
+
+                fn to_pods(values: &[Self]) -> Cow<'_, [Self::Pod]> {
+                    Cow::Borrowed(values)
+                }
+            }
+        )*
+    };
+}
+
+impl_io_type!(f32, u32, i32, u16, i16);
+
+impl IOType for bool {
+    type Pod = u32;
+
+    fn to_pod(self) -> Self::Pod {
+        self as u32
+    }
+
+    fn from_pod(pod: Self::Pod) -> Self {
+        pod != 0
+    }
+}
 
 /// A type that can be stored on the GPU.
 ///
 /// This trait ensures that any type implementing `StorageType` can be safely copied,
 /// cloned, and displayed. Additionally, it defines an associated type `IOType`
-/// which specifies the type used for transferring data to the CPU. Some types, like
-/// bool, can be stored on the GPU but cannot be used to download from or upload data to the GPU.
-/// It is therefore necessary to distinguish between the types, and restrict certain operations
-/// (like retrieving a probe) to specific subset, `IOType` or `StorageType`.
+/// which specifies the type used for transferring data to the CPU. All the implemented types are
+/// transferred as themselves, with booleans relying on the `Pod` representation of their
+/// `IOType`, but the distinction allows restricting certain operations (like creating a tensor
+/// from data) to the `IOType` subset.
 ///
 /// # Implementors
 /// - `f32`
 /// - `u32`
 /// - `i32`
+/// - `u16`
+/// - `i16`
 /// - `bool`
 ///
 /// # Associated Types
 /// - `IOType`: Defines the type that will be used to transfer this storage type to the CPU.
+///
+/// # Associated Constants
+/// - `ZERO`: The additive identity of the type, `false` for `bool`.
+/// - `ONE`: The multiplicative identity of the type, `true` for `bool`.
 pub trait StorageType: Debug + Display + Copy + Clone + Default + Sized + Send + Sync + 'static
 where
     Self::IOType: From<Self>,
@@ -67,6 +140,12 @@ where
     /// The type that will be used to extract this storage type to CPU.
     type IOType: IOType;
 
+    /// The zero value of the type, equal to its default value.
+    const ZERO: Self;
+
+    /// The unit value of the type.
+    const ONE: Self;
+
     /// Converts the storage type to the IO type.
     ///
     /// # Returns
@@ -80,6 +159,8 @@ where
 
 impl StorageType for f32 {
     type IOType = f32;
+    const ZERO: Self = 0.0;
+    const ONE: Self = 1.0;
 
     fn as_type() -> Type {
         Type::F32
@@ -88,6 +169,8 @@ impl StorageType for f32 {
 
 impl StorageType for u32 {
     type IOType = u32;
+    const ZERO: Self = 0;
+    const ONE: Self = 1;
 
     fn as_type() -> Type {
         Type::U32
@@ -96,16 +179,79 @@ impl StorageType for u32 {
 
 impl StorageType for i32 {
     type IOType = i32;
+    const ZERO: Self = 0;
+    const ONE: Self = 1;
 
     fn as_type() -> Type {
         Type::I32
     }
 }
 
+impl StorageType for u16 {
+    type IOType = u16;
+    const ZERO: Self = 0;
+    const ONE: Self = 1;
+
+    fn as_type() -> Type {
+        Type::U16
+    }
+}
+
+impl StorageType for i16 {
+    type IOType = i16;
+    const ZERO: Self = 0;
+    const ONE: Self = 1;
+
+    fn as_type() -> Type {
+        Type::I16
+    }
+}
+
 impl StorageType for bool {
-    type IOType = u32;
+    type IOType = bool;
+    const ZERO: Self = false;
+    const ONE: Self = true;
 
     fn as_type() -> Type {
         Type::Bool
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_identities<T: StorageType + PartialEq>(zero: T::IOType, one: T::IOType)
+    where
+        T::IOType: PartialEq,
+    {
+        assert_eq!(T::ZERO, T::default());
+        assert_eq!(T::ZERO.convert(), zero);
+        assert_eq!(T::ONE.convert(), one);
+    }
+
+    #[test]
+    fn zero_and_one() {
+        assert_identities::<f32>(0.0, 1.0);
+        assert_identities::<u32>(0, 1);
+        assert_identities::<i32>(0, 1);
+        assert_identities::<u16>(0, 1);
+        assert_identities::<i16>(0, 1);
+        assert_identities::<bool>(false, true);
+    }
+
+    #[test]
+    fn pod_roundtrip() {
+        assert_eq!(true.to_pod(), 1u32);
+        assert_eq!(false.to_pod(), 0u32);
+        assert!(bool::from_pod(7));
+        assert_eq!(f32::from_pod(2.5f32.to_pod()), 2.5);
+    }
+
+    #[test]
+    fn pod_slices() {
+        let values = [1i32, -2, 3];
+        assert!(matches!(i32::to_pods(&values), Cow::Borrowed(pods) if pods == values));
+        assert_eq!(bool::to_pods(&[true, false]).as_ref(), [1u32, 0]);
+    }
+}
@@ -5,10 +5,104 @@ use random_string::charsets::ALPHA;
 /// The length of the label generated for tensors if no label is provided.
 const LABEL_LENGTH: usize = 6;
 
-/// Creates a new random label for the tensor.
+/// WGSL keywords, reserved words and predeclared types which cannot be used as tensor labels,
+/// since the labels are declared as shader variables.
+const RESERVED_LABELS: &str = "alias array atomic bool break case const const_assert continue continuing default \
+    diagnostic discard else enable f16 f32 false fn for from i32 if let loop mat2x2 mat2x3 mat2x4 mat3x2 mat3x3 mat3x4 \
+    mat4x2 mat4x3 mat4x4 override ptr requires return sampler struct switch target texture true type u32 var vec2 vec3 \
+    vec4 while";
+
+/// Identifiers declared by the shaders generated by the backends: the entry point, its builtins and
+/// the locals and parameters of the emitted statements and helper functions. A tensor variable with
+/// one of these labels would be shadowed by them.
+const SHADER_IDENTIFIERS: &str = "base bin_value coord flag global_id group i idx indices k lane main max_value \
+    offset out_idx part prior selected sum truncated value";
+
+/// Prefixes of the helper functions and workgroup variables generated by the backends, which are
+/// numbered like `mean_0`.
+const GENERATED_PREFIXES: [&str; 13] = [
+    "cast", "coord", "flip", "mean", "one_hot", "pad", "roll", "scan", "slice", "softmax", "stack", "take", "tile",
+];
+
+/// Creates a new random label for the tensor. Generated labels are always valid.
 ///
 /// # Returns
 /// A string representing the label of the tensor.
 pub fn create_label() -> String {
-    random_string::generate(LABEL_LENGTH, ALPHA)
+    loop {
+        let label = random_string::generate(LABEL_LENGTH, ALPHA);
+        if is_valid_label(&label) {
+            return label;
+        }
+    }
+}
+
+/// Checks whether the label can be used for a tensor. Labels follow the grammar of WGSL
+/// identifiers restricted to ASCII: they consist of letters, digits and underscores, don't start
+/// with a digit or with two underscores, aren't a single underscore, and aren't reserved words or
+/// identifiers of the generated shaders.
+///
+/// # Parameters
+/// - `label`: The label to check.
+///
+/// # Returns
+/// `true` if the label is valid, `false` otherwise.
+pub fn is_valid_label(label: &str) -> bool {
+    let mut chars = label.chars();
+    let Some(first) = chars.next() else {
+        return false;
+    };
+    (first.is_ascii_alphabetic() || first == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && label != "_"
+        && !label.starts_with("__")
+        && !RESERVED_LABELS.split_whitespace().any(|reserved| reserved == label)
+        && !SHADER_IDENTIFIERS.split_whitespace().any(|reserved| reserved == label)
+        && !is_generated(label)
+}
+
+/// Checks whether the label is the name of a numbered helper generated by the backends.
+///
+/// # Parameters
+/// - `label`: The label to check.
+///
+/// # Returns
+/// `true` if the label is a generated name, `false` otherwise.
+fn is_generated(label: &str) -> bool {
+    GENERATED_PREFIXES.iter().any(|prefix| {
+        label
+            .strip_prefix(prefix)
+            .and_then(|rest| rest.strip_prefix('_'))
+            .is_some_and(|number| !number.is_empty() && number.chars().all(|c| c.is_ascii_digit()))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn valid_labels() {
+        assert!(is_valid_label("a"));
+        assert!(is_valid_label("tensor_2"));
+        assert!(is_valid_label("_hidden"));
+        assert!(is_valid_label("mean"));
+        assert!(is_valid_label("mean_x"));
+        assert!(is_valid_label(&create_label()));
+    }
+
+    #[test]
+    fn invalid_labels() {
+        assert!(!is_valid_label(""));
+        assert!(!is_valid_label("_"));
+        assert!(!is_valid_label("__a"));
+        assert!(!is_valid_label("my tensor"));
+        assert!(!is_valid_label("main/a"));
+        assert!(!is_valid_label("2a"));
+        assert!(!is_valid_label("struct"));
+        assert!(!is_valid_label("idx"));
+        assert!(!is_valid_label("selected"));
+        assert!(!is_valid_label("mean_0"));
+        assert!(!is_valid_label("one_hot_12"));
+    }
 }
@@ -6,10 +6,11 @@
 
 use std::collections::HashSet;
 use std::rc::Rc;
+use std::time::Duration;
 
 use tengu_backend::{Error, Result};
-use tengu_backend_tensor::{IOType, StorageType};
-use tengu_wgpu::{BufferUsage, ByteSize, Device, WGPU};
+use tengu_backend_tensor::{IOType, Pod, StorageType, Type};
+use tengu_wgpu::{BufferUsage, ByteSize, Device, Retry, Timer, WGPU};
 use tracing::trace;
 
 use crate::compute::Compute;
@@ -17,33 +18,78 @@ use crate::limits::Limits;
 use crate::linker::Linker;
 use crate::processor::Processor;
 use crate::readout::Readout;
+use crate::stats::Stats;
 use crate::tensor::Tensor;
+use crate::UNSUPPORTED_16BIT;
 
 /// The `Backend` struct is responsible for managing the WGPU device and providing methods to create and manipulate GPU resources.
 pub struct Backend {
-    device: Device,
+    device: Rc<Device>,
+    stats: Stats,
 }
 
 impl Backend {
-    /// Returns a reference to the `Device`.
+    /// Returns a reference to the `Device` the backend runs on. This is an escape hatch for running
+    /// custom GPU work on the same device, e.g. sharing buffers with a renderer.
     ///
     /// # Returns
-    /// A reference to the `Device` object.
-    pub(crate) fn device(&self) -> &Device {
+    /// A reference to the shared `Device` object.
+    pub fn device(&self) -> &Rc<Device> {
         &self.device
     }
+
+    /// Returns the counters of the expensive operations the backend has performed, like device
+    /// polls and staging buffer maps.
+    ///
+    /// # Returns
+    /// A reference to the backend statistics.
+    pub fn stats(&self) -> &Stats {
+        &self.stats
+    }
 }
 
 impl Backend {
-    /// Creates a new `Backend` instance with the provided `Device`.
+    /// Creates a new `Backend` instance with the provided `Device`. The device can be shared, e.g.
+    /// with a renderer drawing to a surface, so that both use the same GPU resources.
     ///
     /// # Parameters
-    /// - `device`: The `Device` object to use for GPU operations.
+    /// - `device`: The `Device` object to use for GPU operations, owned or shared.
     ///
     /// # Returns
     /// A new instance of `Backend`.
-    pub fn from_device(device: Device) -> Rc<Self> {
-        Rc::new(Self { device })
+    pub fn from_device(device: impl Into<Rc<Device>>) -> Rc<Self> {
+        Rc::new(Self {
+            device: device.into(),
+            stats: Stats::default(),
+        })
+    }
+
+    /// Creates a new `Backend` instance asynchronously, retrying failed adapter and device requests
+    /// according to the given policy. If all attempts fail, the error of the first one is returned.
+    ///
+    /// # Parameters
+    /// - `retry`: The policy for retrying failed requests.
+    ///
+    /// # Returns
+    /// A result containing a reference-counted `Backend` instance or an error.
+    pub async fn with_retry(retry: Retry) -> Result<Rc<Self>> {
+        let device = WGPU::default_context_with_retry(retry)
+            .await
+            .map_err(|e| Error::WGPUError(e.into()))?;
+        trace!("Created WGPU instance for backend");
+        Ok(Self::from_device(device))
+    }
+
+    /// Creates a new `Backend` instance asynchronously on a software fallback adapter, e.g. to run
+    /// on machines without a GPU. Computations are much slower than on a hardware adapter.
+    ///
+    /// # Returns
+    /// A result containing a reference-counted `Backend` instance, or an error if there is no
+    /// fallback adapter.
+    pub async fn fallback() -> Result<Rc<Self>> {
+        let device = WGPU::fallback_context().await.map_err(|e| Error::WGPUError(e.into()))?;
+        trace!("Created WGPU instance on fallback adapter for backend");
+        Ok(Self::from_device(device))
     }
 }
 
@@ -56,6 +102,7 @@ impl tengu_backend::Backend for Backend {
     type Linker<'a> = Linker<'a>;
     type Readout<'a> = Readout<'a>;
     type Limits = Limits;
+    type Timer = Timer;
 
     /// Creates a new `Backend` instance asynchronously.
     ///
@@ -64,7 +111,7 @@ impl tengu_backend::Backend for Backend {
     async fn new() -> Result<Rc<Self>> {
         let device = WGPU::default_context().await.map_err(|e| Error::WGPUError(e.into()))?;
         trace!("Created WGPU instance for backend");
-        Ok(Rc::new(Self { device }))
+        Ok(Self::from_device(device))
     }
 
     /// Returns the limits of the backend.
@@ -93,32 +140,123 @@ impl tengu_backend::Backend for Backend {
     fn propagate(&self, call: impl FnOnce(Self::Linker<'_>)) {
         let mut encoder = self.device.encoder("linker");
         trace!("Executing propagation step");
-        call(Linker::new(&mut encoder));
+        call(Linker::new(&self.device, &self.stats, &mut encoder));
         trace!("Submitting propagation commands to the queue");
+        self.stats.record_propagation();
         self.device.submit(encoder.finish());
     }
 
-    /// Executes a compute pass using the provided compute function.
+    /// Executes a compute pass using the provided compute function. The pass is encoded and
+    /// submitted inside a validation error scope, so that GPU-side validation errors are returned
+    /// instead of silently producing invalid results.
     ///
     /// # Parameters
     /// - `label`: A label for compute operations.
     /// - `call`: A function that takes a `Compute` and performs compute operations.
-    fn compute<F>(&self, label: &str, call: F) -> Result<()>
+    ///
+    /// # Returns
+    /// A result indicating success, the error returned by the compute function, which prevents the
+    /// commands from being submitted, or the converted `Error::ValidationError` if the GPU rejects
+    /// the submitted commands.
+    fn compute<F, E>(&self, label: &str, call: F) -> std::result::Result<(), E>
     where
-        F: FnOnce(Self::Compute<'_>) -> anyhow::Result<()>,
+        F: FnOnce(Self::Compute<'_>) -> std::result::Result<(), E>,
+        E: From<Error>,
     {
         trace!("Executing compute step");
-        let commands = self
-            .device
-            .encoder(label)
-            .pass(label, |pass| call(Compute::new(&self.device, label, pass)))
-            .map_err(|e| Error::ComputeError(e.into()))?
-            .finish();
-        trace!("Submitting compute commands to the queue");
-        self.device.submit(commands);
+        let mut result = Ok(());
+        let validation = self.device.validate(|| {
+            let commands = self
+                .device
+                .encoder(label)
+                .pass(label, |pass| {
+                    result = call(Compute::new(&self.device, &self.stats, label, pass));
+                    Ok(())
+                })?
+                .finish();
+            if result.is_ok() {
+                trace!("Submitting compute commands to the queue");
+                self.device.submit(commands);
+            }
+            Ok(())
+        });
+        // NOTE: The error of the compute function takes precedence over the validation errors of
+        // the commands it left unfinished.
+        result?;
+        validation.map_err(compute_error)?;
+        Ok(())
+    }
+
+    /// Creates a timer measuring the GPU execution time of compute passes with timestamp queries.
+    ///
+    /// # Parameters
+    /// - `label`: A label for the timer resources.
+    ///
+    /// # Returns
+    /// A result containing the timer, or `Error::TimestampQueryUnsupported` if the device doesn't
+    /// support timestamp queries.
+    fn timer(&self, label: &str) -> Result<Self::Timer> {
+        self.device.timer(label).map_err(|e| match e {
+            tengu_wgpu::Error::TimestampQueryUnsupported => Error::TimestampQueryUnsupported,
+            e => Error::WGPUError(e.into()),
+        })
+    }
+
+    /// Executes a compute pass using the provided compute function, writing timestamps at its
+    /// beginning and at its end into the timer. The device is only waited for when all slots of the
+    /// timer are used and their timestamps have to be read back.
+    ///
+    /// # Parameters
+    /// - `label`: A label for compute operations.
+    /// - `timer`: The timer measuring the compute pass.
+    /// - `call`: A function that takes a `Compute` and performs compute operations.
+    ///
+    /// # Returns
+    /// A result indicating success, or the errors of `compute`.
+    fn compute_timed<F, E>(&self, label: &str, timer: &Self::Timer, call: F) -> std::result::Result<(), E>
+    where
+        F: FnOnce(Self::Compute<'_>) -> std::result::Result<(), E>,
+        E: From<Error>,
+    {
+        trace!("Executing timed compute step");
+        if timer.is_full() {
+            timer.flush(&self.device).map_err(|e| Error::WGPUError(e.into()))?;
+        }
+        let mut result = Ok(());
+        let validation = self.device.validate(|| {
+            let commands = self
+                .device
+                .encoder(label)
+                .timed_pass(label, timer, |pass| {
+                    result = call(Compute::new(&self.device, &self.stats, label, pass));
+                    Ok(())
+                })?
+                .finish();
+            if result.is_ok() {
+                trace!("Submitting timed compute commands to the queue");
+                self.device.submit(commands);
+                timer.record();
+            }
+            Ok(())
+        });
+        result?;
+        validation.map_err(compute_error)?;
         Ok(())
     }
 
+    /// Waits for the compute passes measured by the timer to be executed and returns their total
+    /// GPU execution time.
+    ///
+    /// # Parameters
+    /// - `timer`: The timer measuring the compute passes.
+    ///
+    /// # Returns
+    /// A result containing the total GPU execution time, or `Error::WGPUError` if the timestamps
+    /// couldn't be read back.
+    fn elapsed(&self, timer: &Self::Timer) -> Result<Duration> {
+        timer.elapsed(&self.device).map_err(|e| Error::WGPUError(e.into()))
+    }
+
     /// Copies data to staging buffers using the provided staging function.
     ///
     /// # Parameters
@@ -132,10 +270,19 @@ impl tengu_backend::Backend for Backend {
             .stage(|encoder| call(Readout::new(encoder)))
             .finish();
         trace!("Submitting readout commands to the queue");
+        self.stats.record_readout();
         self.device.submit(commands);
     }
 
-    /// Creates a new tensor with the provided data.
+    /// Blocks until the device has finished all submitted work, which resolves all pending buffer
+    /// maps issued by tensor retrieves.
+    fn poll(&self) {
+        trace!("Polling the device for pending retrieves");
+        self.stats.record_poll();
+        self.device.poll(wgpu::Maintain::wait()).panic_on_timeout();
+    }
+
+    /// Creates a new tensor with the provided data, converted to its in-memory representation.
     ///
     /// # Parameters
     /// - `label`: A label for the tensor.
@@ -143,17 +290,24 @@ impl tengu_backend::Backend for Backend {
     /// - `data`: A slice of data to initialize the tensor with.
     ///
     /// # Returns
-    /// A new tensor initialized with the provided data.
-    fn tensor<T: IOType>(
+    /// A result wrapping the new tensor initialized with the provided data, `Error::UnsupportedType`
+    /// for 16-bit integer types, or `Error::OutOfMemory` if its buffer cannot be allocated.
+    fn try_tensor<T: IOType>(
         self: &Rc<Self>,
         label: impl Into<String>,
         shape: impl Into<Vec<usize>>,
         data: &[T],
-    ) -> Self::Tensor<T> {
+    ) -> Result<Self::Tensor<T>> {
+        check_supported::<T>()?;
         let label = label.into();
         trace!("Creating new tensor '{label}'");
-        let buffer = self.device().buffer::<T>(&label, BufferUsage::Read).with_data(data);
-        Tensor::new(self, label, shape, buffer)
+        let data = T::to_pods(data);
+        let buffer = self
+            .device()
+            .buffer::<Pod<T>>(&label, BufferUsage::Read)
+            .try_with_data(&*data)
+            .map_err(allocation_error)?;
+        Ok(Tensor::new(self, label, shape, buffer))
     }
 
     /// Creates a new zero-initialized tensor with the specified shape.
@@ -163,17 +317,97 @@ impl tengu_backend::Backend for Backend {
     /// - `shape`: The shape of the tensor.
     ///
     /// # Returns
-    /// A new zero-initialized tensor.
-    fn zero<T: StorageType>(
+    /// A result wrapping the new zero-initialized tensor, `Error::UnsupportedType` for 16-bit
+    /// integer types, or `Error::OutOfMemory` if its buffer cannot be allocated.
+    fn try_zero<T: StorageType>(
         self: &Rc<Self>,
         label: impl Into<String>,
         shape: impl Into<Vec<usize>>,
-    ) -> Self::Tensor<T> {
+    ) -> Result<Self::Tensor<T>> {
+        check_supported::<T>()?;
         let label = label.into();
         let shape = shape.into();
-        let size = shape.iter().product::<usize>().of::<T>();
+        let size = shape.iter().product::<usize>().of::<Pod<T>>();
         trace!("Creating new zero tensor '{label}'");
-        let buffer = self.device().buffer::<T>(&label, BufferUsage::ReadWrite).empty(size);
-        Tensor::new(self, label, shape, buffer)
+        let buffer = self
+            .device()
+            .buffer::<Pod<T>>(&label, BufferUsage::ReadWrite)
+            .try_empty(size)
+            .map_err(allocation_error)?;
+        Ok(Tensor::new(self, label, shape, buffer))
+    }
+}
+
+/// Converts an error raised while allocating the buffer of a tensor into a backend error.
+///
+/// # Parameters
+/// - `error`: The error raised by the WGPU device.
+///
+/// # Returns
+/// `Error::OutOfMemory` for allocation failures, and `Error::WGPUError` otherwise.
+fn allocation_error(error: tengu_wgpu::Error) -> Error {
+    match error {
+        tengu_wgpu::Error::OutOfMemory { requested_bytes } => Error::OutOfMemory { requested_bytes },
+        e => Error::WGPUError(e.into()),
+    }
+}
+
+/// Converts an error raised while encoding or submitting a compute pass into a backend error.
+///
+/// # Parameters
+/// - `error`: The error raised by the WGPU device.
+///
+/// # Returns
+/// `Error::ValidationError` for errors captured by a validation error scope, and
+/// `Error::ComputeError` otherwise.
+fn compute_error(error: tengu_wgpu::Error) -> Error {
+    match error {
+        tengu_wgpu::Error::ValidationError(message) => Error::ValidationError(message),
+        e => Error::ComputeError(e.into()),
+    }
+}
+
+/// Checks that the storage type can be represented in WGSL.
+///
+/// # Returns
+/// A result indicating success, or `Error::UnsupportedType` for 16-bit integer types, which WGSL
+/// doesn't support natively.
+fn check_supported<T: StorageType>() -> Result<()> {
+    match T::as_type() {
+        ty @ (Type::U16 | Type::I16) => Err(Error::UnsupportedType(ty, UNSUPPORTED_16BIT.to_string())),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tengu_backend::{Backend, Error};
+    use tengu_backend_tensor::Type;
+
+    use crate::Backend as WGPUBackend;
+
+    #[tokio::test]
+    async fn validation_error() {
+        let backend = WGPUBackend::new().await.unwrap();
+        let result = backend.compute("invalid", |_| -> Result<(), Error> {
+            backend.device().create_buffer(&wgpu::BufferDescriptor {
+                label: Some("invalid"),
+                size: 4,
+                usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::MAP_WRITE,
+                mapped_at_creation: false,
+            });
+            Ok(())
+        });
+        assert!(matches!(result, Err(Error::ValidationError(_))));
+        assert!(backend.compute("valid", |_| Ok::<_, Error>(())).is_ok());
+    }
+
+    #[tokio::test]
+    async fn unsupported_type() {
+        let backend = WGPUBackend::new().await.unwrap();
+        let result = backend.try_zero::<i16>("a", [2]);
+        assert!(matches!(result, Err(Error::UnsupportedType(Type::I16, _))));
+        let result = backend.try_tensor("b", [2], &[1u16, 2]);
+        assert!(matches!(result, Err(Error::UnsupportedType(Type::U16, _))));
     }
 }
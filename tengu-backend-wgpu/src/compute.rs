@@ -14,21 +14,31 @@
 //! 2. **Resource Binding**: The `run` method sets the pipeline and bind group for the compute pass using the created
 //!    pipeline. It binds the resources required for the compute operations.
 //! 3. **Dispatch Workgroups**: The `run` method then dispatches the workgroups to execute the compute operations on the GPU.
+//!
+//! # Batching
+//! A single `Compute` instance is shared by all processors run in one compute step, e.g. by all blocks of a graph, so
+//! their pipelines are set and dispatched one after another within a single compute pass. This avoids the overhead of
+//! beginning a pass (and encoding a command buffer) per block, which dominates for graphs with many small blocks.
+//!
+//! Pipelines are cached by the processor, so when the same processor is run repeatedly (e.g. on every iteration of a
+//! graph computation), the shaders are compiled only on the first run.
 
 use tengu_backend::Compute as RawCompute;
 use tengu_backend::{Error, Result};
-use tengu_wgpu::{Device, Pipeline};
+use tengu_wgpu::{Buffer, BufferUsage, Device, Pipeline};
 use tracing::trace;
 
 use crate::processor::Processor;
+use crate::stats::Stats;
 use crate::Backend as WGPUBackend;
 
-const WORKGROUP_SIZE: u32 = 64;
+pub(crate) const WORKGROUP_SIZE: u32 = 64;
 
 /// The `Compute` struct is used to manage and execute compute passes on the GPU. A new `Compute`
 /// struct is create for each execution of the commit pass.
 pub struct Compute<'a> {
     device: &'a Device,
+    stats: &'a Stats,
     label: &'a str,
     pass: wgpu::ComputePass<'a>,
 }
@@ -38,13 +48,19 @@ impl<'a> Compute<'a> {
     ///
     /// # Parameters
     /// - `device`: A reference to the `Device` object used for GPU operations.
+    /// - `stats`: A reference to the statistics of the backend, counting the built pipelines.
     /// - `label`: A label for the compute operations.
     /// - `pass`: A `wgpu::ComputePass` object representing the compute pass.
     ///
     /// # Returns
     /// A new instance of `Compute`.
-    pub fn new(device: &'a Device, label: &'a str, pass: wgpu::ComputePass<'a>) -> Self {
-        Self { device, label, pass }
+    pub fn new(device: &'a Device, stats: &'a Stats, label: &'a str, pass: wgpu::ComputePass<'a>) -> Self {
+        Self {
+            device,
+            stats,
+            label,
+            pass,
+        }
     }
 
     /// Creates a pipeline for the compute operations using the given processor.
@@ -57,25 +73,68 @@ impl<'a> Compute<'a> {
     /// the buffer limit is reached.
     fn pipeline(&self, processor: &Processor<'_>) -> Result<Pipeline> {
         trace!("Creating pipeline");
-        let shader = self.device.shader(self.label, processor.shader());
-        let buffers = processor.sources().map(|source| source.buffer()).collect::<Vec<_>>();
+        let buffers = processor
+            .sources()
+            .map(|source| (source.buffer(), processor.usage(source.label())))
+            .collect::<Vec<_>>();
+        self.build_pipeline(processor.shader(), buffers)
+    }
+
+    /// Creates a pipeline clearing the outputs of atomic operations using the given processor.
+    ///
+    /// # Parameters
+    /// - `processor`: A reference to the `Processor` object which provides shader and buffer information.
+    ///
+    /// # Returns
+    /// A `Result` containing the `Pipeline` object if the processor has atomic outputs, `None` if it
+    /// doesn't, or an `Error` if the buffer limit is reached.
+    fn clear_pipeline(&self, processor: &Processor<'_>) -> Result<Option<Pipeline>> {
+        let Some(shader) = processor.clear_shader() else {
+            return Ok(None);
+        };
+        trace!("Creating clear pipeline");
+        let buffers = processor
+            .atomic_sources()
+            .map(|source| (source.buffer(), BufferUsage::ReadWrite))
+            .collect::<Vec<_>>();
+        self.build_pipeline(shader, buffers).map(Some)
+    }
+
+    /// Creates a pipeline from the shader code and the buffers bound to it.
+    ///
+    /// # Parameters
+    /// - `shader`: The shader code.
+    /// - `buffers`: The buffers to bind with the usages they are declared with in the shader, in
+    ///   the order of their bindings.
+    ///
+    /// # Returns
+    /// A `Result` containing the `Pipeline` object if the pipeline creation is successful, or an `Error` if
+    /// the shader fails to compile or the buffer limit is reached.
+    fn build_pipeline(&self, shader: &str, buffers: Vec<(&Buffer, BufferUsage)>) -> Result<Pipeline> {
+        let shader = self.device.shader(self.label, shader).map_err(|e| match e {
+            tengu_wgpu::Error::ShaderCompilation { shader, message } => Error::ShaderCompilation { shader, message },
+            e => Error::WGPUError(e.into()),
+        })?;
         let max_buffers = self.device.limits().max_storage_buffers_per_shader_stage as usize;
         trace!("Max buffer limit: {max_buffers}");
         if buffers.len() > max_buffers {
             return Err(Error::BufferLimitReached(max_buffers));
         }
-        let pipeline = self
-            .device
-            .layout()
-            .add_entries(buffers)
-            .pipeline(self.label)
-            .build(shader);
+        let layout = buffers
+            .into_iter()
+            .try_fold(self.device.layout(), |layout, (buffer, usage)| {
+                layout.add_entry_with_usage(buffer, usage)
+            })
+            .map_err(|e| Error::WGPUError(e.into()))?;
+        let pipeline = layout.pipeline(self.label).build(shader);
+        self.stats.record_pipeline();
         Ok(pipeline)
     }
 }
 
 impl<'a> RawCompute<WGPUBackend> for Compute<'a> {
-    /// Runs the compute operations by setting up the pipeline, bind group, and dispatching workgroups.
+    /// Runs the compute operations by setting up the pipeline, bind group, and dispatching workgroups. The
+    /// pipelines are built on the first run with the given processor and reused on subsequent runs.
     ///
     /// # Parameters
     /// - `processor`: A reference to the processor which provides the necessary data for the compute operations.
@@ -84,9 +143,16 @@ impl<'a> RawCompute<WGPUBackend> for Compute<'a> {
     /// A `Result` indicating whether the compute operations were successful or an error occurred.
     fn run(&mut self, processor: &Processor<'_>) -> Result<()> {
         trace!("Executing compute operation");
-        let pipeline = self.pipeline(processor)?;
+        if let Some(pipeline) = processor.clear_pipeline(|| self.clear_pipeline(processor))? {
+            let workgroup_count = processor.clear_count() as u32 / WORKGROUP_SIZE + 1;
+            self.pass.set_pipeline(pipeline);
+            self.pass.set_bind_group(0, pipeline.bind_group(), &[]);
+            self.pass.dispatch_workgroups(workgroup_count, 1, 1);
+            trace!("Dispatched clear workgroups");
+        }
+        let pipeline = processor.pipeline(|| self.pipeline(processor))?;
         let workgroup_count = processor.element_count() as u32 / WORKGROUP_SIZE + 1;
-        self.pass.set_pipeline(&pipeline);
+        self.pass.set_pipeline(pipeline);
         self.pass.set_bind_group(0, pipeline.bind_group(), &[]);
         self.pass.dispatch_workgroups(workgroup_count, 1, 1);
         trace!("Dispatched workgroups");
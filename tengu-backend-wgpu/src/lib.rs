@@ -18,6 +18,7 @@
 //! - `linker`: Implements the `Linker` struct, which handles copying data between GPU buffers.
 //! - `processor`: Implements the `Processor` struct, which sets up and manages shader programs and their associated resources.
 //! - `readout`: Implements the `Stage` struct, which is responsible for reading out data from the GPU.
+//! - `stats`: Defines the `Stats` struct, which counts the expensive operations performed by the backend.
 //! - `source`: Defines the `Source` struct, which represents a source of data for the backend.
 //! - `tensor`: Defines the `Tensor` struct, which represents a tensor stored on the GPU and provides methods for tensor operations.
 
@@ -28,6 +29,12 @@ mod linker;
 mod processor;
 mod readout;
 mod source;
+mod stats;
 mod tensor;
 
 pub use backend::Backend;
+pub use stats::Stats;
+pub use tengu_wgpu::{Device, Retry};
+
+/// Message used to reject 16-bit integer types, which WGSL doesn't support without extensions.
+const UNSUPPORTED_16BIT: &str = "16-bit integer types are not supported by the WGPU backend, use the CPU backend instead";
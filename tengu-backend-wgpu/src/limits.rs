@@ -1,8 +1,14 @@
 //! Module implementing the `Limits` struct for the WGPU backend. It provides information about the
 //! limitations of the WGPU backend.
 
+use tengu_backend_tensor::Type;
+
+use crate::compute::WORKGROUP_SIZE;
 use crate::Backend as WGPUBackend;
 
+/// The size of the elements of all tensors in bytes, since WGSL has no types narrower than 32 bits.
+const ELEMENT_SIZE: u64 = 4;
+
 /// A struct representing the limits of the WGPU backend.
 pub struct Limits {
     device_limits: wgpu::Limits,
@@ -33,6 +39,38 @@ impl tengu_backend::Limits for Limits {
     fn max_tensor_per_compute(&self) -> Option<usize> {
         Some(self.device_limits.max_storage_buffers_per_shader_stage as usize)
     }
+
+    /// Returns the maximum number of elements of a single tensor, i.e. the number of 32-bit
+    /// elements which fit into the largest buffer the device allows. Since every tensor is bound
+    /// to the shaders as a whole, the size is also limited by the largest storage buffer binding.
+    ///
+    /// # Returns
+    /// The maximum number of elements of a single tensor.
+    fn max_tensor_size(&self) -> Option<usize> {
+        let binding_size = self.device_limits.max_storage_buffer_binding_size as u64;
+        let size = self.device_limits.max_buffer_size.min(binding_size);
+        Some((size / ELEMENT_SIZE) as usize)
+    }
+
+    /// Returns the maximum number of elements computed by a single dispatch. A stage computing `n`
+    /// elements dispatches `n / WORKGROUP_SIZE + 1` workgroups, which must not exceed the maximum
+    /// number of workgroups per dimension.
+    ///
+    /// # Returns
+    /// The maximum number of elements computed at once.
+    fn max_dispatch_size(&self) -> Option<usize> {
+        let workgroups = self.device_limits.max_compute_workgroups_per_dimension as usize;
+        Some(workgroups * WORKGROUP_SIZE as usize - 1)
+    }
+
+    /// Returns the element types the backend can store and compute with. WGSL has no 16-bit
+    /// integer types, so those are left out.
+    ///
+    /// # Returns
+    /// The 32-bit element types and booleans.
+    fn supported_types(&self) -> Vec<Type> {
+        vec![Type::Bool, Type::U32, Type::I32, Type::F32]
+    }
 }
 
 #[cfg(test)]
@@ -66,5 +104,13 @@ mod tests {
             backend.limits().max_tensor_per_compute(),
             Some(max_storage_buffers_per_shader_stage as usize),
         );
+        let limits = backend.device().limits();
+        assert_eq!(
+            backend.limits().max_dispatch_size(),
+            Some(limits.max_compute_workgroups_per_dimension as usize * 64 - 1),
+        );
+        let binding_size = limits.max_storage_buffer_binding_size as u64;
+        let size = limits.max_buffer_size.min(binding_size);
+        assert_eq!(backend.limits().max_tensor_size(), Some(size as usize / 4));
     }
 }
@@ -1,17 +1,26 @@
 //! This module defines the `Linker` struct which implements the `Linker` trait from the `tengu_backend` crate.
-//! The `Linker` is responsible for copying data between GPU buffers using the WGPU backend.
+//! The `Linker` is responsible for copying data between GPU buffers using the WGPU backend. Accumulating links
+//! can't be expressed as a buffer copy, so they are performed by a small compute shader instead.
 
+use indoc::formatdoc;
 use tengu_backend::Linker as RawLinker;
-use tengu_backend_tensor::StorageType;
-use tengu_wgpu::Encoder;
+use tengu_backend_tensor::Tensor as RawTensor;
+use tengu_backend_tensor::{StorageType, Type};
+use tengu_wgpu::{BufferUsage, Device, Encoder};
+use tracing::trace;
 
+use crate::compute::WORKGROUP_SIZE;
+use crate::processor::type_symbol;
 use crate::source::Source;
+use crate::stats::Stats;
 use crate::tensor::Tensor;
 use crate::Backend as WGPUBackend;
 
 /// The `Linker` struct is used to manage and perform copy operations between GPU buffers.
 /// It holds a mutable reference to an `Encoder` which is used to encode the copy operations.
 pub struct Linker<'a> {
+    device: &'a Device,
+    stats: &'a Stats,
     encoder: &'a mut Encoder,
 }
 
@@ -19,12 +28,14 @@ impl<'a> Linker<'a> {
     /// Creates a new `Linker` instance.
     ///
     /// # Parameters
+    /// - `device`: A reference to the `Device` used to build the pipelines of accumulating links.
+    /// - `stats`: A reference to the statistics of the backend, counting the built pipelines.
     /// - `encoder`: A mutable reference to an `Encoder` wrapper from `tengu_wgpu` used for the copy operations.
     ///
     /// # Returns
     /// A new instance of `Linker`.
-    pub fn new(encoder: &'a mut Encoder) -> Self {
-        Self { encoder }
+    pub fn new(device: &'a Device, stats: &'a Stats, encoder: &'a mut Encoder) -> Self {
+        Self { device, stats, encoder }
     }
 }
 
@@ -38,4 +49,60 @@ impl<'a> RawLinker<WGPUBackend> for Linker<'a> {
     fn copy_link<T: StorageType>(&mut self, from: &Tensor<T>, to: &Tensor<T>) {
         self.encoder.copy_buffer(from.buffer(), to.buffer());
     }
+
+    /// Adds data of one tensor buffer to another element-wise by dispatching an accumulation shader.
+    /// The destination is bound read-write whatever its usage, e.g. when it is an initialized input
+    /// tensor, and the pipeline is built on the first propagation and kept by the destination. This
+    /// operation works only on typed tensors, so the downcast conversion from sources should be
+    /// performed elsewhere.
+    ///
+    /// # Parameters
+    /// - `from`: A reference to the source tensor.
+    /// - `to`: A reference to the destination tensor.
+    ///
+    /// # Panics
+    /// Panics if the tensors store booleans, which cannot be added.
+    fn add_link<T: StorageType>(&mut self, from: &Tensor<T>, to: &Tensor<T>) {
+        assert!(T::as_type() != Type::Bool, "cannot accumulate boolean tensors");
+        let pipeline = to.add_pipeline(from, || {
+            trace!("Building accumulation pipeline");
+            let shader = self
+                .device
+                .shader("add_link", &add_shader(T::as_type()))
+                .expect("accumulation shader should compile");
+            self.stats.record_pipeline();
+            self.device
+                .layout()
+                .add_entry_with_usage(from.buffer(), BufferUsage::Read)
+                .and_then(|layout| layout.add_entry_with_usage(to.buffer(), BufferUsage::ReadWrite))
+                .expect("tensor buffers should be bindable")
+                .pipeline("add_link")
+                .build(shader)
+        });
+        let workgroup_count = to.count() as u32 / WORKGROUP_SIZE + 1;
+        self.encoder.dispatch("add_link", &pipeline, workgroup_count);
+    }
+}
+
+/// Generates the shader adding the first bound buffer to the second one element-wise.
+///
+/// # Parameters
+/// - `ty`: The storage type of both buffers.
+///
+/// # Returns
+/// The shader code.
+fn add_shader(ty: Type) -> String {
+    let ty = type_symbol(ty);
+    formatdoc!(
+        r"
+        @group(0) @binding(0) var<storage, read> addend: array<{ty}>;
+        @group(0) @binding(1) var<storage, read_write> accumulator: array<{ty}>;
+
+        @compute
+        @workgroup_size({WORKGROUP_SIZE})
+        fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {{
+            let idx = global_id.x;
+            if (idx < arrayLength(&accumulator)) {{ accumulator[idx] = accumulator[idx] + addend[idx]; }}
+        }}"
+    )
 }
@@ -11,12 +11,18 @@
 //! - Generating shader code using the `Emitter` and `Declarator` components.
 //! - Providing various operations such as variable binding, scalar representation, unary and binary functions,
 //!   type casting, and block generation.
+//! - Caching the pipelines built from the generated shaders, so that a processor reused across
+//!   iterations compiles its shaders only once.
 
-use std::collections::{BTreeMap, HashSet};
+use std::cell::OnceCell;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::ops::Range;
 use tracing::trace;
 
 use tengu_backend::Processor as RawProcessor;
-use tengu_backend_tensor::{Function, Operator, StorageType, Type};
+use tengu_backend::{Error, Result};
+use tengu_backend_tensor::{CastMode, Function, Operator, StorageType, Type};
+use tengu_wgpu::{BufferUsage, Pipeline};
 
 use crate::source::Source;
 use crate::tensor::Tensor;
@@ -27,6 +33,8 @@ use emitter::Emitter;
 mod declarator;
 mod emitter;
 
+pub(crate) use emitter::type_symbol;
+
 /// The `Processor` struct is used to manage and process tensor sources, bind them, and generate shader code.
 /// It holds an emitter for generating code, a declarator for managing variable declarations, and keeps track of various states.
 pub struct Processor<'a> {
@@ -39,6 +47,37 @@ pub struct Processor<'a> {
     readouts: &'a HashSet<String>,
     readout_sources: Vec<&'a dyn Source>,
     current_binding: usize,
+    vars: HashMap<String, &'a dyn Source>,
+    bincounts: HashMap<String, Bincount>,
+    mask_selects: HashMap<String, MaskSelect>,
+    nested_mask_select: bool,
+    atomic_sources: Vec<&'a dyn Source>,
+    clear_count: usize,
+    clear_shader: Option<String>,
+    pipeline: OnceCell<Pipeline>,
+    clear_pipeline: OnceCell<Option<Pipeline>>,
+}
+
+/// Parameters of a bincount reduction that has not been assigned to an output yet. Since the
+/// reduction cannot be expressed as a per-element expression, its shader code is only emitted once
+/// the statement with the output is known.
+struct Bincount {
+    expression: String,
+    count: usize,
+    bins: usize,
+    min: f32,
+    max: f32,
+}
+
+/// Parameters of a masked selection that has not been assigned to an output yet. The positions of
+/// the selected elements depend on all preceding mask values, so like a bincount, its shader code is
+/// only emitted once the statement with the output is known.
+struct MaskSelect {
+    expression: String,
+    mask: String,
+    length: String,
+    count: usize,
+    ty: Type,
 }
 
 impl<'a> Processor<'a> {
@@ -60,6 +99,15 @@ impl<'a> Processor<'a> {
             readouts,
             readout_sources: Vec::new(),
             current_binding: 0,
+            vars: HashMap::new(),
+            bincounts: HashMap::new(),
+            mask_selects: HashMap::new(),
+            nested_mask_select: false,
+            atomic_sources: Vec::new(),
+            clear_count: 0,
+            clear_shader: None,
+            pipeline: OnceCell::new(),
+            clear_pipeline: OnceCell::new(),
         }
     }
 
@@ -88,6 +136,19 @@ impl<'a> Processor<'a> {
         self.readout_sources.iter().copied()
     }
 
+    /// Returns the usage with which the buffer of a source tensor has to be bound to the shader.
+    /// Tensors which no statement writes are bound read-only, even if their buffers could be
+    /// written, so that the bind group matches their declarations.
+    ///
+    /// # Parameters
+    /// - `label`: The label of the source tensor.
+    ///
+    /// # Returns
+    /// The buffer usage of the binding.
+    pub fn usage(&self, label: &str) -> BufferUsage {
+        self.declarator.usage(label)
+    }
+
     /// Returns the generated shader code as a string slice.
     ///
     /// # Returns
@@ -95,12 +156,72 @@ impl<'a> Processor<'a> {
     pub fn shader(&self) -> &str {
         &self.shader
     }
+
+    /// Returns the pipeline built from the shader code, building it with the provided function
+    /// on the first call. Since the shader doesn't change once the processor is built, all
+    /// subsequent calls reuse the cached pipeline.
+    ///
+    /// # Parameters
+    /// - `build`: A function building the pipeline.
+    ///
+    /// # Returns
+    /// A `Result` containing a reference to the cached pipeline or an error if building it failed.
+    pub fn pipeline(&self, build: impl FnOnce() -> Result<Pipeline>) -> Result<&Pipeline> {
+        if let Some(pipeline) = self.pipeline.get() {
+            return Ok(pipeline);
+        }
+        let pipeline = build()?;
+        Ok(self.pipeline.get_or_init(|| pipeline))
+    }
+
+    /// Returns the pipeline built from the clear shader code, building it with the provided
+    /// function on the first call and reusing it afterwards.
+    ///
+    /// # Parameters
+    /// - `build`: A function building the pipeline, if the processor has a clear shader.
+    ///
+    /// # Returns
+    /// A `Result` containing a reference to the cached pipeline, `None` if there is no clear
+    /// shader, or an error if building it failed.
+    pub fn clear_pipeline(&self, build: impl FnOnce() -> Result<Option<Pipeline>>) -> Result<Option<&Pipeline>> {
+        if let Some(pipeline) = self.clear_pipeline.get() {
+            return Ok(pipeline.as_ref());
+        }
+        let pipeline = build()?;
+        Ok(self.clear_pipeline.get_or_init(|| pipeline).as_ref())
+    }
+
+    /// Returns the shader code that clears the outputs of atomic operations. It has to be run
+    /// before the main shader on every compute pass.
+    ///
+    /// # Returns
+    /// A string slice with the clear shader code, or `None` if there are no atomic outputs.
+    pub fn clear_shader(&self) -> Option<&str> {
+        self.clear_shader.as_deref()
+    }
+
+    /// Returns the maximum number of elements among the tensors cleared by the clear shader.
+    ///
+    /// # Returns
+    /// The number of elements as a `usize`.
+    pub fn clear_count(&self) -> usize {
+        self.clear_count
+    }
+
+    /// Returns an iterator over the source tensors that are the outputs of atomic operations, in
+    /// the order of their bindings in the clear shader.
+    ///
+    /// # Returns
+    /// An iterator over source tensor references.
+    pub fn atomic_sources(&'a self) -> impl Iterator<Item = &'a dyn Source> {
+        self.atomic_sources.iter().copied()
+    }
 }
 
 // NOTE: Processor trait implementation
 
 impl<'a> RawProcessor<'a, WGPUBackend> for Processor<'a> {
-    type Repr = (usize, String);
+    type Repr = (usize, String, Type);
 
     /// Processses the tensor. This is the bottom-level call, so the tensor will be added to the
     /// list of available sources, and it will bee used to generate a declaration and part of the
@@ -110,8 +231,8 @@ impl<'a> RawProcessor<'a, WGPUBackend> for Processor<'a> {
     /// - `tensor`: A reference to the tensor to be bound.
     ///
     /// # Returns
-    /// Processor representation of the tensor, consisting of the number of elements in the tensor
-    /// and emitted shader representation of the tensor.
+    /// Processor representation of the tensor, consisting of the number of elements in the tensor,
+    /// emitted shader representation of the tensor and its element type.
     fn var<T: StorageType>(&mut self, tensor: &'a Tensor<T>) -> Self::Repr {
         use tengu_backend_tensor::Tensor;
         let label = Tensor::label(tensor);
@@ -124,20 +245,23 @@ impl<'a> RawProcessor<'a, WGPUBackend> for Processor<'a> {
                 self.readout_sources.push(tensor);
             }
         }
-        (tensor.count(), self.emitter.var(tensor))
+        let var = self.emitter.var(tensor);
+        self.vars.insert(var.clone(), tensor);
+        (tensor.count(), var, T::as_type())
     }
 
-    /// Generates the representation for a scalar value.
+    /// Generates the representation for a scalar value. Scalars are always inlined into the shader
+    /// as literals, so they are never added to the sources and never allocate a buffer.
     ///
     /// # Parameters
     /// - `value`: The scalar value to be represented.
     ///
     /// # Returns
-    /// A tuple containing the number of elements (always 0 for scalars) and its shader representation,
-    /// which in this case will be a literal.
+    /// A tuple containing the number of elements (always 0 for scalars), its shader representation,
+    /// which in this case will be a literal, and its type.
     fn scalar<T: StorageType>(&mut self, value: T) -> Self::Repr {
         self.element_count = 0;
-        (0, self.emitter.scalar(value))
+        (0, self.emitter.scalar(value), T::as_type())
     }
 
     /// Generates the representation for a unary function applied to an inner expression.
@@ -147,11 +271,12 @@ impl<'a> RawProcessor<'a, WGPUBackend> for Processor<'a> {
     /// - `function`: The unary function to apply.
     ///
     /// # Returns
-    /// A tuple containing the number of elements and the resulting expression's shader representation.
+    /// A tuple containing the number of elements, the resulting expression's shader representation
+    /// and its type, which is the type of the inner expression.
     fn unary_fn(&mut self, inner: Self::Repr, function: Function) -> Self::Repr {
-        let expression = self.emitter.unary_fn(inner.1, function);
+        let expression = self.emitter.unary_fn(inner.1, function, inner.2);
         let element_count = inner.0;
-        (element_count, expression)
+        (element_count, expression, inner.2)
     }
 
     /// Generates the representation for a binary operation between two expressions.
@@ -162,26 +287,287 @@ impl<'a> RawProcessor<'a, WGPUBackend> for Processor<'a> {
     /// - `operator`: The binary operator to apply.
     ///
     /// # Returns
-    /// A tuple containing the maximum count of elements between the two expressions and the resulting
-    /// expression's shader representation.
+    /// A tuple containing the maximum count of elements between the two expressions, the resulting
+    /// expression's shader representation and its type, which is `bool` for comparisons and the
+    /// type of the operands otherwise.
     fn binary(&mut self, lhs: Self::Repr, rhs: Self::Repr, operator: Operator) -> Self::Repr {
-        let expression = self.emitter.binary(lhs.1, rhs.1, operator);
+        let expression = self.emitter.binary(lhs.1, rhs.1, operator, lhs.2);
         let element_count = lhs.0.max(rhs.0);
-        (element_count, expression)
+        let ty = match operator {
+            Operator::Eq | Operator::Neq | Operator::Gt | Operator::Lt | Operator::Ge | Operator::Le => Type::Bool,
+            _ => lhs.2,
+        };
+        (element_count, expression, ty)
+    }
+
+    /// Generates the representation for the element-wise selection between two expressions.
+    ///
+    /// # Parameters
+    /// - `mask`: The boolean mask representation.
+    /// - `on_true`: The representation selected where the mask is true.
+    /// - `on_false`: The representation selected where the mask is false.
+    ///
+    /// # Returns
+    /// A tuple containing the maximum count of elements among the three expressions, the resulting
+    /// selection's shader representation and the type of the selected expressions.
+    fn select(&mut self, mask: Self::Repr, on_true: Self::Repr, on_false: Self::Repr) -> Self::Repr {
+        let element_count = mask.0.max(on_true.0).max(on_false.0);
+        let expression = self.emitter.select(mask.1, on_true.1, on_false.1);
+        (element_count, expression, on_true.2)
     }
 
     /// Generates the representation for the type cast of the inner expression to a specified type.
     ///
     /// # Parameters
     /// - `inner`: The inner expression representation.
+    /// - `from`: The type of the inner expression.
     /// - `ty`: The target type to cast to.
+    /// - `mode`: The way out-of-range values are cast.
     ///
     /// # Returns
-    /// A tuple containing the number of elements and the resulting cast expression's shader representation.
-    fn cast(&mut self, inner: Self::Repr, ty: Type) -> Self::Repr {
-        let expression = self.emitter.cast(inner.1, ty);
+    /// A tuple containing the number of elements, the resulting cast expression's shader
+    /// representation and the target type.
+    fn cast(&mut self, inner: Self::Repr, from: Type, ty: Type, mode: CastMode) -> Self::Repr {
+        let expression = self.emitter.cast(inner.1, from, ty, mode);
         let element_count = inner.0;
-        (element_count, expression)
+        (element_count, expression, ty)
+    }
+
+    /// Generates the representation for the histogram of the inner expression. No code is emitted
+    /// at this point: the histogram is computed by atomically incrementing the bins of the output,
+    /// so the code is only generated by the statement that assigns the histogram to a tensor.
+    ///
+    /// # Parameters
+    /// - `inner`: The inner expression representation.
+    /// - `bins`: The number of bins.
+    /// - `min`: The inclusive lower bound of the binned range.
+    /// - `max`: The exclusive upper bound of the binned range.
+    ///
+    /// # Returns
+    /// A tuple containing the number of elements in the inner expression and a placeholder
+    /// representation of the histogram.
+    fn bincount(&mut self, inner: Self::Repr, bins: usize, min: f32, max: f32) -> Self::Repr {
+        let (count, expression, _) = inner;
+        let placeholder = format!("bincount({expression}, {bins}, {min:?}, {max:?})");
+        let bincount = Bincount {
+            expression,
+            count,
+            bins,
+            min,
+            max,
+        };
+        self.bincounts.insert(placeholder.clone(), bincount);
+        (count, placeholder, Type::U32)
+    }
+
+    /// Generates the representation for the mean of the inner expression along the specified axis.
+    ///
+    /// # Parameters
+    /// - `inner`: The inner expression representation.
+    /// - `shape`: The shape of the inner expression.
+    /// - `axis`: The axis along which the mean is computed.
+    ///
+    /// # Returns
+    /// A tuple containing the number of elements in the reduced expression and the call of the
+    /// emitted helper function computing the mean.
+    fn mean(&mut self, inner: Self::Repr, shape: &[usize], axis: usize) -> Self::Repr {
+        let expression = self.emitter.mean(inner.1, shape, axis);
+        let element_count = inner.0 / shape[axis];
+        (element_count, expression, Type::F32)
+    }
+
+    /// Generates the representation for the softmax of the inner expression along the specified axis.
+    ///
+    /// # Parameters
+    /// - `inner`: The inner expression representation.
+    /// - `shape`: The shape of the inner expression.
+    /// - `axis`: The axis along which the softmax is normalized.
+    ///
+    /// # Returns
+    /// A tuple containing the number of elements and the call of the emitted helper function
+    /// computing the softmax.
+    fn softmax(&mut self, inner: Self::Repr, shape: &[usize], axis: usize) -> Self::Repr {
+        let expression = self.emitter.softmax(inner.1, shape, axis);
+        (inner.0, expression, Type::F32)
+    }
+
+    /// Generates the representation for the one-hot encoding of the inner expression.
+    ///
+    /// # Parameters
+    /// - `inner`: The inner expression representation.
+    /// - `classes`: The number of classes.
+    ///
+    /// # Returns
+    /// A tuple containing the number of elements in the encoded expression and the call of the
+    /// emitted helper function reading the class index.
+    fn one_hot(&mut self, inner: Self::Repr, classes: usize) -> Self::Repr {
+        let expression = self.emitter.one_hot(inner.1, classes);
+        (inner.0 * classes, expression, Type::F32)
+    }
+
+    /// Generates the representation for the inner expression tiled along its axes.
+    ///
+    /// # Parameters
+    /// - `inner`: The inner expression representation.
+    /// - `shape`: The shape of the inner expression.
+    /// - `reps`: The number of repetitions along each axis.
+    /// - `ty`: The element type of the inner expression.
+    ///
+    /// # Returns
+    /// A tuple containing the number of elements in the tiled expression and the call of the
+    /// emitted helper function reading the inner expression.
+    fn tile(&mut self, inner: Self::Repr, shape: &[usize], reps: &[usize], ty: Type) -> Self::Repr {
+        let expression = self.emitter.tile(inner.1, shape, reps, ty);
+        let element_count = inner.0 * reps.iter().product::<usize>();
+        (element_count, expression, ty)
+    }
+
+    /// Generates the representation for the inner expression padded with a constant value.
+    ///
+    /// # Parameters
+    /// - `inner`: The inner expression representation.
+    /// - `shape`: The shape of the inner expression.
+    /// - `padding`: The number of elements added before and after each axis.
+    /// - `value`: The representation of the scalar padding value.
+    /// - `ty`: The element type of the inner expression.
+    ///
+    /// # Returns
+    /// A tuple containing the number of elements in the padded expression and the call of the
+    /// emitted helper function reading the inner expression.
+    fn pad(
+        &mut self,
+        inner: Self::Repr,
+        shape: &[usize],
+        padding: &[(usize, usize)],
+        value: Self::Repr,
+        ty: Type,
+    ) -> Self::Repr {
+        let expression = self.emitter.pad(inner.1, shape, padding, value.1, ty);
+        let element_count = shape
+            .iter()
+            .zip(padding)
+            .map(|(dim, (before, after))| before + dim + after)
+            .product();
+        (element_count, expression, ty)
+    }
+
+    /// Generates the representation for the elements of the inner expression at the given flat
+    /// indices.
+    ///
+    /// # Parameters
+    /// - `inner`: The inner expression representation.
+    /// - `indices`: The flat indices of the taken elements.
+    /// - `ty`: The element type of the inner expression.
+    ///
+    /// # Returns
+    /// A tuple containing the number of taken elements and the call of the emitted helper function
+    /// reading the inner expression.
+    fn take(&mut self, inner: Self::Repr, indices: &[usize], ty: Type) -> Self::Repr {
+        let expression = self.emitter.take(inner.1, indices, ty);
+        (indices.len(), expression, ty)
+    }
+
+    /// Generates the representation for a contiguous range of the inner expression along an axis.
+    ///
+    /// # Parameters
+    /// - `inner`: The inner expression representation.
+    /// - `shape`: The shape of the inner expression.
+    /// - `axis`: The axis along which the range is taken.
+    /// - `range`: The range of indices along the axis.
+    /// - `ty`: The element type of the inner expression.
+    ///
+    /// # Returns
+    /// A tuple containing the number of sliced elements and the call of the emitted helper function
+    /// reading the inner expression.
+    fn slice(&mut self, inner: Self::Repr, shape: &[usize], axis: usize, range: Range<usize>, ty: Type) -> Self::Repr {
+        let element_count = shape.iter().product::<usize>() / shape[axis] * range.len();
+        let expression = self.emitter.slice(inner.1, shape, axis, range, ty);
+        (element_count, expression, ty)
+    }
+
+    /// Generates the representation for the inner expression reversed along the specified axes.
+    ///
+    /// # Parameters
+    /// - `inner`: The inner expression representation.
+    /// - `shape`: The shape of the inner expression.
+    /// - `axes`: The axes along which the elements are reversed.
+    /// - `ty`: The element type of the inner expression.
+    ///
+    /// # Returns
+    /// A tuple containing the number of elements and the call of the emitted helper function
+    /// reading the inner expression.
+    fn flip(&mut self, inner: Self::Repr, shape: &[usize], axes: &[usize], ty: Type) -> Self::Repr {
+        let expression = self.emitter.flip(inner.1, shape, axes, ty);
+        (inner.0, expression, ty)
+    }
+
+    /// Generates the representation for the inner expression shifted circularly along an axis.
+    ///
+    /// # Parameters
+    /// - `inner`: The inner expression representation.
+    /// - `shape`: The shape of the inner expression.
+    /// - `axis`: The axis along which the elements are shifted.
+    /// - `shift`: The number of positions the elements are shifted by.
+    /// - `ty`: The element type of the inner expression.
+    ///
+    /// # Returns
+    /// A tuple containing the number of elements and the call of the emitted helper function
+    /// reading the inner expression.
+    fn roll(&mut self, inner: Self::Repr, shape: &[usize], axis: usize, shift: usize, ty: Type) -> Self::Repr {
+        let expression = self.emitter.roll(inner.1, shape, axis, shift, ty);
+        (inner.0, expression, ty)
+    }
+
+    /// Generates the representation for the inner expressions joined along a new axis.
+    ///
+    /// # Parameters
+    /// - `inners`: The inner expression representations.
+    /// - `shape`: The shape shared by the inner expressions.
+    /// - `axis`: The position of the new axis.
+    /// - `ty`: The element type of the inner expressions.
+    ///
+    /// # Returns
+    /// A tuple containing the number of stacked elements and the call of the emitted helper
+    /// function reading the inner expressions.
+    fn stack(&mut self, inners: Vec<Self::Repr>, shape: &[usize], axis: usize, ty: Type) -> Self::Repr {
+        let element_count = inners.len() * shape.iter().product::<usize>();
+        let inners = inners.into_iter().map(|inner| inner.1).collect();
+        let expression = self.emitter.stack(inners, shape, axis, ty);
+        (element_count, expression, ty)
+    }
+
+    /// Generates the representation for the elements of the inner expression where the mask is
+    /// true. No code is emitted at this point: the selected elements are packed into the output by
+    /// a scan over the mask, so the code is only generated by the statement that assigns the
+    /// selection to a tensor. A selection without a length tensor is nested inside another
+    /// expression, which is recorded so that `block` reports it.
+    ///
+    /// # Parameters
+    /// - `inner`: The inner expression representation.
+    /// - `mask`: The boolean mask representation.
+    /// - `length`: The representation of the tensor receiving the number of selected elements, or
+    ///   `None` if the selection is nested inside another expression.
+    /// - `ty`: The element type of the inner expression.
+    ///
+    /// # Returns
+    /// A tuple containing the number of elements in the inner expression and a placeholder
+    /// representation of the selection.
+    fn mask_select(&mut self, inner: Self::Repr, mask: Self::Repr, length: Option<Self::Repr>, ty: Type) -> Self::Repr {
+        let (count, expression, _) = inner;
+        let Some(length) = length else {
+            self.nested_mask_select = true;
+            return (count, format!("mask_select({expression}, {})", mask.1), ty);
+        };
+        let placeholder = format!("mask_select({expression}, {}, {})", mask.1, length.1);
+        let mask_select = MaskSelect {
+            expression,
+            mask: mask.1,
+            length: length.1,
+            count,
+            ty,
+        };
+        self.mask_selects.insert(placeholder.clone(), mask_select);
+        (count, placeholder, ty)
     }
 
     /// Generates the representation of a statement combining an output and an expression.
@@ -191,12 +577,47 @@ impl<'a> RawProcessor<'a, WGPUBackend> for Processor<'a> {
     /// - `expr`: The input expression representation.
     ///
     /// # Returns
-    /// A tuple containing the maximum count of elements between the two expressions and the resulting
-    /// statement's shader representation.
+    /// A tuple containing the maximum count of elements between the two expressions, the resulting
+    /// statement's shader representation and the type of the output.
     fn statement(&mut self, out: Self::Repr, expr: Self::Repr) -> Self::Repr {
-        let expression = self.emitter.statement(out.1, expr.1);
         let element_count = out.0.max(expr.0);
-        (element_count, expression)
+        let source = self.vars[&out.1];
+        let output = self.emitter.output(source.label());
+        self.declarator.write(source.label());
+        if let Some(bincount) = self.bincounts.remove(&expr.1) {
+            self.declarator.atomic(source.label());
+            self.atomic_sources.push(source);
+            self.clear_count = self.clear_count.max(bincount.bins);
+            let Bincount {
+                expression,
+                count,
+                bins,
+                min,
+                max,
+            } = bincount;
+            let statement = self.emitter.bincount(source.label(), expression, count, bins, min, max);
+            return (element_count, statement, out.2);
+        }
+        if let Some(mask_select) = self.mask_selects.remove(&expr.1) {
+            // NOTE: The length is read out whenever the selection is, since probes need it to
+            // truncate the padded output.
+            let length = self.vars[&mask_select.length];
+            self.declarator.write(length.label());
+            if self.readouts.contains(source.label()) && !self.readouts.contains(length.label()) {
+                self.readout_sources.push(length);
+            }
+            let MaskSelect {
+                expression,
+                mask,
+                length,
+                count,
+                ty,
+            } = mask_select;
+            let statement = self.emitter.mask_select(output, expression, mask, length, count, ty);
+            return (element_count, statement, out.2);
+        }
+        let expression = self.emitter.statement(output, expr.1, out.2);
+        (element_count, expression, out.2)
     }
 
     /// Generates a representation for a block of expressions. This is the top-level call and it
@@ -204,8 +625,27 @@ impl<'a> RawProcessor<'a, WGPUBackend> for Processor<'a> {
     ///
     /// # Parameters
     /// - `exprs`: An iterator over expression representations to be included in the block.
-    fn block(&mut self, exprs: impl Iterator<Item = Self::Repr>) {
-        let (count_exprs, emit_exprs): (Vec<_>, Vec<_>) = exprs.unzip();
+    ///
+    /// # Returns
+    /// A result which is an error if the shader cannot be emitted.
+    ///
+    /// # Errors
+    /// Returns `Error::UnsupportedExpression` if a bincount or a masked selection is not the
+    /// top-level expression of a computation.
+    fn block(&mut self, exprs: impl Iterator<Item = Self::Repr>) -> Result<()> {
+        let (count_exprs, emit_exprs): (Vec<_>, Vec<_>) = exprs.map(|(count, expr, _)| (count, expr)).unzip();
+        if !self.bincounts.is_empty() {
+            let message = "bincount should be the top-level expression of a computation";
+            return Err(Error::UnsupportedExpression(message.to_string()));
+        }
+        if !self.mask_selects.is_empty() || self.nested_mask_select {
+            let message = "masked selection should be the top-level expression of a computation";
+            return Err(Error::UnsupportedExpression(message.to_string()));
+        }
+        if !self.atomic_sources.is_empty() {
+            let labels = self.atomic_sources.iter().map(|source| source.label());
+            self.clear_shader = Some(self.emitter.clear(labels));
+        }
         self.emitter.block(emit_exprs.into_iter());
         self.element_count = count_exprs
             .into_iter()
@@ -215,5 +655,53 @@ impl<'a> RawProcessor<'a, WGPUBackend> for Processor<'a> {
         let body = self.emitter.body();
         trace!("Emitting shader for a block");
         self.shader = format!("{}\n\n{}", header, body);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use pretty_assertions::assert_eq;
+    use tengu_backend::{Backend, Processor};
+    use tengu_backend_tensor::Operator;
+    use tengu_wgpu::BufferUsage;
+
+    use crate::Backend as WGPUBackend;
+
+    #[tokio::test]
+    async fn scalar_without_buffer() {
+        let backend = WGPUBackend::new().await.unwrap();
+        let a = backend.tensor("a", [4], &[1.0f32, 2.0, 3.0, 4.0]);
+        let c = backend.zero::<f32>("c", [4]);
+        let readouts = HashSet::new();
+        let mut processor = backend.processor(&readouts);
+        let a_var = processor.var(&a);
+        let scalar = processor.scalar(2.5f32);
+        let sum = processor.binary(a_var, scalar, Operator::Add);
+        let c_var = processor.var(&c);
+        let statement = processor.statement(c_var, sum);
+        processor.block([statement].into_iter()).unwrap();
+        let labels: Vec<_> = processor.sources().map(|source| source.label()).collect();
+        assert_eq!(labels, ["a", "c"]);
+        assert!(processor.shader().contains("(a[idx] + 2.5)"));
+    }
+
+    #[tokio::test]
+    async fn read_only_inputs() {
+        let backend = WGPUBackend::new().await.unwrap();
+        let a = backend.zero::<f32>("a", [4]);
+        let b = backend.zero::<f32>("b", [4]);
+        let readouts = HashSet::new();
+        let mut processor = backend.processor(&readouts);
+        let a_var = processor.var(&a);
+        let b_var = processor.var(&b);
+        let statement = processor.statement(b_var, a_var);
+        processor.block([statement].into_iter()).unwrap();
+        assert!(processor.shader().contains("var<storage, read> a: array<f32>;"));
+        assert!(processor.shader().contains("var<storage, read_write> b: array<f32>;"));
+        assert!(matches!(processor.usage("a"), BufferUsage::Read));
+        assert!(matches!(processor.usage("b"), BufferUsage::ReadWrite));
     }
 }
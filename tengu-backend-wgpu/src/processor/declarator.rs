@@ -3,10 +3,14 @@
 //!
 //! The `Declarator` struct is used to create and manage storage variable declarations
 //! for tensors, which are then used in shader programs.
+//!
+//! Tensors are declared read-only unless a statement of the shader writes them, regardless of the
+//! usage their buffers were created with. The bind group layout has to bind the buffers with the
+//! same usage, which is why the declarator also reports it.
 
 use itertools::Itertools;
 use std::collections::HashMap;
-use tengu_backend_tensor::StorageType;
+use tengu_backend_tensor::{Pod, StorageType};
 use tengu_wgpu::BufferUsage;
 
 use crate::source::Source;
@@ -16,7 +20,14 @@ const GROUP: usize = 0;
 
 /// A struct for declaring shader storage variables.
 pub struct Declarator<'a> {
-    declarations: HashMap<&'a str, String>,
+    declarations: HashMap<&'a str, Declaration>,
+}
+
+/// A single storage variable declaration.
+struct Declaration {
+    binding: usize,
+    usage: BufferUsage,
+    ty: &'static str,
 }
 
 impl Declarator<'_> {
@@ -35,7 +46,10 @@ impl Declarator<'_> {
     /// # Returns
     /// A `String` containing the header with all declarations.
     pub fn header(&self) -> String {
-        self.declarations.values().join("\n")
+        self.declarations
+            .iter()
+            .map(|(label, declaration)| declaration.emit(label))
+            .join("\n")
     }
 }
 
@@ -51,23 +65,80 @@ impl<'a> Declarator<'a> {
     pub fn var<T: StorageType>(&mut self, binding: usize, tensor: &'a Tensor<T>) {
         self.declarations
             .entry(tensor.label())
-            .or_insert_with(|| declaration(binding, tensor));
+            .or_insert_with(|| Declaration::new::<T>(binding));
+    }
+
+    /// Marks a previously declared variable as written by the shader, so that it is declared with
+    /// read-write access.
+    ///
+    /// # Parameters
+    /// - `label`: The label of the variable.
+    pub fn write(&mut self, label: &str) {
+        if let Some(declaration) = self.declarations.get_mut(label) {
+            declaration.usage = BufferUsage::ReadWrite;
+        }
+    }
+
+    /// Returns the usage with which a previously declared variable has to be bound.
+    ///
+    /// # Parameters
+    /// - `label`: The label of the variable.
+    ///
+    /// # Returns
+    /// `BufferUsage::ReadWrite` if the variable is written by the shader and `BufferUsage::Read`
+    /// otherwise.
+    ///
+    /// # Panics
+    /// Panics if the variable has not been declared.
+    pub fn usage(&self, label: &str) -> BufferUsage {
+        self.declarations
+            .get(label)
+            .map(|declaration| declaration.usage)
+            .expect("variable should be declared")
+    }
+
+    /// Turns the declaration of a previously declared `u32` variable into an atomic one, so that it
+    /// can be used as a target of atomic operations.
+    ///
+    /// # Parameters
+    /// - `label`: The label of the variable.
+    pub fn atomic(&mut self, label: &str) {
+        if let Some(declaration) = self.declarations.get_mut(label) {
+            declaration.ty = "atomic<u32>";
+        }
     }
 }
 
-/// Generates a declaration string for a tensor.
-///
-/// # Parameters
-/// - `binding`: The binding index for the shader variable.
-/// - `tensor`: The tensor to declare as a shader variable.
-///
-/// # Returns
-/// A `String` containing the declaration for the tensor.
-fn declaration<T: StorageType>(binding: usize, tensor: &Tensor<T>) -> String {
-    let label = tensor.label();
-    let access = access(tensor.buffer().usage());
-    let ty = std::any::type_name::<T>();
-    format!("@group({GROUP}) @binding({binding}) var<storage, {access}> {label}: array<{ty}>;")
+impl Declaration {
+    /// Creates a declaration for a tensor. Tensors are declared as arrays of their in-memory
+    /// representation, so boolean tensors are declared as `array<u32>`. The declaration is
+    /// read-only until the tensor is marked as written.
+    ///
+    /// # Parameters
+    /// - `binding`: The binding index for the shader variable.
+    ///
+    /// # Returns
+    /// A new `Declaration` for a tensor of type `T`.
+    fn new<T: StorageType>(binding: usize) -> Self {
+        Self {
+            binding,
+            usage: BufferUsage::Read,
+            ty: std::any::type_name::<Pod<T>>(),
+        }
+    }
+
+    /// Generates a declaration string.
+    ///
+    /// # Parameters
+    /// - `label`: The label of the shader variable.
+    ///
+    /// # Returns
+    /// A `String` containing the declaration.
+    fn emit(&self, label: &str) -> String {
+        let Self { binding, usage, ty } = self;
+        let access = access(*usage);
+        format!("@group({GROUP}) @binding({binding}) var<storage, {access}> {label}: array<{ty}>;")
+    }
 }
 
 /// Determines the access type for a buffer based on its usage.
@@ -82,7 +153,7 @@ fn access(usage: BufferUsage) -> &'static str {
         BufferUsage::Read => "read",
         BufferUsage::Write => "write",
         BufferUsage::ReadWrite => "read_write",
-        BufferUsage::Staging => panic!("cannot declare a staging buffer in a shader"),
+        BufferUsage::Staging | BufferUsage::Upload => panic!("cannot declare a staging buffer in a shader"),
     }
 }
 
@@ -116,6 +187,7 @@ mod tests {
         processor.var(0, &a);
         processor.var(1, &b);
         processor.var(2, &c);
+        processor.write("c");
         let header = processor.header();
         let declarations = header.lines().collect::<Vec<_>>();
         let re = RegexSet::new([
@@ -129,4 +201,18 @@ mod tests {
             assert!(re.is_match(declaration));
         }
     }
+
+    #[tokio::test]
+    async fn usage() {
+        let backend = WGPUBackend::new().await.unwrap();
+        let a = backend.zero::<f32>("a", [4]);
+        let b = backend.zero::<f32>("b", [4]);
+        let mut processor = Declarator::new();
+        processor.var(0, &a);
+        processor.var(1, &b);
+        processor.write("b");
+        assert!(matches!(processor.usage("a"), BufferUsage::Read));
+        assert!(matches!(processor.usage("b"), BufferUsage::ReadWrite));
+        assert!(processor.header().contains("var<storage, read> a: array<f32>;"));
+    }
 }
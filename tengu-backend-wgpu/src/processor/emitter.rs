@@ -4,15 +4,20 @@
 //! The `Emitter` struct is used to create and manage expressions and statements
 //! for use in compute shaders, in the tensor body, not in the variable declaration part.
 
+use std::ops::Range;
+
 use indoc::formatdoc;
 use itertools::Itertools;
-use tengu_backend_tensor::{Function, Operator, StorageType, Type};
+use tengu_backend_tensor::{CastMode, Function, Operator, StorageType, Type};
 
 use crate::source::Source;
 use crate::tensor::Tensor;
+use crate::UNSUPPORTED_16BIT;
 
 pub struct Emitter {
     expression: String,
+    functions: Vec<String>,
+    workgroup: bool,
 }
 
 /// A struct for generating shader code expressions and statements.
@@ -24,31 +29,42 @@ impl Emitter {
     pub fn new() -> Self {
         Self {
             expression: String::new(),
+            functions: Vec::new(),
+            workgroup: false,
         }
     }
 
-    /// Generates the body of the compute shader.
+    /// Generates the body of the compute shader, preceded by the helper functions used by the
+    /// expressions. If a statement cooperates within a workgroup, the entry point also receives the
+    /// index of the invocation in its workgroup and the index of the workgroup.
     ///
     /// # Returns
     /// A `String` containing the shader body with all expressions.
     pub fn body(&self) -> String {
-        formatdoc!(
+        let functions = self.functions.iter().map(|function| format!("{function}\n\n")).join("");
+        let builtins = match self.workgroup {
+            true => ", @builtin(local_invocation_index) lane: u32, @builtin(workgroup_id) group: vec3<u32>",
+            false => "",
+        };
+        functions
+            + &formatdoc!(
             r"
             @compute
             @workgroup_size(64)
-            fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {{
+            fn main(@builtin(global_invocation_id) global_id: vec3<u32>{builtins}) {{
                 let idx = global_id.x;
                 {}
             }}",
-            self.expression,
-        )
+                self.expression,
+            )
     }
 }
 
 // NOTE: Processing interface
 
 impl Emitter {
-    /// Returns a string representation of the tensor variable.
+    /// Returns a string representation of the tensor variable. Boolean tensors are stored as `u32`,
+    /// so their elements are converted to `bool` when read.
     ///
     /// # Parameters
     /// - `tensor`: The tensor to declare as a variable.
@@ -56,10 +72,27 @@ impl Emitter {
     /// # Returns
     /// A `String` representing the variable declaration.
     pub fn var<T: StorageType>(&mut self, tensor: &Tensor<T>) -> String {
-        format!("{}[idx]", tensor.label())
+        match T::as_type() {
+            Type::Bool => format!("bool({}[idx])", tensor.label()),
+            _ => format!("{}[idx]", tensor.label()),
+        }
+    }
+
+    /// Returns a string representation of the element of an output tensor written by a statement.
+    /// Unlike `var`, the element is not converted, so boolean outputs are written as their `u32`
+    /// storage.
+    ///
+    /// # Parameters
+    /// - `label`: The label of the output tensor.
+    ///
+    /// # Returns
+    /// A `String` representing the output element.
+    pub fn output(&self, label: &str) -> String {
+        format!("{label}[idx]")
     }
 
-    /// Return a string representation of a scalar literal.
+    /// Return a string representation of a scalar literal. Whole-number floats are formatted with
+    /// a decimal point, since WGSL would otherwise read them as integer literals.
     ///
     /// # Parameters
     /// - `value`: The scalar value.
@@ -67,20 +100,33 @@ impl Emitter {
     /// # Returns
     /// A `String` representing the scalar value.
     pub fn scalar<T: StorageType>(&mut self, value: T) -> String {
-        value.to_string()
+        let literal = value.to_string();
+        if T::as_type() == Type::F32 && !literal.contains(['.', 'e']) {
+            return format!("{literal}.0");
+        }
+        literal
     }
 
     /// Returns a string representation of unary function expression.
     ///
     /// # Parameters
     /// - `inner`: The inner expression.
-    /// - `symbol`: The unary function symbol.
+    /// - `function`: The unary function.
+    /// - `ty`: The type of the inner expression.
     ///
     /// # Returns
     /// A `String` representing the unary function application.
-    pub fn unary_fn(&mut self, inner: String, function: Function) -> String {
-        let symbol = fn_symbol(function);
-        format!("{symbol}({inner})")
+    pub fn unary_fn(&mut self, inner: String, function: Function, ty: Type) -> String {
+        match (function, ty) {
+            // NOTE: WGSL only defines `sign` for signed types. Unsigned values are either zero or
+            // positive, and booleans are their own sign.
+            (Function::Sign, Type::U32 | Type::U16) => format!("min({inner}, 1u)"),
+            (Function::Sign, Type::Bool) => inner,
+            (function, _) => {
+                let symbol = fn_symbol(function);
+                format!("{symbol}({inner})")
+            }
+        }
     }
 
     /// Returns a string representation of a binary expression.
@@ -88,38 +134,593 @@ impl Emitter {
     /// # Parameters
     /// - `lhs`: The left-hand side expression.
     /// - `rhs`: The right-hand side expression.
-    /// - `symbol`: The binary operator symbol.
+    /// - `operation`: The binary operator.
+    /// - `ty`: The type of the operands.
     ///
     /// # Returns
     /// A `String` representing the binary operation.
-    pub fn binary(&mut self, lhs: String, rhs: String, operation: Operator) -> String {
-        let symbol = op_symbol(operation);
-        format!("({lhs} {symbol} {rhs})")
+    pub fn binary(&mut self, lhs: String, rhs: String, operation: Operator, ty: Type) -> String {
+        match operation {
+            // NOTE: WGSL has no copysign, and `sign` would zero the result for zero right-hand sides.
+            // Unsigned values and booleans can't be negative, so they keep their magnitude.
+            Operator::Copysign => match ty {
+                Type::F32 => format!("(abs({lhs}) * select(1.0, -1.0, {rhs} < 0.0))"),
+                Type::I32 | Type::I16 => format!("(abs({lhs}) * select(1, -1, {rhs} < 0))"),
+                Type::U32 | Type::U16 | Type::Bool => lhs,
+            },
+            Operator::Max => format!("max({lhs}, {rhs})"),
+            Operator::Min => format!("min({lhs}, {rhs})"),
+            // NOTE: WGSL only orders numbers, so booleans are compared as `u32` with false before true.
+            Operator::Gt | Operator::Lt | Operator::Ge | Operator::Le if ty == Type::Bool => {
+                let symbol = op_symbol(operation);
+                format!("(u32({lhs}) {symbol} u32({rhs}))")
+            }
+            operation => {
+                let symbol = op_symbol(operation);
+                format!("({lhs} {symbol} {rhs})")
+            }
+        }
     }
 
-    /// Returns a string representation of a cast expression.
+    /// Returns a string representation of an element-wise selection. WGSL's `select` takes the
+    /// value for a false condition first.
+    ///
+    /// # Parameters
+    /// - `mask`: The boolean mask expression.
+    /// - `on_true`: The expression selected where the mask is true.
+    /// - `on_false`: The expression selected where the mask is false.
+    ///
+    /// # Returns
+    /// A `String` representing the selection.
+    pub fn select(&mut self, mask: String, on_true: String, on_false: String) -> String {
+        format!("select({on_false}, {on_true}, {mask})")
+    }
+
+    /// Returns a string representation of a cast expression. WGSL conversions between `i32` and
+    /// `u32` keep the bits, so saturating casts clamp the value first. Casts from floats to integers
+    /// are emitted as helper functions, since the out-of-range results of WGSL conversions differ
+    /// between implementations.
     ///
     /// # Parameters
     /// - `inner`: The inner expression.
+    /// - `from`: The type of the inner expression.
     /// - `ty`: The target type.
+    /// - `mode`: The way out-of-range values are cast.
     ///
     /// # Returns
     /// A `String` representing the casted expression.
-    pub fn cast(&mut self, inner: String, ty: Type) -> String {
+    pub fn cast(&mut self, inner: String, from: Type, ty: Type, mode: CastMode) -> String {
         let symbol = type_symbol(ty);
-        format!("{symbol}({inner})")
+        match (from, ty, mode) {
+            (Type::F32, Type::U32 | Type::I32, mode) => {
+                let name = format!("cast_{}", self.functions.len());
+                let function = match (ty, mode) {
+                    (Type::U32, CastMode::Saturate) => formatdoc!(
+                        r"
+                        fn {name}(value: f32) -> u32 {{
+                            if value >= 4294967296.0 {{
+                                return 4294967295u;
+                            }}
+                            if value > 0.0 {{
+                                return u32(value);
+                            }}
+                            return 0u;
+                        }}"
+                    ),
+                    (_, CastMode::Saturate) => formatdoc!(
+                        r"
+                        fn {name}(value: f32) -> i32 {{
+                            if value >= 2147483648.0 {{
+                                return 2147483647;
+                            }}
+                            if value >= -2147483648.0 {{
+                                return i32(value);
+                            }}
+                            if value < 0.0 {{
+                                return -2147483648;
+                            }}
+                            return 0;
+                        }}"
+                    ),
+                    (_, CastMode::Wrap) => formatdoc!(
+                        r"
+                        fn {name}(value: f32) -> {symbol} {{
+                            let truncated = trunc(value);
+                            if abs(truncated) < 2147483648.0 {{
+                                return bitcast<{symbol}>(i32(truncated));
+                            }}
+                            return bitcast<{symbol}>(u32(truncated - 4294967296.0 * floor(truncated / 4294967296.0)));
+                        }}"
+                    ),
+                };
+                self.functions.push(function);
+                format!("{name}({inner})")
+            }
+            (Type::I32, Type::U32, CastMode::Saturate) => format!("u32(max({inner}, 0))"),
+            (Type::U32, Type::I32, CastMode::Saturate) => format!("i32(min({inner}, 2147483647u))"),
+            _ => format!("{symbol}({inner})"),
+        }
+    }
+
+    /// Returns a string representation of the mean of an expression along an axis. Since the mean
+    /// needs the values of the expression at other indices, it is emitted as a helper function
+    /// which evaluates the inner expression in a loop with a shadowed `idx`, and the returned
+    /// representation is a call of this function for the current index.
+    ///
+    /// # Parameters
+    /// - `inner`: The inner expression.
+    /// - `shape`: The shape of the inner expression.
+    /// - `axis`: The axis along which the mean is computed.
+    ///
+    /// # Returns
+    /// A `String` representing the mean for the current index.
+    pub fn mean(&mut self, inner: String, shape: &[usize], axis: usize) -> String {
+        let name = format!("mean_{}", self.functions.len());
+        let len = shape[axis];
+        let stride = shape[axis + 1..].iter().product::<usize>();
+        let function = formatdoc!(
+            r"
+            fn {name}(out_idx: u32) -> f32 {{
+                let base = (out_idx / {stride}u) * {outer_stride}u + out_idx % {stride}u;
+                var sum = 0.0;
+                for (var k = 0u; k < {len}u; k++) {{
+                    let idx = base + k * {stride}u;
+                    sum += f32({inner});
+                }}
+                return sum / {len_f32:?};
+            }}",
+            outer_stride = len * stride,
+            len_f32 = len as f32,
+        );
+        self.functions.push(function);
+        format!("{name}(idx)")
+    }
+
+    /// Returns a string representation of an expression tiled along its axes. The index of the
+    /// output element is split into coordinates of the output shape, which are wrapped around the
+    /// inner shape with modular arithmetic. The inner expression is then evaluated at the
+    /// resulting index in an emitted helper function, like for the mean.
+    ///
+    /// # Parameters
+    /// - `inner`: The inner expression.
+    /// - `shape`: The shape of the inner expression.
+    /// - `reps`: The number of repetitions along each axis.
+    /// - `ty`: The element type of the inner expression.
+    ///
+    /// # Returns
+    /// A `String` representing the tiled expression for the current index.
+    pub fn tile(&mut self, inner: String, shape: &[usize], reps: &[usize], ty: Type) -> String {
+        let name = format!("tile_{}", self.functions.len());
+        let out_shape = shape.iter().zip(reps).map(|(dim, rep)| dim * rep).collect::<Vec<_>>();
+        let index = (0..shape.len())
+            .map(|axis| {
+                let out_stride = out_shape[axis + 1..].iter().product::<usize>();
+                let stride = shape[axis + 1..].iter().product::<usize>();
+                format!("((out_idx / {out_stride}u) % {}u) * {stride}u", shape[axis])
+            })
+            .join(" + ");
+        let function = formatdoc!(
+            r"
+            fn {name}(out_idx: u32) -> {ty} {{
+                let idx = {index};
+                return {inner};
+            }}",
+            ty = type_symbol(ty),
+        );
+        self.functions.push(function);
+        format!("{name}(idx)")
+    }
+
+    /// Returns a string representation of an expression padded with a constant value. The index
+    /// of the output element is split into coordinates of the padded shape. If any coordinate falls
+    /// into the padding, the padding value is returned, and otherwise the inner expression is
+    /// evaluated at the index shifted by the padding in an emitted helper function, like for the
+    /// tile.
+    ///
+    /// # Parameters
+    /// - `inner`: The inner expression.
+    /// - `shape`: The shape of the inner expression.
+    /// - `padding`: The number of elements added before and after each axis.
+    /// - `value`: The padding value.
+    /// - `ty`: The element type of the inner expression.
+    ///
+    /// # Returns
+    /// A `String` representing the padded expression for the current index.
+    pub fn pad(
+        &mut self,
+        inner: String,
+        shape: &[usize],
+        padding: &[(usize, usize)],
+        value: String,
+        ty: Type,
+    ) -> String {
+        let name = format!("pad_{}", self.functions.len());
+        let out_shape = shape
+            .iter()
+            .zip(padding)
+            .map(|(dim, (before, after))| before + dim + after)
+            .collect::<Vec<_>>();
+        let coords = (0..shape.len())
+            .map(|axis| {
+                let out_stride = out_shape[axis + 1..].iter().product::<usize>();
+                format!("let coord_{axis} = (out_idx / {out_stride}u) % {}u;", out_shape[axis])
+            })
+            .join("\n    ");
+        let outside = padding
+            .iter()
+            .zip(shape)
+            .enumerate()
+            .map(|(axis, ((before, _), dim))| format!("coord_{axis} < {before}u || coord_{axis} >= {}u", before + dim))
+            .join(" || ");
+        let index = padding
+            .iter()
+            .enumerate()
+            .map(|(axis, (before, _))| {
+                let stride = shape[axis + 1..].iter().product::<usize>();
+                format!("(coord_{axis} - {before}u) * {stride}u")
+            })
+            .join(" + ");
+        let function = formatdoc!(
+            r"
+            fn {name}(out_idx: u32) -> {ty} {{
+                {coords}
+                if ({outside}) {{
+                    return {ty}({value});
+                }}
+                let idx = {index};
+                return {inner};
+            }}",
+            ty = type_symbol(ty),
+        );
+        self.functions.push(function);
+        format!("{name}(idx)")
+    }
+
+    /// Returns a string representation of the elements of an expression at the given flat indices.
+    /// The indices are baked into the emitted helper function as a constant array, which is looked
+    /// up by the index of the output element to evaluate the inner expression, like for the tile.
+    ///
+    /// # Parameters
+    /// - `inner`: The inner expression.
+    /// - `indices`: The flat indices of the taken elements.
+    /// - `ty`: The element type of the inner expression.
+    ///
+    /// # Returns
+    /// A `String` representing the taken element for the current index.
+    pub fn take(&mut self, inner: String, indices: &[usize], ty: Type) -> String {
+        let name = format!("take_{}", self.functions.len());
+        let count = indices.len();
+        let indices = indices.iter().map(|idx| format!("{idx}u")).join(", ");
+        let function = formatdoc!(
+            r"
+            fn {name}(out_idx: u32) -> {ty} {{
+                var indices = array<u32, {count}>({indices});
+                let idx = indices[out_idx];
+                return {inner};
+            }}",
+            ty = type_symbol(ty),
+        );
+        self.functions.push(function);
+        format!("{name}(idx)")
+    }
+
+    /// Returns a string representation of a contiguous range of an expression along an axis. Like
+    /// the take, it is emitted as a helper function which evaluates the inner expression at the
+    /// index of the sliced element in the inner expression.
+    ///
+    /// # Parameters
+    /// - `inner`: The inner expression.
+    /// - `shape`: The shape of the inner expression.
+    /// - `axis`: The axis along which the range is taken.
+    /// - `range`: The range of indices along the axis.
+    /// - `ty`: The element type of the inner expression.
+    ///
+    /// # Returns
+    /// A `String` representing the sliced element for the current index.
+    pub fn slice(&mut self, inner: String, shape: &[usize], axis: usize, range: Range<usize>, ty: Type) -> String {
+        let name = format!("slice_{}", self.functions.len());
+        let stride = shape[axis + 1..].iter().product::<usize>();
+        let function = formatdoc!(
+            r"
+            fn {name}(out_idx: u32) -> {ty} {{
+                let idx = (out_idx / {slice_stride}u) * {outer_stride}u + {offset}u + out_idx % {slice_stride}u;
+                return {inner};
+            }}",
+            ty = type_symbol(ty),
+            slice_stride = range.len() * stride,
+            outer_stride = shape[axis] * stride,
+            offset = range.start * stride,
+        );
+        self.functions.push(function);
+        format!("{name}(idx)")
+    }
+
+    /// Returns a string representation of an expression reversed along some of its axes. The index
+    /// of the output element is split into coordinates, and the coordinates along the flipped axes
+    /// are mirrored. The inner expression is then evaluated at the resulting index in an emitted
+    /// helper function, like for the tile.
+    ///
+    /// # Parameters
+    /// - `inner`: The inner expression.
+    /// - `shape`: The shape of the inner expression.
+    /// - `axes`: The axes along which the elements are reversed.
+    /// - `ty`: The element type of the inner expression.
+    ///
+    /// # Returns
+    /// A `String` representing the flipped expression for the current index.
+    pub fn flip(&mut self, inner: String, shape: &[usize], axes: &[usize], ty: Type) -> String {
+        let name = format!("flip_{}", self.functions.len());
+        let index = (0..shape.len())
+            .map(|axis| {
+                let dim = shape[axis];
+                let stride = shape[axis + 1..].iter().product::<usize>();
+                if axes.contains(&axis) {
+                    format!("({}u - (out_idx / {stride}u) % {dim}u) * {stride}u", dim - 1)
+                } else {
+                    format!("((out_idx / {stride}u) % {dim}u) * {stride}u")
+                }
+            })
+            .join(" + ");
+        let function = formatdoc!(
+            r"
+            fn {name}(out_idx: u32) -> {ty} {{
+                let idx = {index};
+                return {inner};
+            }}",
+            ty = type_symbol(ty),
+        );
+        self.functions.push(function);
+        format!("{name}(idx)")
+    }
+
+    /// Returns a string representation of an expression shifted circularly along an axis. The
+    /// coordinate of the output element along the axis is moved back by the shift with wraparound,
+    /// and the inner expression is evaluated at the resulting index in an emitted helper function,
+    /// like for the tile.
+    ///
+    /// # Parameters
+    /// - `inner`: The inner expression.
+    /// - `shape`: The shape of the inner expression.
+    /// - `axis`: The axis along which the elements are shifted.
+    /// - `shift`: The number of positions the elements are shifted by, less than the length of the
+    ///   axis.
+    /// - `ty`: The element type of the inner expression.
+    ///
+    /// # Returns
+    /// A `String` representing the rolled expression for the current index.
+    pub fn roll(&mut self, inner: String, shape: &[usize], axis: usize, shift: usize, ty: Type) -> String {
+        let name = format!("roll_{}", self.functions.len());
+        let dim = shape[axis];
+        let stride = shape[axis + 1..].iter().product::<usize>();
+        let function = formatdoc!(
+            r"
+            fn {name}(out_idx: u32) -> {ty} {{
+                let coord = (out_idx / {stride}u) % {dim}u;
+                let idx = out_idx - coord * {stride}u + ((coord + {back}u) % {dim}u) * {stride}u;
+                return {inner};
+            }}",
+            back = dim - shift,
+            ty = type_symbol(ty),
+        );
+        self.functions.push(function);
+        format!("{name}(idx)")
+    }
+
+    /// Returns a string representation of expressions joined along a new axis. The coordinate of
+    /// the output element along the new axis selects the inner expression, which is evaluated at
+    /// the index with that coordinate removed in an emitted helper function, like for the tile.
+    ///
+    /// # Parameters
+    /// - `inners`: The inner expressions, in the order they appear along the new axis.
+    /// - `shape`: The shape shared by the inner expressions.
+    /// - `axis`: The position of the new axis.
+    /// - `ty`: The element type of the inner expressions.
+    ///
+    /// # Returns
+    /// A `String` representing the stacked expression for the current index.
+    pub fn stack(&mut self, inners: Vec<String>, shape: &[usize], axis: usize, ty: Type) -> String {
+        let name = format!("stack_{}", self.functions.len());
+        let stride = shape[axis..].iter().product::<usize>();
+        let parts = inners.len();
+        let last = parts - 1;
+        let branches = inners[..last]
+            .iter()
+            .enumerate()
+            .map(|(part, inner)| format!("if (part == {part}u) {{\n        return {inner};\n    }}"))
+            .join("\n    ");
+        let function = formatdoc!(
+            r"
+            fn {name}(out_idx: u32) -> {ty} {{
+                let part = (out_idx / {stride}u) % {parts}u;
+                let idx = (out_idx / {outer_stride}u) * {stride}u + out_idx % {stride}u;
+                {branches}
+                return {inner};
+            }}",
+            outer_stride = stride * parts,
+            inner = inners[last],
+            ty = type_symbol(ty),
+        );
+        self.functions.push(function);
+        format!("{name}(idx)")
+    }
+
+    /// Returns a string representation of the softmax of an expression along an axis. Like the
+    /// mean, it is emitted as a helper function evaluating the inner expression in loops over the
+    /// axis: the first one finds the maximum, which is subtracted before exponentiation for numeric
+    /// stability, and the second one computes the normalizing sum.
+    ///
+    /// # Parameters
+    /// - `inner`: The inner expression.
+    /// - `shape`: The shape of the inner expression.
+    /// - `axis`: The axis along which the softmax is normalized.
+    ///
+    /// # Returns
+    /// A `String` representing the softmax for the current index.
+    pub fn softmax(&mut self, inner: String, shape: &[usize], axis: usize) -> String {
+        let name = format!("softmax_{}", self.functions.len());
+        let len = shape[axis];
+        let stride = shape[axis + 1..].iter().product::<usize>();
+        let function = formatdoc!(
+            r"
+            fn {name}(out_idx: u32) -> f32 {{
+                let base = (out_idx / {outer_stride}u) * {outer_stride}u + out_idx % {stride}u;
+                var max_value = {min:?};
+                for (var k = 0u; k < {len}u; k++) {{
+                    let idx = base + k * {stride}u;
+                    max_value = max(max_value, f32({inner}));
+                }}
+                var sum = 0.0;
+                for (var k = 0u; k < {len}u; k++) {{
+                    let idx = base + k * {stride}u;
+                    sum += exp(f32({inner}) - max_value);
+                }}
+                let idx = out_idx;
+                return exp(f32({inner}) - max_value) / sum;
+            }}",
+            outer_stride = len * stride,
+            min = f32::MIN,
+        );
+        self.functions.push(function);
+        format!("{name}(idx)")
+    }
+
+    /// Returns a string representation of the one-hot encoding of an expression of class indices.
+    /// The index of the output element is split into the row, at which the inner expression is
+    /// evaluated in an emitted helper function, and the column, which is compared to the class
+    /// index. Out-of-range class indices never match a column, so their rows are zeros.
+    ///
+    /// # Parameters
+    /// - `inner`: The inner expression.
+    /// - `classes`: The number of classes.
+    ///
+    /// # Returns
+    /// A `String` representing the encoded value for the current index.
+    pub fn one_hot(&mut self, inner: String, classes: usize) -> String {
+        let name = format!("one_hot_{}", self.functions.len());
+        let function = formatdoc!(
+            r"
+            fn {name}(out_idx: u32) -> f32 {{
+                let idx = out_idx / {classes}u;
+                return select(0.0, 1.0, f32({inner}) == f32(out_idx % {classes}u));
+            }}"
+        );
+        self.functions.push(function);
+        format!("{name}(idx)")
     }
 
     /// Return a string representation of a statement.
     ///
     /// # Parameters
-    /// - `out`: The output variable.
+    /// - `out`: The output element, as returned by `output`.
     /// - `expr`: The expression to assign to the output.
+    /// - `ty`: The element type of the output.
+    ///
+    /// # Returns
+    /// A `String` representing the statement.
+    pub fn statement(&mut self, out: String, expr: String, ty: Type) -> String {
+        format!("{};", assignment(&out, &expr, ty))
+    }
+
+    /// Returns a string representation of a statement counting the values of an expression into
+    /// bins of the output. The statement is guarded by the element count of the expression, so that
+    /// no value is counted more than once, and uses atomic operations to increment the bins, so the
+    /// output has to be declared as atomic. Values outside of `[min, max)` range are ignored.
+    ///
+    /// # Parameters
+    /// - `label`: The label of the output tensor, which is indexed by the bin of each value.
+    /// - `expr`: The expression whose values are counted.
+    /// - `count`: The number of elements in the expression.
+    /// - `bins`: The number of bins.
+    /// - `min`: The inclusive lower bound of the binned range.
+    /// - `max`: The exclusive upper bound of the binned range.
+    ///
+    /// # Returns
+    /// A `String` representing the statement.
+    pub fn bincount(&mut self, label: &str, expr: String, count: usize, bins: usize, min: f32, max: f32) -> String {
+        let scale = bins as f32 / (max - min);
+        let last = bins - 1;
+        format!(
+            "if (idx < {count}u) {{ let bin_value = f32({expr}); if (bin_value >= {min:?} && bin_value < {max:?}) \
+             {{ let bin = min(u32((bin_value - {min:?}) * {scale:?}), {last}u); atomicAdd(&{label}[bin], 1u); }} }}"
+        )
+    }
+
+    /// Returns a string representation of a statement packing the values of an expression where the
+    /// mask is true at the start of the output. The output position of each selected value is the
+    /// number of true mask values before it, which the invocations of the first workgroup compute
+    /// with a parallel prefix sum over the mask in workgroup memory, one workgroup-sized chunk at a
+    /// time, carrying the number of selected values over to the next chunk. The rest of the output
+    /// is filled with zeros and the number of selected values is written to the length tensor.
+    ///
+    /// # Parameters
+    /// - `out`: The output variable.
+    /// - `expr`: The expression whose values are selected.
+    /// - `mask`: The boolean mask expression.
+    /// - `length`: The variable receiving the number of selected values.
+    /// - `count`: The number of elements in the expression.
+    /// - `ty`: The element type of the expression.
     ///
     /// # Returns
     /// A `String` representing the statement.
-    pub fn statement(&mut self, out: String, expr: String) -> String {
-        format!("{out} = {expr};")
+    pub fn mask_select(
+        &mut self,
+        out: String,
+        expr: String,
+        mask: String,
+        length: String,
+        count: usize,
+        ty: Type,
+    ) -> String {
+        let scan = format!("scan_{}", self.functions.len());
+        self.functions.push(format!("var<workgroup> {scan}: array<u32, 64>;"));
+        self.workgroup = true;
+        let select = assignment(&out, "value", ty);
+        let clear = assignment(&out, &format!("{}()", type_symbol(ty)), ty);
+        format!(
+            "if (group.x == 0u) {{ var selected = 0u; for (var base = 0u; base < {count}u; base += 64u) \
+             {{ let i = base + lane; var flag = 0u; \
+             if (i < {count}u) {{ let idx = i; flag = select(0u, 1u, {mask}); }} \
+             {scan}[lane] = flag; workgroupBarrier(); \
+             for (var offset = 1u; offset < 64u; offset *= 2u) {{ var prior = 0u; \
+             if (lane >= offset) {{ prior = {scan}[lane - offset]; }} workgroupBarrier(); \
+             {scan}[lane] += prior; workgroupBarrier(); }} \
+             if (flag == 1u) {{ let idx = i; let value = {expr}; \
+             {{ let idx = selected + {scan}[lane] - 1u; {select}; }} }} \
+             selected += workgroupUniformLoad(&{scan}[63]); }} \
+             for (var i = selected + lane; i < {count}u; i += 64u) {{ let idx = i; {clear}; }} \
+             if (lane == 0u) {{ let idx = 0u; {length} = selected; }} }}"
+        )
+    }
+
+    /// Generates a compute shader that sets all elements of the specified `u32` tensors to zero.
+    ///
+    /// # Parameters
+    /// - `labels`: The labels of the tensors to clear. Their bindings are assigned in order.
+    ///
+    /// # Returns
+    /// A `String` containing the full shader code.
+    pub fn clear<'a>(&self, labels: impl Iterator<Item = &'a str>) -> String {
+        let (declarations, statements): (Vec<_>, Vec<_>) = labels
+            .enumerate()
+            .map(|(binding, label)| {
+                let declaration = format!("@group(0) @binding({binding}) var<storage, read_write> {label}: array<u32>;");
+                let statement = format!("if (idx < arrayLength(&{label})) {{ {label}[idx] = 0u; }}");
+                (declaration, statement)
+            })
+            .unzip();
+        formatdoc!(
+            r"
+            {}
+
+            @compute
+            @workgroup_size(64)
+            fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {{
+                let idx = global_id.x;
+                {}
+            }}",
+            declarations.join("\n"),
+            statements.join("\n    "),
+        )
     }
 
     /// Processes a block of expressions. The final representation is stored inside the emitter.
@@ -135,6 +736,7 @@ fn fn_symbol(function: Function) -> &'static str {
     match function {
         Function::Log => "log",
         Function::Exp => "exp",
+        Function::Sign => "sign",
     }
 }
 
@@ -146,15 +748,40 @@ fn op_symbol(operator: Operator) -> &'static str {
         Operator::Div => "/",
         Operator::Eq => "==",
         Operator::Neq => "!=",
+        Operator::Gt => ">",
+        Operator::Lt => "<",
+        Operator::Ge => ">=",
+        Operator::Le => "<=",
+        Operator::Copysign | Operator::Max | Operator::Min => {
+            unreachable!("{operator:?} is not an infix operator")
+        }
     }
 }
 
-fn type_symbol(ty: Type) -> &'static str {
+/// Creates an assignment of a value to an output element. Boolean tensors are stored as `u32`, so
+/// boolean values are converted before they are assigned.
+///
+/// # Parameters
+/// - `out`: The output element.
+/// - `value`: The value to assign.
+/// - `ty`: The element type of the output.
+///
+/// # Returns
+/// A `String` representing the assignment without the trailing semicolon.
+fn assignment(out: &str, value: &str, ty: Type) -> String {
+    match ty {
+        Type::Bool => format!("{out} = u32({value})"),
+        _ => format!("{out} = {value}"),
+    }
+}
+
+pub(crate) fn type_symbol(ty: Type) -> &'static str {
     match ty {
         Type::Bool => "bool",
         Type::U32 => "u32",
         Type::I32 => "i32",
         Type::F32 => "f32",
+        Type::U16 | Type::I16 => panic!("{}", UNSUPPORTED_16BIT),
     }
 }
 
@@ -185,23 +812,49 @@ mod tests {
         assert_eq!(scalar, "2.37");
     }
 
+    #[tokio::test]
+    async fn whole_float_scalar() {
+        let mut processor = Emitter::new();
+        assert_eq!(processor.scalar(1.0f32), "1.0");
+        assert_eq!(processor.scalar(-3.0f32), "-3.0");
+        assert_eq!(processor.scalar(1i32), "1");
+        assert_eq!(processor.scalar(7u32), "7");
+    }
+
     #[tokio::test]
     async fn cast() {
         let backend = WGPUBackend::new().await.unwrap();
         let a = backend.tensor("a", [4], &[1, 2, 3, 4]);
         let mut processor = Emitter::new();
         let a = processor.var(&a);
-        let cast_a = processor.cast(a, Type::F32);
+        let cast_a = processor.cast(a, Type::I32, Type::F32, CastMode::Saturate);
         assert_eq!(cast_a, "f32(a[idx])");
     }
 
+    #[tokio::test]
+    async fn cast_modes() {
+        let backend = WGPUBackend::new().await.unwrap();
+        let a = backend.tensor("a", [4], &[1, 2, 3, 4]);
+        let b = backend.tensor("b", [4], &[1.0, 2.0, 3.0, 4.0]);
+        let mut processor = Emitter::new();
+        let a = processor.var(&a);
+        let b = processor.var(&b);
+        let saturated = processor.cast(a.clone(), Type::I32, Type::U32, CastMode::Saturate);
+        assert_eq!(saturated, "u32(max(a[idx], 0))");
+        let wrapped = processor.cast(a, Type::I32, Type::U32, CastMode::Wrap);
+        assert_eq!(wrapped, "u32(a[idx])");
+        let wrapped = processor.cast(b, Type::F32, Type::I32, CastMode::Wrap);
+        assert_eq!(wrapped, "cast_0(b[idx])");
+        assert!(processor.functions[0].starts_with("fn cast_0(value: f32) -> i32 {"));
+    }
+
     #[tokio::test]
     async fn unary_fn() {
         let backend = WGPUBackend::new().await.unwrap();
         let a = backend.tensor("a", [4], &[1, 2, 3, 4]);
         let mut processor = Emitter::new();
         let a = processor.var(&a);
-        let cast_a = processor.unary_fn(a, Function::Exp);
+        let cast_a = processor.unary_fn(a, Function::Exp, Type::F32);
         assert_eq!(cast_a, "exp(a[idx])");
     }
 
@@ -213,10 +866,79 @@ mod tests {
         let mut processor = Emitter::new();
         let a = processor.var(&a);
         let b = processor.var(&b);
-        let a_add_b = processor.binary(a, b, Operator::Mul);
+        let a_add_b = processor.binary(a, b, Operator::Mul, Type::I32);
         assert_eq!(a_add_b, "(a[idx] * b[idx])");
     }
 
+    #[tokio::test]
+    async fn sign_and_copysign() {
+        let backend = WGPUBackend::new().await.unwrap();
+        let a = backend.tensor("a", [2], &[1.0f32, -2.0]);
+        let b = backend.tensor("b", [2], &[-3.0f32, 0.0]);
+        let mut processor = Emitter::new();
+        let a = processor.var(&a);
+        let b = processor.var(&b);
+        let sign = processor.unary_fn(a.clone(), Function::Sign, Type::F32);
+        assert_eq!(sign, "sign(a[idx])");
+        let copysign = processor.binary(a, b, Operator::Copysign, Type::F32);
+        assert_eq!(copysign, "(abs(a[idx]) * select(1.0, -1.0, b[idx] < 0.0))");
+    }
+
+    #[tokio::test]
+    async fn integer_sign_and_copysign() {
+        let backend = WGPUBackend::new().await.unwrap();
+        let a = backend.tensor("a", [2], &[1i32, -2]);
+        let b = backend.tensor("b", [2], &[3u32, 0]);
+        let mut processor = Emitter::new();
+        let a = processor.var(&a);
+        let b = processor.var(&b);
+        let sign = processor.unary_fn(a.clone(), Function::Sign, Type::I32);
+        assert_eq!(sign, "sign(a[idx])");
+        let copysign = processor.binary(a.clone(), a, Operator::Copysign, Type::I32);
+        assert_eq!(copysign, "(abs(a[idx]) * select(1, -1, a[idx] < 0))");
+        let sign = processor.unary_fn(b.clone(), Function::Sign, Type::U32);
+        assert_eq!(sign, "min(b[idx], 1u)");
+        let copysign = processor.binary(b.clone(), b, Operator::Copysign, Type::U32);
+        assert_eq!(copysign, "b[idx]");
+    }
+
+    #[tokio::test]
+    async fn bool_ordering() {
+        let backend = WGPUBackend::new().await.unwrap();
+        let a = backend.tensor("a", [2], &[true, false]);
+        let mut processor = Emitter::new();
+        let a = processor.var(&a);
+        let eq = processor.binary(a.clone(), a.clone(), Operator::Eq, Type::Bool);
+        assert_eq!(eq, "(bool(a[idx]) == bool(a[idx]))");
+        let gt = processor.binary(a.clone(), a, Operator::Gt, Type::Bool);
+        assert_eq!(gt, "(u32(bool(a[idx])) > u32(bool(a[idx])))");
+    }
+
+    #[tokio::test]
+    async fn max_and_min() {
+        let backend = WGPUBackend::new().await.unwrap();
+        let a = backend.tensor("a", [2], &[1.0f32, -2.0]);
+        let mut processor = Emitter::new();
+        let a = processor.var(&a);
+        let zero = processor.scalar(0.0f32);
+        let max = processor.binary(a.clone(), zero.clone(), Operator::Max, Type::F32);
+        assert_eq!(max, "max(a[idx], 0.0)");
+        let min = processor.binary(a, zero, Operator::Min, Type::F32);
+        assert_eq!(min, "min(a[idx], 0.0)");
+    }
+
+    #[tokio::test]
+    async fn select() {
+        let backend = WGPUBackend::new().await.unwrap();
+        let a = backend.tensor("a", [2], &[1.0f32, -2.0]);
+        let mut processor = Emitter::new();
+        let a = processor.var(&a);
+        let zero = processor.scalar(0.0f32);
+        let mask = processor.binary(zero.clone(), a.clone(), Operator::Gt, Type::F32);
+        let select = processor.select(mask, zero, a);
+        assert_eq!(select, "select(a[idx], 0.0, (0.0 > a[idx]))");
+    }
+
     #[tokio::test]
     async fn statement() {
         let backend = WGPUBackend::new().await.unwrap();
@@ -226,12 +948,318 @@ mod tests {
         let mut processor = Emitter::new();
         let a = processor.var(&a);
         let b = processor.var(&b);
-        let a_add_b = processor.binary(a, b, Operator::Add);
+        let a_add_b = processor.binary(a, b, Operator::Add, Type::I32);
         let c = processor.var(&c);
-        let statement = processor.statement(c, a_add_b);
+        let statement = processor.statement(c, a_add_b, Type::F32);
         assert_eq!(statement, "c[idx] = (a[idx] + b[idx]);");
     }
 
+    #[tokio::test]
+    async fn bincount() {
+        let backend = WGPUBackend::new().await.unwrap();
+        let a = backend.tensor("a", [4], &[1.0, 2.0, 3.0, 4.0]);
+        let c = backend.zero::<u32>("c", [2]);
+        let mut processor = Emitter::new();
+        let a = processor.var(&a);
+        let statement = processor.bincount(c.label(), a, 4, 2, 0.0, 4.0);
+        assert_eq!(
+            statement,
+            "if (idx < 4u) { let bin_value = f32(a[idx]); if (bin_value >= 0.0 && bin_value < 4.0) \
+             { let bin = min(u32((bin_value - 0.0) * 0.5), 1u); atomicAdd(&c[bin], 1u); } }"
+        );
+    }
+
+    #[tokio::test]
+    async fn mask_select() {
+        let backend = WGPUBackend::new().await.unwrap();
+        let a = backend.tensor("a", [4], &[1.0, 2.0, 3.0, 4.0]);
+        let c = backend.zero::<f32>("c", [4]);
+        let c_len = backend.zero::<u32>("c_len", [1]);
+        let mut processor = Emitter::new();
+        let a = processor.var(&a);
+        let mask = processor.binary(a.clone(), "2.0".to_string(), Operator::Neq, Type::F32);
+        let c = processor.var(&c);
+        let c_len = processor.var(&c_len);
+        let statement = processor.mask_select(c, a, mask, c_len, 4, Type::F32);
+        assert_eq!(
+            statement,
+            "if (group.x == 0u) { var selected = 0u; for (var base = 0u; base < 4u; base += 64u) \
+             { let i = base + lane; var flag = 0u; \
+             if (i < 4u) { let idx = i; flag = select(0u, 1u, (a[idx] != 2.0)); } \
+             scan_0[lane] = flag; workgroupBarrier(); \
+             for (var offset = 1u; offset < 64u; offset *= 2u) { var prior = 0u; \
+             if (lane >= offset) { prior = scan_0[lane - offset]; } workgroupBarrier(); \
+             scan_0[lane] += prior; workgroupBarrier(); } \
+             if (flag == 1u) { let idx = i; let value = a[idx]; \
+             { let idx = selected + scan_0[lane] - 1u; c[idx] = value; } } \
+             selected += workgroupUniformLoad(&scan_0[63]); } \
+             for (var i = selected + lane; i < 4u; i += 64u) { let idx = i; c[idx] = f32(); } \
+             if (lane == 0u) { let idx = 0u; c_len[idx] = selected; } }"
+        );
+        processor.block(std::iter::once(statement)).unwrap();
+        assert!(processor.body().starts_with(
+            "var<workgroup> scan_0: array<u32, 64>;\n\n@compute\n@workgroup_size(64)\n\
+             fn main(@builtin(global_invocation_id) global_id: vec3<u32>, \
+             @builtin(local_invocation_index) lane: u32, @builtin(workgroup_id) group: vec3<u32>) {"
+        ));
+    }
+
+    #[tokio::test]
+    async fn bool_storage() {
+        let backend = WGPUBackend::new().await.unwrap();
+        let a = backend.tensor("a", [2], &[true, false]);
+        let c = backend.zero::<bool>("c", [2]);
+        let mut processor = Emitter::new();
+        let a = processor.var(&a);
+        assert_eq!(a, "bool(a[idx])");
+        let c = processor.output(c.label());
+        let statement = processor.statement(c, a, Type::Bool);
+        assert_eq!(statement, "c[idx] = u32(bool(a[idx]));");
+    }
+
+    #[tokio::test]
+    async fn mean() {
+        let backend = WGPUBackend::new().await.unwrap();
+        let a = backend.tensor("a", [2, 3], &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let c = backend.zero::<f32>("c", [2]);
+        let mut processor = Emitter::new();
+        let a = processor.var(&a);
+        let mean = processor.mean(a, &[2, 3], 1);
+        assert_eq!(mean, "mean_0(idx)");
+        let c = processor.var(&c);
+        let statement = processor.statement(c, mean, Type::F32);
+        processor.block(std::iter::once(statement)).unwrap();
+        assert_eq!(
+            processor.body(),
+            indoc!(
+                r"
+                fn mean_0(out_idx: u32) -> f32 {
+                    let base = (out_idx / 1u) * 3u + out_idx % 1u;
+                    var sum = 0.0;
+                    for (var k = 0u; k < 3u; k++) {
+                        let idx = base + k * 1u;
+                        sum += f32(a[idx]);
+                    }
+                    return sum / 3.0;
+                }
+
+                @compute
+                @workgroup_size(64)
+                fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {
+                    let idx = global_id.x;
+                    c[idx] = mean_0(idx);
+                }"
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn softmax() {
+        let backend = WGPUBackend::new().await.unwrap();
+        let a = backend.tensor("a", [2, 2], &[1.0, 2.0, 3.0, 4.0]);
+        let mut processor = Emitter::new();
+        let a = processor.var(&a);
+        let softmax = processor.softmax(a, &[2, 2], 1);
+        assert_eq!(softmax, "softmax_0(idx)");
+        assert_eq!(
+            processor.functions[0],
+            indoc!(
+                r"
+                fn softmax_0(out_idx: u32) -> f32 {
+                    let base = (out_idx / 2u) * 2u + out_idx % 1u;
+                    var max_value = -3.4028235e38;
+                    for (var k = 0u; k < 2u; k++) {
+                        let idx = base + k * 1u;
+                        max_value = max(max_value, f32(a[idx]));
+                    }
+                    var sum = 0.0;
+                    for (var k = 0u; k < 2u; k++) {
+                        let idx = base + k * 1u;
+                        sum += exp(f32(a[idx]) - max_value);
+                    }
+                    let idx = out_idx;
+                    return exp(f32(a[idx]) - max_value) / sum;
+                }"
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn one_hot() {
+        let backend = WGPUBackend::new().await.unwrap();
+        let a = backend.tensor("a", [3], &[0, 2, 1]);
+        let mut processor = Emitter::new();
+        let a = processor.var(&a);
+        let one_hot = processor.one_hot(a, 3);
+        assert_eq!(one_hot, "one_hot_0(idx)");
+        assert_eq!(
+            processor.functions[0],
+            indoc!(
+                r"
+                fn one_hot_0(out_idx: u32) -> f32 {
+                    let idx = out_idx / 3u;
+                    return select(0.0, 1.0, f32(a[idx]) == f32(out_idx % 3u));
+                }"
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn tile() {
+        let backend = WGPUBackend::new().await.unwrap();
+        let a = backend.tensor("a", [1, 3], &[1.0, 2.0, 3.0]);
+        let mut processor = Emitter::new();
+        let a = processor.var(&a);
+        let tile = processor.tile(a, &[1, 3], &[2, 1], Type::F32);
+        assert_eq!(tile, "tile_0(idx)");
+        assert_eq!(
+            processor.functions[0],
+            indoc!(
+                r"
+                fn tile_0(out_idx: u32) -> f32 {
+                    let idx = ((out_idx / 3u) % 1u) * 3u + ((out_idx / 1u) % 3u) * 1u;
+                    return a[idx];
+                }"
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn pad() {
+        let backend = WGPUBackend::new().await.unwrap();
+        let a = backend.tensor("a", [2, 2], &[1.0, 2.0, 3.0, 4.0]);
+        let mut processor = Emitter::new();
+        let a = processor.var(&a);
+        let value = processor.scalar(0.5f32);
+        let pad = processor.pad(a, &[2, 2], &[(1, 1), (0, 0)], value, Type::F32);
+        assert_eq!(pad, "pad_0(idx)");
+        assert_eq!(
+            processor.functions[0],
+            indoc!(
+                r"
+                fn pad_0(out_idx: u32) -> f32 {
+                    let coord_0 = (out_idx / 2u) % 4u;
+                    let coord_1 = (out_idx / 1u) % 2u;
+                    if (coord_0 < 1u || coord_0 >= 3u || coord_1 < 0u || coord_1 >= 2u) {
+                        return f32(0.5);
+                    }
+                    let idx = (coord_0 - 1u) * 2u + (coord_1 - 0u) * 1u;
+                    return a[idx];
+                }"
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn take() {
+        let backend = WGPUBackend::new().await.unwrap();
+        let a = backend.tensor("a", [4], &[1.0, 2.0, 3.0, 4.0]);
+        let mut processor = Emitter::new();
+        let a = processor.var(&a);
+        let take = processor.take(a, &[3, 1, 0], Type::F32);
+        assert_eq!(take, "take_0(idx)");
+        assert_eq!(
+            processor.functions[0],
+            indoc!(
+                r"
+                fn take_0(out_idx: u32) -> f32 {
+                    var indices = array<u32, 3>(3u, 1u, 0u);
+                    let idx = indices[out_idx];
+                    return a[idx];
+                }"
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn slice() {
+        let backend = WGPUBackend::new().await.unwrap();
+        let a = backend.tensor("a", [2, 3], &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let mut processor = Emitter::new();
+        let a = processor.var(&a);
+        let slice = processor.slice(a, &[2, 3], 1, 1..3, Type::F32);
+        assert_eq!(slice, "slice_0(idx)");
+        assert_eq!(
+            processor.functions[0],
+            indoc!(
+                r"
+                fn slice_0(out_idx: u32) -> f32 {
+                    let idx = (out_idx / 2u) * 3u + 1u + out_idx % 2u;
+                    return a[idx];
+                }"
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn flip() {
+        let backend = WGPUBackend::new().await.unwrap();
+        let a = backend.tensor("a", [2, 3], &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let mut processor = Emitter::new();
+        let a = processor.var(&a);
+        let flip = processor.flip(a, &[2, 3], &[1], Type::F32);
+        assert_eq!(flip, "flip_0(idx)");
+        assert_eq!(
+            processor.functions[0],
+            indoc!(
+                r"
+                fn flip_0(out_idx: u32) -> f32 {
+                    let idx = ((out_idx / 3u) % 2u) * 3u + (2u - (out_idx / 1u) % 3u) * 1u;
+                    return a[idx];
+                }"
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn roll() {
+        let backend = WGPUBackend::new().await.unwrap();
+        let a = backend.tensor("a", [2, 3], &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let mut processor = Emitter::new();
+        let a = processor.var(&a);
+        let roll = processor.roll(a, &[2, 3], 1, 2, Type::F32);
+        assert_eq!(roll, "roll_0(idx)");
+        assert_eq!(
+            processor.functions[0],
+            indoc!(
+                r"
+                fn roll_0(out_idx: u32) -> f32 {
+                    let coord = (out_idx / 1u) % 3u;
+                    let idx = out_idx - coord * 1u + ((coord + 1u) % 3u) * 1u;
+                    return a[idx];
+                }"
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn stack() {
+        let backend = WGPUBackend::new().await.unwrap();
+        let a = backend.tensor("a", [2], &[1.0, 2.0]);
+        let b = backend.tensor("b", [2], &[3.0, 4.0]);
+        let mut processor = Emitter::new();
+        let inners = vec![processor.var(&a), processor.var(&b), processor.scalar(0.0f32)];
+        let stack = processor.stack(inners, &[2], 0, Type::F32);
+        assert_eq!(stack, "stack_0(idx)");
+        assert_eq!(
+            processor.functions[0],
+            indoc!(
+                r"
+                fn stack_0(out_idx: u32) -> f32 {
+                    let part = (out_idx / 2u) % 3u;
+                    let idx = (out_idx / 6u) * 2u + out_idx % 2u;
+                    if (part == 0u) {
+                        return a[idx];
+                    }
+                    if (part == 1u) {
+                        return b[idx];
+                    }
+                    return 0.0;
+                }"
+            )
+        );
+    }
+
     #[tokio::test]
     async fn body() {
         let backend = WGPUBackend::new().await.unwrap();
@@ -241,10 +1269,10 @@ mod tests {
         let mut processor = Emitter::new();
         let a = processor.var(&a);
         let b = processor.var(&b);
-        let a_add_b = processor.binary(a, b, Operator::Add);
+        let a_add_b = processor.binary(a, b, Operator::Add, Type::I32);
         let c = processor.var(&c);
-        let statement = processor.statement(c, a_add_b);
-        processor.block(std::iter::once(statement));
+        let statement = processor.statement(c, a_add_b, Type::F32);
+        processor.block(std::iter::once(statement)).unwrap();
         assert_eq!(
             processor.body(),
             indoc!(
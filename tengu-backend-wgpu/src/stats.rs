@@ -0,0 +1,83 @@
+//! This module defines the `Stats` struct, which counts the expensive operations the WGPU backend
+//! performs, like device polls, staging buffer maps, pipeline builds and command submissions. The counters are cheap to
+//! maintain and allow checking that the higher layers batch and cache these operations as
+//! intended, without resorting to wall-clock measurements.
+
+use std::cell::Cell;
+
+/// Counters of the expensive operations performed by the backend since it was created.
+#[derive(Default)]
+pub struct Stats {
+    polls: Cell<usize>,
+    maps: Cell<usize>,
+    pipelines: Cell<usize>,
+    propagations: Cell<usize>,
+    readouts: Cell<usize>,
+}
+
+impl Stats {
+    /// Returns the number of times the device has been polled for pending retrieves.
+    ///
+    /// # Returns
+    /// The number of polls.
+    pub fn polls(&self) -> usize {
+        self.polls.get()
+    }
+
+    /// Returns the number of staging buffer maps requested by tensor retrieves.
+    ///
+    /// # Returns
+    /// The number of maps.
+    pub fn maps(&self) -> usize {
+        self.maps.get()
+    }
+
+    /// Returns the number of compute pipelines built, each of which compiles a shader.
+    ///
+    /// # Returns
+    /// The number of pipelines.
+    pub fn pipelines(&self) -> usize {
+        self.pipelines.get()
+    }
+
+    /// Returns the number of submissions propagating links between tensors.
+    ///
+    /// # Returns
+    /// The number of propagation submissions.
+    pub fn propagations(&self) -> usize {
+        self.propagations.get()
+    }
+
+    /// Returns the number of submissions copying probed tensors into staging buffers.
+    ///
+    /// # Returns
+    /// The number of readout submissions.
+    pub fn readouts(&self) -> usize {
+        self.readouts.get()
+    }
+
+    /// Records a poll of the device.
+    pub(crate) fn record_poll(&self) {
+        self.polls.set(self.polls.get() + 1);
+    }
+
+    /// Records a map of a staging buffer.
+    pub(crate) fn record_map(&self) {
+        self.maps.set(self.maps.get() + 1);
+    }
+
+    /// Records a build of a compute pipeline.
+    pub(crate) fn record_pipeline(&self) {
+        self.pipelines.set(self.pipelines.get() + 1);
+    }
+
+    /// Records a submission of propagation commands.
+    pub(crate) fn record_propagation(&self) {
+        self.propagations.set(self.propagations.get() + 1);
+    }
+
+    /// Records a submission of readout commands.
+    pub(crate) fn record_readout(&self) {
+        self.readouts.set(self.readouts.get() + 1);
+    }
+}
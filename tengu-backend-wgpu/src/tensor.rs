@@ -2,15 +2,16 @@
 //! It includes functionality for creating tensors, managing their data, and interfacing with the GPU for compute operations.
 
 use std::borrow::Cow;
-use std::cell::OnceCell;
+use std::cell::{OnceCell, Ref, RefCell};
 use std::marker::PhantomData;
+use std::ops::Range;
 use std::rc::Rc;
 
 use async_trait::async_trait;
 use tengu_backend::Error;
-use tengu_backend_tensor::StorageType;
 use tengu_backend_tensor::Tensor as RawTensor;
-use tengu_wgpu::{Buffer, BufferUsage, ByteSize, Encoder};
+use tengu_backend_tensor::{IOType, Pod, StorageType};
+use tengu_wgpu::{Buffer, BufferUsage, ByteSize, Encoder, Pipeline};
 
 use crate::source::Source;
 use crate::Backend as WGPUBackend;
@@ -23,6 +24,7 @@ pub struct Tensor<T> {
     shape: Vec<usize>,
     staging_buffer: OnceCell<Buffer>,
     buffer: Rc<Buffer>,
+    add_pipelines: RefCell<Vec<(Rc<Buffer>, Pipeline)>>,
     phantom: PhantomData<T>,
 }
 
@@ -47,23 +49,53 @@ impl<T: StorageType> Tensor<T> {
             shape,
             staging_buffer: OnceCell::new(),
             buffer: buffer.into(),
+            add_pipelines: RefCell::default(),
             phantom: PhantomData,
         }
     }
 
-    /// Returns a reference to the tensor's staging object, initializing it if necessary.
+    /// Returns a reference to the tensor's staging object, initializing it if necessary. The staging
+    /// buffer is only needed for reading the tensor out, so tensors that are never read out (i.e.
+    /// that aren't in the processor readouts) never allocate it. Its label is the tensor label with
+    /// a `_staging` suffix, so that it isn't mistaken for the storage buffer in GPU captures.
     ///
     /// # Returns
     /// A reference to the tensor's staging object.
     fn stage(&self) -> &Buffer {
         self.staging_buffer.get_or_init(|| {
-            let size = self.count.of::<T>();
+            let size = self.count.of::<Pod<T>>();
+            let label = format!("{}_staging", self.label);
             self.backend
                 .device()
-                .buffer::<T>(&self.label, BufferUsage::Staging)
+                .buffer::<Pod<T>>(&label, BufferUsage::Staging)
                 .empty(size)
         })
     }
+
+    /// Returns the pipeline adding another tensor to this one element-wise, building it with the
+    /// provided function on the first call for that tensor. The pipelines are kept by the
+    /// destination tensor, so an accumulating link compiles its shader only once rather than on
+    /// every propagation.
+    ///
+    /// # Parameters
+    /// - `addend`: The tensor added to this one.
+    /// - `build`: A function building the pipeline.
+    ///
+    /// # Returns
+    /// A reference to the cached pipeline.
+    pub(crate) fn add_pipeline(&self, addend: &Self, build: impl FnOnce() -> Pipeline) -> Ref<'_, Pipeline> {
+        let cached = self
+            .add_pipelines
+            .borrow()
+            .iter()
+            .position(|(buffer, _)| Rc::ptr_eq(buffer, &addend.buffer));
+        let index = cached.unwrap_or_else(|| {
+            let mut pipelines = self.add_pipelines.borrow_mut();
+            pipelines.push((Rc::clone(&addend.buffer), build()));
+            pipelines.len() - 1
+        });
+        Ref::map(self.add_pipelines.borrow(), |pipelines| &pipelines[index].1)
+    }
 }
 
 // NOTE: Source trait implementation.
@@ -122,7 +154,10 @@ impl<T: StorageType> RawTensor<T> for Tensor<T> {
         &self.shape
     }
 
-    /// Retrieves staging buffer data from the GPU memory into the CPU buffer.
+    /// Retrieves staging buffer data from the GPU memory into the CPU buffer. The buffer map is
+    /// requested immediately, but it resolves only once the device is polled with `Backend::poll`,
+    /// allowing many retrieves to share a single poll. Booleans are stored as `u32` and converted
+    /// back to `bool` here.
     ///
     /// # Returns
     /// A `Cow` containing either a reference or owned buffer with the tensor data.
@@ -131,18 +166,106 @@ impl<T: StorageType> RawTensor<T> for Tensor<T> {
         let buffer_slice = staging_buffer.slice(..);
         let (sender, receiver) = flume::bounded(1);
         buffer_slice.map_async(wgpu::MapMode::Read, move |v| sender.send(v).unwrap());
-        self.backend.device().poll(wgpu::Maintain::wait()).panic_on_timeout();
+        self.backend.stats().record_map();
         receiver
             .recv_async()
             .await
             .map_err(|e| Error::WGPUError(e.into()))?
             .map_err(|e| Error::WGPUError(e.into()))?;
         let data = buffer_slice.get_mapped_range();
-        let buffer = bytemuck::cast_slice(&data).to_vec();
+        // NOTE: The staging buffer may be padded to the copy alignment, so only `count` values are read.
+        let buffer = bytemuck::cast_slice(&data)[..self.count]
+            .iter()
+            .map(|pod| <T::IOType as IOType>::from_pod(*pod))
+            .collect::<Vec<_>>();
         drop(data);
         staging_buffer.unmap();
         Ok(buffer.into())
     }
+
+    /// Retrieves a range of elements from the staging buffer. Only the bytes covering the range
+    /// are mapped, widened to satisfy the mapping alignment requirements of WGPU, so reading a
+    /// small window of a large tensor avoids transferring the whole buffer to the CPU.
+    ///
+    /// # Parameters
+    /// - `range`: The range of elements to retrieve.
+    ///
+    /// # Returns
+    /// A `Cow` containing the tensor data in the requested range.
+    async fn retrieve_range(&self, range: Range<usize>) -> anyhow::Result<Cow<'_, [T::IOType]>> {
+        assert!(
+            range.end <= self.count,
+            "range {range:?} is out of bounds of {} elements",
+            self.count
+        );
+        let staging_buffer = self.stage();
+        let start = range.start.of::<Pod<T>>() as u64 / wgpu::MAP_ALIGNMENT * wgpu::MAP_ALIGNMENT;
+        let end = (range.end.of::<Pod<T>>() as u64)
+            .next_multiple_of(wgpu::COPY_BUFFER_ALIGNMENT)
+            .min(staging_buffer.size());
+        let buffer_slice = staging_buffer.slice(start..end);
+        let (sender, receiver) = flume::bounded(1);
+        buffer_slice.map_async(wgpu::MapMode::Read, move |v| sender.send(v).unwrap());
+        self.backend.stats().record_map();
+        receiver
+            .recv_async()
+            .await
+            .map_err(|e| Error::WGPUError(e.into()))?
+            .map_err(|e| Error::WGPUError(e.into()))?;
+        let data = buffer_slice.get_mapped_range();
+        let offset = range.start - start as usize / 1.of::<Pod<T>>();
+        let buffer = bytemuck::cast_slice(&data)[offset..offset + range.len()]
+            .iter()
+            .map(|pod| <T::IOType as IOType>::from_pod(*pod))
+            .collect::<Vec<_>>();
+        drop(data);
+        staging_buffer.unmap();
+        Ok(buffer.into())
+    }
+
+    /// Writes the data into the GPU buffer of the tensor. The values are converted to their
+    /// in-memory representation first, so booleans are written as `u32`.
+    ///
+    /// # Parameters
+    /// - `data`: The new data of the tensor.
+    ///
+    /// # Returns
+    /// A result indicating success, or `Error::WriteError` if the data couldn't be uploaded.
+    fn write(&self, data: &[T::IOType]) -> anyhow::Result<()> {
+        assert_eq!(
+            data.len(),
+            self.count,
+            "data length doesn't match the tensor '{}'",
+            self.label
+        );
+        let data = data.iter().map(|value| value.to_pod()).collect::<Vec<_>>();
+        self.backend
+            .device()
+            .write(&self.buffer, &data)
+            .map_err(|e| Error::WriteError(e.into()))?;
+        Ok(())
+    }
+
+    /// Returns a tensor with another label sharing the GPU buffer of this one. The buffer keeps its
+    /// original label in GPU captures, while the staging buffer is created with the new label.
+    ///
+    /// # Parameters
+    /// - `label`: The label of the new tensor.
+    ///
+    /// # Returns
+    /// A new tensor with the specified label and the same buffer.
+    fn relabeled(&self, label: String) -> Self {
+        Self {
+            backend: Rc::clone(&self.backend),
+            label,
+            count: self.count,
+            shape: self.shape.clone(),
+            staging_buffer: OnceCell::new(),
+            buffer: Rc::clone(&self.buffer),
+            add_pipelines: RefCell::default(),
+            phantom: PhantomData,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -150,7 +273,9 @@ mod tests {
     use crate::source::Source;
     use crate::Backend as WGPUBackend;
     use pretty_assertions::assert_eq;
-    use tengu_backend::Backend;
+    use std::collections::HashSet;
+    use tengu_backend::{Backend, Compute, Processor, Readout};
+    use tengu_backend_tensor::Operator;
 
     #[tokio::test]
     async fn tensor_emit() {
@@ -158,4 +283,38 @@ mod tests {
         let tensor = backend.zero::<u32>("tenzor", [6]);
         assert_eq!(tensor.label(), "tenzor");
     }
+
+    #[tokio::test]
+    async fn buffer_labels() {
+        let backend = WGPUBackend::new().await.unwrap();
+        let tensor = backend.zero::<f32>("tenzor", [4]);
+        assert_eq!(tensor.buffer.label(), "tenzor");
+        assert_eq!(tensor.stage().label(), "tenzor_staging");
+    }
+
+    #[tokio::test]
+    async fn staging_only_for_readouts() {
+        let backend = WGPUBackend::new().await.unwrap();
+        let a = backend.tensor("a", [4], &[1.0f32, 2.0, 3.0, 4.0]);
+        let b = backend.tensor("b", [4], &[5.0f32, 6.0, 7.0, 8.0]);
+        let c = backend.zero::<f32>("c", [4]);
+        let d = backend.zero::<f32>("d", [4]);
+        let readouts = HashSet::from(["d".to_string()]);
+        let mut processor = backend.processor(&readouts);
+        let (a_var, b_var, c_var) = (processor.var(&a), processor.var(&b), processor.var(&c));
+        let sum = processor.binary(a_var, b_var.clone(), Operator::Add);
+        let c_statement = processor.statement(c_var.clone(), sum);
+        let product = processor.binary(c_var, b_var, Operator::Mul);
+        let d_var = processor.var(&d);
+        let d_statement = processor.statement(d_var, product);
+        processor.block([c_statement, d_statement].into_iter()).unwrap();
+        backend
+            .compute("compute", |mut compute| compute.run(&processor))
+            .unwrap();
+        backend.readout("readout", |mut readout| readout.run(&processor));
+        assert!(a.staging_buffer.get().is_none());
+        assert!(b.staging_buffer.get().is_none());
+        assert!(c.staging_buffer.get().is_none());
+        assert!(d.staging_buffer.get().is_some());
+    }
 }
@@ -6,6 +6,7 @@
 
 use std::collections::HashSet;
 use std::rc::Rc;
+use std::time::Duration;
 
 use tengu_backend_tensor::{IOType, StorageType, Tensor};
 
@@ -36,6 +37,9 @@ pub trait Backend: Sized {
     /// The underliying limits type.
     type Limits: Limits;
 
+    /// The type measuring the execution time of computations.
+    type Timer;
+
     /// Asynchronously creates a new backend instance.
     ///
     /// # Returns
@@ -70,14 +74,100 @@ pub trait Backend: Sized {
     ///   staging operation.
     fn readout(&self, label: &str, call: impl FnOnce(Self::Readout<'_>));
 
+    /// Blocks until all pending tensor retrieves have been resolved. Backends are allowed to defer
+    /// the actual readback of tensor data until this method is called, so that retrieves of many
+    /// tensors can be issued at once and resolved together.
+    fn poll(&self);
+
     /// Computes the specified function on the backend using the provided callback.
     ///
+    /// # Type Parameters
+    /// - `E`: The error type of the callback, which the errors of the backend are converted into.
+    ///
+    /// # Parameters
+    /// - `label`: A label for the computation, to be used by backend for debugging purposes.
+    /// - `call`: A callback function that takes the compute instance as an argument.
+    ///
+    /// # Returns
+    /// A result indicating success, the error returned by the callback as it is, or the converted
+    /// error of the backend if it failed to run the computation.
+    fn compute<F, E>(&self, label: &str, call: F) -> std::result::Result<(), E>
+    where
+        F: FnOnce(Self::Compute<'_>) -> std::result::Result<(), E>,
+        E: From<Error>;
+
+    /// Creates a timer measuring the execution time of the computations run with `compute_timed`.
+    /// A timer is meant to be created once and reused by many computations.
+    ///
+    /// # Parameters
+    /// - `label`: A label for the timer, to be used by backend for debugging purposes.
+    ///
+    /// # Returns
+    /// A result containing the timer, or `Error::TimestampQueryUnsupported` if the backend cannot
+    /// measure execution times.
+    fn timer(&self, label: &str) -> Result<Self::Timer>;
+
+    /// Computes the specified function on the backend like `compute`, measuring the time the
+    /// device spends executing it with the given timer. Unlike wall-clock timing, the measurement
+    /// excludes the overhead of preparing and submitting the computation. The backend doesn't have
+    /// to wait for the computation to finish, so the time is only available from `elapsed`.
+    ///
+    /// # Type Parameters
+    /// - `E`: The error type of the callback, which the errors of the backend are converted into.
+    ///
     /// # Parameters
     /// - `label`: A label for the computation, to be used by backend for debugging purposes.
+    /// - `timer`: The timer measuring the computation.
     /// - `call`: A callback function that takes the compute instance as an argument.
-    fn compute<F>(&self, label: &str, call: F) -> Result<()>
+    ///
+    /// # Returns
+    /// A result indicating success, or the errors of `compute`.
+    fn compute_timed<F, E>(&self, label: &str, timer: &Self::Timer, call: F) -> std::result::Result<(), E>
     where
-        F: FnOnce(Self::Compute<'_>) -> anyhow::Result<()>;
+        F: FnOnce(Self::Compute<'_>) -> std::result::Result<(), E>,
+        E: From<Error>;
+
+    /// Waits for the computations measured by the timer to finish and returns their total
+    /// execution time.
+    ///
+    /// # Parameters
+    /// - `timer`: The timer measuring the computations.
+    ///
+    /// # Returns
+    /// A result containing the total execution time of the computations measured by the timer.
+    fn elapsed(&self, timer: &Self::Timer) -> Result<Duration>;
+
+    /// Creates a new zero-initialized tensor with the specified label and element count.
+    ///
+    /// # Parameters
+    /// - `label`: A label for the tensor.
+    /// - `shape`: The shape of the tensor.
+    ///
+    /// # Returns
+    /// A result wrapping the new zero-initialized tensor, or `Error::OutOfMemory` if the backend
+    /// cannot allocate it.
+    fn try_zero<T: StorageType>(
+        self: &Rc<Self>,
+        label: impl Into<String>,
+        shape: impl Into<Vec<usize>>,
+    ) -> Result<Self::Tensor<T>>;
+
+    /// Creates a new tensor with the specified label and data.
+    ///
+    /// # Parameters
+    /// - `label`: A label for the tensor.
+    /// - `shape`: The shape of the tensor.
+    /// - `data`: A slice of data to initialize the tensor.
+    ///
+    /// # Returns
+    /// A result wrapping the new tensor initialized with the given data, or `Error::OutOfMemory` if
+    /// the backend cannot allocate it.
+    fn try_tensor<T: IOType>(
+        self: &Rc<Self>,
+        label: impl Into<String>,
+        shape: impl Into<Vec<usize>>,
+        data: &[T],
+    ) -> Result<Self::Tensor<T>>;
 
     /// Creates a new zero-initialized tensor with the specified label and element count.
     ///
@@ -87,8 +177,17 @@ pub trait Backend: Sized {
     ///
     /// # Returns
     /// A new zero-initialized tensor.
-    fn zero<T: StorageType>(self: &Rc<Self>, label: impl Into<String>, shape: impl Into<Vec<usize>>)
-        -> Self::Tensor<T>;
+    ///
+    /// # Panics
+    /// Panics if the backend cannot allocate the tensor. Use `try_zero` to handle allocation
+    /// failures gracefully.
+    fn zero<T: StorageType>(
+        self: &Rc<Self>,
+        label: impl Into<String>,
+        shape: impl Into<Vec<usize>>,
+    ) -> Self::Tensor<T> {
+        self.try_zero(label, shape).unwrap_or_else(|e| panic!("{e}"))
+    }
 
     /// Creates a new tensor with the specified label and data.
     ///
@@ -99,10 +198,16 @@ pub trait Backend: Sized {
     ///
     /// # Returns
     /// A new tensor initialized with the given data.
+    ///
+    /// # Panics
+    /// Panics if the backend cannot allocate the tensor. Use `try_tensor` to handle allocation
+    /// failures gracefully.
     fn tensor<T: IOType>(
         self: &Rc<Self>,
         label: impl Into<String>,
         shape: impl Into<Vec<usize>>,
         data: &[T],
-    ) -> Self::Tensor<T>;
+    ) -> Self::Tensor<T> {
+        self.try_tensor(label, shape, data).unwrap_or_else(|e| panic!("{e}"))
+    }
 }
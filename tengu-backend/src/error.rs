@@ -4,6 +4,8 @@ use thiserror::Error;
 pub enum Error {
     #[error("Retrieve error: {0}")]
     RetrieveError(#[source] anyhow::Error),
+    #[error("Write error: {0}")]
+    WriteError(#[source] anyhow::Error),
     #[error("Compute error: {0}")]
     ComputeError(#[source] anyhow::Error),
     #[error("WGPU error: {0}")]
@@ -12,6 +14,18 @@ pub enum Error {
     OSError(#[source] anyhow::Error),
     #[error("Storage buffer limit reached: {0} buffers used")]
     BufferLimitReached(usize),
+    #[error("Timestamp queries are not supported by the backend")]
+    TimestampQueryUnsupported,
+    #[error("GPU validation error: {0}")]
+    ValidationError(String),
+    #[error("Shader compilation error: {message}\n{shader}")]
+    ShaderCompilation { shader: String, message: String },
+    #[error("Out of memory: cannot allocate {requested_bytes} bytes")]
+    OutOfMemory { requested_bytes: u64 },
+    #[error("Unsupported type {0}: {1}")]
+    UnsupportedType(tengu_backend_tensor::Type, String),
+    #[error("Unsupported expression: {0}")]
+    UnsupportedExpression(String),
 }
 
 pub type Result<T> = std::result::Result<T, self::Error>;
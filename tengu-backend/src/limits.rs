@@ -1,6 +1,8 @@
 //! This module defines the `Limits` trait, which is used to give user information about particular
 //! backend limitations such as maximum number of tensors in one compute stage.
 
+use tengu_backend_tensor::Type;
+
 /// The `Limits` trait defines a set of operations for querying the limitations of a backend.
 pub trait Limits {
     /// Returns the maximum number of tensors that can be used in a single compute stage.
@@ -8,4 +10,29 @@ pub trait Limits {
     /// # Returns
     /// The maximum number of tensors that can be used in a single compute stage.
     fn max_tensor_per_compute(&self) -> Option<usize>;
+
+    /// Returns the maximum number of elements of a single tensor.
+    ///
+    /// # Returns
+    /// The maximum number of elements of a single tensor, or `None` if there is no limit. This is
+    /// the default.
+    fn max_tensor_size(&self) -> Option<usize> {
+        None
+    }
+
+    /// Returns the maximum number of elements computed by a single dispatch of a compute stage, which
+    /// processes as many elements as its largest tensor has.
+    ///
+    /// # Returns
+    /// The maximum number of elements computed at once, or `None` if there is no limit. This is the
+    /// default.
+    fn max_dispatch_size(&self) -> Option<usize> {
+        None
+    }
+
+    /// Returns the element types the backend can store and compute with.
+    ///
+    /// # Returns
+    /// The supported element types.
+    fn supported_types(&self) -> Vec<Type>;
 }
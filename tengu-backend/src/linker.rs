@@ -18,4 +18,15 @@ pub trait Linker<B: Backend> {
     /// # Type Parameters
     /// - `T`: The type of data stored in the tensors, which must implement the `StorageType` trait.
     fn copy_link<T: StorageType>(&mut self, from: &B::Tensor<T>, to: &B::Tensor<T>);
+
+    /// Adds tensor data of one tensor to another element-wise, accumulating the result in the
+    /// destination tensor.
+    ///
+    /// # Parameters
+    /// - `from`: A reference to the source tensor whose data will be added.
+    /// - `to`: A reference to the destination tensor which will hold the sum.
+    ///
+    /// # Type Parameters
+    /// - `T`: The type of data stored in the tensors, which must implement the `StorageType` trait.
+    fn add_link<T: StorageType>(&mut self, from: &B::Tensor<T>, to: &B::Tensor<T>);
 }
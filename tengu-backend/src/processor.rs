@@ -2,9 +2,11 @@
 //! of tensor expressions in a final tagless style. Implementations of the `Processor` trait are responsible
 //! for transforming or evaluating the AST nodes according to specific backend requirements.
 
-use tengu_backend_tensor::{Function, Operator, StorageType, Type};
+use std::ops::Range;
 
-use crate::Backend;
+use tengu_backend_tensor::{CastMode, Function, Operator, StorageType, Type};
+
+use crate::{Backend, Result};
 
 /// The `Processor` trait defines a set of operations for processing tensor expressions.
 /// Types that implement this trait can produce and accept representations of tensor expressions
@@ -55,15 +57,214 @@ pub trait Processor<'a, B: Backend> {
     /// A new representation after applying the binary operation.
     fn binary(&mut self, lhs: Self::Repr, rhs: Self::Repr, operator: Operator) -> Self::Repr;
 
-    /// Creates a representation of a type cast applyied to a tensor expression.
+    /// Selects between two representations element-wise according to a boolean mask, taking the
+    /// element of `on_true` where the mask is true and the element of `on_false` otherwise. Scalar
+    /// operands are broadcast to the shape of the others, in the same way as for binary operations.
+    ///
+    /// # Parameters
+    /// - `mask`: The representation of the boolean mask.
+    /// - `on_true`: The representation selected where the mask is true.
+    /// - `on_false`: The representation selected where the mask is false.
+    ///
+    /// # Returns
+    /// A new representation holding the selected elements.
+    fn select(&mut self, mask: Self::Repr, on_true: Self::Repr, on_false: Self::Repr) -> Self::Repr;
+
+    /// Creates a representation of a type cast applyied to a tensor expression. Values which are out
+    /// of the range of the target type are cast according to the mode, in the same way on every
+    /// backend.
     ///
     /// # Parameters
     /// - `inner`: The inner representation to be cast.
+    /// - `from`: The type of the inner expression.
     /// - `ty`: The target type to cast to.
+    /// - `mode`: The way out-of-range values are cast.
     ///
     /// # Returns
     /// A new representation after casting.
-    fn cast(&mut self, inner: Self::Repr, ty: Type) -> Self::Repr;
+    fn cast(&mut self, inner: Self::Repr, from: Type, ty: Type, mode: CastMode) -> Self::Repr;
+
+    /// Creates a representation of a histogram of the values of a tensor expression. The values are
+    /// split into `bins` equal-width bins covering the `[min, max)` range, and the number of values
+    /// falling into each bin is counted. Values outside of the range are ignored.
+    ///
+    /// # Parameters
+    /// - `inner`: The inner representation whose values are counted.
+    /// - `bins`: The number of bins.
+    /// - `min`: The inclusive lower bound of the range.
+    /// - `max`: The exclusive upper bound of the range.
+    ///
+    /// # Returns
+    /// A new representation of a `u32` tensor holding the bin counts.
+    fn bincount(&mut self, inner: Self::Repr, bins: usize, min: f32, max: f32) -> Self::Repr;
+
+    /// Creates a representation of the mean of a tensor expression along the specified axis. The
+    /// result has the shape of the inner expression with the axis removed, and since tensors are
+    /// stored in row-major order, its layout is the same whether or not the reduced axis is kept
+    /// with size 1.
+    ///
+    /// # Parameters
+    /// - `inner`: The inner representation to be reduced.
+    /// - `shape`: The shape of the inner expression.
+    /// - `axis`: The axis along which the mean is computed.
+    ///
+    /// # Returns
+    /// A new representation of an `f32` tensor holding the means.
+    fn mean(&mut self, inner: Self::Repr, shape: &[usize], axis: usize) -> Self::Repr;
+
+    /// Creates a representation of the softmax of a tensor expression along the specified axis. The
+    /// maximum along the axis is subtracted before exponentiation, so that large values don't
+    /// overflow. The result has the same shape as the inner expression.
+    ///
+    /// # Parameters
+    /// - `inner`: The inner representation.
+    /// - `shape`: The shape of the inner expression.
+    /// - `axis`: The axis along which the softmax is normalized.
+    ///
+    /// # Returns
+    /// A new representation of an `f32` tensor holding the softmax values.
+    fn softmax(&mut self, inner: Self::Repr, shape: &[usize], axis: usize) -> Self::Repr;
+
+    /// Creates a representation of the one-hot encoding of a 1-D tensor expression of class indices.
+    /// The result has the shape `[n, classes]`, where row `i` holds 1.0 in the column given by the
+    /// `i`-th index and 0.0 everywhere else. Indices which are negative or not less than the number
+    /// of classes produce rows of zeros.
+    ///
+    /// # Parameters
+    /// - `inner`: The inner representation holding the class indices.
+    /// - `classes`: The number of classes.
+    ///
+    /// # Returns
+    /// A new representation of an `f32` tensor holding the encoded values.
+    fn one_hot(&mut self, inner: Self::Repr, classes: usize) -> Self::Repr;
+
+    /// Creates a representation of a tensor expression tiled along its axes, i.e. repeated
+    /// `reps[i]` times along each axis `i`. The result has the shape `shape[i] * reps[i]` and the
+    /// element type of the inner expression.
+    ///
+    /// # Parameters
+    /// - `inner`: The inner representation to be tiled.
+    /// - `shape`: The shape of the inner expression.
+    /// - `reps`: The number of repetitions along each axis.
+    /// - `ty`: The element type of the inner expression.
+    ///
+    /// # Returns
+    /// A new representation of the tiled tensor.
+    fn tile(&mut self, inner: Self::Repr, shape: &[usize], reps: &[usize], ty: Type) -> Self::Repr;
+
+    /// Creates a representation of a tensor expression padded with a constant value. Each axis `i`
+    /// of the result is extended by `padding[i].0` elements before and `padding[i].1` elements after
+    /// the elements of the inner expression, which are filled with the padding value. The result has
+    /// the element type of the inner expression.
+    ///
+    /// # Parameters
+    /// - `inner`: The inner representation to be padded.
+    /// - `shape`: The shape of the inner expression.
+    /// - `padding`: The number of elements added before and after each axis.
+    /// - `value`: The representation of the scalar padding value.
+    /// - `ty`: The element type of the inner expression.
+    ///
+    /// # Returns
+    /// A new representation of the padded tensor.
+    fn pad(
+        &mut self,
+        inner: Self::Repr,
+        shape: &[usize],
+        padding: &[(usize, usize)],
+        value: Self::Repr,
+        ty: Type,
+    ) -> Self::Repr;
+
+    /// Creates a representation of the elements of a tensor expression at the given flat indices.
+    /// The result is 1-D with one element per index and the element type of the inner expression.
+    ///
+    /// # Parameters
+    /// - `inner`: The inner representation whose elements are taken.
+    /// - `indices`: The flat row-major indices of the taken elements.
+    /// - `ty`: The element type of the inner expression.
+    ///
+    /// # Returns
+    /// A new representation of the taken elements.
+    fn take(&mut self, inner: Self::Repr, indices: &[usize], ty: Type) -> Self::Repr;
+
+    /// Creates a representation of a contiguous range of a tensor expression along an axis. The
+    /// result has the shape of the inner expression with the axis shortened to the length of the
+    /// range, and the element type of the inner expression.
+    ///
+    /// # Parameters
+    /// - `inner`: The inner representation to be sliced.
+    /// - `shape`: The shape of the inner expression.
+    /// - `axis`: The axis along which the range is taken.
+    /// - `range`: The range of indices along the axis.
+    /// - `ty`: The element type of the inner expression.
+    ///
+    /// # Returns
+    /// A new representation of the sliced tensor.
+    fn slice(&mut self, inner: Self::Repr, shape: &[usize], axis: usize, range: Range<usize>, ty: Type) -> Self::Repr;
+
+    /// Creates a representation of a tensor expression with the order of the elements reversed
+    /// along the specified axes. The result has the shape and the element type of the inner
+    /// expression.
+    ///
+    /// # Parameters
+    /// - `inner`: The inner representation to be flipped.
+    /// - `shape`: The shape of the inner expression.
+    /// - `axes`: The axes along which the elements are reversed.
+    /// - `ty`: The element type of the inner expression.
+    ///
+    /// # Returns
+    /// A new representation of the flipped tensor.
+    fn flip(&mut self, inner: Self::Repr, shape: &[usize], axes: &[usize], ty: Type) -> Self::Repr;
+
+    /// Creates a representation of a tensor expression shifted circularly along an axis, i.e. the
+    /// element at coordinate `i` along the axis moves to coordinate `(i + shift) % n`, where `n` is
+    /// the length of the axis. The result has the shape and the element type of the inner
+    /// expression.
+    ///
+    /// # Parameters
+    /// - `inner`: The inner representation to be rolled.
+    /// - `shape`: The shape of the inner expression.
+    /// - `axis`: The axis along which the elements are shifted.
+    /// - `shift`: The number of positions the elements are shifted by, less than the length of the
+    ///   axis.
+    /// - `ty`: The element type of the inner expression.
+    ///
+    /// # Returns
+    /// A new representation of the rolled tensor.
+    fn roll(&mut self, inner: Self::Repr, shape: &[usize], axis: usize, shift: usize, ty: Type) -> Self::Repr;
+
+    /// Creates a representation of tensor expressions of the same shape joined along a new axis.
+    /// The result has the shape of the inner expressions with an axis of the length of `inners`
+    /// inserted at `axis`, and the element type of the inner expressions.
+    ///
+    /// # Parameters
+    /// - `inners`: The representations to be stacked, in the order they appear along the new axis.
+    /// - `shape`: The shape shared by the inner expressions.
+    /// - `axis`: The position of the new axis in the shape of the result.
+    /// - `ty`: The element type of the inner expressions.
+    ///
+    /// # Returns
+    /// A new representation of the stacked tensor.
+    fn stack(&mut self, inners: Vec<Self::Repr>, shape: &[usize], axis: usize, ty: Type) -> Self::Repr;
+
+    /// Creates a representation of the elements of a tensor expression where the mask is true. The
+    /// selected elements are packed at the start of a 1-D result with the element count of the inner
+    /// expression, the rest of which is filled with zeros. The number of selected elements is only
+    /// known after the computation, so it is written to the single element of the `length` tensor.
+    /// Only selections assigned directly to the output of a computation have a length tensor, so
+    /// backends which can't select elements inside other expressions report an error from `block`
+    /// when it is missing.
+    ///
+    /// # Parameters
+    /// - `inner`: The inner representation whose elements are selected.
+    /// - `mask`: The representation of the boolean mask with the shape of the inner expression.
+    /// - `length`: The representation of the `[1]`-shaped `u32` tensor receiving the number of
+    ///   selected elements, or `None` if the selection is nested inside another expression.
+    /// - `ty`: The element type of the inner expression.
+    ///
+    /// # Returns
+    /// A new representation of the selected elements.
+    fn mask_select(&mut self, inner: Self::Repr, mask: Self::Repr, length: Option<Self::Repr>, ty: Type) -> Self::Repr;
 
     /// Creates a representation of a statement that assigns an expression to an output.
     ///
@@ -79,5 +280,8 @@ pub trait Processor<'a, B: Backend> {
     ///
     /// # Parameters
     /// - `exprs`: An iterator over the expressions to be processed.
-    fn block(&mut self, exprs: impl Iterator<Item = Self::Repr>);
+    ///
+    /// # Returns
+    /// A result which is an error if the backend cannot process the expressions.
+    fn block(&mut self, exprs: impl Iterator<Item = Self::Repr>) -> Result<()>;
 }
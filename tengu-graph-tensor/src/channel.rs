@@ -1,15 +1,29 @@
 //! Channel for communication between tensors and associated probes. This module provides
 //! functionalities for sending and receiving tensor data between tensors and probes.
+//!
+//! Every message carries the range of elements it holds, since probes can request only a window
+//! of the tensor to be read out. The requested window is shared between the channel and all the
+//! probes obtained from it.
+
+use std::ops::Range;
+use std::sync::{Arc, Mutex};
 
 use flume::{Receiver, Sender};
 use tengu_backend_tensor::StorageType;
 
 use crate::{Error, Result};
 
+/// The data sent to probes along with the range of elements it covers.
+pub type Message<T> = (Range<usize>, Vec<<T as StorageType>::IOType>);
+
+/// The range of elements requested by the probes, or `None` if the whole tensor is requested.
+pub type Window = Arc<Mutex<Option<Range<usize>>>>;
+
 /// A struct for managing communication between tensors and probes.
 pub struct Channel<T: StorageType> {
-    sender: Sender<Vec<T::IOType>>,
-    receiver: Receiver<Vec<T::IOType>>,
+    sender: Sender<Message<T>>,
+    receiver: Receiver<Message<T>>,
+    window: Window,
 }
 
 impl<T: StorageType> Channel<T> {
@@ -19,7 +33,11 @@ impl<T: StorageType> Channel<T> {
     /// A new `Channel` instance.
     pub fn new() -> Self {
         let (sender, receiver) = flume::bounded(1);
-        Self { sender, receiver }
+        Self {
+            sender,
+            receiver,
+            window: Window::default(),
+        }
     }
 
     /// Checks if the channel is full.
@@ -34,20 +52,28 @@ impl<T: StorageType> Channel<T> {
     ///
     /// # Returns
     /// A clone of the receiver.
-    pub fn receiver(&self) -> Receiver<Vec<T::IOType>> {
+    pub fn receiver(&self) -> Receiver<Message<T>> {
         self.receiver.clone()
     }
 
+    /// Returns the window of elements shared with the probes.
+    ///
+    /// # Returns
+    /// A reference to the shared window.
+    pub fn window(&self) -> &Window {
+        &self.window
+    }
+
     /// Sends tensor data to the channel.
     ///
     /// # Parameters
-    /// - `data`: The tensor data to send.
+    /// - `message`: The tensor data to send along with its range.
     ///
     /// # Returns
     /// A result indicating success or failure.
-    pub async fn send(&self, data: Vec<T::IOType>) -> Result<()> {
+    pub async fn send(&self, message: Message<T>) -> Result<()> {
         self.sender
-            .send_async(data)
+            .send_async(message)
             .await
             .map_err(|e| Error::ChannelError(e.into()))
     }
@@ -64,6 +90,7 @@ impl<T: StorageType> Clone for Channel<T> {
         Self {
             sender: self.sender.clone(),
             receiver: self.receiver.clone(),
+            window: Arc::clone(&self.window),
         }
     }
 }
@@ -4,6 +4,14 @@ use thiserror::Error;
 pub enum Error {
     #[error("Channel error: {0}")]
     ChannelError(#[source] anyhow::Error),
+    #[error("Range {0:?} is out of bounds for a tensor of {1} elements")]
+    RangeOutOfBounds(std::ops::Range<usize>, usize),
+    #[error("Write error: {0}")]
+    WriteError(#[source] anyhow::Error),
+    #[error("Invalid .npy data: {0}")]
+    InvalidNpy(String),
+    #[error("Data of shape {0:?} cannot be written to a tensor of shape {1:?}")]
+    ShapeMismatch(Vec<usize>, Vec<usize>),
 }
 
 pub type Result<T> = std::result::Result<T, self::Error>;
@@ -14,14 +14,19 @@
 //! ## Modules
 //! - `channel`: Defines the `Channel` struct for managing asynchronous data retrieval from tensors.
 //! - `error`: Defines the `Error` and `Result` types for error handling.
+//! - `npy`: Encodes retrieved tensor data in the NumPy `.npy` format.
 //! - `probe`: Defines the `Probe` struct for asynchronously retrieving data from tensors.
 //! - `tensor`: Defines the `Tensor` struct for managing tensor objects.
+//! - `view`: Defines the `TensorView` struct for multi-dimensional access to retrieved data.
 
 mod channel;
 mod error;
+mod npy;
 mod probe;
 mod tensor;
+mod view;
 
 pub use error::{Error, Result};
 pub use probe::Probe;
 pub use tensor::Tensor;
+pub use view::TensorView;
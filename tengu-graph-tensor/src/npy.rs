@@ -0,0 +1,120 @@
+//! This module encodes retrieved tensor data in the NumPy `.npy` format, so that it can be loaded
+//! with `numpy.load` for inspection and comparison outside of Tengu. Only the version 1.0 of the
+//! format is produced, which is enough for any tensor Tengu can hold. Files produced this way can
+//! also be decoded again, e.g. to restore the tensors from a saved state.
+
+use tengu_backend_tensor::{IOType, StorageType, Type};
+
+use crate::{Error, Result};
+
+/// The magic string every `.npy` file starts with.
+const MAGIC: &[u8] = b"\x93NUMPY";
+
+/// The alignment of the header, including the magic string, version and header length.
+const ALIGNMENT: usize = 64;
+
+/// Encodes the tensor data as the contents of a `.npy` file. The values are encoded in their
+/// in-memory representation, so booleans are encoded as `u32`. If the number of elements doesn't
+/// match the shape, e.g. because the tensor has a data-dependent length, the data is encoded as a
+/// 1-D array of the elements.
+///
+/// # Parameters
+/// - `shape`: The shape of the tensor.
+/// - `data`: The data of the tensor in row-major order.
+///
+/// # Returns
+/// The bytes of the `.npy` file.
+pub fn encode<T: IOType>(shape: &[usize], data: &[T]) -> Vec<u8> {
+    let shape = match shape.iter().product::<usize>() == data.len() {
+        true => shape.iter().map(|dim| format!("{dim},")).collect::<Vec<_>>().join(" "),
+        false => format!("{},", data.len()),
+    };
+    let data: Vec<T::Pod> = data.iter().map(|value| value.to_pod()).collect();
+    let endian = if cfg!(target_endian = "little") { '<' } else { '>' };
+    let mut header = format!(
+        "{{'descr': '{endian}{}', 'fortran_order': False, 'shape': ({shape}), }}",
+        descr(<T::Pod as StorageType>::as_type())
+    );
+    // NOTE: The header is padded with spaces and terminated with a newline to keep the data aligned.
+    let unpadded = MAGIC.len() + 2 + 2 + header.len() + 1;
+    header.push_str(&" ".repeat(unpadded.next_multiple_of(ALIGNMENT) - unpadded));
+    header.push('\n');
+    let mut bytes = Vec::with_capacity(MAGIC.len() + 4 + header.len() + std::mem::size_of_val(data.as_slice()));
+    bytes.extend_from_slice(MAGIC);
+    bytes.extend_from_slice(&[1, 0]);
+    bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+    bytes.extend_from_slice(header.as_bytes());
+    bytes.extend_from_slice(bytemuck::cast_slice(&data));
+    bytes
+}
+
+/// Decodes the contents of a `.npy` file produced by `encode`. The file has to hold values of the
+/// in-memory representation of `T` in the native byte order and C order.
+///
+/// # Parameters
+/// - `bytes`: The bytes of the `.npy` file.
+///
+/// # Returns
+/// A result containing the shape and the data of the array, or `Error::InvalidNpy` if the bytes
+/// are not a valid `.npy` file of the expected type.
+pub fn decode<T: IOType>(bytes: &[u8]) -> Result<(Vec<usize>, Vec<T>)> {
+    let invalid = |reason: &str| Error::InvalidNpy(reason.to_string());
+    let start = MAGIC.len() + 4;
+    if bytes.len() < start || !bytes.starts_with(MAGIC) {
+        return Err(invalid("missing magic string"));
+    }
+    if !bytes[MAGIC.len()..].starts_with(&[1, 0]) {
+        return Err(invalid("unsupported format version"));
+    }
+    let length = u16::from_le_bytes([bytes[MAGIC.len() + 2], bytes[MAGIC.len() + 3]]) as usize;
+    let header = bytes
+        .get(start..start + length)
+        .and_then(|header| std::str::from_utf8(header).ok())
+        .ok_or_else(|| invalid("truncated header"))?;
+    let endian = if cfg!(target_endian = "little") { '<' } else { '>' };
+    let descr = format!("'descr': '{endian}{}'", descr(<T::Pod as StorageType>::as_type()));
+    if !header.contains(&descr) {
+        return Err(invalid("unexpected element type"));
+    }
+    if !header.contains("'fortran_order': False") {
+        return Err(invalid("only C order is supported"));
+    }
+    let shape = header
+        .split_once("'shape': (")
+        .and_then(|(_, rest)| rest.split_once(')'))
+        .ok_or_else(|| invalid("missing shape"))?
+        .0
+        .split(',')
+        .map(str::trim)
+        .filter(|dim| !dim.is_empty())
+        .map(|dim| dim.parse::<usize>().map_err(|_| invalid("invalid shape")))
+        .collect::<Result<Vec<_>>>()?;
+    let data = &bytes[start + length..];
+    let size = std::mem::size_of::<T::Pod>();
+    if data.len() != shape.iter().product::<usize>() * size {
+        return Err(invalid("data size doesn't match the shape"));
+    }
+    let data = data
+        .chunks_exact(size)
+        .map(|chunk| T::from_pod(bytemuck::pod_read_unaligned(chunk)))
+        .collect();
+    Ok((shape, data))
+}
+
+/// Returns the NumPy type code of the specified type, without the byte order.
+///
+/// # Parameters
+/// - `ty`: The type of the tensor elements.
+///
+/// # Returns
+/// The type code, e.g. `f4` for `f32`.
+fn descr(ty: Type) -> &'static str {
+    match ty {
+        Type::F32 => "f4",
+        Type::U32 => "u4",
+        Type::I32 => "i4",
+        Type::U16 => "u2",
+        Type::I16 => "i2",
+        Type::Bool => "b1",
+    }
+}
@@ -1,32 +1,88 @@
 //! This module defines the `Probe` struct and associated methods for inspecting and retrieving
 //! values from tensors. It provides functionalities to turn probing on and off and to retrieve
 //! tensor data asynchronously.
+//!
+//! Probes don't hold any reference to the graph, the block, or the tensor they were obtained from:
+//! they only own the receiving end of a channel. As a result, they are `Send` and `Sync` and can
+//! be moved to another thread which awaits the data, even when the graph itself (which relies on
+//! `Rc` internally) has to stay on the thread that computes it.
+
+use std::ops::Range;
+use std::sync::{Arc, Mutex};
 
 use flume::Receiver;
-use tengu_backend_tensor::StorageType;
+use tengu_backend_tensor::{IOType, Pod, StorageType, Type};
 
-use crate::{Error, Result};
+use crate::channel::{Message, Window};
+use crate::{npy, Error, Result, TensorView};
 
 /// A struct for probing tensor values.
 ///
 /// The `Probe` struct holds to store recently retrieved values. Since retrieval operations is
 /// asyncronous and time-consuming, we use this cache to allow accessing retrieved values
-/// synchronously.
+/// synchronously. Once data has been retrieved, retrieving again before the next readout of the
+/// tensor returns the cached data instead of waiting for a new transfer, which is convenient for
+/// UIs that poll probes on every frame.
+///
+/// Probes are cheap to clone. All clones share the same channel, so every retrieved value is
+/// delivered to exactly one of them: clones can split the work of consuming the data, but they
+/// don't receive copies of it. They do share the cache though, so a clone retrieving data which
+/// has already been consumed by another clone gets the cached copy. Clones also share the
+/// requested window of the tensor, so mixing `retrieve` and `retrieve_range` calls on clones of the
+/// same probe makes them wait for each other's data.
 pub struct Probe<T: StorageType> {
-    receiver: Receiver<Vec<T::IOType>>,
+    receiver: Receiver<Message<T>>,
+    window: Window,
+    cache: Arc<Mutex<Option<Message<T>>>>,
+    shape: Vec<usize>,
+    count: usize,
 }
 
 impl<T: StorageType> Probe<T> {
     /// Creates a new `Probe` instance.
     ///
     /// # Parameters
-    /// - `probe`: A reference to the probe object.
-    /// - `count`: The number of elements to initialize in the buffer.
+    /// - `receiver`: The receiving end of the tensor channel.
+    /// - `window`: The window of elements shared with the tensor channel.
+    /// - `shape`: The shape of the tensor.
     ///
     /// # Returns
     /// A new `Probe` instance.
-    pub fn new(receiver: Receiver<Vec<T::IOType>>) -> Self {
-        Self { receiver }
+    pub fn new(receiver: Receiver<Message<T>>, window: Window, shape: impl Into<Vec<usize>>) -> Self {
+        let shape = shape.into();
+        Self {
+            receiver,
+            window,
+            cache: Arc::default(),
+            count: shape.iter().product(),
+            shape,
+        }
+    }
+
+    /// Returns the shape of the probed tensor.
+    ///
+    /// # Returns
+    /// A slice representing the dimensions of the tensor.
+    pub fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+
+    /// Returns the type of the probed tensor.
+    ///
+    /// # Returns
+    /// The storage type of the tensor.
+    pub fn dtype(&self) -> Type {
+        T::as_type()
+    }
+
+    /// Checks whether the tensor has been read out since the last retrieval, i.e. whether the next
+    /// retrieval will receive new data instead of returning the cached one. The flag is set by the
+    /// readout after a computation and cleared when a retrieval receives the data.
+    ///
+    /// # Returns
+    /// `true` if new data is waiting to be retrieved, `false` otherwise.
+    pub fn is_dirty(&self) -> bool {
+        !self.receiver.is_empty()
     }
 
     /// Asynchronously retrieves tensor values into the inner buffer.
@@ -35,9 +91,202 @@ impl<T: StorageType> Probe<T> {
     /// A reference or an owned copy of the retrieved data if there are no errors. Otherwise,
     /// an error is returned.
     pub async fn retrieve(&self) -> Result<Vec<T::IOType>> {
-        self.receiver
-            .recv_async()
-            .await
-            .map_err(|e| Error::ChannelError(e.into()))
+        self.receive(0..self.count).await
+    }
+
+    /// Asynchronously retrieves tensor values as raw bytes, without converting them to any type.
+    /// The bytes are laid out the way the backend transfers the tensor, i.e. as native-endian
+    /// values of the in-memory representation of the tensor type, so booleans take four bytes
+    /// each. Together with `dtype` and `shape`, they describe the tensor completely.
+    ///
+    /// # Returns
+    /// The raw bytes of the retrieved data if there are no errors. Otherwise, an error is returned.
+    pub async fn retrieve_bytes(&self) -> Result<Vec<u8>> {
+        let data = self.receive(0..self.count).await?;
+        let data: Vec<Pod<T>> = data.into_iter().map(IOType::to_pod).collect();
+        Ok(bytemuck::cast_slice(&data).to_vec())
+    }
+
+    /// Asynchronously retrieves tensor values encoded in the NumPy `.npy` format, e.g. to save them
+    /// to a file and load them with `numpy.load`. Like with `retrieve_bytes`, booleans are encoded as
+    /// four-byte `u32` values.
+    ///
+    /// # Returns
+    /// The bytes of the `.npy` file if there are no errors. Otherwise, an error is returned.
+    pub async fn retrieve_npy(&self) -> Result<Vec<u8>> {
+        let data = self.receive(0..self.count).await?;
+        Ok(npy::encode(&self.shape, &data))
+    }
+
+    /// Asynchronously retrieves tensor values together with the shape of the tensor, which allows
+    /// indexing them with multi-dimensional coordinates instead of computing the flat offsets.
+    ///
+    /// # Returns
+    /// A view of the retrieved data if there are no errors. Otherwise, an error is returned.
+    pub async fn view(&self) -> Result<TensorView<T::IOType>> {
+        let data = self.receive(0..self.count).await?;
+        Ok(TensorView::new(self.shape.clone(), data))
+    }
+
+    /// Asynchronously retrieves a range of tensor values. The range is remembered, so that the
+    /// following readouts of the tensor only transfer the requested elements from the backend
+    /// until the whole tensor is requested again with `retrieve`. Since the cached data then only
+    /// covers the range, a following `retrieve` of the same probe waits for the next computation.
+    ///
+    /// # Parameters
+    /// - `range`: The non-empty range of elements to retrieve.
+    ///
+    /// # Returns
+    /// The retrieved data in the requested range if there are no errors. Otherwise, an error is
+    /// returned, including `Error::RangeOutOfBounds` when the range is empty or out of bounds of
+    /// the tensor.
+    pub async fn retrieve_range(&self, range: Range<usize>) -> Result<Vec<T::IOType>> {
+        if range.start >= range.end || range.end > self.count {
+            return Err(Error::RangeOutOfBounds(range, self.count));
+        }
+        self.receive(range).await
+    }
+
+    /// Requests the given range from the tensor and waits for a message covering it. If the tensor
+    /// hasn't been read out since the last message and the cached message covers the range, it is
+    /// used without waiting. Messages read out before the range was requested are used if they
+    /// cover the range, and skipped otherwise.
+    ///
+    /// # Parameters
+    /// - `range`: The range of elements to receive.
+    ///
+    /// # Returns
+    /// The received data in the requested range, or an error if the channel is disconnected.
+    async fn receive(&self, range: Range<usize>) -> Result<Vec<T::IOType>> {
+        let full = range == (0..self.count);
+        *self.window.lock().expect("window lock should not be poisoned") = (!full).then(|| range.clone());
+        if !self.is_dirty() {
+            let cache = self.cache.lock().expect("cache lock should not be poisoned");
+            if let Some(data) = cache.as_ref().and_then(|message| slice(message, &range)) {
+                return Ok(data);
+            }
+        }
+        loop {
+            let message = self
+                .receiver
+                .recv_async()
+                .await
+                .map_err(|e| Error::ChannelError(e.into()))?;
+            let slice = slice(&message, &range);
+            *self.cache.lock().expect("cache lock should not be poisoned") = Some(message);
+            if let Some(data) = slice {
+                return Ok(data);
+            }
+        }
+    }
+}
+
+/// Extracts the requested range from the data of a message. Messages of masked selections only
+/// hold the selected elements, which may be fewer than the range of the message, so the requested
+/// range is clamped to the received data.
+///
+/// # Parameters
+/// - `message`: The range of elements held by the message together with their data.
+/// - `range`: The requested range of elements.
+///
+/// # Returns
+/// The data in the requested range, or `None` if the message doesn't cover it.
+fn slice<T: Clone>((received, data): &(Range<usize>, Vec<T>), range: &Range<usize>) -> Option<Vec<T>> {
+    if received.start <= range.start && range.end <= received.end {
+        let start = (range.start - received.start).min(data.len());
+        let end = (range.end - received.start).min(data.len());
+        Some(data[start..end].to_vec())
+    } else {
+        None
+    }
+}
+
+impl<T: StorageType> Probe<T>
+where
+    T::IOType: PartialEq,
+{
+    /// Asynchronously retrieves a mask and returns the indices of its nonzero elements, e.g. of the
+    /// elements where a comparison producing the mask is true. This is a convenience for
+    /// post-processing the retrieved data on the CPU, so the whole mask is still transferred.
+    ///
+    /// # Returns
+    /// The ascending indices of the nonzero elements if there are no errors. Otherwise, an error
+    /// is returned.
+    pub async fn true_indices(&self) -> Result<Vec<u32>> {
+        let mask = self.retrieve().await?;
+        Ok(mask
+            .iter()
+            .enumerate()
+            .filter(|(_, value)| **value != <T::IOType as StorageType>::ZERO)
+            .map(|(index, _)| index as u32)
+            .collect())
+    }
+}
+
+#[cfg(feature = "testing")]
+impl<T: StorageType> Probe<T>
+where
+    T::IOType: Into<f64>,
+{
+    /// Asynchronously retrieves the tensor and asserts that it matches the reference array stored
+    /// in a `.npy` file, e.g. one saved from `retrieve_npy` as a golden output of a regression test.
+    /// The shapes have to be equal, and every element may differ from the reference by at most the
+    /// tolerance. This is only available with the `testing` feature enabled.
+    ///
+    /// # Parameters
+    /// - `path`: The path to the reference `.npy` file.
+    /// - `tolerance`: The maximum absolute difference allowed between corresponding elements.
+    ///
+    /// # Panics
+    /// Panics if the reference can't be read or decoded, if the tensor can't be retrieved, or if
+    /// it doesn't match the reference. The message of a mismatch names the element differing the
+    /// most from the reference and the number of elements out of tolerance.
+    pub async fn assert_matches_npy(&self, path: impl AsRef<std::path::Path>, tolerance: f64) {
+        let path = path.as_ref();
+        let bytes = std::fs::read(path).unwrap_or_else(|e| panic!("failed to read {}: {e}", path.display()));
+        let (shape, expected) =
+            npy::decode::<T::IOType>(&bytes).unwrap_or_else(|e| panic!("failed to decode {}: {e}", path.display()));
+        let actual = self.retrieve().await.expect("probed tensor should be retrieved");
+        assert_eq!(
+            self.shape,
+            shape,
+            "shape of the tensor doesn't match the shape of {}",
+            path.display()
+        );
+        let pairs: Vec<(f64, f64)> = actual
+            .into_iter()
+            .zip(expected)
+            .map(|(actual, expected)| (actual.into(), expected.into()))
+            .collect();
+        // NOTE: NaNs only match NaNs, and the total order puts them above any finite difference.
+        let close = |(actual, expected): &(f64, f64)| {
+            (actual - expected).abs() <= tolerance || (actual.is_nan() && expected.is_nan())
+        };
+        let mismatches = pairs.iter().filter(|pair| !close(pair)).count();
+        if mismatches > 0 {
+            let (index, (actual, expected)) = pairs
+                .iter()
+                .enumerate()
+                .filter(|(_, pair)| !close(pair))
+                .max_by(|(_, (a, b)), (_, (c, d))| (a - b).abs().total_cmp(&(c - d).abs()))
+                .expect("mismatching elements should exist");
+            panic!(
+                "{mismatches} element(s) differ from {} by more than {tolerance}, the worst at index {index}: \
+                 expected {expected}, got {actual}",
+                path.display()
+            );
+        }
+    }
+}
+
+impl<T: StorageType> Clone for Probe<T> {
+    fn clone(&self) -> Self {
+        Self {
+            receiver: self.receiver.clone(),
+            window: self.window.clone(),
+            cache: self.cache.clone(),
+            shape: self.shape.clone(),
+            count: self.count,
+        }
     }
 }
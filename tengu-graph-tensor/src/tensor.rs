@@ -2,14 +2,17 @@
 //! using a specified backend. It also includes implementations for the `Source` and `Shape` traits,
 //! enabling tensor operations and shape management.
 
+use std::borrow::Cow;
 use std::cell::OnceCell;
 use std::rc::Rc;
+use std::sync::Arc;
 
 use tengu_backend::Backend;
 use tengu_backend_tensor::StorageType;
 use tengu_backend_tensor::Tensor as RawTensor;
 
 use crate::channel::Channel;
+use crate::npy;
 use crate::probe::Probe;
 use crate::{Error, Result};
 
@@ -20,6 +23,7 @@ use crate::{Error, Result};
 pub struct Tensor<T: StorageType, B: Backend> {
     backend: Rc<B>,
     raw: Rc<B::Tensor<T>>,
+    length: Option<Rc<B::Tensor<u32>>>,
     channel: OnceCell<Channel<T>>,
 }
 
@@ -38,10 +42,43 @@ impl<T: StorageType, B: Backend> Tensor<T, B> {
         Self {
             backend: Rc::clone(backend),
             raw: tensor.into(),
+            length: None,
             channel: OnceCell::new(),
         }
     }
 
+    /// Returns a copy of the tensor whose data has a data-dependent length, stored in the single
+    /// element of the specified tensor. Probes of such a tensor only receive the elements up to
+    /// that length when retrieving the whole tensor.
+    ///
+    /// # Parameters
+    /// - `length`: The `[1]`-shaped tensor holding the number of valid elements.
+    ///
+    /// # Returns
+    /// A new `Tensor` instance sharing the data of the original one.
+    pub fn with_length(&self, length: &Tensor<u32, B>) -> Self {
+        Self {
+            length: Some(Rc::clone(&length.raw)),
+            ..self.clone()
+        }
+    }
+
+    /// Returns a copy of the tensor with another label. The copy shares the data of the original
+    /// tensor and the channel of its probes, so probes obtained for the original tensor receive
+    /// the data read out from the copy.
+    ///
+    /// # Parameters
+    /// - `label`: The label of the copy.
+    ///
+    /// # Returns
+    /// A new `Tensor` instance sharing the data and probes of the original one.
+    pub fn relabeled(&self, label: impl Into<String>) -> Self {
+        Self {
+            raw: Rc::new(self.raw.relabeled(label.into())),
+            ..self.clone()
+        }
+    }
+
     /// Returns a reference to the underlying backend tensor.
     ///
     /// # Returns
@@ -55,7 +92,8 @@ impl<T: StorageType, B: Backend> Tensor<T, B> {
     /// # Returns
     /// A `Probe` object for the tensor.
     pub fn probe(&self) -> Probe<T> {
-        Probe::new(self.channel().receiver())
+        let channel = self.channel();
+        Probe::new(channel.receiver(), Arc::clone(channel.window()), self.raw.shape())
     }
 
     /// Returns the label of the tensor.
@@ -69,7 +107,8 @@ impl<T: StorageType, B: Backend> Tensor<T, B> {
     /// Reads the tensor data from the source and sends it to associated probes.
     /// If the channel is full then there is no point wasting time on reading the data out - the
     /// previous message hasn't been read out by the probe yet. In this case the method will return
-    /// immediately without retrieving and sending anything.
+    /// immediately without retrieving and sending anything. If the probes requested a window of
+    /// the tensor, only the elements in that window are read out.
     ///
     /// # Returns
     /// A result indicating the success of the operation.
@@ -77,13 +116,99 @@ impl<T: StorageType, B: Backend> Tensor<T, B> {
         if self.channel().is_full() {
             return Ok(());
         }
-        let data: Vec<_> = self.raw().retrieve().await.map_err(Error::ChannelError)?.into_owned();
+        let window = self
+            .channel()
+            .window()
+            .lock()
+            .expect("window lock should not be poisoned")
+            .clone();
+        let (range, data) = match window {
+            Some(range) => (range.clone(), self.raw().retrieve_range(range).await),
+            None => (0..self.raw().count(), self.retrieve_valid().await),
+        };
+        let data: Vec<_> = data.map_err(Error::ChannelError)?.into_owned();
         self.channel()
-            .send(data)
+            .send((range, data))
             .await
             .map_err(|e| Error::ChannelError(e.into()))
     }
 
+    /// Reads the tensor data from the source. Unlike `retrieve`, the data is returned directly
+    /// instead of being sent to the probes, so the probes still receive their data as usual.
+    ///
+    /// # Returns
+    /// A result containing the valid elements of the tensor.
+    pub async fn read(&self) -> Result<Vec<T::IOType>> {
+        let data = self.retrieve_valid().await.map_err(Error::ChannelError)?;
+        Ok(data.into_owned())
+    }
+
+    /// Reads the tensor data from the source and encodes it in the NumPy `.npy` format. Unlike
+    /// `retrieve`, the data is returned directly instead of being sent to the probes, so the probes
+    /// still receive their data as usual.
+    ///
+    /// # Returns
+    /// A result containing the bytes of the `.npy` file.
+    pub async fn retrieve_npy(&self) -> Result<Vec<u8>> {
+        let data = self.retrieve_valid().await.map_err(Error::ChannelError)?;
+        Ok(npy::encode(self.raw().shape(), &data))
+    }
+
+    /// Overwrites the data of the tensor. Computations run afterwards see the new data, while the
+    /// probes keep the data they have already received.
+    ///
+    /// # Parameters
+    /// - `data`: The new data of the tensor in row-major order.
+    ///
+    /// # Returns
+    /// A result indicating success, or `Error::ShapeMismatch` if the number of elements in the
+    /// data doesn't match the tensor.
+    pub fn write(&self, data: &[T::IOType]) -> Result<()> {
+        if data.len() != self.raw().count() {
+            return Err(Error::ShapeMismatch(vec![data.len()], self.raw().shape().to_vec()));
+        }
+        self.raw().write(data).map_err(Error::WriteError)
+    }
+
+    /// Overwrites the data of the tensor with the contents of a `.npy` file, like the ones produced
+    /// by `retrieve_npy`. If the tensor has a data-dependent length, only its valid elements are
+    /// saved in the file, so the file may hold fewer elements than the tensor, and the rest of the
+    /// tensor is filled with zeros.
+    ///
+    /// # Parameters
+    /// - `bytes`: The bytes of the `.npy` file.
+    ///
+    /// # Returns
+    /// A result indicating success, `Error::InvalidNpy` if the bytes are not a valid `.npy` file of
+    /// the tensor type, or `Error::ShapeMismatch` if the shape of the file doesn't match the tensor.
+    pub fn write_npy(&self, bytes: &[u8]) -> Result<()> {
+        let (shape, mut data) = npy::decode::<T::IOType>(bytes)?;
+        let truncated = self.length.is_some() && shape.len() == 1 && data.len() <= self.raw().count();
+        if shape != self.raw().shape() && !truncated {
+            return Err(Error::ShapeMismatch(shape, self.raw().shape().to_vec()));
+        }
+        data.resize(self.raw().count(), Default::default());
+        self.write(&data)
+    }
+
+    /// Retrieves the valid elements of the tensor. If the tensor has a data-dependent length, the
+    /// length is retrieved together with the data, so that both are requested before the backend
+    /// is polled, and the data is truncated to it.
+    ///
+    /// # Returns
+    /// A result containing the valid elements of the tensor.
+    async fn retrieve_valid(&self) -> anyhow::Result<Cow<'_, [T::IOType]>> {
+        let Some(length) = &self.length else {
+            return self.raw().retrieve().await;
+        };
+        let (data, length) = futures::join!(self.raw().retrieve(), length.retrieve());
+        let (mut data, length) = (data?, length?[0] as usize);
+        if length < data.len() {
+            data.to_mut().truncate(length);
+        }
+        Ok(data)
+    }
+
     /// Returns a reference to the channel for the tensor.
     ///
     /// # Returns
@@ -104,6 +229,7 @@ impl<T: StorageType, B: Backend> Clone for Tensor<T, B> {
         Self {
             backend: Rc::clone(&self.backend),
             raw: Rc::clone(&self.raw),
+            length: self.length.clone(),
             channel: self.channel.clone(),
         }
     }
@@ -0,0 +1,92 @@
+//! This module defines the `TensorView` struct, which gives multi-dimensional access to tensor data
+//! retrieved by probes. Probes return the data flattened in row-major order, so the view keeps the
+//! shape of the tensor along with the data and does the stride arithmetic when indexing into it.
+
+use std::ops::Index;
+
+/// A read-only multi-dimensional view of retrieved tensor data.
+pub struct TensorView<T> {
+    shape: Vec<usize>,
+    strides: Vec<usize>,
+    data: Vec<T>,
+}
+
+impl<T> TensorView<T> {
+    /// Creates a new `TensorView` instance.
+    ///
+    /// # Parameters
+    /// - `shape`: The shape of the tensor.
+    /// - `data`: The data of the tensor in row-major order.
+    ///
+    /// # Returns
+    /// A new `TensorView` instance.
+    ///
+    /// # Panics
+    /// Panics if the number of elements doesn't match the shape.
+    pub fn new(shape: impl Into<Vec<usize>>, data: Vec<T>) -> Self {
+        let shape = shape.into();
+        let count = shape.iter().product::<usize>();
+        assert_eq!(data.len(), count, "expected {count} elements for shape {shape:?}");
+        let strides = (0..shape.len())
+            .map(|axis| shape[axis + 1..].iter().product())
+            .collect();
+        Self { shape, strides, data }
+    }
+
+    /// Returns the shape of the viewed tensor.
+    ///
+    /// # Returns
+    /// A slice representing the dimensions of the tensor.
+    pub fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+
+    /// Returns the viewed data in row-major order.
+    ///
+    /// # Returns
+    /// A slice of the tensor data.
+    pub fn data(&self) -> &[T] {
+        &self.data
+    }
+
+    /// Returns the element at the given multi-dimensional index.
+    ///
+    /// # Parameters
+    /// - `index`: The coordinates of the element, one per axis.
+    ///
+    /// # Returns
+    /// A reference to the element, or `None` if the index doesn't match the rank of the tensor or
+    /// is out of bounds along any axis.
+    pub fn get(&self, index: &[usize]) -> Option<&T> {
+        if index.len() != self.shape.len() || index.iter().zip(&self.shape).any(|(i, dim)| i >= dim) {
+            return None;
+        }
+        let offset = index
+            .iter()
+            .zip(&self.strides)
+            .map(|(i, stride)| i * stride)
+            .sum::<usize>();
+        self.data.get(offset)
+    }
+}
+
+// NOTE: Index implementation.
+
+impl<T, const N: usize> Index<[usize; N]> for TensorView<T> {
+    type Output = T;
+
+    /// Returns the element at the given multi-dimensional index.
+    ///
+    /// # Parameters
+    /// - `index`: The coordinates of the element, one per axis.
+    ///
+    /// # Returns
+    /// A reference to the element.
+    ///
+    /// # Panics
+    /// Panics if the index doesn't match the rank of the tensor or is out of bounds along any axis.
+    fn index(&self, index: [usize; N]) -> &Self::Output {
+        self.get(&index)
+            .unwrap_or_else(|| panic!("index {index:?} is out of bounds for shape {:?}", self.shape))
+    }
+}
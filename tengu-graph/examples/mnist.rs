@@ -21,7 +21,7 @@ pub async fn main() {
 
     // Create computation graph.
     let mut graph = tengu.graph();
-    graph.add_block("main").unwrap().add_computation("m1", w1);
+    graph.add_block("main").unwrap().add_computation("m1", w1).unwrap();
     //
     // Set up probes.
     let p1 = graph.add_probe::<f32>("main/w1").unwrap();
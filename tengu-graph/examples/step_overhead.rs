@@ -0,0 +1,66 @@
+// This example measures the per-iteration overhead of running a small graph many times. The graph
+// is tiny, so the wall-clock time of an iteration is dominated by encoding and submitting the
+// commands rather than by the computation itself.
+//
+// WGPU command encoders are consumed when finished and command buffers when submitted, so neither
+// can be reused across iterations. Instead, iterations only submit what they need: links and
+// probes each add a submission, which is skipped when the graph has none of them. The graph is
+// benchmarked without links and probes, with a link, and with a link and a probe to show what each
+// of them costs, and every variant is measured both as it runs now and with the empty link and
+// readout submissions that iterations used to make when the graph had no links or probes. All the
+// iterations of a measurement run on a single executor, so the shader is compiled only once.
+
+use std::time::Instant;
+
+use tengu_backend::Backend;
+use tengu_graph::Tengu;
+
+const WARMUP: usize = 100;
+const MEASURED: usize = 1000;
+
+/// The benchmarked variants: their names and whether they have a link and a probe.
+const VARIANTS: [(&str, bool, bool); 3] = [
+    ("plain", false, false),
+    ("link", true, false),
+    ("link+probe", true, true),
+];
+
+#[pollster::main]
+pub async fn main() {
+    let tengu = Tengu::wgpu().await.unwrap();
+    println!("{:>10}  {:>10}  {:>10}", "", "skipped", "submitted");
+    for (name, link, probe) in VARIANTS {
+        // Create the iteration x <- x + 1.
+        let x = tengu.tensor([64]).label("x").zero::<f32>();
+        let mut graph = tengu.graph();
+        graph.add_block("main").unwrap().add_computation("y", x + 1.0).unwrap();
+        if link {
+            graph.add_link("main/y", "main/x").unwrap();
+        }
+        let _probe = probe.then(|| graph.add_probe::<f32>("main/y").unwrap());
+
+        // Measure the iterations as they run now and followed by the empty submissions they used to
+        // make in place of the skipped ones, waiting for the device after each iteration.
+        let mut means = Vec::new();
+        for empty_submissions in [false, true] {
+            let mut start = Instant::now();
+            graph
+                .process(WARMUP + MEASURED, |i| {
+                    if empty_submissions && !link {
+                        tengu.backend().propagate(|_| {});
+                    }
+                    if empty_submissions && !probe {
+                        tengu.backend().readout("readout", |_| {});
+                    }
+                    tengu.backend().poll();
+                    if i + 1 == WARMUP {
+                        start = Instant::now();
+                    }
+                })
+                .await
+                .unwrap();
+            means.push(start.elapsed() / MEASURED as u32);
+        }
+        println!("{name:>10}: {:>10.1?}  {:>10.1?}", means[0], means[1]);
+    }
+}
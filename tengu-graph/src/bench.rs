@@ -0,0 +1,75 @@
+//! Module for summarizing benchmarks of graphs in the Tengu tensor computation framework.
+//!
+//! This module defines the `BenchResult` struct, which holds the durations of the measured
+//! iterations of a benchmark run with `Graph::benchmark` and provides their statistics.
+
+use std::time::Duration;
+
+/// The durations of the measured iterations of a benchmark.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BenchResult {
+    samples: Vec<Duration>,
+}
+
+impl BenchResult {
+    /// Creates a new `BenchResult` instance from the durations of the measured iterations.
+    ///
+    /// # Parameters
+    /// - `samples`: The duration of each measured iteration, in order.
+    ///
+    /// # Returns
+    /// A new `BenchResult` instance.
+    pub fn new(samples: Vec<Duration>) -> Self {
+        Self { samples }
+    }
+
+    /// Returns the durations of the measured iterations.
+    ///
+    /// # Returns
+    /// A slice with the duration of each measured iteration, in order.
+    pub fn samples(&self) -> &[Duration] {
+        &self.samples
+    }
+
+    /// Returns the duration of the fastest iteration.
+    ///
+    /// # Returns
+    /// The minimum duration, or zero if no iterations were measured.
+    pub fn min(&self) -> Duration {
+        self.samples.iter().min().copied().unwrap_or_default()
+    }
+
+    /// Returns the mean duration of the iterations.
+    ///
+    /// # Returns
+    /// The mean duration, or zero if no iterations were measured.
+    pub fn mean(&self) -> Duration {
+        if self.samples.is_empty() {
+            return Duration::ZERO;
+        }
+        self.samples.iter().sum::<Duration>() / self.samples.len() as u32
+    }
+
+    /// Returns the duration of the slowest iteration.
+    ///
+    /// # Returns
+    /// The maximum duration, or zero if no iterations were measured.
+    pub fn max(&self) -> Duration {
+        self.samples.iter().max().copied().unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn statistics() {
+        let millis = [3, 1, 2].map(Duration::from_millis);
+        let result = BenchResult::new(millis.to_vec());
+        assert_eq!(result.min(), Duration::from_millis(1));
+        assert_eq!(result.mean(), Duration::from_millis(2));
+        assert_eq!(result.max(), Duration::from_millis(3));
+        assert_eq!(BenchResult::default().mean(), Duration::ZERO);
+    }
+}
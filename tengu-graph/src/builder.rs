@@ -5,14 +5,17 @@
 
 use rand::distributions::uniform::SampleUniform;
 use rand::distributions::Uniform;
+use rand::rngs::StdRng;
 use rand::Rng;
 use rand_distr::Distribution;
 use rand_distr::Normal;
 use rand_distr::StandardNormal;
 use tengu_graph_tensor::Tensor;
 
+use std::cell::RefCell;
 use std::rc::Rc;
 
+use num::traits::AsPrimitive;
 use num::Float;
 use tengu_backend::Backend;
 use tengu_backend_tensor::IOType;
@@ -28,27 +31,38 @@ pub struct Builder<B: Backend> {
     count: usize,
     label: Option<String>,
     backend: Rc<B>,
+    rng: Rc<RefCell<Option<StdRng>>>,
 }
 
 impl<B: Backend> Builder<B> {
-    /// Creates a new `Builder` instance with the specified backend and shape.
+    /// Creates a new `Builder` instance with the specified backend and shape. Tensors always hold at
+    /// least one element, so the shape has to have at least one dimension and none of its
+    /// dimensions can be zero.
     ///
     /// # Parameters
     /// - `backend`: A reference-counted backend instance.
+    /// - `rng`: The seeded random number generator shared by the builders of a `Tengu` instance,
+    ///   if it has been seeded.
     /// - `shape`: The shape of the tensor.
     ///
     /// # Returns
-    /// A new `Builder` instance.
-    pub fn new(backend: &Rc<B>, shape: impl Into<Vec<usize>>) -> Self {
+    /// A new `Builder` instance, or `Error::InvalidShape` if the shape is empty or contains a zero
+    /// dimension.
+    pub fn new(backend: &Rc<B>, rng: &Rc<RefCell<Option<StdRng>>>, shape: impl Into<Vec<usize>>) -> Result<Self> {
         let shape = shape.into();
+        if shape.is_empty() || shape.contains(&0) {
+            return Err(Error::InvalidShape(shape));
+        }
         let count = shape.iter().product();
-        Self {
+        Ok(Self {
             shape,
             count,
             label: None,
             backend: Rc::clone(backend),
-        }
+            rng: Rc::clone(rng),
+        })
     }
+
     /// Sets the label for the tensor.
     ///
     /// # Parameters
@@ -56,9 +70,31 @@ impl<B: Backend> Builder<B> {
     ///
     /// # Returns
     /// The `Builder` instance with the updated label.
-    pub fn label(mut self, label: impl Into<String>) -> Self {
-        self.label = Some(label.into());
-        self
+    ///
+    /// # Panics
+    /// Panics if the label is not a valid identifier. Use `try_label` to handle invalid labels
+    /// gracefully.
+    pub fn label(self, label: impl Into<String>) -> Self {
+        self.try_label(label).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Sets the label for the tensor, validating it first. Labels are used as identifiers by the
+    /// backends, so they may only contain letters, digits and underscores and cannot start with a
+    /// digit or be a reserved word.
+    ///
+    /// # Parameters
+    /// - `label`: The label for the tensor.
+    ///
+    /// # Returns
+    /// The `Builder` instance with the updated label, or `Error::InvalidLabel` if the label is not a
+    /// valid identifier.
+    pub fn try_label(mut self, label: impl Into<String>) -> Result<Self> {
+        let label = label.into();
+        if !tengu_backend_tensor::is_valid_label(&label) {
+            return Err(Error::InvalidLabel(label));
+        }
+        self.label = Some(label);
+        Ok(self)
     }
 
     /// Creates a tensor initialized to zero.
@@ -68,11 +104,88 @@ impl<B: Backend> Builder<B> {
     ///
     /// # Returns
     /// An expression representing the tensor initialized to zero.
-    pub fn zero<T: StorageType>(mut self) -> Expression<T, B> {
+    ///
+    /// # Panics
+    /// Panics if the backend cannot allocate the tensor. Use `try_zero` to handle allocation
+    /// failures gracefully.
+    pub fn zero<T: StorageType>(self) -> Expression<T, B> {
+        self.try_zero().unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Creates a tensor initialized to zero, returning allocation failures instead of panicking,
+    /// e.g. so that the application can retry with a smaller batch size.
+    ///
+    /// # Type Parameters
+    /// - `T`: The storage type of the tensor.
+    ///
+    /// # Returns
+    /// A result wrapping the expression representing the tensor initialized to zero, or
+    /// `Error::BackendError` wrapping `OutOfMemory` if the backend cannot allocate the tensor.
+    pub fn try_zero<T: StorageType>(mut self) -> Result<Expression<T, B>> {
         let label = self.get_or_create_label();
-        let tensor = self.backend.zero(label, self.shape);
+        let tensor = self.backend.try_zero(label, self.shape)?;
         let tensor = Tensor::new(&self.backend, tensor);
-        Expression::Tensor(tensor)
+        Ok(Expression::Tensor(tensor))
+    }
+
+    /// Creates a `f32` tensor initialized to zero. This is `zero::<f32>()` for contexts where the
+    /// type can't be inferred, without the turbofish.
+    ///
+    /// # Returns
+    /// An expression representing the tensor initialized to zero.
+    ///
+    /// # Panics
+    /// Panics if the backend cannot allocate the tensor.
+    pub fn zeros_f32(self) -> Expression<f32, B> {
+        self.zero()
+    }
+
+    /// Creates a `i32` tensor initialized to zero. This is `zero::<i32>()` for contexts where the
+    /// type can't be inferred, without the turbofish.
+    ///
+    /// # Returns
+    /// An expression representing the tensor initialized to zero.
+    ///
+    /// # Panics
+    /// Panics if the backend cannot allocate the tensor.
+    pub fn zeros_i32(self) -> Expression<i32, B> {
+        self.zero()
+    }
+
+    /// Creates a `u32` tensor initialized to zero. This is `zero::<u32>()` for contexts where the
+    /// type can't be inferred, without the turbofish.
+    ///
+    /// # Returns
+    /// An expression representing the tensor initialized to zero.
+    ///
+    /// # Panics
+    /// Panics if the backend cannot allocate the tensor.
+    pub fn zeros_u32(self) -> Expression<u32, B> {
+        self.zero()
+    }
+
+    /// Creates a `i16` tensor initialized to zero. This is `zero::<i16>()` for contexts where the
+    /// type can't be inferred, without the turbofish.
+    ///
+    /// # Returns
+    /// An expression representing the tensor initialized to zero.
+    ///
+    /// # Panics
+    /// Panics if the backend cannot allocate the tensor.
+    pub fn zeros_i16(self) -> Expression<i16, B> {
+        self.zero()
+    }
+
+    /// Creates a `u16` tensor initialized to zero. This is `zero::<u16>()` for contexts where the
+    /// type can't be inferred, without the turbofish.
+    ///
+    /// # Returns
+    /// An expression representing the tensor initialized to zero.
+    ///
+    /// # Panics
+    /// Panics if the backend cannot allocate the tensor.
+    pub fn zeros_u16(self) -> Expression<u16, B> {
+        self.zero()
     }
 
     /// Creates a tensor initialized with the specified data.
@@ -87,13 +200,74 @@ impl<B: Backend> Builder<B> {
     /// An expression representing the tensor initialized with the data.
     ///
     /// # Panics
+    /// Panics if the length of the data does not match the shape of the tensor, or if the backend
+    /// cannot allocate the tensor. Use `try_init` to handle allocation failures gracefully.
+    pub fn init<T: IOType>(self, data: &[T]) -> Expression<T, B> {
+        self.try_init(data).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Creates a tensor initialized with the specified data, returning allocation failures instead
+    /// of panicking.
+    ///
+    /// # Type Parameters
+    /// - `T`: The I/O type of the tensor.
+    ///
+    /// # Parameters
+    /// - `data`: A slice of data to initialize the tensor.
+    ///
+    /// # Returns
+    /// A result wrapping the expression representing the tensor initialized with the data, or
+    /// `Error::BackendError` wrapping `OutOfMemory` if the backend cannot allocate the tensor.
+    ///
+    /// # Panics
     /// Panics if the length of the data does not match the shape of the tensor.
-    pub fn init<T: IOType>(mut self, data: &[T]) -> Expression<T, B> {
+    pub fn try_init<T: IOType>(mut self, data: &[T]) -> Result<Expression<T, B>> {
         assert_eq!(data.len(), self.count, "data length does not match shape");
         let label = self.get_or_create_label();
-        let tensor = self.backend.tensor(label, self.shape, data);
+        let tensor = self.backend.try_tensor(label, self.shape, data)?;
         let tensor = Tensor::new(&self.backend, tensor);
-        Expression::Tensor(tensor)
+        Ok(Expression::Tensor(tensor))
+    }
+
+    /// Creates a tensor initialized with the specified data converted to the element type of the
+    /// tensor, e.g. `f64` host data uploaded into an `f32` tensor. Values are converted like with
+    /// the `as` operator, so floats are rounded towards zero and saturated when converted to
+    /// integers.
+    ///
+    /// # Type Parameters
+    /// - `Src`: The type of the host data.
+    /// - `Dst`: The I/O type of the tensor.
+    ///
+    /// # Parameters
+    /// - `data`: A slice of data to convert and initialize the tensor with.
+    ///
+    /// # Returns
+    /// An expression representing the tensor initialized with the converted data.
+    ///
+    /// # Panics
+    /// Panics if the length of the data does not match the shape of the tensor.
+    pub fn cast_init<Src, Dst>(self, data: &[Src]) -> Expression<Dst, B>
+    where
+        Src: AsPrimitive<Dst>,
+        Dst: IOType,
+    {
+        let data = data.iter().map(|value| value.as_()).collect::<Vec<_>>();
+        self.init(&data)
+    }
+
+    /// Creates a tensor with every element set to the specified value.
+    ///
+    /// # Type Parameters
+    /// - `T`: The I/O type of the tensor.
+    ///
+    /// # Parameters
+    /// - `value`: The value of every element of the tensor.
+    ///
+    /// # Returns
+    /// An expression representing the tensor filled with the value.
+    pub fn full<T: IOType>(self, value: T) -> Expression<T, B> {
+        let data = vec![value; self.count];
+        self.init(&data)
     }
 
     /// Creates a tensor initialized with random data. This is the most general method if you need
@@ -124,6 +298,28 @@ impl<B: Backend> Builder<B> {
         Expression::Tensor(tensor)
     }
 
+    /// Creates a tensor initialized with random data drawn from the given distribution, using the
+    /// seeded random number generator of the `Tengu` instance if there is one and the thread-local
+    /// one otherwise.
+    ///
+    /// # Type Parameters
+    /// - `T`: The I/O type of the tensor.
+    /// - `D`: The distribution type for generating random data.
+    ///
+    /// # Parameters
+    /// - `distr`: The distribution for generating random data.
+    ///
+    /// # Returns
+    /// An expression representing the tensor initialized with random data.
+    fn sample<T: IOType, D: Distribution<T>>(self, distr: D) -> Expression<T, B> {
+        let rng = Rc::clone(&self.rng);
+        let mut rng = rng.borrow_mut();
+        match rng.as_mut() {
+            Some(rng) => self.random(rng, distr),
+            None => self.random(rand::thread_rng(), distr),
+        }
+    }
+
     /// Creates a tensor initialized with random data drawn from a uniform distribution.
     /// The data will be drawn from the range `[low, high)`.
     ///
@@ -137,8 +333,7 @@ impl<B: Backend> Builder<B> {
     /// # Returns
     /// An expression representing the tensor initialized with random data drawn from a uniform distribution.
     pub fn uniform<T: IOType + SampleUniform>(self, low: T, high: T) -> Expression<T, B> {
-        let rng = rand::thread_rng();
-        self.random(rng, Uniform::new(low, high))
+        self.sample(Uniform::new(low, high))
     }
 
     /// Creates a tensor initialized with random data drawn from a normal distribution.
@@ -159,13 +354,12 @@ impl<B: Backend> Builder<B> {
         T: IOType + Float,
         StandardNormal: Distribution<T>,
     {
-        let rng = rand::thread_rng();
         let distr = Normal::new(mean, std_dev).map_err(|e| Error::ParameterError(e.into()))?;
-        Ok(self.random(rng, distr))
+        Ok(self.sample(distr))
     }
 
-    /// Creates a tensor initialized with the specified boolean data. The resulting tensor will
-    /// use u32 instead of bool because bool cannot be used as an IOType on some backends.
+    /// Creates a `u32` tensor initialized with the specified boolean data, e.g. for masks which
+    /// are multiplied with other tensors. Use `init` to create a `bool` tensor instead.
     ///
     /// # Parameters
     /// - `data`: A slice of boolean data to initialize the tensor.
@@ -2,16 +2,21 @@
 //! within a computation graph. The collector is used to gather all sources required for a computation
 //! and to ensure that only the necessary sources are processed.
 
+use std::collections::hash_map::Entry;
 use std::collections::{HashMap, HashSet};
 
 use tengu_backend::Backend;
+use tengu_backend_tensor::Type;
 
+use crate::expression::Materialized;
 use crate::source::Source;
 
 /// Struct representing a source collector for a computation graph.
 pub struct Collector<'a, B: Backend> {
-    filter: &'a HashSet<String>,
+    filter: Option<&'a HashSet<String>>,
     sources: HashMap<&'a str, &'a dyn Source<B>>,
+    conflicts: Vec<&'a dyn Source<B>>,
+    materialized: Vec<&'a dyn Materialized<B>>,
 }
 
 impl<'a, B: Backend + 'static> Collector<'a, B> {
@@ -25,21 +30,92 @@ impl<'a, B: Backend + 'static> Collector<'a, B> {
     /// A new `Collector` instance.
     pub fn new(filter: &'a HashSet<String>) -> Self {
         Self {
-            filter,
+            filter: Some(filter),
             sources: HashMap::new(),
+            conflicts: Vec::new(),
+            materialized: Vec::new(),
         }
     }
 
-    /// Adds a source to the collector.
+    /// Creates a new `Collector` instance which collects all sources regardless of their labels.
+    ///
+    /// # Returns
+    /// A new `Collector` instance.
+    pub fn all() -> Self {
+        Self {
+            filter: None,
+            sources: HashMap::new(),
+            conflicts: Vec::new(),
+            materialized: Vec::new(),
+        }
+    }
+
+    /// Adds a source to the collector. Sources are identified by their labels, so if a source with
+    /// the same label but a different shape has already been added, the new one is recorded as a
+    /// conflict instead.
     ///
     /// # Parameters
     /// - `source`: A reference to the source to add.
     pub fn add(&mut self, source: &'a dyn Source<B>) {
-        if self.filter.contains(source.label()) {
-            self.sources.entry(source.label()).or_insert(source);
+        if self.filter.is_none_or(|filter| filter.contains(source.label())) {
+            match self.sources.entry(source.label()) {
+                Entry::Occupied(entry) if entry.get().shape() != source.shape() => self.conflicts.push(source),
+                Entry::Occupied(_) => {}
+                Entry::Vacant(entry) => {
+                    entry.insert(source);
+                }
+            }
+        }
+    }
+
+    /// Returns the first source which was added with the label of an earlier source, but with a
+    /// different shape.
+    ///
+    /// # Returns
+    /// The conflicting source together with the earlier source of the same label, or `None` if
+    /// there are no conflicts.
+    pub(crate) fn conflict(&self) -> Option<(&'a dyn Source<B>, &'a dyn Source<B>)> {
+        let source = *self.conflicts.first()?;
+        Some((source, self.sources[source.label()]))
+    }
+
+    /// Registers a subexpression which has to be materialized into its own tensor. Subexpressions
+    /// are kept in the order they are registered, and each of them is registered only once.
+    ///
+    /// # Parameters
+    /// - `materialized`: A reference to the materialized subexpression.
+    pub(crate) fn materialize(&mut self, materialized: &'a dyn Materialized<B>) {
+        let label = materialized.label();
+        if self.materialized.iter().all(|other| other.label() != label) {
+            self.materialized.push(materialized);
         }
     }
 
+    /// Consumes the collector and returns an iterator over the registered materialized
+    /// subexpressions, in the order they have to be computed.
+    ///
+    /// # Returns
+    /// An iterator over the materialized subexpressions.
+    pub(crate) fn into_materialized(self) -> impl Iterator<Item = &'a dyn Materialized<B>> {
+        self.materialized.into_iter()
+    }
+
+    /// Returns the number of distinct sources in the collector.
+    ///
+    /// # Returns
+    /// The number of sources.
+    pub fn count(&self) -> usize {
+        self.sources.len()
+    }
+
+    /// Consumes the collector and returns an iterator over the collected sources.
+    ///
+    /// # Returns
+    /// An iterator over the sources in the collector.
+    pub fn into_sources(self) -> impl Iterator<Item = &'a dyn Source<B>> {
+        self.sources.into_values()
+    }
+
     /// Returns an iterator over the sources in the collector.
     ///
     /// # Returns
@@ -47,4 +123,16 @@ impl<'a, B: Backend + 'static> Collector<'a, B> {
     pub fn sources(&'a self) -> impl Iterator<Item = &'a dyn Source<B>> {
         self.sources.values().copied()
     }
+
+    /// Returns an iterator over the sources in the collector with the specified element type, e.g.
+    /// for passes which only process float tensors.
+    ///
+    /// # Parameters
+    /// - `ty`: The element type of the returned sources.
+    ///
+    /// # Returns
+    /// An iterator over the sources of the element type.
+    pub fn sources_of_dtype(&'a self, ty: Type) -> impl Iterator<Item = &'a dyn Source<B>> {
+        self.sources().filter(move |source| source.dtype() == ty)
+    }
 }
@@ -0,0 +1,117 @@
+//! Module for estimating the computational cost of expressions in the Tengu tensor computation
+//! framework.
+//!
+//! This module defines the `CostEstimate` struct, which sums up the work done by the nodes of an
+//! expression tree. The estimate is heuristic: it doesn't account for the backend or the hardware,
+//! but it is good enough to compare expressions with each other, e.g. to decide whether an
+//! expression is worth running on the GPU.
+
+use std::ops::{Add, AddAssign};
+
+/// The weight of a reduced element relative to an elementwise operation in the total cost.
+/// Reductions are harder to parallelize and usually need several passes over the data.
+const REDUCTION_WEIGHT: usize = 4;
+
+/// The number of bytes of memory traffic weighted as a single elementwise operation in the total
+/// cost.
+const BYTES_PER_OP: usize = 4;
+
+/// An estimate of the computational cost of an expression.
+///
+/// Every node of the expression tree contributes to the estimate proportionally to the number of
+/// elements it processes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CostEstimate {
+    /// The number of elementwise operations, e.g. additions, casts or exponentials.
+    pub elementwise: usize,
+    /// The number of elements processed by reductions, e.g. means, softmaxes or histograms.
+    pub reductions: usize,
+    /// The number of bytes read from and written to tensors.
+    pub memory: usize,
+}
+
+impl CostEstimate {
+    /// Creates an estimate of elementwise operations.
+    ///
+    /// # Parameters
+    /// - `count`: The number of elementwise operations.
+    ///
+    /// # Returns
+    /// A new `CostEstimate` instance.
+    pub fn elementwise(count: usize) -> Self {
+        Self {
+            elementwise: count,
+            ..Self::default()
+        }
+    }
+
+    /// Creates an estimate of a reduction.
+    ///
+    /// # Parameters
+    /// - `count`: The number of reduced elements.
+    ///
+    /// # Returns
+    /// A new `CostEstimate` instance.
+    pub fn reduction(count: usize) -> Self {
+        Self {
+            reductions: count,
+            ..Self::default()
+        }
+    }
+
+    /// Creates an estimate of memory traffic.
+    ///
+    /// # Parameters
+    /// - `bytes`: The number of bytes read or written.
+    ///
+    /// # Returns
+    /// A new `CostEstimate` instance.
+    pub fn memory(bytes: usize) -> Self {
+        Self {
+            memory: bytes,
+            ..Self::default()
+        }
+    }
+
+    /// Combines the parts of the estimate into a single number, measured in elementwise
+    /// operations, so that estimates can be compared with each other.
+    ///
+    /// # Returns
+    /// The total cost.
+    pub fn total(&self) -> usize {
+        self.elementwise + self.reductions * REDUCTION_WEIGHT + self.memory / BYTES_PER_OP
+    }
+}
+
+// NOTE: Arithmetic implementations.
+
+impl Add for CostEstimate {
+    type Output = Self;
+
+    /// Sums up two estimates.
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            elementwise: self.elementwise + rhs.elementwise,
+            reductions: self.reductions + rhs.reductions,
+            memory: self.memory + rhs.memory,
+        }
+    }
+}
+
+impl AddAssign for CostEstimate {
+    /// Adds another estimate to this one.
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn total() {
+        let cost = CostEstimate::elementwise(10) + CostEstimate::reduction(2) + CostEstimate::memory(16);
+        assert_eq!(cost.total(), 10 + 2 * REDUCTION_WEIGHT + 16 / BYTES_PER_OP);
+    }
+}
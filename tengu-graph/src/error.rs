@@ -10,14 +10,49 @@ pub enum Error {
     SourceNotFound(String),
     #[error("Link path {0} does not contain '/'")]
     InvalidLinkPath(String),
+    #[error("Source with label {0} already exists in the block")]
+    SourceAlreadyExists(String),
+    #[error("Computation with output {0} already exists in the block")]
+    DuplicateOutput(String),
+    #[error("Tensor {0} has no probe")]
+    ProbeNotFound(String),
     #[error("Cannot find block with label {0}")]
     BlockNotFound(String),
     #[error("Block with id {0} already exists in the graph")]
     BlockAlreadyExists(String),
+    #[error("Block {0} depends on block {1}, which has not been computed yet")]
+    DependencyNotComputed(String, String),
+    #[error("Block {0} uses {1} tensors, but the backend allows at most {2} in a single compute stage")]
+    TooManyTensors(String, usize, usize),
+    #[error("Computation '{0}' in block '{1}' failed: {2}")]
+    ComputationError(String, String, #[source] tengu_backend::Error),
+    #[error("Expression materialized into {0} is already computed by block {1}")]
+    MaterializedElsewhere(String, String),
+    #[error("Link from {from} to {to} refers to a tensor which no longer exists")]
+    DanglingLink { from: String, to: String },
+    #[error(
+        "Computation {computation} in block {block} uses tensor {tensor} with shape {shape:?}, \
+         but it has shape {expected:?} elsewhere in the block"
+    )]
+    ShapeConflict {
+        block: String,
+        computation: String,
+        tensor: String,
+        shape: Vec<usize>,
+        expected: Vec<usize>,
+    },
     #[error("Types don't match")]
     TypeMismatch,
     #[error("Shapes don't match")]
     ShapeMismatch,
+    #[error("Invalid tensor shape {0:?}: shapes must be non-empty and have no zero dimensions")]
+    InvalidShape(Vec<usize>),
+    #[error("Invalid label {0:?}: labels must be identifiers made of letters, digits and underscores")]
+    InvalidLabel(String),
+    #[error("State saved at {0} doesn't match the structure of the graph")]
+    StateMismatch(String),
+    #[error("I/O error: {0}")]
+    IoError(#[from] std::io::Error),
     #[error("Invalid method paramter: {0}")]
     ParameterError(#[from] anyhow::Error),
 }
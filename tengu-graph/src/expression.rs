@@ -5,32 +5,87 @@
 //! unary functions, and statements. It provides a comprehensive interface for constructing
 //! and processing these expressions.
 
+use std::fmt;
+
 use tengu_backend::{Backend, Processor};
-use tengu_backend_tensor::StorageType;
+use tengu_backend_tensor::{CastMode, Pod, StorageType, Type};
 use tengu_graph_tensor::Tensor;
 
+use bincount::Bincount;
 use cast::Cast;
+pub use erased::ErasedExpression;
+use flip::Flip;
+use fold::Constant;
+use mask_select::MaskSelect;
+use materialize::Materialize;
+pub(crate) use materialize::Materialized;
+use mean::Mean;
+use one_hot::OneHot;
 use ops::Binary;
+use pad::Pad;
+use roll::Roll;
+use select::Select;
+use slice::Slice;
+use softmax::Softmax;
+use stack::Stack;
 use statement::Statement;
+use take::Take;
+use tile::Tile;
 use unary_fn::UnaryFn;
 
 use crate::collector::Collector;
+use crate::cost::CostEstimate;
 use crate::node::Node;
 use crate::shape::Shape;
 use crate::source::Source;
 
 mod binary;
+mod bincount;
 mod cast;
+mod erased;
+mod flip;
+mod fold;
+mod mask_select;
+mod materialize;
+mod mean;
+mod one_hot;
 mod ops;
+mod pad;
+mod roll;
+mod select;
+mod slice;
+mod softmax;
+mod stack;
 mod statement;
+mod take;
+mod tile;
 mod unary_fn;
 
+/// The largest integer exponent magnitude `powf` lowers to repeated multiplication. Larger ones
+/// would grow the emitted expression without being cheaper than the logarithm.
+const MAX_UNROLLED_POWER: f32 = 16.0;
+
+/// The value added to the squared norm or the variance by `normalize` before taking the square
+/// root, so that all-zero or constant lanes give zeros instead of NaN.
+const NORMALIZE_EPSILON: f32 = 1e-12;
+
+/// The way `Expression::normalize` scales the values along an axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NormalizeMode {
+    /// The values are divided by their Euclidean norm, `x / sqrt(sum(x^2))`.
+    L2,
+    /// The values are shifted to zero mean and scaled to unit standard deviation,
+    /// `(x - mean) / std`, using the population standard deviation.
+    Standardize,
+}
+
 // NOTE: Expression implementation.
 
 /// An enum representing various types of expressions in the Tengu framework.
 ///
-/// The `Expression` enum can represent scalar values, tensors, binary operations, casts,
-/// unary functions, and statements.
+/// The `Expression` enum can represent scalar values, tensors, binary operations, selections,
+/// casts, unary functions, reductions, one-hot encodings, tiles, paddings, takes, slices, flips, rolls,
+/// masked selections, materializations, and statements.
 pub enum Expression<T: StorageType, B: Backend + 'static>
 where
     T: StorageType,
@@ -39,8 +94,22 @@ where
     Scalar(T),
     Tensor(Tensor<T, B>),
     Binary(Binary<B>),
+    Select(Select<B>),
     Cast(Cast<T, B>),
     UnaryFn(UnaryFn<B>),
+    Bincount(Bincount<B>),
+    Mean(Mean<B>),
+    Softmax(Softmax<B>),
+    OneHot(OneHot<B>),
+    Tile(Tile<T, B>),
+    Pad(Pad<T, B>),
+    Take(Take<T, B>),
+    Slice(Slice<T, B>),
+    Flip(Flip<T, B>),
+    Roll(Roll<T, B>),
+    Stack(Stack<T, B>),
+    MaskSelect(MaskSelect<T, B>),
+    Materialize(Materialize<T, B>),
     Statement(Statement<B>),
 }
 
@@ -60,7 +129,10 @@ where
         }
     }
 
-    /// Create the cast expression.
+    /// Create the cast expression. Like the native conversions, casts between integers wrap values
+    /// out of the range of the target type, keeping their bits, while casts from floats saturate,
+    /// i.e. clamp them to the nearest value of the target type (see `CastMode::default_for`); use
+    /// `cast_with_mode` to choose explicitly.
     ///
     /// # Parameters
     /// - `S`: The target storage type.
@@ -68,7 +140,49 @@ where
     /// # Returns
     /// A new cast expression with the target storage type.
     pub fn cast<S: StorageType>(self) -> Expression<S, B> {
-        Expression::Cast(Cast::new(self))
+        self.cast_with_mode(CastMode::default_for(T::as_type(), S::as_type()))
+    }
+
+    /// Create the cast expression handling values out of the range of the target type according to
+    /// the mode, in the same way on every backend. Scalars whose value can be represented exactly in
+    /// the target type are folded into a scalar of that type instead.
+    ///
+    /// # Parameters
+    /// - `S`: The target storage type.
+    /// - `mode`: The way out-of-range values are cast.
+    ///
+    /// # Returns
+    /// A new cast expression with the target storage type.
+    pub fn cast_with_mode<S: StorageType>(self, mode: CastMode) -> Expression<S, B> {
+        if let Self::Scalar(value) = &self {
+            if let Some(value) = Constant::of(*value).cast(S::as_type()).and_then(Constant::to) {
+                return Expression::Scalar(value);
+            }
+        }
+        Expression::Cast(Cast::new(self, mode))
+    }
+
+    /// Create the cast expression with the target type chosen at runtime. Since the storage type
+    /// of the result is not known at compile time, the expression is returned type-erased. Casting
+    /// to the type the expression already has leaves it unchanged.
+    ///
+    /// # Parameters
+    /// - `ty`: The target type.
+    ///
+    /// # Returns
+    /// A new type-erased expression with the target storage type.
+    pub fn cast_dtype(self, ty: Type) -> ErasedExpression<B> {
+        if T::as_type() == ty {
+            return ErasedExpression::new(self);
+        }
+        match ty {
+            Type::Bool => ErasedExpression::Bool(self.cast()),
+            Type::U32 => ErasedExpression::U32(self.cast()),
+            Type::I32 => ErasedExpression::I32(self.cast()),
+            Type::F32 => ErasedExpression::F32(self.cast()),
+            Type::U16 => ErasedExpression::U16(self.cast()),
+            Type::I16 => ErasedExpression::I16(self.cast()),
+        }
     }
 
     /// Create the log expression.
@@ -87,6 +201,440 @@ where
         Self::UnaryFn(UnaryFn::exp(self))
     }
 
+    /// Create the sign expression, which is `-1` for negative values, `1` for positive values, and
+    /// `0` for zeros (including negative zeros) on all backends. Unsigned integers and booleans are
+    /// never negative, so their sign is either `0` or `1`.
+    ///
+    /// # Returns
+    /// A new expression representing the sign of the original expression.
+    pub fn sign(self) -> Expression<T, B> {
+        Self::UnaryFn(UnaryFn::sign(self))
+    }
+
+    /// Create the copysign expression, which has the magnitude of the original expression and the
+    /// sign of `other`. Zeros in `other` (including negative zeros) are treated as positive on all
+    /// backends, so the result is never negative for them. Unsigned integers and booleans keep their
+    /// value, and the absolute value of the minimum signed integer wraps around to itself.
+    ///
+    /// # Parameters
+    /// - `other`: The expression providing the sign.
+    ///
+    /// # Returns
+    /// A new expression representing the original magnitudes with the signs of `other`.
+    pub fn copysign(self, other: Expression<T, B>) -> Expression<T, B> {
+        Binary::copysign(self, other)
+    }
+
+    /// Create the element-wise maximum of the original expression and `other`.
+    ///
+    /// # Parameters
+    /// - `other`: The expression to compare against.
+    ///
+    /// # Returns
+    /// A new expression holding the larger of the two values for each element.
+    pub fn max(self, other: Expression<T, B>) -> Expression<T, B> {
+        Binary::max(self, other)
+    }
+
+    /// Create the element-wise minimum of the original expression and `other`.
+    ///
+    /// # Parameters
+    /// - `other`: The expression to compare against.
+    ///
+    /// # Returns
+    /// A new expression holding the smaller of the two values for each element.
+    pub fn min(self, other: Expression<T, B>) -> Expression<T, B> {
+        Binary::min(self, other)
+    }
+
+    /// Clamp the original expression from below, e.g. `clamp_min(0.0)` for a ReLU. This is a
+    /// shorthand for `max` with a scalar bound.
+    ///
+    /// # Parameters
+    /// - `lo`: The lower bound.
+    ///
+    /// # Returns
+    /// A new expression with the values below `lo` replaced by `lo`.
+    pub fn clamp_min(self, lo: T) -> Expression<T, B> {
+        self.max(Self::Scalar(lo))
+    }
+
+    /// Clamp the original expression from above. This is a shorthand for `min` with a scalar bound.
+    ///
+    /// # Parameters
+    /// - `hi`: The upper bound.
+    ///
+    /// # Returns
+    /// A new expression with the values above `hi` replaced by `hi`.
+    pub fn clamp_max(self, hi: T) -> Expression<T, B> {
+        self.min(Self::Scalar(hi))
+    }
+
+    /// Create the expression raising the original expression to a positive integer power. It is
+    /// lowered to multiplications by repeated squaring, e.g. `a.powi(4)` is `(a * a) * (a * a)`,
+    /// which is exact for integer types and cheaper and more precise than a transcendental power
+    /// for floats. Since every factor reads the base, a base which isn't a tensor or a scalar is
+    /// materialized, so that it is computed once rather than once per factor.
+    ///
+    /// # Parameters
+    /// - `n`: The exponent.
+    ///
+    /// # Returns
+    /// A new expression holding the powers of the original expression.
+    ///
+    /// # Panics
+    /// Panics if the exponent is zero.
+    pub fn powi(self, n: u32) -> Expression<T, B> {
+        assert!(n > 0, "exponent should be positive");
+        let mut base = match self {
+            Self::Tensor(_) | Self::Scalar(_) => self,
+            expr => expr.materialize(),
+        };
+        let mut power = None;
+        let mut n = n;
+        loop {
+            if n & 1 == 1 {
+                power = Some(match power {
+                    Some(power) => power * base.clone(),
+                    None => base.clone(),
+                });
+            }
+            n >>= 1;
+            if n == 0 {
+                return power.expect("power should have a factor for the lowest set bit");
+            }
+            base = base.clone() * base;
+        }
+    }
+
+    /// Create the bincount expression, which counts the values of the original expression falling
+    /// into each of the `bins` equal-width bins covering the `[min, max)` range. Values outside of
+    /// the range are ignored rather than clamped into the first or last bin.
+    ///
+    /// On the WGPU backend the bins are incremented atomically, so the bincount should be the
+    /// top-level expression of a computation and cannot be nested into other expressions. Computing
+    /// a graph with a nested bincount returns `tengu_backend::Error::UnsupportedExpression`.
+    ///
+    /// # Parameters
+    /// - `bins`: The number of bins.
+    /// - `min`: The inclusive lower bound of the binned range.
+    /// - `max`: The exclusive upper bound of the binned range.
+    ///
+    /// # Returns
+    /// A new one-dimensional expression of shape `[bins]` holding the bin counts.
+    ///
+    /// # Panics
+    /// Panics if there are no bins or if the range is empty or not finite.
+    pub fn bincount(self, bins: usize, min: f32, max: f32) -> Expression<u32, B> {
+        Expression::Bincount(Bincount::new(self, bins, min, max))
+    }
+
+    /// Create the expression computing the mean of the original expression along the specified
+    /// axis. With `keepdim` the reduced axis is kept in the shape with size 1, so a `[2, 3]`
+    /// expression reduced along axis 1 has the shape `[2, 1]` instead of `[2]`.
+    ///
+    /// Since shapes never have zero dimensions, the reduced axis is never empty, so the mean is
+    /// always defined. Over an axis of length 1 it is the original value.
+    ///
+    /// # Parameters
+    /// - `axis`: The axis along which the mean is computed.
+    /// - `keepdim`: Whether the reduced axis is kept in the shape with size 1.
+    ///
+    /// # Returns
+    /// A new expression holding the means.
+    ///
+    /// # Panics
+    /// Panics if the axis is out of bounds of the expression shape.
+    pub fn mean_axis(self, axis: usize, keepdim: bool) -> Expression<f32, B> {
+        Expression::Mean(Mean::new(self, axis, keepdim))
+    }
+
+    /// Create the softmax expression normalizing the original expression along the specified axis,
+    /// i.e. `exp(x - max) / sum(exp(x - max))` with the maximum and the sum taken along the axis.
+    /// Subtracting the maximum doesn't change the result, but keeps the exponentials from
+    /// overflowing for large values.
+    ///
+    /// # Parameters
+    /// - `axis`: The axis along which the softmax is normalized.
+    ///
+    /// # Returns
+    /// A new expression of the same shape holding the softmax values.
+    ///
+    /// # Panics
+    /// Panics if the axis is out of bounds of the expression shape.
+    pub fn softmax(self, axis: usize) -> Expression<f32, B> {
+        Expression::Softmax(Softmax::new(self, axis))
+    }
+
+    /// Create the one-hot encoding of the original expression, which holds class indices. For
+    /// example, encoding `[0, 2, 1]` with 3 classes gives the `[3, 3]` expression whose rows are
+    /// `[1, 0, 0]`, `[0, 0, 1]` and `[0, 1, 0]`. Indices which are out of range, i.e. negative or
+    /// not less than the number of classes, produce rows of zeros.
+    ///
+    /// # Parameters
+    /// - `classes`: The number of classes.
+    ///
+    /// # Returns
+    /// A new expression of shape `[n, classes]` holding the encoded values.
+    ///
+    /// # Panics
+    /// Panics if the original expression is not 1-D or if there are no classes.
+    pub fn one_hot(self, classes: usize) -> Expression<f32, B> {
+        Expression::OneHot(OneHot::new(self, classes))
+    }
+
+    /// Create the expression tiling the original expression along its axes, i.e. repeating it
+    /// `reps[i]` times along each axis `i`. For example, repeating a `[1, 3]` expression with
+    /// `[2, 1]` gives a `[2, 3]` expression whose rows are both the original row.
+    ///
+    /// # Parameters
+    /// - `reps`: The number of repetitions along each axis.
+    ///
+    /// # Returns
+    /// A new expression of shape `shape[i] * reps[i]` holding the tiled values.
+    ///
+    /// # Panics
+    /// Panics if the number of repetitions doesn't match the rank of the expression, or if any of
+    /// them is zero.
+    pub fn repeat(self, reps: &[usize]) -> Expression<T, B> {
+        Self::Tile(Tile::new(self, reps))
+    }
+
+    /// Create the expression padding the original expression with a constant value, i.e. adding
+    /// `padding[i].0` elements before and `padding[i].1` elements after the original elements along
+    /// each axis `i`. For example, padding a `[2, 2]` expression with `[(1, 1), (0, 0)]` gives a
+    /// `[4, 2]` expression whose first and last rows are filled with the value.
+    ///
+    /// # Parameters
+    /// - `padding`: The number of elements added before and after each axis.
+    /// - `value`: The value of the added elements.
+    ///
+    /// # Returns
+    /// A new expression of shape `padding[i].0 + shape[i] + padding[i].1` holding the padded values.
+    ///
+    /// # Panics
+    /// Panics if the length of the padding doesn't match the rank of the expression.
+    pub fn pad(self, padding: &[(usize, usize)], value: T) -> Expression<T, B> {
+        Self::Pad(Pad::new(self, padding, value))
+    }
+
+    /// Create the expression taking the elements of the original expression at the given flat
+    /// indices, in the given order. For example, taking `[3, 1, 0]` from a `[4]` expression gives a
+    /// `[3]` expression holding its last, second and first elements. The indices are fixed when the
+    /// expression is built, which makes it suitable for constant reorderings.
+    ///
+    /// # Parameters
+    /// - `indices`: The flat row-major indices of the taken elements.
+    ///
+    /// # Returns
+    /// A new 1-D expression of shape `[indices.len()]` holding the taken values.
+    ///
+    /// # Panics
+    /// Panics if no indices are provided or if any index is out of bounds of the expression.
+    pub fn take(self, indices: &[usize]) -> Expression<T, B> {
+        Self::Take(Take::new(self, indices))
+    }
+
+    /// Split the expression along an axis into consecutive chunks of the given sizes, e.g. to
+    /// separate the heads or channels packed into a single tensor. Each chunk has the shape of the
+    /// original expression with the axis shortened to the chunk size.
+    ///
+    /// # Parameters
+    /// - `axis`: The axis along which the expression is split.
+    /// - `sizes`: The sizes of the chunks along the axis, which must add up to the axis length.
+    ///
+    /// # Returns
+    /// A vector of expressions, one for each chunk, in order.
+    ///
+    /// # Panics
+    /// Panics if the axis is out of bounds of the expression shape, if any size is zero, or if the
+    /// sizes don't add up to the axis length.
+    pub fn split(self, axis: usize, sizes: &[usize]) -> Vec<Expression<T, B>> {
+        let rank = self.shape().len();
+        assert!(
+            axis < rank,
+            "axis {axis} is out of bounds for expression of rank {rank}"
+        );
+        let len = self.shape()[axis];
+        let total = sizes.iter().sum::<usize>();
+        assert_eq!(
+            total, len,
+            "chunk sizes {sizes:?} don't add up to the axis length {len}"
+        );
+        let mut start = 0;
+        let mut chunks = Vec::with_capacity(sizes.len());
+        for &size in sizes {
+            assert!(size > 0, "chunk sizes should be positive");
+            chunks.push(Self::Slice(Slice::new(self.clone(), axis, start..start + size)));
+            start += size;
+        }
+        chunks
+    }
+
+    /// Create the expression holding the differences of adjacent elements along an axis, i.e.
+    /// `out[i] = a[i + 1] - a[i]`. The result has the shape of the original expression with the
+    /// axis shortened by one. It is the difference of two slices of the original expression, so
+    /// it's fused into the consuming shader like any other arithmetic.
+    ///
+    /// # Parameters
+    /// - `axis`: The axis along which the differences are taken.
+    ///
+    /// # Returns
+    /// A new expression holding the adjacent differences.
+    ///
+    /// # Panics
+    /// Panics if the axis is out of bounds of the expression shape or if the axis is shorter than 2.
+    pub fn diff(self, axis: usize) -> Expression<T, B> {
+        let rank = self.shape().len();
+        assert!(
+            axis < rank,
+            "axis {axis} is out of bounds for expression of rank {rank}"
+        );
+        let len = self.shape()[axis];
+        assert!(len >= 2, "axis {axis} of length {len} is too short to take differences");
+        let next = Self::Slice(Slice::new(self.clone(), axis, 1..len));
+        let prev = Self::Slice(Slice::new(self, axis, 0..len - 1));
+        next - prev
+    }
+
+    /// Create the expression with the order of the elements reversed along an axis, i.e.
+    /// `out[i] = a[len - 1 - i]`. The result has the shape of the original expression.
+    ///
+    /// # Parameters
+    /// - `axis`: The axis along which the elements are reversed.
+    ///
+    /// # Returns
+    /// A new expression holding the flipped elements.
+    ///
+    /// # Panics
+    /// Panics if the axis is out of bounds of the expression shape.
+    pub fn flip(self, axis: usize) -> Expression<T, B> {
+        self.flip_all(&[axis])
+    }
+
+    /// Create the expression with the order of the elements reversed along each of the specified
+    /// axes. The result has the shape of the original expression.
+    ///
+    /// # Parameters
+    /// - `axes`: The axes along which the elements are reversed.
+    ///
+    /// # Returns
+    /// A new expression holding the flipped elements.
+    ///
+    /// # Panics
+    /// Panics if any axis is out of bounds of the expression shape or appears more than once.
+    pub fn flip_all(self, axes: &[usize]) -> Expression<T, B> {
+        Self::Flip(Flip::new(self, axes))
+    }
+
+    /// Create the expression with the elements shifted circularly along an axis, i.e.
+    /// `out[(i + shift) % len] = a[i]`, so that rolling `[1, 2, 3, 4]` by 1 gives `[4, 1, 2, 3]`.
+    /// Negative shifts move the elements towards the start of the axis. The result has the shape
+    /// of the original expression.
+    ///
+    /// # Parameters
+    /// - `shift`: The number of positions the elements are shifted by.
+    /// - `axis`: The axis along which the elements are shifted.
+    ///
+    /// # Returns
+    /// A new expression holding the rolled elements.
+    ///
+    /// # Panics
+    /// Panics if the axis is out of bounds of the expression shape.
+    pub fn roll(self, shift: isize, axis: usize) -> Expression<T, B> {
+        Self::Roll(Roll::new(self, shift, axis))
+    }
+
+    /// Create the expression joining expressions of the same shape along a new axis, e.g. stacking
+    /// three `[2]`-shaped expressions along axis 0 gives a `[3, 2]`-shaped expression whose rows
+    /// are the stacked expressions.
+    ///
+    /// # Parameters
+    /// - `exprs`: The expressions to stack, in the order they appear along the new axis.
+    /// - `axis`: The position of the new axis, from 0 up to the rank of the expressions.
+    ///
+    /// # Returns
+    /// A new expression holding the stacked elements.
+    ///
+    /// # Panics
+    /// Panics if there are no expressions, if their shapes differ, or if the axis is out of bounds.
+    pub fn stack(exprs: &[Expression<T, B>], axis: usize) -> Expression<T, B> {
+        Self::Stack(Stack::new(exprs, axis))
+    }
+
+    /// Create the expression selecting the elements of the original expression where the mask is
+    /// true, in row-major order. The number of selected elements is only known after the
+    /// computation, so the shape of the selection is the 1-D upper bound `[count]`: the selected
+    /// elements come first and the rest is filled with zeros. Probes of the computation output
+    /// report only the selected elements.
+    ///
+    /// The selection should be the top-level expression of a computation, whose output gets an
+    /// additional `[1]`-shaped `u32` tensor labelled `<output>_len` holding the number of selected
+    /// elements. Computing a graph with a nested selection on the WGPU backend returns
+    /// `tengu_backend::Error::UnsupportedExpression`.
+    ///
+    /// # Parameters
+    /// - `mask`: The boolean mask with the shape of the original expression.
+    ///
+    /// # Returns
+    /// A new 1-D expression holding the selected elements.
+    ///
+    /// # Panics
+    /// Panics if the shapes of the expression and the mask do not match.
+    pub fn mask_select(self, mask: Expression<bool, B>) -> Expression<T, B> {
+        Self::MaskSelect(MaskSelect::new(self, mask))
+    }
+
+    /// Create the expression materializing the original expression in the contiguous row-major
+    /// layout, e.g. before handing it over to a custom shader. All expressions are currently
+    /// evaluated element by element into contiguous outputs, so there are no strided views to
+    /// materialize and this is a no-op returning the original expression.
+    ///
+    /// # Returns
+    /// The expression in the contiguous layout.
+    pub fn contiguous(self) -> Expression<T, B> {
+        self
+    }
+
+    /// Create the expression materializing the original expression into its own tensor. Expressions
+    /// are normally fused into the shader of the computation using them, so a subexpression used by
+    /// an expensive operation like `softmax` may be recomputed many times. When the computation is
+    /// added to a block, the materialized subexpression is added as a separate computation before it,
+    /// and the consuming computation reads its output tensor. Clones of the expression can be used by
+    /// other computations of the same block, but not by other blocks. Outside of a block, the
+    /// subexpression is fused as usual.
+    ///
+    /// # Returns
+    /// The expression reading the materialized tensor.
+    pub fn materialize(self) -> Expression<T, B> {
+        Self::Materialize(Materialize::new(self))
+    }
+
+    /// Estimates the computational cost of the expression by summing up the costs of its nodes,
+    /// each weighted by the number of elements it processes. The estimate is heuristic, but it can
+    /// be used to compare expressions, e.g. to decide whether to run them on the CPU or the GPU.
+    ///
+    /// # Returns
+    /// The estimated cost of the expression.
+    pub fn cost(&self) -> CostEstimate {
+        Node::cost(self)
+    }
+
+    /// Attaches the tensor holding the data-dependent length of a tensor expression, so that its
+    /// probes only receive the elements up to that length. Other expressions are left unchanged.
+    ///
+    /// # Parameters
+    /// - `length`: The `[1]`-shaped tensor expression holding the length.
+    ///
+    /// # Returns
+    /// The expression with the length attached.
+    pub(crate) fn with_length(self, length: &Expression<u32, B>) -> Self {
+        match (self, length) {
+            (Self::Tensor(tensor), Expression::Tensor(length)) => Self::Tensor(tensor.with_length(length)),
+            (expr, _) => expr,
+        }
+    }
+
     /// Creates a statement expression.
     ///
     /// # Parameters
@@ -100,6 +648,120 @@ where
     }
 }
 
+impl<B: Backend + 'static> Expression<bool, B> {
+    /// Create the expression choosing element-wise between two expressions according to the mask,
+    /// i.e. `out[i] = if mask[i] { on_true[i] } else { on_false[i] }`. The mask and the choices are
+    /// broadcast in the same way as the operands of binary operations.
+    ///
+    /// # Parameters
+    /// - `on_true`: The expression selected where the mask is true.
+    /// - `on_false`: The expression selected where the mask is false.
+    ///
+    /// # Returns
+    /// A new expression holding the selected elements.
+    ///
+    /// # Panics
+    /// Panics if the shapes of the mask and the choices don't match.
+    pub fn select<T: StorageType>(self, on_true: Expression<T, B>, on_false: Expression<T, B>) -> Expression<T, B> {
+        Expression::Select(Select::new(self, on_true, on_false))
+    }
+
+    /// Create the expression setting the masked elements of `expr` to a constant, e.g.
+    /// `a.clone().lt_scalar(0.0).where_scalar(a, 0.0)` zeroes the negative elements of `a`. This
+    /// is a shorthand for `select` with a scalar, which the backends inline as a literal instead of
+    /// filling a tensor with it.
+    ///
+    /// # Parameters
+    /// - `expr`: The expression whose elements are kept where the mask is false.
+    /// - `fill`: The value of the elements where the mask is true.
+    ///
+    /// # Returns
+    /// A new expression holding the elements of `expr` with the masked ones replaced by `fill`.
+    ///
+    /// # Panics
+    /// Panics if the shapes of the mask and `expr` don't match.
+    pub fn where_scalar<T: StorageType>(self, expr: Expression<T, B>, fill: T) -> Expression<T, B> {
+        self.select(Expression::Scalar(fill), expr)
+    }
+}
+
+impl<B: Backend + 'static> Expression<f32, B> {
+    /// Create the expression raising the original expression to a power. Integer exponents of up to
+    /// 16 in magnitude are lowered to repeated multiplication with `powi`, e.g. `a.powf(2.0)` is
+    /// `a * a` and `a.powf(-2.0)` is `1 / (a * a)`, and a zero exponent gives ones for any base.
+    /// Other exponents are computed as `exp(log(a) * exponent)`, which like WGSL `pow` is undefined
+    /// for negative bases.
+    ///
+    /// # Parameters
+    /// - `exponent`: The exponent.
+    ///
+    /// # Returns
+    /// A new expression holding the powers of the original expression.
+    pub fn powf(self, exponent: f32) -> Expression<f32, B> {
+        if exponent == 0.0 {
+            // NOTE: The mask only provides the shape, since both choices are one.
+            return self.eq_scalar(0.0).select(Self::Scalar(1.0), Self::Scalar(1.0));
+        }
+        let magnitude = exponent.abs();
+        if magnitude.trunc() == magnitude && magnitude <= MAX_UNROLLED_POWER {
+            let power = self.powi(magnitude as u32);
+            return if exponent < 0.0 {
+                Self::Scalar(1.0) / power
+            } else {
+                power
+            };
+        }
+        (self.log() * exponent).exp()
+    }
+
+    /// Create the logarithm of the original expression clamped from below, i.e. `log(max(a, eps))`.
+    /// Unlike `log`, which yields `-inf` or NaN for non-positive values depending on the backend,
+    /// the result is finite and the same on all backends, e.g. for the logarithms in loss functions.
+    ///
+    /// # Parameters
+    /// - `eps`: The smallest value the logarithm is taken of, which should be positive.
+    ///
+    /// # Returns
+    /// A new expression holding the clamped logarithms.
+    pub fn log_clamped(self, eps: f32) -> Expression<f32, B> {
+        self.clamp_min(eps).log()
+    }
+
+    /// Create the expression normalizing the original expression along the specified axis, either
+    /// to unit L2 norm or to zero mean and unit standard deviation. A small epsilon is added under
+    /// the square root, so lanes which are all zero or constant give zeros instead of NaN.
+    ///
+    /// # Parameters
+    /// - `axis`: The axis along which the values are normalized.
+    /// - `mode`: The way the values are normalized.
+    ///
+    /// # Returns
+    /// A new expression of the same shape holding the normalized values.
+    ///
+    /// # Panics
+    /// Panics if the axis is out of bounds of the expression shape.
+    pub fn normalize(self, axis: usize, mode: NormalizeMode) -> Expression<f32, B> {
+        let shape = self.shape().to_vec();
+        assert!(axis < shape.len(), "axis {axis} is out of bounds for shape {shape:?}");
+        let length = shape[axis];
+        // NOTE: Binary operations don't broadcast reduced axes, so the reductions are tiled back.
+        let mut reps = vec![1; shape.len()];
+        reps[axis] = length;
+        let reduce = |expr: Self| expr.mean_axis(axis, true).repeat(&reps);
+        let (centered, denominator) = match mode {
+            NormalizeMode::L2 => {
+                let squares = reduce(self.clone().powi(2)) * length as f32;
+                (self, squares)
+            }
+            NormalizeMode::Standardize => {
+                let centered = self.clone() - reduce(self);
+                (centered.clone(), reduce(centered.powi(2)))
+            }
+        };
+        centered / (denominator + NORMALIZE_EPSILON).powf(0.5)
+    }
+}
+
 // NOTE: Shape implementation.
 
 impl<T, B> Shape for Expression<T, B>
@@ -116,8 +778,22 @@ where
             Self::Scalar(_) => &[1],
             Self::Tensor(tensor) => tensor.shape(),
             Self::Binary(binary) => binary.shape(),
+            Self::Select(select) => select.shape(),
             Self::Cast(cast) => cast.shape(),
             Self::UnaryFn(unary_fn) => unary_fn.shape(),
+            Self::Bincount(bincount) => bincount.shape(),
+            Self::Mean(mean) => mean.shape(),
+            Self::Softmax(softmax) => softmax.shape(),
+            Self::OneHot(one_hot) => one_hot.shape(),
+            Self::Tile(tile) => tile.shape(),
+            Self::Pad(pad) => pad.shape(),
+            Self::Take(take) => take.shape(),
+            Self::Slice(slice) => slice.shape(),
+            Self::Flip(flip) => flip.shape(),
+            Self::Roll(roll) => roll.shape(),
+            Self::Stack(stack) => stack.shape(),
+            Self::MaskSelect(mask_select) => mask_select.shape(),
+            Self::Materialize(materialize) => materialize.shape(),
             Self::Statement(statement) => statement.shape(),
         }
     }
@@ -130,8 +806,22 @@ where
             Self::Scalar(_) => 1,
             Self::Tensor(tensor) => tensor.count(),
             Self::Binary(binary) => binary.count(),
+            Self::Select(select) => select.count(),
             Self::Cast(cast) => cast.count(),
             Self::UnaryFn(unary_fn) => unary_fn.count(),
+            Self::Bincount(bincount) => bincount.count(),
+            Self::Mean(mean) => mean.count(),
+            Self::Softmax(softmax) => softmax.count(),
+            Self::OneHot(one_hot) => one_hot.count(),
+            Self::Tile(tile) => tile.count(),
+            Self::Pad(pad) => pad.count(),
+            Self::Take(take) => take.count(),
+            Self::Slice(slice) => slice.count(),
+            Self::Flip(flip) => flip.count(),
+            Self::Roll(roll) => roll.count(),
+            Self::Stack(stack) => stack.count(),
+            Self::MaskSelect(mask_select) => mask_select.count(),
+            Self::Materialize(materialize) => materialize.count(),
             Self::Statement(statement) => statement.count(),
         }
     }
@@ -161,8 +851,22 @@ where
             Self::Scalar(_) => {}
             Self::Tensor(tensor) => collector.add(tensor),
             Self::Binary(binary) => binary.collect(collector),
+            Self::Select(select) => select.collect(collector),
             Self::Cast(cast) => cast.collect(collector),
             Self::UnaryFn(unary_fn) => unary_fn.collect(collector),
+            Self::Bincount(bincount) => bincount.collect(collector),
+            Self::Mean(mean) => mean.collect(collector),
+            Self::Softmax(softmax) => softmax.collect(collector),
+            Self::OneHot(one_hot) => one_hot.collect(collector),
+            Self::Tile(tile) => tile.collect(collector),
+            Self::Pad(pad) => pad.collect(collector),
+            Self::Take(take) => take.collect(collector),
+            Self::Slice(slice) => slice.collect(collector),
+            Self::Flip(flip) => flip.collect(collector),
+            Self::Roll(roll) => roll.collect(collector),
+            Self::Stack(stack) => stack.collect(collector),
+            Self::MaskSelect(mask_select) => mask_select.collect(collector),
+            Self::Materialize(materialize) => materialize.collect(collector),
             Self::Statement(statement) => statement.collect(collector),
         }
     }
@@ -179,8 +883,22 @@ where
             Self::Scalar(_) => None,
             Self::Tensor(tensor) => (tensor.label() == label).then_some(tensor),
             Self::Binary(binary) => binary.find(label),
+            Self::Select(select) => select.find(label),
             Self::Cast(cast) => cast.find(label),
             Self::UnaryFn(unary_fn) => unary_fn.find(label),
+            Self::Bincount(bincount) => bincount.find(label),
+            Self::Mean(mean) => mean.find(label),
+            Self::Softmax(softmax) => softmax.find(label),
+            Self::OneHot(one_hot) => one_hot.find(label),
+            Self::Tile(tile) => tile.find(label),
+            Self::Pad(pad) => pad.find(label),
+            Self::Take(take) => take.find(label),
+            Self::Slice(slice) => slice.find(label),
+            Self::Flip(flip) => flip.find(label),
+            Self::Roll(roll) => roll.find(label),
+            Self::Stack(stack) => stack.find(label),
+            Self::MaskSelect(mask_select) => mask_select.find(label),
+            Self::Materialize(materialize) => materialize.find(label),
             Self::Statement(statement) => statement.find(label),
         }
     }
@@ -197,11 +915,143 @@ where
             Self::Scalar(scalar) => processor.scalar(*scalar),
             Self::Tensor(tensor) => processor.var(tensor.raw()),
             Self::Binary(binary) => binary.visit(processor),
+            Self::Select(select) => select.visit(processor),
             Self::Cast(cast) => cast.visit(processor),
             Self::UnaryFn(unary_fn) => unary_fn.visit(processor),
+            Self::Bincount(bincount) => bincount.visit(processor),
+            Self::Mean(mean) => mean.visit(processor),
+            Self::Softmax(softmax) => softmax.visit(processor),
+            Self::OneHot(one_hot) => one_hot.visit(processor),
+            Self::Tile(tile) => tile.visit(processor),
+            Self::Pad(pad) => pad.visit(processor),
+            Self::Take(take) => take.visit(processor),
+            Self::Slice(slice) => slice.visit(processor),
+            Self::Flip(flip) => flip.visit(processor),
+            Self::Roll(roll) => roll.visit(processor),
+            Self::Stack(stack) => stack.visit(processor),
+            Self::MaskSelect(mask_select) => mask_select.visit(processor),
+            Self::Materialize(materialize) => materialize.visit(processor),
             Self::Statement(statement) => statement.visit(processor),
         }
     }
+
+    /// Estimates the cost of the expression tree. Tensors contribute the memory traffic of reading
+    /// their elements, and scalars don't contribute anything.
+    ///
+    /// # Returns
+    /// The estimated cost.
+    fn cost(&self) -> CostEstimate {
+        match self {
+            Self::Scalar(_) => CostEstimate::default(),
+            Self::Tensor(tensor) => CostEstimate::memory(tensor.count() * std::mem::size_of::<Pod<T>>()),
+            Self::Binary(binary) => binary.cost(),
+            Self::Select(select) => select.cost(),
+            Self::Cast(cast) => cast.cost(),
+            Self::UnaryFn(unary_fn) => unary_fn.cost(),
+            Self::Bincount(bincount) => bincount.cost(),
+            Self::Mean(mean) => mean.cost(),
+            Self::Softmax(softmax) => softmax.cost(),
+            Self::OneHot(one_hot) => one_hot.cost(),
+            Self::Tile(tile) => tile.cost(),
+            Self::Pad(pad) => pad.cost(),
+            Self::Take(take) => take.cost(),
+            Self::Slice(slice) => slice.cost(),
+            Self::Flip(flip) => flip.cost(),
+            Self::Roll(roll) => roll.cost(),
+            Self::Stack(stack) => stack.cost(),
+            Self::MaskSelect(mask_select) => mask_select.cost(),
+            Self::Materialize(materialize) => materialize.cost(),
+            Self::Statement(statement) => statement.cost(),
+        }
+    }
+
+    /// Returns the subexpressions of the wrapped node, so that the expression itself doesn't count
+    /// as a separate level of the tree.
+    ///
+    /// # Returns
+    /// A vector holding the subexpressions, which is empty for tensors and scalars.
+    fn children(&self) -> Vec<&dyn Node<B>> {
+        match self {
+            Self::Scalar(_) | Self::Tensor(_) => Vec::new(),
+            Self::Binary(binary) => binary.children(),
+            Self::Select(select) => select.children(),
+            Self::Cast(cast) => cast.children(),
+            Self::UnaryFn(unary_fn) => unary_fn.children(),
+            Self::Bincount(bincount) => bincount.children(),
+            Self::Mean(mean) => mean.children(),
+            Self::Softmax(softmax) => softmax.children(),
+            Self::OneHot(one_hot) => one_hot.children(),
+            Self::Tile(tile) => tile.children(),
+            Self::Pad(pad) => pad.children(),
+            Self::Take(take) => take.children(),
+            Self::Slice(slice) => slice.children(),
+            Self::Flip(flip) => flip.children(),
+            Self::Roll(roll) => roll.children(),
+            Self::Stack(stack) => stack.children(),
+            Self::MaskSelect(mask_select) => mask_select.children(),
+            Self::Materialize(materialize) => materialize.children(),
+            Self::Statement(statement) => statement.children(),
+        }
+    }
+
+    /// Renders the expression tree for debugging. Tensors are rendered with their labels and
+    /// scalars with their values.
+    ///
+    /// # Parameters
+    /// - `f`: The formatter to write the rendered expression tree to.
+    ///
+    /// # Returns
+    /// A result indicating whether the formatting succeeded.
+    fn fmt_tree(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Scalar(scalar) => write!(f, "Scalar({scalar:?})"),
+            Self::Tensor(tensor) => write!(f, "Tensor({:?})", tensor.label()),
+            Self::Binary(binary) => binary.fmt_tree(f),
+            Self::Select(select) => select.fmt_tree(f),
+            Self::Cast(cast) => cast.fmt_tree(f),
+            Self::UnaryFn(unary_fn) => unary_fn.fmt_tree(f),
+            Self::Bincount(bincount) => bincount.fmt_tree(f),
+            Self::Mean(mean) => mean.fmt_tree(f),
+            Self::Softmax(softmax) => softmax.fmt_tree(f),
+            Self::OneHot(one_hot) => one_hot.fmt_tree(f),
+            Self::Tile(tile) => tile.fmt_tree(f),
+            Self::Pad(pad) => pad.fmt_tree(f),
+            Self::Take(take) => take.fmt_tree(f),
+            Self::Slice(slice) => slice.fmt_tree(f),
+            Self::Flip(flip) => flip.fmt_tree(f),
+            Self::Roll(roll) => roll.fmt_tree(f),
+            Self::Stack(stack) => stack.fmt_tree(f),
+            Self::MaskSelect(mask_select) => mask_select.fmt_tree(f),
+            Self::Materialize(materialize) => materialize.fmt_tree(f),
+            Self::Statement(statement) => statement.fmt_tree(f),
+        }
+    }
+}
+
+// NOTE: Debug implementation.
+
+impl<T: StorageType, B: Backend + 'static> fmt::Debug for Expression<T, B> {
+    /// Formats the expression as its expression tree, e.g. `Add(Mul(Tensor("a"), Scalar(2)), Tensor("b"))`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_tree(f)
+    }
+}
+
+// NOTE: Display implementation.
+
+impl<T: StorageType, B: Backend + 'static> fmt::Display for Expression<T, B> {
+    /// Formats the expression as a one-line summary of its label, shape and element type, e.g.
+    /// `Tensor("a", [2, 3], f32)`. Expressions other than tensors have no label, so they are
+    /// summarized by their shape and element type only, e.g. `Expression([2, 3], f32)`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Tensor(tensor) => {
+                let (label, shape) = (tensor.label(), tensor.shape());
+                write!(f, "Tensor({label:?}, {shape:?}, {})", T::as_type())
+            }
+            _ => write!(f, "Expression({:?}, {})", self.shape(), T::as_type()),
+        }
+    }
 }
 
 // NOTE: Clone implementation.
@@ -216,9 +1066,129 @@ impl<T: StorageType, B: Backend> Clone for Expression<T, B> {
             Self::Scalar(scalar) => Self::Scalar(*scalar),
             Self::Tensor(tensor) => Self::Tensor(tensor.clone()),
             Self::Binary(binary) => Self::Binary(binary.clone()),
+            Self::Select(select) => Self::Select(select.clone()),
             Self::Cast(cast) => Self::Cast(cast.clone()),
             Self::UnaryFn(unary_fn) => Self::UnaryFn(unary_fn.clone()),
+            Self::Bincount(bincount) => Self::Bincount(bincount.clone()),
+            Self::Mean(mean) => Self::Mean(mean.clone()),
+            Self::Softmax(softmax) => Self::Softmax(softmax.clone()),
+            Self::OneHot(one_hot) => Self::OneHot(one_hot.clone()),
+            Self::Tile(tile) => Self::Tile(tile.clone()),
+            Self::Pad(pad) => Self::Pad(pad.clone()),
+            Self::Take(take) => Self::Take(take.clone()),
+            Self::Slice(slice) => Self::Slice(slice.clone()),
+            Self::Flip(flip) => Self::Flip(flip.clone()),
+            Self::Roll(roll) => Self::Roll(roll.clone()),
+            Self::Stack(stack) => Self::Stack(stack.clone()),
+            Self::MaskSelect(mask_select) => Self::MaskSelect(mask_select.clone()),
+            Self::Materialize(materialize) => Self::Materialize(materialize.clone()),
             Self::Statement(statement) => Self::Statement(statement.clone()),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::node::Node;
+    use crate::shape::Shape;
+    use crate::Tengu;
+
+    #[tokio::test]
+    async fn debug_tree() {
+        let tengu = Tengu::cpu().await.unwrap();
+        let a = tengu.tensor([2]).label("a").zero::<i32>();
+        let b = tengu.tensor([2]).label("b").zero::<i32>();
+        let expr = a * tengu.scalar(2) + b;
+        assert_eq!(format!("{expr:?}"), r#"Add(Mul(Tensor("a"), Scalar(2)), Tensor("b"))"#);
+    }
+
+    #[tokio::test]
+    async fn cast_folding() {
+        let tengu = Tengu::cpu().await.unwrap();
+        assert_eq!(format!("{:?}", tengu.scalar(1).cast::<f32>()), "Scalar(1.0)");
+        assert_eq!(
+            format!("{:?}", tengu.scalar(2.5).cast::<i32>()),
+            "Cast(Scalar(2.5), i32)"
+        );
+        assert_eq!(format!("{:?}", tengu.scalar(-1).cast::<u32>()), "Cast(Scalar(-1), u32)");
+    }
+
+    #[tokio::test]
+    async fn cost() {
+        let tengu = Tengu::cpu().await.unwrap();
+        let a = tengu.tensor([4]).label("a").zero::<f32>();
+        let shallow = a.clone() + a.clone();
+        let deep = (a.clone() * a.clone() + a.clone()).exp().log();
+        assert_eq!(shallow.cost().elementwise, 4);
+        assert_eq!(shallow.cost().memory, 2 * 4 * std::mem::size_of::<f32>());
+        assert!(deep.cost().total() > shallow.cost().total());
+        assert!(a.softmax(0).cost().reductions > 0);
+    }
+
+    #[tokio::test]
+    async fn contiguous() {
+        let tengu = Tengu::cpu().await.unwrap();
+        let a = tengu.tensor([2, 3]).label("a").zero::<f32>();
+        let expr = (a.clone() + a).contiguous();
+        assert_eq!(expr.shape(), &[2, 3]);
+        assert_eq!(format!("{expr:?}"), r#"Add(Tensor("a"), Tensor("a"))"#);
+    }
+
+    #[tokio::test]
+    async fn debug_tree_nodes() {
+        let tengu = Tengu::cpu().await.unwrap();
+        let a = tengu.tensor([2, 3]).label("a").zero::<f32>();
+        let expr = a.exp().softmax(1).mean_axis(0, false).cast::<u32>();
+        assert_eq!(format!("{expr:?}"), r#"Cast(Mean(Softmax(Exp(Tensor("a")), 1), 0), u32)"#);
+    }
+
+    #[tokio::test]
+    async fn depth_and_node_count() {
+        let tengu = Tengu::cpu().await.unwrap();
+        let a = tengu.tensor([2]).label("a").zero::<f32>();
+        let b = tengu.tensor([2]).label("b").zero::<f32>();
+        let c = tengu.tensor([2]).label("c").zero::<f32>();
+        assert_eq!(a.depth(), 0);
+        assert_eq!(a.node_count(), 1);
+        let expr = ((a + b) * c).exp();
+        assert_eq!(expr.depth(), 3);
+        assert_eq!(expr.node_count(), 6);
+    }
+
+    #[tokio::test]
+    async fn power_lowering() {
+        let tengu = Tengu::cpu().await.unwrap();
+        let a = tengu.tensor([2]).label("a").zero::<f32>();
+        assert_eq!(format!("{:?}", a.clone().powf(2.0)), r#"Mul(Tensor("a"), Tensor("a"))"#);
+        assert_eq!(
+            format!("{:?}", a.clone().powf(-2.0)),
+            r#"Div(Scalar(1.0), Mul(Tensor("a"), Tensor("a")))"#
+        );
+        assert_eq!(
+            format!("{:?}", a.powf(0.5)),
+            r#"Exp(Mul(Log(Tensor("a")), Scalar(0.5)))"#
+        );
+        let b = tengu.tensor([2]).label("b").zero::<i32>();
+        assert_eq!(
+            format!("{:?}", b.clone().powi(3)),
+            r#"Mul(Tensor("b"), Mul(Tensor("b"), Tensor("b")))"#
+        );
+        let base = r#"Materialize(Add(Tensor("b"), Tensor("b")))"#;
+        assert_eq!(
+            format!("{:?}", (b.clone() + b).powi(4)),
+            format!("Mul(Mul({base}, {base}), Mul({base}, {base}))")
+        );
+        assert_eq!(format!("{:?}", tengu.scalar(3).powi(2)), "Scalar(9)");
+    }
+
+    #[tokio::test]
+    async fn display_summary() {
+        let tengu = Tengu::cpu().await.unwrap();
+        let a = tengu.tensor([2, 3]).label("a").zero::<f32>();
+        assert_eq!(a.to_string(), r#"Tensor("a", [2, 3], f32)"#);
+        assert_eq!(a.find("a").unwrap().to_string(), r#"Tensor("a", [2, 3], f32)"#);
+        assert_eq!(a.cast::<u32>().to_string(), "Expression([2, 3], u32)");
+    }
+}
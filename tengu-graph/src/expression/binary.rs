@@ -2,11 +2,15 @@
 //! such as addition, subtraction, multiplication, division, and equality comparisons in tensor expressions.
 //! It leverages the backend processing capabilities to apply these operations on tensor data.
 
+use std::fmt;
+
 use tengu_backend::{Backend, Processor};
 use tengu_backend_tensor::{Operator, StorageType};
 
+use super::fold::Constant;
 use super::Expression;
 use crate::collector::Collector;
+use crate::cost::CostEstimate;
 use crate::node::Node;
 use crate::shape::Shape;
 use crate::source::Source;
@@ -111,11 +115,43 @@ where
     ///
     /// # Returns
     /// The inner representation used by the processor.
-    fn visit<'a>(&'a self, processor: &mut B::Processor<'a>) -> <B::Processor<'a> as Processor<B>>::Repr {
+    fn visit<'a>(&'a self, processor: &mut B::Processor<'a>) -> <B::Processor<'a> as Processor<'a, B>>::Repr {
         let lhs = self.lhs.visit(processor);
         let rhs = self.rhs.visit(processor);
         processor.binary(lhs, rhs, self.operator)
     }
+
+    /// Estimates the cost of the binary operation, including the elementwise operation and its
+    /// operands.
+    ///
+    /// # Returns
+    /// The estimated cost.
+    fn cost(&self) -> CostEstimate {
+        self.lhs.cost() + self.rhs.cost() + CostEstimate::elementwise(self.count())
+    }
+
+    /// Returns the operands of the binary operation.
+    ///
+    /// # Returns
+    /// A vector holding the left-hand side and the right-hand side operand.
+    fn children(&self) -> Vec<&dyn Node<B>> {
+        vec![self.lhs.as_ref(), self.rhs.as_ref()]
+    }
+
+    /// Renders the binary operation with its operands for debugging.
+    ///
+    /// # Parameters
+    /// - `f`: The formatter to write the rendered expression tree to.
+    ///
+    /// # Returns
+    /// A result indicating whether the formatting succeeded.
+    fn fmt_tree(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}(", self.operator)?;
+        self.lhs.fmt_tree(f)?;
+        write!(f, ", ")?;
+        self.rhs.fmt_tree(f)?;
+        write!(f, ")")
+    }
 }
 
 // NOTE: Clone implementation.
@@ -139,6 +175,27 @@ impl<B: Backend> Clone for Binary<B> {
 // NOTE: Binary expression constructors.
 
 impl<B: Backend + 'static> Binary<B> {
+    /// Creates the binary operation of the specified operator, folding it into a single scalar if
+    /// both operands are scalars and the operation can be evaluated in the same way as on the
+    /// backends.
+    ///
+    /// # Parameters
+    /// - `operator`: The binary operator to apply.
+    /// - `lhs`: The left-hand side tensor expression.
+    /// - `rhs`: The right-hand side tensor expression.
+    ///
+    /// # Returns
+    /// The folded scalar or the binary expression.
+    fn fold<T: StorageType>(operator: Operator, lhs: Expression<T, B>, rhs: Expression<T, B>) -> Expression<T, B> {
+        if let (Expression::Scalar(l), Expression::Scalar(r)) = (&lhs, &rhs) {
+            let folded = Constant::of(*l).binary(operator, Constant::of(*r));
+            if let Some(value) = folded.and_then(Constant::to) {
+                return Expression::Scalar(value);
+            }
+        }
+        Expression::Binary(Self::new(operator, lhs, rhs))
+    }
+
     /// Creates a new `Binary` instance for addition.
     ///
     /// # Parameters
@@ -148,7 +205,7 @@ impl<B: Backend + 'static> Binary<B> {
     /// # Returns
     /// A new `Expression` instance with the addition operation.
     pub fn add<T: StorageType>(lhs: Expression<T, B>, rhs: Expression<T, B>) -> Expression<T, B> {
-        Expression::Binary(Self::new(Operator::Add, lhs, rhs))
+        Self::fold(Operator::Add, lhs, rhs)
     }
 
     /// Creates a new `Binary` instance for subtraction.
@@ -160,7 +217,7 @@ impl<B: Backend + 'static> Binary<B> {
     /// # Returns
     /// A new `Expression` instance with the subtraction operation.
     pub fn sub<T: StorageType>(lhs: Expression<T, B>, rhs: Expression<T, B>) -> Expression<T, B> {
-        Expression::Binary(Self::new(Operator::Sub, lhs, rhs))
+        Self::fold(Operator::Sub, lhs, rhs)
     }
 
     /// Creates a new `Binary` instance for multiplication.
@@ -172,7 +229,7 @@ impl<B: Backend + 'static> Binary<B> {
     /// # Returns
     /// A new `Expression` instance with the multiplication operation.
     pub fn mul<T: StorageType>(lhs: Expression<T, B>, rhs: Expression<T, B>) -> Expression<T, B> {
-        Expression::Binary(Self::new(Operator::Mul, lhs, rhs))
+        Self::fold(Operator::Mul, lhs, rhs)
     }
 
     /// Creates a new `Binary` instance for division.
@@ -184,7 +241,7 @@ impl<B: Backend + 'static> Binary<B> {
     /// # Returns
     /// A new `Expression` instance with the division operation.
     pub fn div<T: StorageType>(lhs: Expression<T, B>, rhs: Expression<T, B>) -> Expression<T, B> {
-        Expression::Binary(Self::new(Operator::Div, lhs, rhs))
+        Self::fold(Operator::Div, lhs, rhs)
     }
 
     /// Creates a new `Binary` instance for equality comparison.
@@ -210,11 +267,97 @@ impl<B: Backend + 'static> Binary<B> {
     pub fn neq<T: StorageType>(lhs: Expression<T, B>, rhs: Expression<T, B>) -> Expression<bool, B> {
         Expression::Binary(Binary::new(Operator::Neq, lhs, rhs))
     }
+
+    /// Creates a new `Binary` instance for greater-than comparison.
+    ///
+    /// # Parameters
+    /// - `lhs`: The left-hand side tensor expression.
+    /// - `rhs`: The right-hand side tensor expression.
+    ///
+    /// # Returns
+    /// A new `Expression` instance with the greater-than comparison operation.
+    pub fn gt<T: StorageType>(lhs: Expression<T, B>, rhs: Expression<T, B>) -> Expression<bool, B> {
+        Expression::Binary(Binary::new(Operator::Gt, lhs, rhs))
+    }
+
+    /// Creates a new `Binary` instance for less-than comparison.
+    ///
+    /// # Parameters
+    /// - `lhs`: The left-hand side tensor expression.
+    /// - `rhs`: The right-hand side tensor expression.
+    ///
+    /// # Returns
+    /// A new `Expression` instance with the less-than comparison operation.
+    pub fn lt<T: StorageType>(lhs: Expression<T, B>, rhs: Expression<T, B>) -> Expression<bool, B> {
+        Expression::Binary(Binary::new(Operator::Lt, lhs, rhs))
+    }
+
+    /// Creates a new `Binary` instance for greater-than-or-equal comparison.
+    ///
+    /// # Parameters
+    /// - `lhs`: The left-hand side tensor expression.
+    /// - `rhs`: The right-hand side tensor expression.
+    ///
+    /// # Returns
+    /// A new `Expression` instance with the greater-than-or-equal comparison operation.
+    pub fn ge<T: StorageType>(lhs: Expression<T, B>, rhs: Expression<T, B>) -> Expression<bool, B> {
+        Expression::Binary(Binary::new(Operator::Ge, lhs, rhs))
+    }
+
+    /// Creates a new `Binary` instance for less-than-or-equal comparison.
+    ///
+    /// # Parameters
+    /// - `lhs`: The left-hand side tensor expression.
+    /// - `rhs`: The right-hand side tensor expression.
+    ///
+    /// # Returns
+    /// A new `Expression` instance with the less-than-or-equal comparison operation.
+    pub fn le<T: StorageType>(lhs: Expression<T, B>, rhs: Expression<T, B>) -> Expression<bool, B> {
+        Expression::Binary(Binary::new(Operator::Le, lhs, rhs))
+    }
+
+    /// Creates a new `Binary` instance taking the magnitude of `lhs` and the sign of `rhs`.
+    ///
+    /// # Parameters
+    /// - `lhs`: The tensor expression providing the magnitude.
+    /// - `rhs`: The tensor expression providing the sign.
+    ///
+    /// # Returns
+    /// A new `Expression` instance with the copysign operation.
+    pub fn copysign<T: StorageType>(lhs: Expression<T, B>, rhs: Expression<T, B>) -> Expression<T, B> {
+        Self::fold(Operator::Copysign, lhs, rhs)
+    }
+
+    /// Creates a new `Binary` instance taking the element-wise maximum.
+    ///
+    /// # Parameters
+    /// - `lhs`: The left-hand side tensor expression.
+    /// - `rhs`: The right-hand side tensor expression.
+    ///
+    /// # Returns
+    /// A new `Expression` instance with the maximum operation.
+    pub fn max<T: StorageType>(lhs: Expression<T, B>, rhs: Expression<T, B>) -> Expression<T, B> {
+        Self::fold(Operator::Max, lhs, rhs)
+    }
+
+    /// Creates a new `Binary` instance taking the element-wise minimum.
+    ///
+    /// # Parameters
+    /// - `lhs`: The left-hand side tensor expression.
+    /// - `rhs`: The right-hand side tensor expression.
+    ///
+    /// # Returns
+    /// A new `Expression` instance with the minimum operation.
+    pub fn min<T: StorageType>(lhs: Expression<T, B>, rhs: Expression<T, B>) -> Expression<T, B> {
+        Self::fold(Operator::Min, lhs, rhs)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::expression::Shape;
+    use pretty_assertions::assert_eq;
+
+    use crate::expression::{Expression, Shape};
     use crate::Tengu;
 
     #[tokio::test]
@@ -226,4 +369,22 @@ mod tests {
         assert_eq!(add.shape(), &[4, 2, 3]);
         assert_eq!(add.count(), 24);
     }
+
+    #[tokio::test]
+    async fn constant_folding() {
+        let tengu = Tengu::cpu().await.unwrap();
+        let product = tengu.scalar(2) * tengu.scalar(3);
+        assert!(matches!(product, Expression::Scalar(6)));
+        let nested = (tengu.scalar(1.0) + tengu.scalar(0.5)) * 2.0;
+        assert!(matches!(nested, Expression::Scalar(value) if value == 3.0));
+        let overflow = tengu.scalar(i32::MAX) + tengu.scalar(1);
+        assert!(matches!(overflow, Expression::Binary(_)));
+        let division_by_zero = tengu.scalar(1u32) / tengu.scalar(0u32);
+        assert!(matches!(division_by_zero, Expression::Binary(_)));
+        let a = tengu.tensor([2]).label("a").zero::<i32>();
+        assert_eq!(
+            format!("{:?}", a * tengu.scalar(2) * 3),
+            r#"Mul(Mul(Tensor("a"), Scalar(2)), Scalar(3))"#
+        );
+    }
 }
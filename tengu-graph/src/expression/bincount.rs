@@ -0,0 +1,175 @@
+//! This module defines the `Bincount` struct and associated functionality for computing histograms
+//! of tensor expressions. Unlike other expressions, a bincount is a reduction: its shape is not
+//! related to the shape of the inner expression, but is equal to the number of bins.
+
+use std::fmt;
+
+use tengu_backend::{Backend, Processor};
+use tengu_backend_tensor::StorageType;
+
+use super::Expression;
+use crate::collector::Collector;
+use crate::cost::CostEstimate;
+use crate::node::Node;
+use crate::shape::Shape;
+use crate::source::Source;
+
+/// Struct representing a histogram of the values of a tensor expression.
+pub struct Bincount<B> {
+    shape: Vec<usize>,
+    min: f32,
+    max: f32,
+    expression: Box<dyn Node<B>>,
+}
+
+impl<B: Backend + 'static> Bincount<B> {
+    /// Creates a new `Bincount` instance.
+    ///
+    /// # Parameters
+    /// - `expr`: The tensor expression whose values are counted.
+    /// - `bins`: The number of bins.
+    /// - `min`: The inclusive lower bound of the binned range.
+    /// - `max`: The exclusive upper bound of the binned range.
+    ///
+    /// # Returns
+    /// A new `Bincount` instance.
+    ///
+    /// # Panics
+    /// Panics if there are no bins or if the range is empty or not finite.
+    pub fn new<T: StorageType>(expr: Expression<T, B>, bins: usize, min: f32, max: f32) -> Self {
+        assert!(bins > 0, "bincount should have at least one bin");
+        assert!(min.is_finite() && max.is_finite(), "bincount range should be finite");
+        assert!(min < max, "bincount range should not be empty");
+        Self {
+            shape: vec![bins],
+            min,
+            max,
+            expression: Box::new(expr),
+        }
+    }
+}
+
+// NOTE: Shape implementation.
+
+impl<B> Shape for Bincount<B> {
+    /// Returns the number of bins.
+    ///
+    /// # Returns
+    /// The number of elements in the tensor.
+    fn count(&self) -> usize {
+        self.shape[0]
+    }
+
+    /// Returns the shape of the histogram, which is always one-dimensional.
+    ///
+    /// # Returns
+    /// A slice representing the dimensions of the tensor.
+    fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+}
+
+// NOTE: Node implementation.
+
+impl<B: Backend + 'static> Node<B> for Bincount<B> {
+    /// Returns a boxed clone of the `Bincount` instance.
+    ///
+    /// # Returns
+    /// A boxed clone of the `Bincount` instance.
+    fn clone_box(&self) -> Box<dyn Node<B>> {
+        Box::new(self.clone())
+    }
+
+    /// Collect sources from the bincount operation.
+    ///
+    /// # Parameters
+    /// - `collector`: A mutable reference to the collector.
+    fn collect<'a>(&'a self, collector: &mut Collector<'a, B>) {
+        self.expression.collect(collector);
+    }
+
+    /// Finds a source node by its label.
+    ///
+    /// # Parameters
+    /// - `label`: The label of the source node to find.
+    ///
+    /// # Returns
+    /// An optional reference to the found source node.
+    fn find<'a>(&'a self, label: &str) -> Option<&'a dyn Source<B>> {
+        self.expression.find(label)
+    }
+
+    /// Visits the node with the given processor and counts the values of the inner expression.
+    ///
+    /// # Parameters
+    /// - `processor`: The processor used to visit the node.
+    ///
+    /// # Returns
+    /// The inner representation used by the processor.
+    fn visit<'a>(&'a self, processor: &mut B::Processor<'a>) -> <B::Processor<'a> as Processor<'a, B>>::Repr {
+        let expr = self.expression.visit(processor);
+        processor.bincount(expr, self.count(), self.min, self.max)
+    }
+
+    /// Estimates the cost of the histogram, including the reduction over every element of its
+    /// subexpression.
+    ///
+    /// # Returns
+    /// The estimated cost.
+    fn cost(&self) -> CostEstimate {
+        self.expression.cost() + CostEstimate::reduction(self.expression.count())
+    }
+
+    /// Returns the subexpression of the bincount.
+    ///
+    /// # Returns
+    /// A vector holding the subexpression.
+    fn children(&self) -> Vec<&dyn Node<B>> {
+        vec![self.expression.as_ref()]
+    }
+
+    /// Renders the bincount with its subexpression, bin count and range for debugging.
+    ///
+    /// # Parameters
+    /// - `f`: The formatter to write the rendered expression tree to.
+    ///
+    /// # Returns
+    /// A result indicating whether the formatting succeeded.
+    fn fmt_tree(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Bincount(")?;
+        self.expression.fmt_tree(f)?;
+        write!(f, ", {}, {:?}, {:?})", self.count(), self.min, self.max)
+    }
+}
+
+// NOTE: Clone implementation.
+
+impl<B: Backend> Clone for Bincount<B> {
+    /// Creates a clone of the `Bincount` instance.
+    ///
+    /// # Returns
+    /// A clone of the `Bincount` instance.
+    fn clone(&self) -> Self {
+        Self {
+            shape: self.shape.clone(),
+            min: self.min,
+            max: self.max,
+            expression: self.expression.clone_box(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::expression::Shape;
+    use crate::Tengu;
+
+    #[tokio::test]
+    async fn shape() {
+        let tengu = Tengu::cpu().await.unwrap();
+        let a = tengu.tensor([4, 3]).zero::<f32>();
+        let counts = a.bincount(5, 0.0, 1.0);
+        assert_eq!(counts.shape(), &[5]);
+        assert_eq!(counts.count(), 5);
+    }
+}
@@ -1,19 +1,23 @@
 //! This module defines the `Cast` struct and associated functionality for handling type casting in tensor expressions.
 //! It leverages the backend processing capabilities to apply type casting on tensor data.
 
+use std::fmt;
 use std::marker::PhantomData;
 
 use tengu_backend::{Backend, Processor};
-use tengu_backend_tensor::StorageType;
+use tengu_backend_tensor::{CastMode, StorageType, Type};
 
 use super::Expression;
 use crate::collector::Collector;
+use crate::cost::CostEstimate;
 use crate::node::Node;
 use crate::shape::Shape;
 use crate::source::Source;
 
 /// Struct representing a type cast on a tensor expression.
 pub struct Cast<T, B> {
+    from: Type,
+    mode: CastMode,
     expression: Box<dyn Node<B>>,
     phantom: PhantomData<T>,
 }
@@ -23,11 +27,14 @@ impl<T: StorageType, B: Backend + 'static> Cast<T, B> {
     ///
     /// # Parameters
     /// - `expr`: The tensor expression to be cast to a different type.
+    /// - `mode`: The way values out of the range of the target type are cast.
     ///
     /// # Returns
     /// A new `Cast` instance.
-    pub fn new<S: StorageType>(expr: Expression<S, B>) -> Self {
+    pub fn new<S: StorageType>(expr: Expression<S, B>, mode: CastMode) -> Self {
         Self {
+            from: S::as_type(),
+            mode,
             expression: Box::new(expr),
             phantom: PhantomData,
         }
@@ -95,9 +102,38 @@ where
     ///
     /// # Returns
     /// The inner representation used by the processor.
-    fn visit<'a>(&'a self, processor: &mut B::Processor<'a>) -> <B::Processor<'a> as Processor<B>>::Repr {
+    fn visit<'a>(&'a self, processor: &mut B::Processor<'a>) -> <B::Processor<'a> as Processor<'a, B>>::Repr {
         let epxression = self.expression.visit(processor);
-        processor.cast(epxression, T::as_type())
+        processor.cast(epxression, self.from, T::as_type(), self.mode)
+    }
+
+    /// Estimates the cost of the cast, including the cast of every element and its subexpression.
+    ///
+    /// # Returns
+    /// The estimated cost.
+    fn cost(&self) -> CostEstimate {
+        self.expression.cost() + CostEstimate::elementwise(self.count())
+    }
+
+    /// Returns the subexpression of the cast.
+    ///
+    /// # Returns
+    /// A vector holding the subexpression.
+    fn children(&self) -> Vec<&dyn Node<B>> {
+        vec![self.expression.as_ref()]
+    }
+
+    /// Renders the cast with its subexpression and target type for debugging.
+    ///
+    /// # Parameters
+    /// - `f`: The formatter to write the rendered expression tree to.
+    ///
+    /// # Returns
+    /// A result indicating whether the formatting succeeded.
+    fn fmt_tree(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Cast(")?;
+        self.expression.fmt_tree(f)?;
+        write!(f, ", {})", T::as_type())
     }
 }
 
@@ -110,6 +146,8 @@ impl<T, B: Backend> Clone for Cast<T, B> {
     /// A clone of the `Cast` instance.
     fn clone(&self) -> Self {
         Self {
+            from: self.from,
+            mode: self.mode,
             expression: self.expression.clone_box(),
             phantom: PhantomData,
         }
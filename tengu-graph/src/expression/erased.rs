@@ -0,0 +1,148 @@
+//! This module defines the `ErasedExpression` enum, which holds an expression whose storage type is
+//! only known at runtime. It is used when the target type of an expression comes from a `Type`
+//! value (e.g. parsed from a configuration) rather than from a type parameter.
+
+use std::any::Any;
+
+use tengu_backend::Backend;
+use tengu_backend_tensor::{StorageType, Type};
+
+use super::Expression;
+use crate::shape::Shape;
+use crate::{Error, Result};
+
+/// An expression with its storage type erased, holding one variant per supported storage type.
+pub enum ErasedExpression<B: Backend + 'static> {
+    Bool(Expression<bool, B>),
+    U32(Expression<u32, B>),
+    I32(Expression<i32, B>),
+    F32(Expression<f32, B>),
+    U16(Expression<u16, B>),
+    I16(Expression<i16, B>),
+}
+
+impl<B: Backend + 'static> ErasedExpression<B> {
+    /// Creates a new `ErasedExpression` from a typed expression, picking the variant corresponding
+    /// to its storage type.
+    ///
+    /// # Parameters
+    /// - `expr`: The typed expression.
+    ///
+    /// # Returns
+    /// A new `ErasedExpression` instance.
+    pub fn new<T: StorageType>(expr: Expression<T, B>) -> Self {
+        let expr: Box<dyn Any> = Box::new(expr);
+        match T::as_type() {
+            Type::Bool => Self::Bool(*expr.downcast().expect("type should be bool")),
+            Type::U32 => Self::U32(*expr.downcast().expect("type should be u32")),
+            Type::I32 => Self::I32(*expr.downcast().expect("type should be i32")),
+            Type::F32 => Self::F32(*expr.downcast().expect("type should be f32")),
+            Type::U16 => Self::U16(*expr.downcast().expect("type should be u16")),
+            Type::I16 => Self::I16(*expr.downcast().expect("type should be i16")),
+        }
+    }
+
+    /// Returns the storage type of the expression.
+    ///
+    /// # Returns
+    /// The `Type` variant corresponding to the storage type of the expression.
+    pub fn dtype(&self) -> Type {
+        match self {
+            Self::Bool(_) => Type::Bool,
+            Self::U32(_) => Type::U32,
+            Self::I32(_) => Type::I32,
+            Self::F32(_) => Type::F32,
+            Self::U16(_) => Type::U16,
+            Self::I16(_) => Type::I16,
+        }
+    }
+
+    /// Converts the expression back into a typed expression.
+    ///
+    /// # Type Parameters
+    /// - `T`: The expected storage type of the expression.
+    ///
+    /// # Returns
+    /// A result containing the typed expression, or `Error::TypeMismatch` if the storage type of
+    /// the expression is not `T`.
+    pub fn downcast<T: StorageType>(self) -> Result<Expression<T, B>> {
+        let expr: Box<dyn Any> = match self {
+            Self::Bool(expr) => Box::new(expr),
+            Self::U32(expr) => Box::new(expr),
+            Self::I32(expr) => Box::new(expr),
+            Self::F32(expr) => Box::new(expr),
+            Self::U16(expr) => Box::new(expr),
+            Self::I16(expr) => Box::new(expr),
+        };
+        expr.downcast().map(|expr| *expr).map_err(|_| Error::TypeMismatch)
+    }
+}
+
+// NOTE: Shape implementation.
+
+impl<B: Backend + 'static> Shape for ErasedExpression<B> {
+    /// Returns the shape of the expression.
+    ///
+    /// # Returns
+    /// A slice representing the shape of the expression.
+    fn shape(&self) -> &[usize] {
+        match self {
+            Self::Bool(expr) => expr.shape(),
+            Self::U32(expr) => expr.shape(),
+            Self::I32(expr) => expr.shape(),
+            Self::F32(expr) => expr.shape(),
+            Self::U16(expr) => expr.shape(),
+            Self::I16(expr) => expr.shape(),
+        }
+    }
+
+    /// Returns the number of elements in the expression.
+    ///
+    /// # Returns
+    /// The number of elements.
+    fn count(&self) -> usize {
+        match self {
+            Self::Bool(expr) => expr.count(),
+            Self::U32(expr) => expr.count(),
+            Self::I32(expr) => expr.count(),
+            Self::F32(expr) => expr.count(),
+            Self::U16(expr) => expr.count(),
+            Self::I16(expr) => expr.count(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use tengu_backend_tensor::Type;
+
+    use super::ErasedExpression;
+    use crate::Tengu;
+
+    #[tokio::test]
+    async fn dtype() {
+        let tengu = Tengu::cpu().await.unwrap();
+        let a = tengu.tensor([2]).label("a").zero::<i32>();
+        assert_eq!(ErasedExpression::new(a.clone()).dtype(), Type::I32);
+        assert_eq!(a.cast_dtype(Type::U16).dtype(), Type::U16);
+    }
+
+    #[tokio::test]
+    async fn downcast() {
+        let tengu = Tengu::cpu().await.unwrap();
+        let a = tengu.tensor([2]).label("a").zero::<i32>();
+        let erased = a.cast_dtype(Type::F32);
+        assert!(erased.downcast::<f32>().is_ok());
+        let erased = ErasedExpression::new(tengu.tensor([2]).zero::<u32>());
+        assert!(erased.downcast::<i32>().is_err());
+    }
+
+    #[tokio::test]
+    async fn same_dtype() {
+        let tengu = Tengu::cpu().await.unwrap();
+        let a = tengu.tensor([2]).label("a").zero::<f32>();
+        let erased = a.cast_dtype(Type::F32);
+        assert_eq!(format!("{:?}", erased.downcast::<f32>().unwrap()), r#"Tensor("a")"#);
+    }
+}
@@ -0,0 +1,196 @@
+//! This module defines the `Flip` struct and associated functionality for reversing tensor
+//! expressions along some of their axes. The result has the shape of the original expression, and
+//! the order of the elements along each flipped axis is reversed.
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use tengu_backend::{Backend, Processor};
+use tengu_backend_tensor::StorageType;
+
+use super::Expression;
+use crate::collector::Collector;
+use crate::cost::CostEstimate;
+use crate::node::Node;
+use crate::shape::Shape;
+use crate::source::Source;
+
+/// Struct representing a tensor expression reversed along some of its axes.
+pub struct Flip<T, B> {
+    axes: Vec<usize>,
+    expression: Box<dyn Node<B>>,
+    phantom: PhantomData<T>,
+}
+
+impl<T: StorageType, B: Backend + 'static> Flip<T, B> {
+    /// Creates a new `Flip` instance.
+    ///
+    /// # Parameters
+    /// - `expr`: The tensor expression to flip.
+    /// - `axes`: The axes along which the expression is reversed.
+    ///
+    /// # Returns
+    /// A new `Flip` instance.
+    ///
+    /// # Panics
+    /// Panics if any axis is out of bounds of the expression shape or appears more than once.
+    pub fn new(expr: Expression<T, B>, axes: &[usize]) -> Self {
+        let rank = expr.shape().len();
+        for (i, &axis) in axes.iter().enumerate() {
+            assert!(
+                axis < rank,
+                "axis {axis} is out of bounds for expression of rank {rank}"
+            );
+            assert!(!axes[..i].contains(&axis), "axis {axis} is flipped more than once");
+        }
+        Self {
+            axes: axes.to_vec(),
+            expression: Box::new(expr),
+            phantom: PhantomData,
+        }
+    }
+}
+
+// NOTE: Shape implementation.
+
+impl<T, B> Shape for Flip<T, B> {
+    /// Returns the number of elements in the flipped tensor.
+    ///
+    /// # Returns
+    /// The number of elements in the tensor.
+    fn count(&self) -> usize {
+        self.expression.count()
+    }
+
+    /// Returns the shape of the flipped tensor, which is the same as the shape of the inner
+    /// expression.
+    ///
+    /// # Returns
+    /// A slice representing the dimensions of the tensor.
+    fn shape(&self) -> &[usize] {
+        self.expression.shape()
+    }
+}
+
+// NOTE: Node implementation.
+
+impl<T: StorageType, B: Backend + 'static> Node<B> for Flip<T, B> {
+    /// Returns a boxed clone of the `Flip` instance.
+    ///
+    /// # Returns
+    /// A boxed clone of the `Flip` instance.
+    fn clone_box(&self) -> Box<dyn Node<B>> {
+        Box::new(self.clone())
+    }
+
+    /// Collect sources from the flip operation.
+    ///
+    /// # Parameters
+    /// - `collector`: A mutable reference to the collector.
+    fn collect<'a>(&'a self, collector: &mut Collector<'a, B>) {
+        self.expression.collect(collector);
+    }
+
+    /// Finds a source node by its label.
+    ///
+    /// # Parameters
+    /// - `label`: The label of the source node to find.
+    ///
+    /// # Returns
+    /// An optional reference to the found source node.
+    fn find<'a>(&'a self, label: &str) -> Option<&'a dyn Source<B>> {
+        self.expression.find(label)
+    }
+
+    /// Visits the node with the given processor and flips the inner expression.
+    ///
+    /// # Parameters
+    /// - `processor`: The processor used to visit the node.
+    ///
+    /// # Returns
+    /// The inner representation used by the processor.
+    fn visit<'a>(&'a self, processor: &mut B::Processor<'a>) -> <B::Processor<'a> as Processor<'a, B>>::Repr {
+        let expr = self.expression.visit(processor);
+        processor.flip(expr, self.expression.shape(), &self.axes, T::as_type())
+    }
+
+    /// Estimates the cost of the flip, including the index computation for every element of the
+    /// result and its subexpression.
+    ///
+    /// # Returns
+    /// The estimated cost.
+    fn cost(&self) -> CostEstimate {
+        self.expression.cost() + CostEstimate::elementwise(self.count())
+    }
+
+    /// Returns the subexpression of the flip.
+    ///
+    /// # Returns
+    /// A vector holding the subexpression.
+    fn children(&self) -> Vec<&dyn Node<B>> {
+        vec![self.expression.as_ref()]
+    }
+
+    /// Renders the flip with its subexpression and axes for debugging.
+    ///
+    /// # Parameters
+    /// - `f`: The formatter to write the rendered expression tree to.
+    ///
+    /// # Returns
+    /// A result indicating whether the formatting succeeded.
+    fn fmt_tree(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Flip(")?;
+        self.expression.fmt_tree(f)?;
+        write!(f, ", {:?})", self.axes)
+    }
+}
+
+// NOTE: Clone implementation.
+
+impl<T, B: Backend> Clone for Flip<T, B> {
+    /// Creates a clone of the `Flip` instance.
+    ///
+    /// # Returns
+    /// A clone of the `Flip` instance.
+    fn clone(&self) -> Self {
+        Self {
+            axes: self.axes.clone(),
+            expression: self.expression.clone_box(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::expression::Shape;
+    use crate::Tengu;
+
+    #[tokio::test]
+    async fn flipped_shape() {
+        let tengu = Tengu::cpu().await.unwrap();
+        let a = tengu.tensor([2, 3]).label("a").zero::<f32>();
+        let flip = a.flip_all(&[0, 1]);
+        assert_eq!(flip.shape(), &[2, 3]);
+        assert_eq!(flip.count(), 6);
+        assert_eq!(format!("{flip:?}"), r#"Flip(Tensor("a"), [0, 1])"#);
+    }
+
+    #[tokio::test]
+    #[should_panic]
+    async fn axis_out_of_bounds() {
+        let tengu = Tengu::cpu().await.unwrap();
+        let a = tengu.tensor([2, 3]).zero::<f32>();
+        let _ = a.flip(2);
+    }
+
+    #[tokio::test]
+    #[should_panic]
+    async fn repeated_axis() {
+        let tengu = Tengu::cpu().await.unwrap();
+        let a = tengu.tensor([2, 3]).zero::<f32>();
+        let _ = a.flip_all(&[1, 1]);
+    }
+}
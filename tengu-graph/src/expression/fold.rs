@@ -0,0 +1,156 @@
+//! This module implements constant folding of scalar expressions. Binary operations and casts whose
+//! operands are all scalars are evaluated when the expression is constructed, so that they end up
+//! as a single scalar instead of a subtree evaluated for every element, e.g. in the emitted WGSL.
+//!
+//! Only operations whose result is the same on every backend are folded. Integer results which
+//! overflow their type, integer divisions by zero and casts of values which are out of the range of
+//! the target type or can't be represented exactly are left to the backends.
+
+use std::any::Any;
+
+use tengu_backend_tensor::{Operator, StorageType, Type};
+
+/// A scalar value whose type is only known at runtime. All integer types fit into `i64`, so their
+/// arithmetic is exact and overflows can be detected when converting back.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Constant {
+    Bool(bool),
+    Int(i64),
+    Float(f32),
+}
+
+impl Constant {
+    /// Creates a constant holding the specified scalar value.
+    ///
+    /// # Parameters
+    /// - `value`: The scalar value.
+    ///
+    /// # Returns
+    /// The constant holding the value.
+    pub(crate) fn of<T: StorageType>(value: T) -> Self {
+        let any: &dyn Any = &value;
+        let constant = match T::as_type() {
+            Type::Bool => any.downcast_ref::<bool>().map(|v| Self::Bool(*v)),
+            Type::U32 => any.downcast_ref::<u32>().map(|v| Self::Int(*v as i64)),
+            Type::I32 => any.downcast_ref::<i32>().map(|v| Self::Int(*v as i64)),
+            Type::F32 => any.downcast_ref::<f32>().map(|v| Self::Float(*v)),
+            Type::U16 => any.downcast_ref::<u16>().map(|v| Self::Int(*v as i64)),
+            Type::I16 => any.downcast_ref::<i16>().map(|v| Self::Int(*v as i64)),
+        };
+        constant.expect("storage type should match its type tag")
+    }
+
+    /// Converts the constant back to a scalar value of the specified type.
+    ///
+    /// # Returns
+    /// The scalar value, or `None` if the constant doesn't fit into the type.
+    pub(crate) fn to<T: StorageType>(self) -> Option<T> {
+        let value: Box<dyn Any> = match (T::as_type(), self) {
+            (Type::Bool, Self::Bool(v)) => Box::new(v),
+            (Type::U32, Self::Int(v)) => Box::new(u32::try_from(v).ok()?),
+            (Type::I32, Self::Int(v)) => Box::new(i32::try_from(v).ok()?),
+            (Type::F32, Self::Float(v)) => Box::new(v),
+            (Type::U16, Self::Int(v)) => Box::new(u16::try_from(v).ok()?),
+            (Type::I16, Self::Int(v)) => Box::new(i16::try_from(v).ok()?),
+            _ => return None,
+        };
+        value.downcast::<T>().ok().map(|value| *value)
+    }
+
+    /// Applies a binary operator to two constants of the same type.
+    ///
+    /// # Parameters
+    /// - `operator`: The binary operator to apply.
+    /// - `rhs`: The right-hand side constant.
+    ///
+    /// # Returns
+    /// The result of the operation, or `None` if it isn't folded.
+    pub(crate) fn binary(self, operator: Operator, rhs: Self) -> Option<Self> {
+        match (self, rhs) {
+            (Self::Int(lhs), Self::Int(rhs)) => match operator {
+                Operator::Add => Some(Self::Int(lhs + rhs)),
+                Operator::Sub => Some(Self::Int(lhs - rhs)),
+                Operator::Mul => Some(Self::Int(lhs * rhs)),
+                Operator::Div => lhs.checked_div(rhs).map(Self::Int),
+                Operator::Max => Some(Self::Int(lhs.max(rhs))),
+                Operator::Min => Some(Self::Int(lhs.min(rhs))),
+                _ => None,
+            },
+            (Self::Float(lhs), Self::Float(rhs)) => match operator {
+                Operator::Add => Some(Self::Float(lhs + rhs)),
+                Operator::Sub => Some(Self::Float(lhs - rhs)),
+                Operator::Mul => Some(Self::Float(lhs * rhs)),
+                Operator::Div => Some(Self::Float(lhs / rhs)),
+                Operator::Max => Some(Self::Float(if rhs > lhs { rhs } else { lhs })),
+                Operator::Min => Some(Self::Float(if rhs < lhs { rhs } else { lhs })),
+                // NOTE: Zeros are treated as positive to match the backends.
+                Operator::Copysign => Some(Self::Float(if rhs < 0.0 { -lhs.abs() } else { lhs.abs() })),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Casts the constant to the specified type. The range of integer types is only checked when
+    /// converting the constant back with `to`.
+    ///
+    /// # Parameters
+    /// - `ty`: The target type.
+    ///
+    /// # Returns
+    /// The cast constant, or `None` if the value can't be represented exactly in the target type.
+    pub(crate) fn cast(self, ty: Type) -> Option<Self> {
+        match (self, ty) {
+            (Self::Bool(v), Type::Bool) => Some(Self::Bool(v)),
+            (Self::Bool(v), Type::F32) => Some(Self::Float(if v { 1.0 } else { 0.0 })),
+            (Self::Bool(v), _) => Some(Self::Int(v as i64)),
+            (Self::Int(v), Type::Bool) => Some(Self::Bool(v != 0)),
+            (Self::Int(v), Type::F32) => Some(Self::Float(v as f32)).filter(|_| v as f32 as i64 == v),
+            (Self::Int(v), _) => Some(Self::Int(v)),
+            (Self::Float(v), Type::Bool) => Some(Self::Bool(v != 0.0)).filter(|_| !v.is_nan()),
+            (Self::Float(v), Type::F32) => Some(Self::Float(v)),
+            (Self::Float(v), _) => Some(Self::Int(v as i64)).filter(|_| v.trunc() == v),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use tengu_backend_tensor::{Operator, Type};
+
+    use super::Constant;
+
+    #[test]
+    fn roundtrip() {
+        assert_eq!(Constant::of(3u16), Constant::Int(3));
+        assert_eq!(Constant::of(-2.5f32).to::<f32>(), Some(-2.5));
+        assert_eq!(Constant::of(true).to::<bool>(), Some(true));
+        assert_eq!(Constant::Int(-1).to::<u32>(), None);
+        assert_eq!(Constant::Int(70000).to::<i16>(), None);
+    }
+
+    #[test]
+    fn binary() {
+        let fold = |lhs, operator, rhs| Constant::Int(lhs).binary(operator, Constant::Int(rhs));
+        assert_eq!(fold(2, Operator::Mul, 3), Some(Constant::Int(6)));
+        assert_eq!(fold(-7, Operator::Div, 2), Some(Constant::Int(-3)));
+        assert_eq!(fold(1, Operator::Div, 0), None);
+        assert_eq!(fold(1, Operator::Eq, 1), None);
+        let lhs = Constant::Float(1.5);
+        assert_eq!(
+            lhs.binary(Operator::Copysign, Constant::Float(-0.0)),
+            Some(Constant::Float(1.5))
+        );
+    }
+
+    #[test]
+    fn cast() {
+        assert_eq!(Constant::Int(1).cast(Type::F32), Some(Constant::Float(1.0)));
+        assert_eq!(Constant::Int(16777217).cast(Type::F32), None);
+        assert_eq!(Constant::Float(2.0).cast(Type::I32), Some(Constant::Int(2)));
+        assert_eq!(Constant::Float(2.5).cast(Type::I32), None);
+        assert_eq!(Constant::Float(f32::NAN).cast(Type::U32), None);
+        assert_eq!(Constant::Float(0.5).cast(Type::Bool), Some(Constant::Bool(true)));
+    }
+}
@@ -0,0 +1,213 @@
+//! This module defines the `MaskSelect` struct and associated functionality for selecting the
+//! elements of tensor expressions where a boolean mask is true. The number of selected elements is
+//! data-dependent, so the shape of the selection is an upper bound: the element count of the inner
+//! expression. The actual number of selected elements is written to a separate length tensor, which
+//! is attached by the computation assigning the selection to its output.
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use tengu_backend::{Backend, Processor};
+use tengu_backend_tensor::StorageType;
+
+use super::Expression;
+use crate::collector::Collector;
+use crate::cost::CostEstimate;
+use crate::node::Node;
+use crate::shape::Shape;
+use crate::source::Source;
+
+/// Struct representing the elements of a tensor expression selected by a boolean mask.
+pub struct MaskSelect<T, B> {
+    shape: Vec<usize>,
+    expression: Box<dyn Node<B>>,
+    mask: Box<dyn Node<B>>,
+    length: Option<Box<dyn Node<B>>>,
+    phantom: PhantomData<T>,
+}
+
+impl<T: StorageType, B: Backend + 'static> MaskSelect<T, B> {
+    /// Creates a new `MaskSelect` instance.
+    ///
+    /// # Parameters
+    /// - `expr`: The tensor expression whose elements are selected.
+    /// - `mask`: The boolean mask with the shape of the expression.
+    ///
+    /// # Returns
+    /// A new `MaskSelect` instance.
+    ///
+    /// # Panics
+    /// Panics if the shapes of the expression and the mask do not match.
+    pub fn new(expr: Expression<T, B>, mask: Expression<bool, B>) -> Self {
+        assert_eq!(
+            expr.shape(),
+            mask.shape(),
+            "mask shape doesn't match the expression shape"
+        );
+        Self {
+            shape: vec![expr.count()],
+            expression: Box::new(expr),
+            mask: Box::new(mask),
+            length: None,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns a copy of the selection which writes the number of selected elements to the
+    /// specified tensor.
+    ///
+    /// # Parameters
+    /// - `length`: The `[1]`-shaped tensor receiving the number of selected elements.
+    ///
+    /// # Returns
+    /// A new `MaskSelect` instance with the length tensor attached.
+    pub fn with_length(&self, length: Expression<u32, B>) -> Self {
+        Self {
+            length: Some(Box::new(length)),
+            ..self.clone()
+        }
+    }
+}
+
+// NOTE: Shape implementation.
+
+impl<T, B> Shape for MaskSelect<T, B> {
+    /// Returns the maximum number of selected elements.
+    ///
+    /// # Returns
+    /// The number of elements in the tensor.
+    fn count(&self) -> usize {
+        self.shape[0]
+    }
+
+    /// Returns the shape of the selection, which is 1-D.
+    ///
+    /// # Returns
+    /// A slice representing the dimensions of the tensor.
+    fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+}
+
+// NOTE: Node implementation.
+
+impl<T: StorageType, B: Backend + 'static> Node<B> for MaskSelect<T, B> {
+    /// Returns a boxed clone of the `MaskSelect` instance.
+    ///
+    /// # Returns
+    /// A boxed clone of the `MaskSelect` instance.
+    fn clone_box(&self) -> Box<dyn Node<B>> {
+        Box::new(self.clone())
+    }
+
+    /// Collect sources from the masked selection, including the length tensor.
+    ///
+    /// # Parameters
+    /// - `collector`: A mutable reference to the collector.
+    fn collect<'a>(&'a self, collector: &mut Collector<'a, B>) {
+        self.expression.collect(collector);
+        self.mask.collect(collector);
+        if let Some(length) = &self.length {
+            length.collect(collector);
+        }
+    }
+
+    /// Finds a source node by its label.
+    ///
+    /// # Parameters
+    /// - `label`: The label of the source node to find.
+    ///
+    /// # Returns
+    /// An optional reference to the found source node.
+    fn find<'a>(&'a self, label: &str) -> Option<&'a dyn Source<B>> {
+        self.expression
+            .find(label)
+            .or_else(|| self.mask.find(label))
+            .or_else(|| self.length.as_ref().and_then(|length| length.find(label)))
+    }
+
+    /// Visits the node with the given processor and selects the elements of the inner expression.
+    ///
+    /// # Parameters
+    /// - `processor`: The processor used to visit the node.
+    ///
+    /// # Returns
+    /// The inner representation used by the processor. A selection which is not assigned directly
+    /// to the output of a computation has no length tensor, so backends which don't support nested
+    /// selections report `tengu_backend::Error::UnsupportedExpression` once the block is processed.
+    fn visit<'a>(&'a self, processor: &mut B::Processor<'a>) -> <B::Processor<'a> as Processor<'a, B>>::Repr {
+        let expr = self.expression.visit(processor);
+        let mask = self.mask.visit(processor);
+        let length = self.length.as_ref().map(|length| length.visit(processor));
+        processor.mask_select(expr, mask, length, T::as_type())
+    }
+
+    /// Estimates the cost of the masked selection, including the scan over every element of its
+    /// subexpression, the subexpression itself and the mask.
+    ///
+    /// # Returns
+    /// The estimated cost.
+    fn cost(&self) -> CostEstimate {
+        let length = self.length.as_ref().map(|length| length.cost()).unwrap_or_default();
+        self.expression.cost() + self.mask.cost() + length + CostEstimate::reduction(self.count())
+    }
+
+    /// Returns the subexpression, the mask and, if attached, the length tensor of the selection.
+    ///
+    /// # Returns
+    /// A vector holding the subexpressions.
+    fn children(&self) -> Vec<&dyn Node<B>> {
+        let mut children = vec![self.expression.as_ref(), self.mask.as_ref()];
+        children.extend(self.length.as_deref());
+        children
+    }
+
+    /// Renders the masked selection with its subexpression and mask for debugging.
+    ///
+    /// # Parameters
+    /// - `f`: The formatter to write the rendered expression tree to.
+    ///
+    /// # Returns
+    /// A result indicating whether the formatting succeeded.
+    fn fmt_tree(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "MaskSelect(")?;
+        self.expression.fmt_tree(f)?;
+        write!(f, ", ")?;
+        self.mask.fmt_tree(f)?;
+        write!(f, ")")
+    }
+}
+
+// NOTE: Clone implementation.
+
+impl<T, B: Backend> Clone for MaskSelect<T, B> {
+    /// Creates a clone of the `MaskSelect` instance.
+    ///
+    /// # Returns
+    /// A clone of the `MaskSelect` instance.
+    fn clone(&self) -> Self {
+        Self {
+            shape: self.shape.clone(),
+            expression: self.expression.clone_box(),
+            mask: self.mask.clone_box(),
+            length: self.length.as_ref().map(|length| length.clone_box()),
+            phantom: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::expression::Shape;
+    use crate::Tengu;
+
+    #[tokio::test]
+    async fn shape() {
+        let tengu = Tengu::cpu().await.unwrap();
+        let a = tengu.tensor([2, 2]).zero::<f32>();
+        let mask = tengu.tensor([2, 2]).bools(&[true, false, true, true]).cast::<bool>();
+        let selected = a.mask_select(mask);
+        assert_eq!(selected.shape(), &[4]);
+        assert_eq!(selected.count(), 4);
+    }
+}
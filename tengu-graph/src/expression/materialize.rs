@@ -0,0 +1,250 @@
+//! This module defines the `Materialize` struct and associated functionality for breaking the fusion
+//! of tensor expressions. A materialized expression is computed into its own tensor by a separate
+//! computation when it is added to a block, and the consuming expression reads that tensor instead
+//! of evaluating the subexpression inline.
+
+use std::cell::OnceCell;
+use std::fmt;
+use std::rc::Rc;
+
+use as_any::Downcast;
+use tengu_backend::{Backend, Processor};
+use tengu_backend_tensor::StorageType;
+use tengu_graph_tensor::Tensor;
+
+use super::Expression;
+use crate::collector::Collector;
+use crate::cost::CostEstimate;
+use crate::graph::Computation;
+use crate::node::Node;
+use crate::shape::Shape;
+use crate::source::Source;
+use crate::{Result, Tengu};
+
+/// A subexpression which has to be computed into its own tensor before the expression using it.
+pub(crate) trait Materialized<B: Backend> {
+    /// Returns the label of the tensor the subexpression is computed into.
+    ///
+    /// # Returns
+    /// A reference to the label string.
+    fn label(&self) -> &str;
+
+    /// Returns the label of the block computing the subexpression, if it has been materialized.
+    ///
+    /// # Returns
+    /// An optional reference to the block label.
+    fn block(&self) -> Option<&str>;
+
+    /// Creates the computation assigning the subexpression to its tensor. From then on, the
+    /// expression using the subexpression reads the output of the computation.
+    ///
+    /// # Parameters
+    /// - `tengu`: A reference-counted Tengu instance used to create the output tensor.
+    /// - `block`: The label of the block the computation is added to.
+    ///
+    /// # Returns
+    /// A result wrapping the computation producing the materialized tensor, or an error if its
+    /// output tensor cannot be allocated.
+    fn computation(&self, tengu: &Rc<Tengu<B>>, block: &str) -> Result<Computation<B>>;
+}
+
+/// Struct representing a tensor expression materialized into its own tensor.
+pub struct Materialize<T: StorageType, B: Backend + 'static> {
+    label: String,
+    expression: Box<Expression<T, B>>,
+    output: Rc<OnceCell<(String, Expression<T, B>)>>,
+}
+
+impl<T: StorageType, B: Backend + 'static> Materialize<T, B> {
+    /// Creates a new `Materialize` instance with a generated label for the materialized tensor.
+    ///
+    /// # Parameters
+    /// - `expr`: The tensor expression to materialize.
+    ///
+    /// # Returns
+    /// A new `Materialize` instance.
+    pub fn new(expr: Expression<T, B>) -> Self {
+        Self {
+            label: tengu_backend_tensor::create_label(),
+            expression: Box::new(expr),
+            output: Rc::new(OnceCell::new()),
+        }
+    }
+}
+
+// NOTE: Shape implementation.
+
+impl<T: StorageType, B: Backend + 'static> Shape for Materialize<T, B> {
+    /// Returns the number of elements in the materialized expression.
+    ///
+    /// # Returns
+    /// The number of elements in the tensor.
+    fn count(&self) -> usize {
+        self.expression.count()
+    }
+
+    /// Returns the shape of the materialized expression.
+    ///
+    /// # Returns
+    /// A slice representing the dimensions of the tensor.
+    fn shape(&self) -> &[usize] {
+        self.expression.shape()
+    }
+}
+
+// NOTE: Node implementation.
+
+impl<T: StorageType, B: Backend + 'static> Node<B> for Materialize<T, B> {
+    /// Returns a boxed clone of the `Materialize` instance.
+    ///
+    /// # Returns
+    /// A boxed clone of the `Materialize` instance.
+    fn clone_box(&self) -> Box<dyn Node<B>> {
+        Box::new(self.clone())
+    }
+
+    /// Collect sources from the materialized expression. Once the expression has been materialized,
+    /// the only source is its tensor. Before that, the sources of the subexpression are collected.
+    /// Either way, the subexpression is registered with the collector, after any subexpressions it
+    /// materializes itself, so that the block adding it can check which block computes it.
+    ///
+    /// # Parameters
+    /// - `collector`: A mutable reference to the collector.
+    fn collect<'a>(&'a self, collector: &mut Collector<'a, B>) {
+        match self.output.get() {
+            Some((_, output)) => output.collect(collector),
+            None => self.expression.collect(collector),
+        }
+        collector.materialize(self);
+    }
+
+    /// Finds a source node by its label.
+    ///
+    /// # Parameters
+    /// - `label`: The label of the source node to find.
+    ///
+    /// # Returns
+    /// An optional reference to the found source node.
+    fn find<'a>(&'a self, label: &str) -> Option<&'a dyn Source<B>> {
+        match self.output.get() {
+            Some((_, output)) => output.find(label),
+            None => self.expression.find(label),
+        }
+    }
+
+    /// Visits the node with the given processor. The materialized tensor is visited if there is one,
+    /// otherwise the subexpression is fused into the consuming expression as usual, e.g. when the
+    /// expression is computed outside of a block.
+    ///
+    /// # Parameters
+    /// - `processor`: The processor used to visit the node.
+    ///
+    /// # Returns
+    /// The inner representation used by the processor.
+    fn visit<'a>(&'a self, processor: &mut B::Processor<'a>) -> <B::Processor<'a> as Processor<'a, B>>::Repr {
+        match self.output.get() {
+            Some((_, output)) => output.visit(processor),
+            None => self.expression.visit(processor),
+        }
+    }
+
+    /// Estimates the cost of the materialized expression. Once materialized, only reading the tensor
+    /// is paid for by the consuming expression.
+    ///
+    /// # Returns
+    /// The estimated cost.
+    fn cost(&self) -> CostEstimate {
+        match self.output.get() {
+            Some((_, output)) => output.cost(),
+            None => self.expression.cost(),
+        }
+    }
+
+    /// Returns the materialized tensor if there is one, otherwise the subexpression.
+    ///
+    /// # Returns
+    /// A vector holding the tensor or the subexpression.
+    fn children(&self) -> Vec<&dyn Node<B>> {
+        let child: &dyn Node<B> = match self.output.get() {
+            Some((_, output)) => output,
+            None => &*self.expression,
+        };
+        vec![child]
+    }
+
+    /// Renders the materialized expression with its subexpression for debugging.
+    ///
+    /// # Parameters
+    /// - `f`: The formatter to write the rendered expression tree to.
+    ///
+    /// # Returns
+    /// A result indicating whether the formatting succeeded.
+    fn fmt_tree(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Materialize(")?;
+        self.expression.fmt_tree(f)?;
+        write!(f, ")")
+    }
+}
+
+// NOTE: Materialized implementation.
+
+impl<T: StorageType, B: Backend + 'static> Materialized<B> for Materialize<T, B> {
+    /// Returns the label of the materialized tensor.
+    ///
+    /// # Returns
+    /// A reference to the label string.
+    fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Returns the label of the block computing the materialized tensor, if there is one.
+    ///
+    /// # Returns
+    /// An optional reference to the block label.
+    fn block(&self) -> Option<&str> {
+        self.output.get().map(|(block, _)| block.as_str())
+    }
+
+    /// Creates the computation assigning the subexpression to the materialized tensor, and makes the
+    /// node and all of its clones read that tensor.
+    ///
+    /// # Parameters
+    /// - `tengu`: A reference-counted Tengu instance used to create the output tensor.
+    /// - `block`: The label of the block the computation is added to.
+    ///
+    /// # Returns
+    /// A result wrapping the computation producing the materialized tensor, or
+    /// `Error::BackendError` wrapping `OutOfMemory` if the tensor cannot be allocated.
+    ///
+    /// # Panics
+    /// Panics if the expression has already been materialized.
+    fn computation(&self, tengu: &Rc<Tengu<B>>, block: &str) -> Result<Computation<B>> {
+        let computation = Computation::new(tengu, &self.label, (*self.expression).clone())?;
+        let output = computation
+            .source(&self.label)
+            .and_then(|source| source.downcast_ref::<Tensor<T, B>>())
+            .expect("materialized output should be a tensor of the expression type")
+            .clone();
+        self.output
+            .set((block.to_string(), Expression::Tensor(output)))
+            .expect("expression should only be materialized once");
+        Ok(computation)
+    }
+}
+
+// NOTE: Clone implementation.
+
+impl<T: StorageType, B: Backend + 'static> Clone for Materialize<T, B> {
+    /// Creates a clone of the `Materialize` instance, sharing the materialized tensor with the
+    /// original. Clones can therefore only be used in the block which materialized the expression.
+    ///
+    /// # Returns
+    /// A clone of the `Materialize` instance.
+    fn clone(&self) -> Self {
+        Self {
+            label: self.label.clone(),
+            expression: Box::new((*self.expression).clone()),
+            output: Rc::clone(&self.output),
+        }
+    }
+}
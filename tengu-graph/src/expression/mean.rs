@@ -0,0 +1,198 @@
+//! This module defines the `Mean` struct and associated functionality for computing means of tensor
+//! expressions along an axis. The reduced axis can be either removed from the shape or kept with
+//! size 1, which is useful for broadcasting the result back against the original expression.
+
+use std::fmt;
+
+use tengu_backend::{Backend, Processor};
+use tengu_backend_tensor::StorageType;
+
+use super::Expression;
+use crate::collector::Collector;
+use crate::cost::CostEstimate;
+use crate::node::Node;
+use crate::shape::Shape;
+use crate::source::Source;
+
+/// Struct representing the mean of a tensor expression along an axis.
+pub struct Mean<B> {
+    axis: usize,
+    shape: Vec<usize>,
+    count: usize,
+    expression: Box<dyn Node<B>>,
+}
+
+impl<B: Backend + 'static> Mean<B> {
+    /// Creates a new `Mean` instance.
+    ///
+    /// # Parameters
+    /// - `expr`: The tensor expression to reduce.
+    /// - `axis`: The axis along which the mean is computed.
+    /// - `keepdim`: Whether the reduced axis is kept in the shape with size 1.
+    ///
+    /// # Returns
+    /// A new `Mean` instance.
+    ///
+    /// # Panics
+    /// Panics if the axis is out of bounds of the expression shape.
+    pub fn new<T: StorageType>(expr: Expression<T, B>, axis: usize, keepdim: bool) -> Self {
+        let rank = expr.shape().len();
+        assert!(axis < rank, "axis {axis} is out of bounds for expression of rank {rank}");
+        let mut shape = expr.shape().to_vec();
+        if keepdim {
+            shape[axis] = 1;
+        } else {
+            shape.remove(axis);
+        }
+        if shape.is_empty() {
+            shape.push(1);
+        }
+        Self {
+            axis,
+            count: shape.iter().product(),
+            shape,
+            expression: Box::new(expr),
+        }
+    }
+}
+
+// NOTE: Shape implementation.
+
+impl<B> Shape for Mean<B> {
+    /// Returns the number of elements in the reduced tensor.
+    ///
+    /// # Returns
+    /// The number of elements in the tensor.
+    fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the shape of the reduced tensor.
+    ///
+    /// # Returns
+    /// A slice representing the dimensions of the tensor.
+    fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+}
+
+// NOTE: Node implementation.
+
+impl<B: Backend + 'static> Node<B> for Mean<B> {
+    /// Returns a boxed clone of the `Mean` instance.
+    ///
+    /// # Returns
+    /// A boxed clone of the `Mean` instance.
+    fn clone_box(&self) -> Box<dyn Node<B>> {
+        Box::new(self.clone())
+    }
+
+    /// Collect sources from the mean operation.
+    ///
+    /// # Parameters
+    /// - `collector`: A mutable reference to the collector.
+    fn collect<'a>(&'a self, collector: &mut Collector<'a, B>) {
+        self.expression.collect(collector);
+    }
+
+    /// Finds a source node by its label.
+    ///
+    /// # Parameters
+    /// - `label`: The label of the source node to find.
+    ///
+    /// # Returns
+    /// An optional reference to the found source node.
+    fn find<'a>(&'a self, label: &str) -> Option<&'a dyn Source<B>> {
+        self.expression.find(label)
+    }
+
+    /// Visits the node with the given processor and reduces the inner expression.
+    ///
+    /// # Parameters
+    /// - `processor`: The processor used to visit the node.
+    ///
+    /// # Returns
+    /// The inner representation used by the processor.
+    fn visit<'a>(&'a self, processor: &mut B::Processor<'a>) -> <B::Processor<'a> as Processor<'a, B>>::Repr {
+        let expr = self.expression.visit(processor);
+        processor.mean(expr, self.expression.shape(), self.axis)
+    }
+
+    /// Estimates the cost of the mean, including the reduction over every element of its subexpression.
+    ///
+    /// # Returns
+    /// The estimated cost.
+    fn cost(&self) -> CostEstimate {
+        self.expression.cost() + CostEstimate::reduction(self.expression.count())
+    }
+
+    /// Returns the subexpression of the mean.
+    ///
+    /// # Returns
+    /// A vector holding the subexpression.
+    fn children(&self) -> Vec<&dyn Node<B>> {
+        vec![self.expression.as_ref()]
+    }
+
+    /// Renders the mean with its subexpression and axis for debugging.
+    ///
+    /// # Parameters
+    /// - `f`: The formatter to write the rendered expression tree to.
+    ///
+    /// # Returns
+    /// A result indicating whether the formatting succeeded.
+    fn fmt_tree(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Mean(")?;
+        self.expression.fmt_tree(f)?;
+        write!(f, ", {})", self.axis)
+    }
+}
+
+// NOTE: Clone implementation.
+
+impl<B: Backend> Clone for Mean<B> {
+    /// Creates a clone of the `Mean` instance.
+    ///
+    /// # Returns
+    /// A clone of the `Mean` instance.
+    fn clone(&self) -> Self {
+        Self {
+            axis: self.axis,
+            shape: self.shape.clone(),
+            count: self.count,
+            expression: self.expression.clone_box(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::expression::Shape;
+    use crate::Tengu;
+
+    #[tokio::test]
+    async fn keepdim_shape() {
+        let tengu = Tengu::cpu().await.unwrap();
+        let a = tengu.tensor([2, 3]).zero::<f32>();
+        let mean = a.mean_axis(1, true);
+        assert_eq!(mean.shape(), &[2, 1]);
+        assert_eq!(mean.count(), 2);
+    }
+
+    #[tokio::test]
+    async fn reduced_shape() {
+        let tengu = Tengu::cpu().await.unwrap();
+        let a = tengu.tensor([2, 3]).zero::<f32>();
+        let mean = a.mean_axis(1, false);
+        assert_eq!(mean.shape(), &[2]);
+        assert_eq!(mean.count(), 2);
+    }
+
+    #[tokio::test]
+    #[should_panic]
+    async fn axis_out_of_bounds() {
+        let tengu = Tengu::cpu().await.unwrap();
+        let a = tengu.tensor([2, 3]).zero::<f32>();
+        let _ = a.mean_axis(2, false);
+    }
+}
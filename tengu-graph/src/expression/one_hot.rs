@@ -0,0 +1,185 @@
+//! This module defines the `OneHot` struct and associated functionality for one-hot encoding tensor
+//! expressions. Each value of a 1-D expression of class indices becomes a row of the result which
+//! holds 1.0 in the column of the class and 0.0 everywhere else.
+
+use std::fmt;
+
+use tengu_backend::{Backend, Processor};
+use tengu_backend_tensor::StorageType;
+
+use super::Expression;
+use crate::collector::Collector;
+use crate::cost::CostEstimate;
+use crate::node::Node;
+use crate::shape::Shape;
+use crate::source::Source;
+
+/// Struct representing the one-hot encoding of a tensor expression of class indices.
+pub struct OneHot<B> {
+    classes: usize,
+    shape: Vec<usize>,
+    count: usize,
+    expression: Box<dyn Node<B>>,
+}
+
+impl<B: Backend + 'static> OneHot<B> {
+    /// Creates a new `OneHot` instance.
+    ///
+    /// # Parameters
+    /// - `expr`: The 1-D tensor expression holding the class indices.
+    /// - `classes`: The number of classes.
+    ///
+    /// # Returns
+    /// A new `OneHot` instance.
+    ///
+    /// # Panics
+    /// Panics if the expression is not 1-D or if there are no classes.
+    pub fn new<T: StorageType>(expr: Expression<T, B>, classes: usize) -> Self {
+        let rank = expr.shape().len();
+        assert_eq!(rank, 1, "expected 1-D expression of class indices, got rank {rank}");
+        assert!(classes > 0, "number of classes should be positive");
+        let shape = vec![expr.count(), classes];
+        Self {
+            classes,
+            count: shape.iter().product(),
+            shape,
+            expression: Box::new(expr),
+        }
+    }
+}
+
+// NOTE: Shape implementation.
+
+impl<B> Shape for OneHot<B> {
+    /// Returns the number of elements in the encoded tensor.
+    ///
+    /// # Returns
+    /// The number of elements in the tensor.
+    fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the shape of the encoded tensor, with one row per index and one column per class.
+    ///
+    /// # Returns
+    /// A slice representing the dimensions of the tensor.
+    fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+}
+
+// NOTE: Node implementation.
+
+impl<B: Backend + 'static> Node<B> for OneHot<B> {
+    /// Returns a boxed clone of the `OneHot` instance.
+    ///
+    /// # Returns
+    /// A boxed clone of the `OneHot` instance.
+    fn clone_box(&self) -> Box<dyn Node<B>> {
+        Box::new(self.clone())
+    }
+
+    /// Collect sources from the one-hot operation.
+    ///
+    /// # Parameters
+    /// - `collector`: A mutable reference to the collector.
+    fn collect<'a>(&'a self, collector: &mut Collector<'a, B>) {
+        self.expression.collect(collector);
+    }
+
+    /// Finds a source node by its label.
+    ///
+    /// # Parameters
+    /// - `label`: The label of the source node to find.
+    ///
+    /// # Returns
+    /// An optional reference to the found source node.
+    fn find<'a>(&'a self, label: &str) -> Option<&'a dyn Source<B>> {
+        self.expression.find(label)
+    }
+
+    /// Visits the node with the given processor and encodes the inner expression.
+    ///
+    /// # Parameters
+    /// - `processor`: The processor used to visit the node.
+    ///
+    /// # Returns
+    /// The inner representation used by the processor.
+    fn visit<'a>(&'a self, processor: &mut B::Processor<'a>) -> <B::Processor<'a> as Processor<'a, B>>::Repr {
+        let expr = self.expression.visit(processor);
+        processor.one_hot(expr, self.classes)
+    }
+
+    /// Estimates the cost of the one-hot encoding, including the comparison for every element of
+    /// the result and its subexpression.
+    ///
+    /// # Returns
+    /// The estimated cost.
+    fn cost(&self) -> CostEstimate {
+        self.expression.cost() + CostEstimate::elementwise(self.count())
+    }
+
+    /// Returns the subexpression of the one-hot encoding.
+    ///
+    /// # Returns
+    /// A vector holding the subexpression.
+    fn children(&self) -> Vec<&dyn Node<B>> {
+        vec![self.expression.as_ref()]
+    }
+
+    /// Renders the one-hot encoding with its subexpression and number of classes for debugging.
+    ///
+    /// # Parameters
+    /// - `f`: The formatter to write the rendered expression tree to.
+    ///
+    /// # Returns
+    /// A result indicating whether the formatting succeeded.
+    fn fmt_tree(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "OneHot(")?;
+        self.expression.fmt_tree(f)?;
+        write!(f, ", {})", self.classes)
+    }
+}
+
+// NOTE: Clone implementation.
+
+impl<B: Backend> Clone for OneHot<B> {
+    /// Creates a clone of the `OneHot` instance.
+    ///
+    /// # Returns
+    /// A clone of the `OneHot` instance.
+    fn clone(&self) -> Self {
+        Self {
+            classes: self.classes,
+            shape: self.shape.clone(),
+            count: self.count,
+            expression: self.expression.clone_box(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::expression::Shape;
+    use crate::Tengu;
+
+    #[tokio::test]
+    async fn encoded_shape() {
+        let tengu = Tengu::cpu().await.unwrap();
+        let a = tengu.tensor([4]).label("a").zero::<u32>();
+        let one_hot = a.one_hot(3);
+        assert_eq!(one_hot.shape(), &[4, 3]);
+        assert_eq!(one_hot.count(), 12);
+        assert_eq!(format!("{one_hot:?}"), r#"OneHot(Tensor("a"), 3)"#);
+    }
+
+    #[tokio::test]
+    #[should_panic]
+    async fn not_1d() {
+        let tengu = Tengu::cpu().await.unwrap();
+        let a = tengu.tensor([2, 2]).zero::<u32>();
+        let _ = a.one_hot(3);
+    }
+}
@@ -52,6 +52,37 @@ impl_op!(Sub, sub);
 impl_op!(Mul, mul);
 impl_op!(Div, div);
 
+/// A macro to implement arithmetic operations between `f32` tensor expressions and integer literals.
+///
+/// Unsuffixed integer literals can never be `f32`, so `a * 2` wouldn't compile for an `f32`
+/// expression with only the scalar operations above. The literal is taken as `i32` and converted
+/// to `f32` instead, so it adapts to the expression without annotations. Float literals need no
+/// such conversion, since `f32` is the only float type the expressions accept.
+///
+/// # Parameters
+/// - `$trait`: The trait to implement (e.g., `Add`).
+/// - `$method`: The method corresponding to the trait (e.g., `add`).
+macro_rules! impl_int_literal_op {
+    ( $trait:ident, $method:ident ) => {
+        impl<B> $trait<i32> for Expression<f32, B>
+        where
+            B: Backend + 'static,
+        {
+            type Output = Expression<f32, B>;
+
+            fn $method(self, rhs: i32) -> Self::Output {
+                let rhs = Expression::Scalar(rhs as f32);
+                Binary::$method(self, rhs)
+            }
+        }
+    };
+}
+
+impl_int_literal_op!(Add, add);
+impl_int_literal_op!(Sub, sub);
+impl_int_literal_op!(Mul, mul);
+impl_int_literal_op!(Div, div);
+
 #[cfg(test)]
 mod tests {
     use crate::Tengu;
@@ -66,6 +97,15 @@ mod tests {
         let _ = tengu.scalar(2) / lhs.clone() / 3;
     }
 
+    #[tokio::test]
+    async fn literal_arithmetic() {
+        let tengu = Tengu::wgpu().await.unwrap();
+        let lhs = tengu.tensor([1, 2, 3]).zero::<f32>();
+        let _ = lhs.clone() + 1.0 - 2;
+        let _ = lhs.clone() * 2 / 0.5;
+        let _ = (lhs.clone() + 1).exp() * 3.0;
+    }
+
     #[tokio::test]
     async fn tensor_arithmetic() {
         let tengu = Tengu::wgpu().await.unwrap();
@@ -1,4 +1,7 @@
-//! This module defines relational operations for tensor expressions, specifically the equality operation.
+//! This module defines relational operations for tensor expressions, such as equality and ordering
+//! comparisons. Every comparison comes in two flavors: against another expression and against a
+//! scalar value, which the backends can inline as a literal instead of broadcasting a tensor.
+//! Booleans are ordered with false before true.
 //! It leverages the backend processing capabilities to apply these operations on tensor data.
 
 use tengu_backend::Backend;
@@ -11,7 +14,7 @@ where
     T: StorageType,
     B: Backend + 'static,
 {
-    /// Compares two tensor expressions for equality.
+    /// Compares two tensor expressions using an equality comparison.
     ///
     /// # Parameters
     /// - `rhs`: The right-hand side tensor expression to compare.
@@ -21,4 +24,192 @@ where
     pub fn eq(self, rhs: Self) -> Expression<bool, B> {
         Binary::eq(self, rhs)
     }
+
+    /// Compares the tensor expression to a scalar value using an equality comparison.
+    ///
+    /// # Parameters
+    /// - `rhs`: The scalar value to compare against.
+    ///
+    /// # Returns
+    /// An `Expression` of type `bool` indicating the result of the equality comparison.
+    pub fn eq_scalar(self, rhs: T) -> Expression<bool, B> {
+        Binary::eq(self, Expression::Scalar(rhs))
+    }
+
+    /// Compares two tensor expressions using an inequality comparison.
+    ///
+    /// # Parameters
+    /// - `rhs`: The right-hand side tensor expression to compare.
+    ///
+    /// # Returns
+    /// An `Expression` of type `bool` indicating the result of the inequality comparison.
+    pub fn neq(self, rhs: Self) -> Expression<bool, B> {
+        Binary::neq(self, rhs)
+    }
+
+    /// Compares the tensor expression to a scalar value using an inequality comparison.
+    ///
+    /// # Parameters
+    /// - `rhs`: The scalar value to compare against.
+    ///
+    /// # Returns
+    /// An `Expression` of type `bool` indicating the result of the inequality comparison.
+    pub fn neq_scalar(self, rhs: T) -> Expression<bool, B> {
+        Binary::neq(self, Expression::Scalar(rhs))
+    }
+
+    /// Compares two tensor expressions using a greater-than comparison.
+    ///
+    /// # Parameters
+    /// - `rhs`: The right-hand side tensor expression to compare.
+    ///
+    /// # Returns
+    /// An `Expression` of type `bool` indicating the result of the greater-than comparison.
+    pub fn gt(self, rhs: Self) -> Expression<bool, B> {
+        Binary::gt(self, rhs)
+    }
+
+    /// Compares the tensor expression to a scalar value using a greater-than comparison.
+    ///
+    /// # Parameters
+    /// - `rhs`: The scalar value to compare against.
+    ///
+    /// # Returns
+    /// An `Expression` of type `bool` indicating the result of the greater-than comparison.
+    pub fn gt_scalar(self, rhs: T) -> Expression<bool, B> {
+        Binary::gt(self, Expression::Scalar(rhs))
+    }
+
+    /// Compares two tensor expressions using a less-than comparison.
+    ///
+    /// # Parameters
+    /// - `rhs`: The right-hand side tensor expression to compare.
+    ///
+    /// # Returns
+    /// An `Expression` of type `bool` indicating the result of the less-than comparison.
+    pub fn lt(self, rhs: Self) -> Expression<bool, B> {
+        Binary::lt(self, rhs)
+    }
+
+    /// Compares the tensor expression to a scalar value using a less-than comparison.
+    ///
+    /// # Parameters
+    /// - `rhs`: The scalar value to compare against.
+    ///
+    /// # Returns
+    /// An `Expression` of type `bool` indicating the result of the less-than comparison.
+    pub fn lt_scalar(self, rhs: T) -> Expression<bool, B> {
+        Binary::lt(self, Expression::Scalar(rhs))
+    }
+
+    /// Compares two tensor expressions using a greater-than-or-equal comparison.
+    ///
+    /// # Parameters
+    /// - `rhs`: The right-hand side tensor expression to compare.
+    ///
+    /// # Returns
+    /// An `Expression` of type `bool` indicating the result of the greater-than-or-equal comparison.
+    pub fn ge(self, rhs: Self) -> Expression<bool, B> {
+        Binary::ge(self, rhs)
+    }
+
+    /// Compares the tensor expression to a scalar value using a greater-than-or-equal comparison.
+    ///
+    /// # Parameters
+    /// - `rhs`: The scalar value to compare against.
+    ///
+    /// # Returns
+    /// An `Expression` of type `bool` indicating the result of the greater-than-or-equal comparison.
+    pub fn ge_scalar(self, rhs: T) -> Expression<bool, B> {
+        Binary::ge(self, Expression::Scalar(rhs))
+    }
+
+    /// Compares two tensor expressions using a less-than-or-equal comparison.
+    ///
+    /// # Parameters
+    /// - `rhs`: The right-hand side tensor expression to compare.
+    ///
+    /// # Returns
+    /// An `Expression` of type `bool` indicating the result of the less-than-or-equal comparison.
+    pub fn le(self, rhs: Self) -> Expression<bool, B> {
+        Binary::le(self, rhs)
+    }
+
+    /// Compares the tensor expression to a scalar value using a less-than-or-equal comparison.
+    ///
+    /// # Parameters
+    /// - `rhs`: The scalar value to compare against.
+    ///
+    /// # Returns
+    /// An `Expression` of type `bool` indicating the result of the less-than-or-equal comparison.
+    pub fn le_scalar(self, rhs: T) -> Expression<bool, B> {
+        Binary::le(self, Expression::Scalar(rhs))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use tengu_backend::Backend;
+
+    use crate::{Tengu, CPU, WGPU};
+
+    #[tokio::test]
+    async fn threshold_cpu() {
+        threshold::<CPU>().await;
+    }
+
+    #[tokio::test]
+    async fn threshold_wgpu() {
+        threshold::<WGPU>().await;
+    }
+
+    #[tokio::test]
+    async fn bool_ordering_cpu() {
+        bool_ordering::<CPU>().await;
+    }
+
+    #[tokio::test]
+    async fn bool_ordering_wgpu() {
+        bool_ordering::<WGPU>().await;
+    }
+
+    async fn threshold<B: Backend + 'static>() {
+        let tengu = Tengu::<B>::new().await.unwrap();
+        let a = tengu.tensor([5]).label("a").init(&[0.1, 0.5, 0.7, 0.2, 0.9]);
+        let mut graph = tengu.graph();
+        let block = graph.add_block("main").unwrap();
+        block
+            .add_computation("gt", a.clone().gt_scalar(0.5).cast::<u32>())
+            .unwrap();
+        block
+            .add_computation("le", a.clone().le_scalar(0.5).cast::<u32>())
+            .unwrap();
+        block.add_computation("eq", a.eq_scalar(0.5).cast::<u32>()).unwrap();
+        let gt = graph.add_probe::<u32>("main/gt").unwrap();
+        let le = graph.add_probe::<u32>("main/le").unwrap();
+        let eq = graph.add_probe::<u32>("main/eq").unwrap();
+        graph.compute(1).await.unwrap();
+        assert_eq!(gt.retrieve().await.unwrap(), [0, 0, 1, 0, 1]);
+        assert_eq!(le.retrieve().await.unwrap(), [1, 1, 0, 1, 0]);
+        assert_eq!(eq.retrieve().await.unwrap(), [0, 1, 0, 0, 0]);
+    }
+
+    async fn bool_ordering<B: Backend + 'static>() {
+        let tengu = Tengu::<B>::new().await.unwrap();
+        let a = tengu.tensor([4]).label("a").init(&[false, false, true, true]);
+        let b = tengu.tensor([4]).label("b").init(&[false, true, false, true]);
+        let mut graph = tengu.graph();
+        let block = graph.add_block("main").unwrap();
+        block.add_computation("gt", a.clone().gt(b.clone())).unwrap();
+        block.add_computation("le", a.clone().le(b.clone())).unwrap();
+        block.add_computation("neq", a.neq(b)).unwrap();
+        let gt = graph.add_probe::<bool>("main/gt").unwrap();
+        let le = graph.add_probe::<bool>("main/le").unwrap();
+        let neq = graph.add_probe::<bool>("main/neq").unwrap();
+        graph.compute(1).await.unwrap();
+        assert_eq!(gt.retrieve().await.unwrap(), [false, false, true, false]);
+        assert_eq!(le.retrieve().await.unwrap(), [true, true, false, true]);
+        assert_eq!(neq.retrieve().await.unwrap(), [false, true, true, false]);
+    }
 }
@@ -0,0 +1,198 @@
+//! This module defines the `Pad` struct and associated functionality for padding tensor
+//! expressions with a constant value. Each axis of the result is the corresponding axis of the
+//! original expression extended by the given number of elements before and after it.
+
+use std::fmt;
+
+use tengu_backend::{Backend, Processor};
+use tengu_backend_tensor::StorageType;
+
+use super::Expression;
+use crate::collector::Collector;
+use crate::cost::CostEstimate;
+use crate::node::Node;
+use crate::shape::Shape;
+use crate::source::Source;
+
+/// Struct representing a tensor expression padded with a constant value along its axes.
+pub struct Pad<T, B> {
+    padding: Vec<(usize, usize)>,
+    value: T,
+    shape: Vec<usize>,
+    count: usize,
+    expression: Box<dyn Node<B>>,
+}
+
+impl<T: StorageType, B: Backend + 'static> Pad<T, B> {
+    /// Creates a new `Pad` instance.
+    ///
+    /// # Parameters
+    /// - `expr`: The tensor expression to pad.
+    /// - `padding`: The number of elements added before and after each axis.
+    /// - `value`: The value of the added elements.
+    ///
+    /// # Returns
+    /// A new `Pad` instance.
+    ///
+    /// # Panics
+    /// Panics if the length of the padding doesn't match the rank of the expression.
+    pub fn new(expr: Expression<T, B>, padding: &[(usize, usize)], value: T) -> Self {
+        let rank = expr.shape().len();
+        assert_eq!(
+            padding.len(),
+            rank,
+            "expected {rank} paddings for expression of rank {rank}"
+        );
+        let shape: Vec<_> = expr
+            .shape()
+            .iter()
+            .zip(padding)
+            .map(|(dim, (before, after))| before + dim + after)
+            .collect();
+        Self {
+            padding: padding.to_vec(),
+            value,
+            count: shape.iter().product(),
+            shape,
+            expression: Box::new(expr),
+        }
+    }
+}
+
+// NOTE: Shape implementation.
+
+impl<T, B> Shape for Pad<T, B> {
+    /// Returns the number of elements in the padded tensor.
+    ///
+    /// # Returns
+    /// The number of elements in the tensor.
+    fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the shape of the padded tensor.
+    ///
+    /// # Returns
+    /// A slice representing the dimensions of the tensor.
+    fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+}
+
+// NOTE: Node implementation.
+
+impl<T: StorageType, B: Backend + 'static> Node<B> for Pad<T, B> {
+    /// Returns a boxed clone of the `Pad` instance.
+    ///
+    /// # Returns
+    /// A boxed clone of the `Pad` instance.
+    fn clone_box(&self) -> Box<dyn Node<B>> {
+        Box::new(self.clone())
+    }
+
+    /// Collect sources from the pad operation.
+    ///
+    /// # Parameters
+    /// - `collector`: A mutable reference to the collector.
+    fn collect<'a>(&'a self, collector: &mut Collector<'a, B>) {
+        self.expression.collect(collector);
+    }
+
+    /// Finds a source node by its label.
+    ///
+    /// # Parameters
+    /// - `label`: The label of the source node to find.
+    ///
+    /// # Returns
+    /// An optional reference to the found source node.
+    fn find<'a>(&'a self, label: &str) -> Option<&'a dyn Source<B>> {
+        self.expression.find(label)
+    }
+
+    /// Visits the node with the given processor and pads the inner expression.
+    ///
+    /// # Parameters
+    /// - `processor`: The processor used to visit the node.
+    ///
+    /// # Returns
+    /// The inner representation used by the processor.
+    fn visit<'a>(&'a self, processor: &mut B::Processor<'a>) -> <B::Processor<'a> as Processor<'a, B>>::Repr {
+        let expr = self.expression.visit(processor);
+        let value = processor.scalar(self.value);
+        processor.pad(expr, self.expression.shape(), &self.padding, value, T::as_type())
+    }
+
+    /// Estimates the cost of the pad, including the index computation for every element of the
+    /// result and its subexpression.
+    ///
+    /// # Returns
+    /// The estimated cost.
+    fn cost(&self) -> CostEstimate {
+        self.expression.cost() + CostEstimate::elementwise(self.count())
+    }
+
+    /// Returns the subexpression of the padding.
+    ///
+    /// # Returns
+    /// A vector holding the subexpression.
+    fn children(&self) -> Vec<&dyn Node<B>> {
+        vec![self.expression.as_ref()]
+    }
+
+    /// Renders the pad with its subexpression, padding and value for debugging.
+    ///
+    /// # Parameters
+    /// - `f`: The formatter to write the rendered expression tree to.
+    ///
+    /// # Returns
+    /// A result indicating whether the formatting succeeded.
+    fn fmt_tree(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Pad(")?;
+        self.expression.fmt_tree(f)?;
+        write!(f, ", {:?}, {:?})", self.padding, self.value)
+    }
+}
+
+// NOTE: Clone implementation.
+
+impl<T: StorageType, B: Backend> Clone for Pad<T, B> {
+    /// Creates a clone of the `Pad` instance.
+    ///
+    /// # Returns
+    /// A clone of the `Pad` instance.
+    fn clone(&self) -> Self {
+        Self {
+            padding: self.padding.clone(),
+            value: self.value,
+            shape: self.shape.clone(),
+            count: self.count,
+            expression: self.expression.clone_box(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::expression::Shape;
+    use crate::Tengu;
+
+    #[tokio::test]
+    async fn padded_shape() {
+        let tengu = Tengu::cpu().await.unwrap();
+        let a = tengu.tensor([2, 3]).label("a").zero::<f32>();
+        let pad = a.pad(&[(1, 1), (0, 2)], 0.0);
+        assert_eq!(pad.shape(), &[4, 5]);
+        assert_eq!(pad.count(), 20);
+        assert_eq!(format!("{pad:?}"), r#"Pad(Tensor("a"), [(1, 1), (0, 2)], 0.0)"#);
+    }
+
+    #[tokio::test]
+    #[should_panic]
+    async fn padding_rank_mismatch() {
+        let tengu = Tengu::cpu().await.unwrap();
+        let a = tengu.tensor([2, 3]).zero::<f32>();
+        let _ = a.pad(&[(1, 1)], 0.0);
+    }
+}
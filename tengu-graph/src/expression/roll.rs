@@ -0,0 +1,191 @@
+//! This module defines the `Roll` struct and associated functionality for shifting tensor
+//! expressions circularly along an axis. The result has the shape of the original expression, and
+//! the elements shifted past the end of the axis wrap around to its start.
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use tengu_backend::{Backend, Processor};
+use tengu_backend_tensor::StorageType;
+
+use super::Expression;
+use crate::collector::Collector;
+use crate::cost::CostEstimate;
+use crate::node::Node;
+use crate::shape::Shape;
+use crate::source::Source;
+
+/// Struct representing a tensor expression shifted circularly along an axis.
+pub struct Roll<T, B> {
+    axis: usize,
+    shift: usize,
+    expression: Box<dyn Node<B>>,
+    phantom: PhantomData<T>,
+}
+
+impl<T: StorageType, B: Backend + 'static> Roll<T, B> {
+    /// Creates a new `Roll` instance. The shift is reduced modulo the length of the axis, so that
+    /// negative shifts move the elements towards the start of the axis.
+    ///
+    /// # Parameters
+    /// - `expr`: The tensor expression to roll.
+    /// - `shift`: The number of positions the elements are shifted by.
+    /// - `axis`: The axis along which the elements are shifted.
+    ///
+    /// # Returns
+    /// A new `Roll` instance.
+    ///
+    /// # Panics
+    /// Panics if the axis is out of bounds of the expression shape.
+    pub fn new(expr: Expression<T, B>, shift: isize, axis: usize) -> Self {
+        let rank = expr.shape().len();
+        assert!(
+            axis < rank,
+            "axis {axis} is out of bounds for expression of rank {rank}"
+        );
+        let dim = expr.shape()[axis] as isize;
+        Self {
+            axis,
+            shift: shift.rem_euclid(dim) as usize,
+            expression: Box::new(expr),
+            phantom: PhantomData,
+        }
+    }
+}
+
+// NOTE: Shape implementation.
+
+impl<T, B> Shape for Roll<T, B> {
+    /// Returns the number of elements in the rolled tensor.
+    ///
+    /// # Returns
+    /// The number of elements in the tensor.
+    fn count(&self) -> usize {
+        self.expression.count()
+    }
+
+    /// Returns the shape of the rolled tensor, which is the same as the shape of the inner
+    /// expression.
+    ///
+    /// # Returns
+    /// A slice representing the dimensions of the tensor.
+    fn shape(&self) -> &[usize] {
+        self.expression.shape()
+    }
+}
+
+// NOTE: Node implementation.
+
+impl<T: StorageType, B: Backend + 'static> Node<B> for Roll<T, B> {
+    /// Returns a boxed clone of the `Roll` instance.
+    ///
+    /// # Returns
+    /// A boxed clone of the `Roll` instance.
+    fn clone_box(&self) -> Box<dyn Node<B>> {
+        Box::new(self.clone())
+    }
+
+    /// Collect sources from the roll operation.
+    ///
+    /// # Parameters
+    /// - `collector`: A mutable reference to the collector.
+    fn collect<'a>(&'a self, collector: &mut Collector<'a, B>) {
+        self.expression.collect(collector);
+    }
+
+    /// Finds a source node by its label.
+    ///
+    /// # Parameters
+    /// - `label`: The label of the source node to find.
+    ///
+    /// # Returns
+    /// An optional reference to the found source node.
+    fn find<'a>(&'a self, label: &str) -> Option<&'a dyn Source<B>> {
+        self.expression.find(label)
+    }
+
+    /// Visits the node with the given processor and rolls the inner expression.
+    ///
+    /// # Parameters
+    /// - `processor`: The processor used to visit the node.
+    ///
+    /// # Returns
+    /// The inner representation used by the processor.
+    fn visit<'a>(&'a self, processor: &mut B::Processor<'a>) -> <B::Processor<'a> as Processor<'a, B>>::Repr {
+        let expr = self.expression.visit(processor);
+        processor.roll(expr, self.expression.shape(), self.axis, self.shift, T::as_type())
+    }
+
+    /// Estimates the cost of the roll, including the index computation for every element of the
+    /// result and its subexpression.
+    ///
+    /// # Returns
+    /// The estimated cost.
+    fn cost(&self) -> CostEstimate {
+        self.expression.cost() + CostEstimate::elementwise(self.count())
+    }
+
+    /// Returns the subexpression of the roll.
+    ///
+    /// # Returns
+    /// A vector holding the subexpression.
+    fn children(&self) -> Vec<&dyn Node<B>> {
+        vec![self.expression.as_ref()]
+    }
+
+    /// Renders the roll with its subexpression, shift and axis for debugging.
+    ///
+    /// # Parameters
+    /// - `f`: The formatter to write the rendered expression tree to.
+    ///
+    /// # Returns
+    /// A result indicating whether the formatting succeeded.
+    fn fmt_tree(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Roll(")?;
+        self.expression.fmt_tree(f)?;
+        write!(f, ", {}, {})", self.shift, self.axis)
+    }
+}
+
+// NOTE: Clone implementation.
+
+impl<T, B: Backend> Clone for Roll<T, B> {
+    /// Creates a clone of the `Roll` instance.
+    ///
+    /// # Returns
+    /// A clone of the `Roll` instance.
+    fn clone(&self) -> Self {
+        Self {
+            axis: self.axis,
+            shift: self.shift,
+            expression: self.expression.clone_box(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::expression::Shape;
+    use crate::Tengu;
+
+    #[tokio::test]
+    async fn rolled_shape() {
+        let tengu = Tengu::cpu().await.unwrap();
+        let a = tengu.tensor([2, 3]).label("a").zero::<f32>();
+        let roll = a.roll(-1, 1);
+        assert_eq!(roll.shape(), &[2, 3]);
+        assert_eq!(roll.count(), 6);
+        assert_eq!(format!("{roll:?}"), r#"Roll(Tensor("a"), 2, 1)"#);
+    }
+
+    #[tokio::test]
+    #[should_panic]
+    async fn axis_out_of_bounds() {
+        let tengu = Tengu::cpu().await.unwrap();
+        let a = tengu.tensor([2, 3]).zero::<f32>();
+        let _ = a.roll(1, 2);
+    }
+}
@@ -0,0 +1,211 @@
+//! This module defines the `Select` struct and associated functionality for choosing between two
+//! tensor expressions element-wise according to a boolean mask. The operands are broadcast in the
+//! same way as the operands of binary operations, so either of the choices can be a scalar which
+//! the backends inline as a literal.
+
+use std::fmt;
+
+use tengu_backend::{Backend, Processor};
+use tengu_backend_tensor::StorageType;
+
+use super::Expression;
+use crate::collector::Collector;
+use crate::cost::CostEstimate;
+use crate::node::Node;
+use crate::shape::Shape;
+use crate::source::Source;
+use crate::unify::Unify;
+
+/// Struct representing an element-wise selection between two tensor expressions.
+pub struct Select<B> {
+    shape: Vec<usize>,
+    count: usize,
+    mask: Box<dyn Node<B>>,
+    on_true: Box<dyn Node<B>>,
+    on_false: Box<dyn Node<B>>,
+}
+
+impl<B: Backend + 'static> Select<B> {
+    /// Creates a new `Select` instance.
+    ///
+    /// # Parameters
+    /// - `mask`: The boolean mask expression.
+    /// - `on_true`: The tensor expression selected where the mask is true.
+    /// - `on_false`: The tensor expression selected where the mask is false.
+    ///
+    /// # Returns
+    /// A new `Select` instance.
+    ///
+    /// # Panics
+    /// Panics if the shapes of the mask and the two choices cannot be unified.
+    pub fn new<T: StorageType>(
+        mask: Expression<bool, B>,
+        on_true: Expression<T, B>,
+        on_false: Expression<T, B>,
+    ) -> Self {
+        let shape = on_true
+            .shape()
+            .unify(on_false.shape())
+            .and_then(|shape| mask.shape().unify(shape.as_slice()))
+            .expect("Shapes don't match");
+        Self {
+            count: shape.iter().product(),
+            shape,
+            mask: Box::new(mask),
+            on_true: Box::new(on_true),
+            on_false: Box::new(on_false),
+        }
+    }
+}
+
+// NOTE: Shape implementation.
+
+impl<B> Shape for Select<B> {
+    /// Returns the number of elements in the selection.
+    ///
+    /// # Returns
+    /// The number of elements in the tensor.
+    fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the shape of the selection, which is the result of unification on the shapes of the
+    /// mask and the two choices.
+    ///
+    /// # Returns
+    /// A slice representing the dimensions of the tensor.
+    fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+}
+
+// NOTE: Node implementation.
+
+impl<B: Backend + 'static> Node<B> for Select<B> {
+    /// Returns a boxed clone of the `Select` instance.
+    ///
+    /// # Returns
+    /// A boxed clone of the `Select` instance.
+    fn clone_box(&self) -> Box<dyn Node<B>> {
+        Box::new(self.clone())
+    }
+
+    /// Collect sources from the mask and the two choices.
+    ///
+    /// # Parameters
+    /// - `collector`: A mutable reference to the collector.
+    fn collect<'a>(&'a self, collector: &mut Collector<'a, B>) {
+        self.mask.collect(collector);
+        self.on_true.collect(collector);
+        self.on_false.collect(collector);
+    }
+
+    /// Finds a source node by its label in the mask or either of the choices.
+    ///
+    /// # Parameters
+    /// - `label`: The label of the source node to find.
+    ///
+    /// # Returns
+    /// An optional reference to the found source node.
+    fn find<'a>(&'a self, label: &str) -> Option<&'a dyn Source<B>> {
+        self.mask
+            .find(label)
+            .or_else(|| self.on_true.find(label))
+            .or_else(|| self.on_false.find(label))
+    }
+
+    /// Visits the node with the given processor and applies the selection.
+    ///
+    /// # Parameters
+    /// - `processor`: The processor used to visit the node.
+    ///
+    /// # Returns
+    /// The inner representation used by the processor.
+    fn visit<'a>(&'a self, processor: &mut B::Processor<'a>) -> <B::Processor<'a> as Processor<'a, B>>::Repr {
+        let mask = self.mask.visit(processor);
+        let on_true = self.on_true.visit(processor);
+        let on_false = self.on_false.visit(processor);
+        processor.select(mask, on_true, on_false)
+    }
+
+    /// Estimates the cost of the selection, including the elementwise choice and its operands.
+    ///
+    /// # Returns
+    /// The estimated cost.
+    fn cost(&self) -> CostEstimate {
+        self.mask.cost() + self.on_true.cost() + self.on_false.cost() + CostEstimate::elementwise(self.count())
+    }
+
+    /// Returns the mask and the two choices of the selection.
+    ///
+    /// # Returns
+    /// A vector holding the mask, the choice where it is true and the choice where it is false.
+    fn children(&self) -> Vec<&dyn Node<B>> {
+        vec![self.mask.as_ref(), self.on_true.as_ref(), self.on_false.as_ref()]
+    }
+
+    /// Renders the selection with the mask and the two choices for debugging.
+    ///
+    /// # Parameters
+    /// - `f`: The formatter to write the rendered expression tree to.
+    ///
+    /// # Returns
+    /// A result indicating whether the formatting succeeded.
+    fn fmt_tree(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Select(")?;
+        self.mask.fmt_tree(f)?;
+        write!(f, ", ")?;
+        self.on_true.fmt_tree(f)?;
+        write!(f, ", ")?;
+        self.on_false.fmt_tree(f)?;
+        write!(f, ")")
+    }
+}
+
+// NOTE: Clone implementation.
+
+impl<B: Backend> Clone for Select<B> {
+    /// Creates a clone of the `Select` instance.
+    ///
+    /// # Returns
+    /// A clone of the `Select` instance.
+    fn clone(&self) -> Self {
+        Self {
+            shape: self.shape.clone(),
+            count: self.count,
+            mask: self.mask.clone_box(),
+            on_true: self.on_true.clone_box(),
+            on_false: self.on_false.clone_box(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::expression::Shape;
+    use crate::Tengu;
+
+    #[tokio::test]
+    async fn selected_shape() {
+        let tengu = Tengu::cpu().await.unwrap();
+        let a = tengu.tensor([2, 3]).label("a").zero::<f32>();
+        let select = a.clone().lt_scalar(0.0).where_scalar(a, 0.0);
+        assert_eq!(select.shape(), &[2, 3]);
+        assert_eq!(select.count(), 6);
+        assert_eq!(
+            format!("{select:?}"),
+            r#"Select(Lt(Tensor("a"), Scalar(0.0)), Scalar(0.0), Tensor("a"))"#
+        );
+    }
+
+    #[tokio::test]
+    #[should_panic]
+    async fn shape_mismatch() {
+        let tengu = Tengu::cpu().await.unwrap();
+        let a = tengu.tensor([2, 3]).zero::<f32>();
+        let b = tengu.tensor([3, 2]).zero::<f32>();
+        let _ = a.clone().gt_scalar(0.0).select(a, b);
+    }
+}
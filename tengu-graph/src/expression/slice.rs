@@ -0,0 +1,209 @@
+//! This module defines the `Slice` struct and associated functionality for taking contiguous ranges
+//! of tensor expressions along an axis. Slices are produced by splitting an expression into chunks,
+//! so each of them keeps the rank of the inner expression and only shortens the sliced axis.
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::Range;
+
+use tengu_backend::{Backend, Processor};
+use tengu_backend_tensor::StorageType;
+
+use super::Expression;
+use crate::collector::Collector;
+use crate::cost::CostEstimate;
+use crate::node::Node;
+use crate::shape::Shape;
+use crate::source::Source;
+
+/// Struct representing a contiguous range of a tensor expression along an axis.
+pub struct Slice<T, B> {
+    axis: usize,
+    range: Range<usize>,
+    inner_shape: Vec<usize>,
+    shape: Vec<usize>,
+    count: usize,
+    expression: Box<dyn Node<B>>,
+    phantom: PhantomData<T>,
+}
+
+impl<T: StorageType, B: Backend + 'static> Slice<T, B> {
+    /// Creates a new `Slice` instance.
+    ///
+    /// # Parameters
+    /// - `expr`: The tensor expression to slice.
+    /// - `axis`: The axis along which the range is taken.
+    /// - `range`: The range of indices along the axis.
+    ///
+    /// # Returns
+    /// A new `Slice` instance.
+    ///
+    /// # Panics
+    /// Panics if the axis is out of bounds of the expression shape, or if the range is empty or out
+    /// of bounds of the axis.
+    pub fn new(expr: Expression<T, B>, axis: usize, range: Range<usize>) -> Self {
+        let rank = expr.shape().len();
+        assert!(
+            axis < rank,
+            "axis {axis} is out of bounds for expression of rank {rank}"
+        );
+        let len = expr.shape()[axis];
+        assert!(
+            !range.is_empty() && range.end <= len,
+            "range {range:?} is empty or out of bounds for axis of length {len}"
+        );
+        let inner_shape = expr.shape().to_vec();
+        let mut shape = inner_shape.clone();
+        shape[axis] = range.len();
+        Self {
+            axis,
+            range,
+            inner_shape,
+            count: shape.iter().product(),
+            shape,
+            expression: Box::new(expr),
+            phantom: PhantomData,
+        }
+    }
+}
+
+// NOTE: Shape implementation.
+
+impl<T, B> Shape for Slice<T, B> {
+    /// Returns the number of elements in the slice.
+    ///
+    /// # Returns
+    /// The number of elements in the tensor.
+    fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the shape of the slice, which is the shape of the inner expression with the sliced
+    /// axis shortened to the length of the range.
+    ///
+    /// # Returns
+    /// A slice representing the dimensions of the tensor.
+    fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+}
+
+// NOTE: Node implementation.
+
+impl<T: StorageType, B: Backend + 'static> Node<B> for Slice<T, B> {
+    /// Returns a boxed clone of the `Slice` instance.
+    ///
+    /// # Returns
+    /// A boxed clone of the `Slice` instance.
+    fn clone_box(&self) -> Box<dyn Node<B>> {
+        Box::new(self.clone())
+    }
+
+    /// Collect sources from the slice operation.
+    ///
+    /// # Parameters
+    /// - `collector`: A mutable reference to the collector.
+    fn collect<'a>(&'a self, collector: &mut Collector<'a, B>) {
+        self.expression.collect(collector);
+    }
+
+    /// Finds a source node by its label.
+    ///
+    /// # Parameters
+    /// - `label`: The label of the source node to find.
+    ///
+    /// # Returns
+    /// An optional reference to the found source node.
+    fn find<'a>(&'a self, label: &str) -> Option<&'a dyn Source<B>> {
+        self.expression.find(label)
+    }
+
+    /// Visits the node with the given processor and slices the inner expression.
+    ///
+    /// # Parameters
+    /// - `processor`: The processor used to visit the node.
+    ///
+    /// # Returns
+    /// The inner representation used by the processor.
+    fn visit<'a>(&'a self, processor: &mut B::Processor<'a>) -> <B::Processor<'a> as Processor<'a, B>>::Repr {
+        let expr = self.expression.visit(processor);
+        processor.slice(expr, &self.inner_shape, self.axis, self.range.clone(), T::as_type())
+    }
+
+    /// Estimates the cost of the slice, including the index computation for every sliced element
+    /// and its subexpression.
+    ///
+    /// # Returns
+    /// The estimated cost.
+    fn cost(&self) -> CostEstimate {
+        self.expression.cost() + CostEstimate::elementwise(self.count())
+    }
+
+    /// Returns the subexpression of the slice.
+    ///
+    /// # Returns
+    /// A vector holding the subexpression.
+    fn children(&self) -> Vec<&dyn Node<B>> {
+        vec![self.expression.as_ref()]
+    }
+
+    /// Renders the slice with its subexpression, axis and range for debugging.
+    ///
+    /// # Parameters
+    /// - `f`: The formatter to write the rendered expression tree to.
+    ///
+    /// # Returns
+    /// A result indicating whether the formatting succeeded.
+    fn fmt_tree(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Slice(")?;
+        self.expression.fmt_tree(f)?;
+        write!(f, ", {}, {:?})", self.axis, self.range)
+    }
+}
+
+// NOTE: Clone implementation.
+
+impl<T, B: Backend> Clone for Slice<T, B> {
+    /// Creates a clone of the `Slice` instance.
+    ///
+    /// # Returns
+    /// A clone of the `Slice` instance.
+    fn clone(&self) -> Self {
+        Self {
+            axis: self.axis,
+            range: self.range.clone(),
+            inner_shape: self.inner_shape.clone(),
+            shape: self.shape.clone(),
+            count: self.count,
+            expression: self.expression.clone_box(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::expression::Shape;
+    use crate::Tengu;
+
+    #[tokio::test]
+    async fn split_shapes() {
+        let tengu = Tengu::cpu().await.unwrap();
+        let a = tengu.tensor([2, 6]).label("a").zero::<f32>();
+        let chunks = a.split(1, &[2, 4]);
+        assert_eq!(chunks[0].shape(), &[2, 2]);
+        assert_eq!(chunks[1].shape(), &[2, 4]);
+        assert_eq!(chunks[1].count(), 8);
+        assert_eq!(format!("{:?}", chunks[1]), r#"Slice(Tensor("a"), 1, 2..6)"#);
+    }
+
+    #[tokio::test]
+    #[should_panic]
+    async fn sizes_mismatch() {
+        let tengu = Tengu::cpu().await.unwrap();
+        let a = tengu.tensor([6]).zero::<f32>();
+        let _ = a.split(0, &[2, 3]);
+    }
+}
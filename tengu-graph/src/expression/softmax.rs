@@ -0,0 +1,152 @@
+//! This module defines the `Softmax` struct and associated functionality for normalizing tensor
+//! expressions with the softmax function along an axis. The maximum along the axis is subtracted
+//! before exponentiation by the backends, so that the computation is numerically stable.
+
+use std::fmt;
+
+use tengu_backend::{Backend, Processor};
+use tengu_backend_tensor::StorageType;
+
+use super::Expression;
+use crate::collector::Collector;
+use crate::cost::CostEstimate;
+use crate::node::Node;
+use crate::shape::Shape;
+use crate::source::Source;
+
+/// Struct representing the softmax of a tensor expression along an axis.
+pub struct Softmax<B> {
+    axis: usize,
+    expression: Box<dyn Node<B>>,
+}
+
+impl<B: Backend + 'static> Softmax<B> {
+    /// Creates a new `Softmax` instance.
+    ///
+    /// # Parameters
+    /// - `expr`: The tensor expression to normalize.
+    /// - `axis`: The axis along which the softmax is normalized.
+    ///
+    /// # Returns
+    /// A new `Softmax` instance.
+    ///
+    /// # Panics
+    /// Panics if the axis is out of bounds of the expression shape.
+    pub fn new<T: StorageType>(expr: Expression<T, B>, axis: usize) -> Self {
+        let rank = expr.shape().len();
+        assert!(axis < rank, "axis {axis} is out of bounds for expression of rank {rank}");
+        Self {
+            axis,
+            expression: Box::new(expr),
+        }
+    }
+}
+
+// NOTE: Shape implementation.
+
+impl<B> Shape for Softmax<B> {
+    /// Returns the number of elements in the tensor.
+    ///
+    /// # Returns
+    /// The number of elements in the tensor.
+    fn count(&self) -> usize {
+        self.expression.count()
+    }
+
+    /// Returns the shape of the tensor, which is the same as the shape of the inner expression.
+    ///
+    /// # Returns
+    /// A slice representing the dimensions of the tensor.
+    fn shape(&self) -> &[usize] {
+        self.expression.shape()
+    }
+}
+
+// NOTE: Node implementation.
+
+impl<B: Backend + 'static> Node<B> for Softmax<B> {
+    /// Returns a boxed clone of the `Softmax` instance.
+    ///
+    /// # Returns
+    /// A boxed clone of the `Softmax` instance.
+    fn clone_box(&self) -> Box<dyn Node<B>> {
+        Box::new(self.clone())
+    }
+
+    /// Collect sources from the softmax operation.
+    ///
+    /// # Parameters
+    /// - `collector`: A mutable reference to the collector.
+    fn collect<'a>(&'a self, collector: &mut Collector<'a, B>) {
+        self.expression.collect(collector);
+    }
+
+    /// Finds a source node by its label.
+    ///
+    /// # Parameters
+    /// - `label`: The label of the source node to find.
+    ///
+    /// # Returns
+    /// An optional reference to the found source node.
+    fn find<'a>(&'a self, label: &str) -> Option<&'a dyn Source<B>> {
+        self.expression.find(label)
+    }
+
+    /// Visits the node with the given processor and normalizes the inner expression.
+    ///
+    /// # Parameters
+    /// - `processor`: The processor used to visit the node.
+    ///
+    /// # Returns
+    /// The inner representation used by the processor.
+    fn visit<'a>(&'a self, processor: &mut B::Processor<'a>) -> <B::Processor<'a> as Processor<'a, B>>::Repr {
+        let expr = self.expression.visit(processor);
+        processor.softmax(expr, self.expression.shape(), self.axis)
+    }
+
+    /// Estimates the cost of the softmax, including the two reductions along the axis, the
+    /// exponentiation and the normalization of every element, and its subexpression.
+    ///
+    /// # Returns
+    /// The estimated cost.
+    fn cost(&self) -> CostEstimate {
+        let count = self.count();
+        self.expression.cost() + CostEstimate::reduction(2 * count) + CostEstimate::elementwise(2 * count)
+    }
+
+    /// Returns the subexpression of the softmax.
+    ///
+    /// # Returns
+    /// A vector holding the subexpression.
+    fn children(&self) -> Vec<&dyn Node<B>> {
+        vec![self.expression.as_ref()]
+    }
+
+    /// Renders the softmax with its subexpression and axis for debugging.
+    ///
+    /// # Parameters
+    /// - `f`: The formatter to write the rendered expression tree to.
+    ///
+    /// # Returns
+    /// A result indicating whether the formatting succeeded.
+    fn fmt_tree(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Softmax(")?;
+        self.expression.fmt_tree(f)?;
+        write!(f, ", {})", self.axis)
+    }
+}
+
+// NOTE: Clone implementation.
+
+impl<B: Backend> Clone for Softmax<B> {
+    /// Creates a clone of the `Softmax` instance.
+    ///
+    /// # Returns
+    /// A clone of the `Softmax` instance.
+    fn clone(&self) -> Self {
+        Self {
+            axis: self.axis,
+            expression: self.expression.clone_box(),
+        }
+    }
+}
@@ -0,0 +1,234 @@
+//! This module defines the `Stack` struct and associated functionality for joining tensor
+//! expressions of the same shape along a new axis. Unlike joining along an existing axis, the
+//! result has one more axis than the stacked expressions, whose length is the number of them.
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use tengu_backend::{Backend, Processor};
+use tengu_backend_tensor::StorageType;
+
+use super::Expression;
+use crate::collector::Collector;
+use crate::cost::CostEstimate;
+use crate::node::Node;
+use crate::shape::Shape;
+use crate::source::Source;
+
+/// Struct representing tensor expressions joined along a new axis.
+pub struct Stack<T, B> {
+    shape: Vec<usize>,
+    count: usize,
+    axis: usize,
+    expressions: Vec<Box<dyn Node<B>>>,
+    phantom: PhantomData<T>,
+}
+
+impl<T: StorageType, B: Backend + 'static> Stack<T, B> {
+    /// Creates a new `Stack` instance.
+    ///
+    /// # Parameters
+    /// - `exprs`: The tensor expressions to stack, in the order they appear along the new axis.
+    /// - `axis`: The position of the new axis in the shape of the result.
+    ///
+    /// # Returns
+    /// A new `Stack` instance.
+    ///
+    /// # Panics
+    /// Panics if there are no expressions, if their shapes differ, or if the axis is greater than
+    /// their rank.
+    pub fn new(exprs: &[Expression<T, B>], axis: usize) -> Self {
+        let first = exprs.first().expect("at least one expression should be stacked");
+        let rank = first.shape().len();
+        assert!(
+            axis <= rank,
+            "axis {axis} is out of bounds for stacking expressions of rank {rank}"
+        );
+        for expr in &exprs[1..] {
+            assert_eq!(
+                expr.shape(),
+                first.shape(),
+                "stacked expressions should have the same shape"
+            );
+        }
+        let mut shape = first.shape().to_vec();
+        shape.insert(axis, exprs.len());
+        Self {
+            count: shape.iter().product(),
+            shape,
+            axis,
+            expressions: exprs.iter().map(|expr| expr.clone_box()).collect(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+// NOTE: Shape implementation.
+
+impl<T, B> Shape for Stack<T, B> {
+    /// Returns the number of elements in the stacked tensor.
+    ///
+    /// # Returns
+    /// The number of elements in the tensor.
+    fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the shape of the stacked tensor, which is the shape of the stacked expressions with
+    /// the new axis inserted.
+    ///
+    /// # Returns
+    /// A slice representing the dimensions of the tensor.
+    fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+}
+
+// NOTE: Node implementation.
+
+impl<T: StorageType, B: Backend + 'static> Node<B> for Stack<T, B> {
+    /// Returns a boxed clone of the `Stack` instance.
+    ///
+    /// # Returns
+    /// A boxed clone of the `Stack` instance.
+    fn clone_box(&self) -> Box<dyn Node<B>> {
+        Box::new(self.clone())
+    }
+
+    /// Collect sources from the stacked expressions.
+    ///
+    /// # Parameters
+    /// - `collector`: A mutable reference to the collector.
+    fn collect<'a>(&'a self, collector: &mut Collector<'a, B>) {
+        for expression in &self.expressions {
+            expression.collect(collector);
+        }
+    }
+
+    /// Finds a source node by its label.
+    ///
+    /// # Parameters
+    /// - `label`: The label of the source node to find.
+    ///
+    /// # Returns
+    /// An optional reference to the found source node.
+    fn find<'a>(&'a self, label: &str) -> Option<&'a dyn Source<B>> {
+        self.expressions.iter().find_map(|expression| expression.find(label))
+    }
+
+    /// Visits the node with the given processor and stacks the inner expressions.
+    ///
+    /// # Parameters
+    /// - `processor`: The processor used to visit the node.
+    ///
+    /// # Returns
+    /// The inner representation used by the processor.
+    fn visit<'a>(&'a self, processor: &mut B::Processor<'a>) -> <B::Processor<'a> as Processor<'a, B>>::Repr {
+        let inners = self
+            .expressions
+            .iter()
+            .map(|expression| expression.visit(processor))
+            .collect();
+        processor.stack(inners, self.expressions[0].shape(), self.axis, T::as_type())
+    }
+
+    /// Estimates the cost of the stack, including the index computation for every element of the
+    /// result and its subexpressions.
+    ///
+    /// # Returns
+    /// The estimated cost.
+    fn cost(&self) -> CostEstimate {
+        self.expressions
+            .iter()
+            .fold(CostEstimate::elementwise(self.count), |cost, expression| {
+                cost + expression.cost()
+            })
+    }
+
+    /// Returns the stacked subexpressions.
+    ///
+    /// # Returns
+    /// A vector holding the subexpressions in the order they appear along the new axis.
+    fn children(&self) -> Vec<&dyn Node<B>> {
+        self.expressions.iter().map(|expression| expression.as_ref()).collect()
+    }
+
+    /// Renders the stack with its subexpressions and axis for debugging.
+    ///
+    /// # Parameters
+    /// - `f`: The formatter to write the rendered expression tree to.
+    ///
+    /// # Returns
+    /// A result indicating whether the formatting succeeded.
+    fn fmt_tree(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Stack([")?;
+        for (i, expression) in self.expressions.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            expression.fmt_tree(f)?;
+        }
+        write!(f, "], {})", self.axis)
+    }
+}
+
+// NOTE: Clone implementation.
+
+impl<T, B: Backend> Clone for Stack<T, B> {
+    /// Creates a clone of the `Stack` instance.
+    ///
+    /// # Returns
+    /// A clone of the `Stack` instance.
+    fn clone(&self) -> Self {
+        Self {
+            shape: self.shape.clone(),
+            count: self.count,
+            axis: self.axis,
+            expressions: self
+                .expressions
+                .iter()
+                .map(|expression| expression.clone_box())
+                .collect(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::expression::{Expression, Shape};
+    use crate::Tengu;
+
+    #[tokio::test]
+    async fn stacked_shape() {
+        let tengu = Tengu::cpu().await.unwrap();
+        let a = tengu.tensor([2]).label("a").zero::<f32>();
+        let b = tengu.tensor([2]).label("b").zero::<f32>();
+        let stack = Expression::stack(&[a.clone(), b, a], 1);
+        assert_eq!(stack.shape(), &[2, 3]);
+        assert_eq!(stack.count(), 6);
+        assert_eq!(
+            format!("{stack:?}"),
+            r#"Stack([Tensor("a"), Tensor("b"), Tensor("a")], 1)"#
+        );
+    }
+
+    #[tokio::test]
+    #[should_panic]
+    async fn shape_mismatch() {
+        let tengu = Tengu::cpu().await.unwrap();
+        let a = tengu.tensor([2]).zero::<f32>();
+        let b = tengu.tensor([3]).zero::<f32>();
+        let _ = Expression::stack(&[a, b], 0);
+    }
+
+    #[tokio::test]
+    #[should_panic]
+    async fn axis_out_of_bounds() {
+        let tengu = Tengu::cpu().await.unwrap();
+        let a = tengu.tensor([2]).zero::<f32>();
+        let _ = Expression::stack(&[a.clone(), a], 2);
+    }
+}
@@ -2,11 +2,14 @@
 //! tensor statements within expressions. It ensures that tensor shapes match and provides
 //! methods for processing and visiting tensor expressions.
 
+use std::fmt;
+
 use tengu_backend::{Backend, Processor};
 use tengu_backend_tensor::StorageType;
 
 use super::Expression;
 use crate::collector::Collector;
+use crate::cost::CostEstimate;
 use crate::node::Node;
 use crate::shape::Shape;
 use crate::source::Source;
@@ -96,11 +99,42 @@ impl<B: Backend + 'static> Node<B> for Statement<B> {
     ///
     /// # Returns
     /// The inner representation used by the processor.
-    fn visit<'a>(&'a self, processor: &mut B::Processor<'a>) -> <B::Processor<'a> as Processor<B>>::Repr {
+    fn visit<'a>(&'a self, processor: &mut B::Processor<'a>) -> <B::Processor<'a> as Processor<'a, B>>::Repr {
         let output = self.output.visit(processor);
         let expression = self.expression.visit(processor);
         processor.statement(output, expression)
     }
+
+    /// Estimates the cost of the statement, including the write to its output and its expression.
+    ///
+    /// # Returns
+    /// The estimated cost.
+    fn cost(&self) -> CostEstimate {
+        self.output.cost() + self.expression.cost()
+    }
+
+    /// Returns the output and the expression of the statement.
+    ///
+    /// # Returns
+    /// A vector holding the output and the expression.
+    fn children(&self) -> Vec<&dyn Node<B>> {
+        vec![self.output.as_ref(), self.expression.as_ref()]
+    }
+
+    /// Renders the statement with its output and expression for debugging.
+    ///
+    /// # Parameters
+    /// - `f`: The formatter to write the rendered expression tree to.
+    ///
+    /// # Returns
+    /// A result indicating whether the formatting succeeded.
+    fn fmt_tree(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Statement(")?;
+        self.output.fmt_tree(f)?;
+        write!(f, ", ")?;
+        self.expression.fmt_tree(f)?;
+        write!(f, ")")
+    }
 }
 
 // NOTE: Clone implementation.
@@ -0,0 +1,187 @@
+//! This module defines the `Take` struct and associated functionality for taking the elements of
+//! tensor expressions at a fixed list of flat indices. The indices are provided by the host when the
+//! expression is built, so the result is always 1-D with one element per index.
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use tengu_backend::{Backend, Processor};
+use tengu_backend_tensor::StorageType;
+
+use super::Expression;
+use crate::collector::Collector;
+use crate::cost::CostEstimate;
+use crate::node::Node;
+use crate::shape::Shape;
+use crate::source::Source;
+
+/// Struct representing the elements of a tensor expression at the given flat indices.
+pub struct Take<T, B> {
+    indices: Vec<usize>,
+    shape: Vec<usize>,
+    expression: Box<dyn Node<B>>,
+    phantom: PhantomData<T>,
+}
+
+impl<T: StorageType, B: Backend + 'static> Take<T, B> {
+    /// Creates a new `Take` instance.
+    ///
+    /// # Parameters
+    /// - `expr`: The tensor expression whose elements are taken.
+    /// - `indices`: The flat row-major indices of the taken elements.
+    ///
+    /// # Returns
+    /// A new `Take` instance.
+    ///
+    /// # Panics
+    /// Panics if no indices are provided or if any index is out of bounds of the expression.
+    pub fn new(expr: Expression<T, B>, indices: &[usize]) -> Self {
+        assert!(!indices.is_empty(), "at least one index should be taken");
+        let count = expr.count();
+        if let Some(index) = indices.iter().find(|&&index| index >= count) {
+            panic!("index {index} is out of bounds for expression of {count} elements");
+        }
+        Self {
+            indices: indices.to_vec(),
+            shape: vec![indices.len()],
+            expression: Box::new(expr),
+            phantom: PhantomData,
+        }
+    }
+}
+
+// NOTE: Shape implementation.
+
+impl<T, B> Shape for Take<T, B> {
+    /// Returns the number of taken elements.
+    ///
+    /// # Returns
+    /// The number of elements in the tensor.
+    fn count(&self) -> usize {
+        self.shape[0]
+    }
+
+    /// Returns the shape of the taken elements, which is 1-D.
+    ///
+    /// # Returns
+    /// A slice representing the dimensions of the tensor.
+    fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+}
+
+// NOTE: Node implementation.
+
+impl<T: StorageType, B: Backend + 'static> Node<B> for Take<T, B> {
+    /// Returns a boxed clone of the `Take` instance.
+    ///
+    /// # Returns
+    /// A boxed clone of the `Take` instance.
+    fn clone_box(&self) -> Box<dyn Node<B>> {
+        Box::new(self.clone())
+    }
+
+    /// Collect sources from the take operation.
+    ///
+    /// # Parameters
+    /// - `collector`: A mutable reference to the collector.
+    fn collect<'a>(&'a self, collector: &mut Collector<'a, B>) {
+        self.expression.collect(collector);
+    }
+
+    /// Finds a source node by its label.
+    ///
+    /// # Parameters
+    /// - `label`: The label of the source node to find.
+    ///
+    /// # Returns
+    /// An optional reference to the found source node.
+    fn find<'a>(&'a self, label: &str) -> Option<&'a dyn Source<B>> {
+        self.expression.find(label)
+    }
+
+    /// Visits the node with the given processor and takes the elements of the inner expression.
+    ///
+    /// # Parameters
+    /// - `processor`: The processor used to visit the node.
+    ///
+    /// # Returns
+    /// The inner representation used by the processor.
+    fn visit<'a>(&'a self, processor: &mut B::Processor<'a>) -> <B::Processor<'a> as Processor<'a, B>>::Repr {
+        let expr = self.expression.visit(processor);
+        processor.take(expr, &self.indices, T::as_type())
+    }
+
+    /// Estimates the cost of the take, including the index lookup for every taken element and its
+    /// subexpression.
+    ///
+    /// # Returns
+    /// The estimated cost.
+    fn cost(&self) -> CostEstimate {
+        self.expression.cost() + CostEstimate::elementwise(self.count())
+    }
+
+    /// Returns the subexpression of the take.
+    ///
+    /// # Returns
+    /// A vector holding the subexpression.
+    fn children(&self) -> Vec<&dyn Node<B>> {
+        vec![self.expression.as_ref()]
+    }
+
+    /// Renders the take with its subexpression and indices for debugging.
+    ///
+    /// # Parameters
+    /// - `f`: The formatter to write the rendered expression tree to.
+    ///
+    /// # Returns
+    /// A result indicating whether the formatting succeeded.
+    fn fmt_tree(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Take(")?;
+        self.expression.fmt_tree(f)?;
+        write!(f, ", {:?})", self.indices)
+    }
+}
+
+// NOTE: Clone implementation.
+
+impl<T, B: Backend> Clone for Take<T, B> {
+    /// Creates a clone of the `Take` instance.
+    ///
+    /// # Returns
+    /// A clone of the `Take` instance.
+    fn clone(&self) -> Self {
+        Self {
+            indices: self.indices.clone(),
+            shape: self.shape.clone(),
+            expression: self.expression.clone_box(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::expression::Shape;
+    use crate::Tengu;
+
+    #[tokio::test]
+    async fn taken_shape() {
+        let tengu = Tengu::cpu().await.unwrap();
+        let a = tengu.tensor([2, 3]).label("a").zero::<f32>();
+        let take = a.take(&[5, 0]);
+        assert_eq!(take.shape(), &[2]);
+        assert_eq!(take.count(), 2);
+        assert_eq!(format!("{take:?}"), r#"Take(Tensor("a"), [5, 0])"#);
+    }
+
+    #[tokio::test]
+    #[should_panic]
+    async fn index_out_of_bounds() {
+        let tengu = Tengu::cpu().await.unwrap();
+        let a = tengu.tensor([2, 3]).zero::<f32>();
+        let _ = a.take(&[6]);
+    }
+}
@@ -0,0 +1,198 @@
+//! This module defines the `Tile` struct and associated functionality for tiling tensor
+//! expressions, i.e. repeating them along their axes. Each axis of the result is the corresponding
+//! axis of the original expression repeated the given number of times.
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use tengu_backend::{Backend, Processor};
+use tengu_backend_tensor::StorageType;
+
+use super::Expression;
+use crate::collector::Collector;
+use crate::cost::CostEstimate;
+use crate::node::Node;
+use crate::shape::Shape;
+use crate::source::Source;
+
+/// Struct representing a tensor expression tiled along its axes.
+pub struct Tile<T, B> {
+    reps: Vec<usize>,
+    shape: Vec<usize>,
+    count: usize,
+    expression: Box<dyn Node<B>>,
+    phantom: PhantomData<T>,
+}
+
+impl<T: StorageType, B: Backend + 'static> Tile<T, B> {
+    /// Creates a new `Tile` instance.
+    ///
+    /// # Parameters
+    /// - `expr`: The tensor expression to tile.
+    /// - `reps`: The number of repetitions along each axis.
+    ///
+    /// # Returns
+    /// A new `Tile` instance.
+    ///
+    /// # Panics
+    /// Panics if the number of repetitions doesn't match the rank of the expression, or if any of
+    /// them is zero, since that would produce an empty axis.
+    pub fn new(expr: Expression<T, B>, reps: &[usize]) -> Self {
+        let rank = expr.shape().len();
+        assert_eq!(reps.len(), rank, "expected {rank} repetitions for expression of rank {rank}");
+        assert!(!reps.contains(&0), "repetitions {reps:?} should all be positive");
+        let shape: Vec<_> = expr.shape().iter().zip(reps).map(|(dim, rep)| dim * rep).collect();
+        Self {
+            reps: reps.to_vec(),
+            count: shape.iter().product(),
+            shape,
+            expression: Box::new(expr),
+            phantom: PhantomData,
+        }
+    }
+}
+
+// NOTE: Shape implementation.
+
+impl<T, B> Shape for Tile<T, B> {
+    /// Returns the number of elements in the tiled tensor.
+    ///
+    /// # Returns
+    /// The number of elements in the tensor.
+    fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the shape of the tiled tensor.
+    ///
+    /// # Returns
+    /// A slice representing the dimensions of the tensor.
+    fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+}
+
+// NOTE: Node implementation.
+
+impl<T: StorageType, B: Backend + 'static> Node<B> for Tile<T, B> {
+    /// Returns a boxed clone of the `Tile` instance.
+    ///
+    /// # Returns
+    /// A boxed clone of the `Tile` instance.
+    fn clone_box(&self) -> Box<dyn Node<B>> {
+        Box::new(self.clone())
+    }
+
+    /// Collect sources from the tile operation.
+    ///
+    /// # Parameters
+    /// - `collector`: A mutable reference to the collector.
+    fn collect<'a>(&'a self, collector: &mut Collector<'a, B>) {
+        self.expression.collect(collector);
+    }
+
+    /// Finds a source node by its label.
+    ///
+    /// # Parameters
+    /// - `label`: The label of the source node to find.
+    ///
+    /// # Returns
+    /// An optional reference to the found source node.
+    fn find<'a>(&'a self, label: &str) -> Option<&'a dyn Source<B>> {
+        self.expression.find(label)
+    }
+
+    /// Visits the node with the given processor and tiles the inner expression.
+    ///
+    /// # Parameters
+    /// - `processor`: The processor used to visit the node.
+    ///
+    /// # Returns
+    /// The inner representation used by the processor.
+    fn visit<'a>(&'a self, processor: &mut B::Processor<'a>) -> <B::Processor<'a> as Processor<'a, B>>::Repr {
+        let expr = self.expression.visit(processor);
+        processor.tile(expr, self.expression.shape(), &self.reps, T::as_type())
+    }
+
+    /// Estimates the cost of the tile, including the index computation for every element of the result
+    /// and its subexpression.
+    ///
+    /// # Returns
+    /// The estimated cost.
+    fn cost(&self) -> CostEstimate {
+        self.expression.cost() + CostEstimate::elementwise(self.count())
+    }
+
+    /// Returns the subexpression of the tile.
+    ///
+    /// # Returns
+    /// A vector holding the subexpression.
+    fn children(&self) -> Vec<&dyn Node<B>> {
+        vec![self.expression.as_ref()]
+    }
+
+    /// Renders the tile with its subexpression and repetitions for debugging.
+    ///
+    /// # Parameters
+    /// - `f`: The formatter to write the rendered expression tree to.
+    ///
+    /// # Returns
+    /// A result indicating whether the formatting succeeded.
+    fn fmt_tree(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Tile(")?;
+        self.expression.fmt_tree(f)?;
+        write!(f, ", {:?})", self.reps)
+    }
+}
+
+// NOTE: Clone implementation.
+
+impl<T, B: Backend> Clone for Tile<T, B> {
+    /// Creates a clone of the `Tile` instance.
+    ///
+    /// # Returns
+    /// A clone of the `Tile` instance.
+    fn clone(&self) -> Self {
+        Self {
+            reps: self.reps.clone(),
+            shape: self.shape.clone(),
+            count: self.count,
+            expression: self.expression.clone_box(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::expression::Shape;
+    use crate::Tengu;
+
+    #[tokio::test]
+    async fn tiled_shape() {
+        let tengu = Tengu::cpu().await.unwrap();
+        let a = tengu.tensor([2, 3]).label("a").zero::<f32>();
+        let tile = a.repeat(&[3, 2]);
+        assert_eq!(tile.shape(), &[6, 6]);
+        assert_eq!(tile.count(), 36);
+        assert_eq!(format!("{tile:?}"), r#"Tile(Tensor("a"), [3, 2])"#);
+    }
+
+    #[tokio::test]
+    #[should_panic]
+    async fn reps_rank_mismatch() {
+        let tengu = Tengu::cpu().await.unwrap();
+        let a = tengu.tensor([2, 3]).zero::<f32>();
+        let _ = a.repeat(&[2]);
+    }
+
+    #[tokio::test]
+    #[should_panic]
+    async fn zero_reps() {
+        let tengu = Tengu::cpu().await.unwrap();
+        let a = tengu.tensor([2, 3]).zero::<f32>();
+        let _ = a.repeat(&[2, 0]);
+    }
+}
@@ -2,11 +2,14 @@
 //! such as logarithm and exponentiation in tensor expressions. This is a helper struct for storing
 //! `UnaryFn` variant on the `Expression` struct.
 
+use std::fmt;
+
 use tengu_backend::{Backend, Processor};
 use tengu_backend_tensor::{Function, StorageType};
 
 use super::Expression;
 use crate::collector::Collector;
+use crate::cost::CostEstimate;
 use crate::node::Node;
 use crate::shape::Shape;
 use crate::source::Source;
@@ -56,6 +59,17 @@ impl<B: Backend + 'static> UnaryFn<B> {
     pub fn log<T: StorageType>(expr: Expression<T, B>) -> Self {
         Self::new(Function::Log, expr)
     }
+
+    /// Creates a new `UnaryFn` instance for the sign function.
+    ///
+    /// # Parameters
+    /// - `expr`: The tensor expression to which the sign function is applied.
+    ///
+    /// # Returns
+    /// A new `UnaryFn` instance with the sign function.
+    pub fn sign<T: StorageType>(expr: Expression<T, B>) -> Self {
+        Self::new(Function::Sign, expr)
+    }
 }
 
 // NOTE: Trait implementations.
@@ -113,10 +127,40 @@ impl<B: Backend + 'static> Node<B> for UnaryFn<B> {
     ///
     /// # Returns
     /// The inner representation used by the processor.
-    fn visit<'a>(&'a self, processor: &mut B::Processor<'a>) -> <B::Processor<'a> as Processor<B>>::Repr {
+    fn visit<'a>(&'a self, processor: &mut B::Processor<'a>) -> <B::Processor<'a> as Processor<'a, B>>::Repr {
         let expr = self.expression.visit(processor);
         processor.unary_fn(expr, self.function)
     }
+
+    /// Estimates the cost of the unary function, including the function applied to every element and
+    /// its subexpression.
+    ///
+    /// # Returns
+    /// The estimated cost.
+    fn cost(&self) -> CostEstimate {
+        self.expression.cost() + CostEstimate::elementwise(self.count())
+    }
+
+    /// Returns the subexpression of the unary function.
+    ///
+    /// # Returns
+    /// A vector holding the subexpression.
+    fn children(&self) -> Vec<&dyn Node<B>> {
+        vec![self.expression.as_ref()]
+    }
+
+    /// Renders the function application with its subexpression for debugging.
+    ///
+    /// # Parameters
+    /// - `f`: The formatter to write the rendered expression tree to.
+    ///
+    /// # Returns
+    /// A result indicating whether the formatting succeeded.
+    fn fmt_tree(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}(", self.function)?;
+        self.expression.fmt_tree(f)?;
+        write!(f, ")")
+    }
 }
 
 // NOTE: Clone implementation.
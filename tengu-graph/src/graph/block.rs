@@ -11,16 +11,23 @@
 use std::collections::HashSet;
 use std::rc::Rc;
 
-use tengu_backend::{Backend, Compute, Processor, Readout};
+use itertools::Itertools;
+use tengu_backend::{Backend, Compute, Limits, Processor, Readout};
 use tengu_backend_tensor::StorageType;
+use tracing::warn;
 
 use super::computation::Computation;
+use super::feasibility::Violation;
 use crate::collector::Collector;
-use crate::expression::Expression;
-use crate::shape::Shape;
+use crate::expression::{ErasedExpression, Expression};
+use crate::node::Node;
 use crate::source::Source;
 use crate::{Error, Result, Tengu};
 
+/// The default number of nodes of a single statement above which adding it to a block logs a
+/// warning.
+const DEFAULT_NODE_WARNING: usize = 1000;
+
 /// A struct representing a computational block in the Tengu framework.
 ///
 /// The `Block` struct holds computations and provides methods to add, label, and process these computations.
@@ -29,6 +36,17 @@ pub struct Block<B: Backend> {
     label: String,
     computations: Vec<Computation<B>>,
     probes: HashSet<String>,
+    update_every: usize,
+    node_warning: usize,
+}
+
+/// A compute stage of a block, i.e. a group of its computations which are run together, e.g. in a
+/// single shader on backends like WGPU.
+pub(crate) struct Stage<'a, B: Backend + 'a> {
+    /// The output labels of the computations of the stage, joined with commas.
+    pub(crate) computations: String,
+    /// The processor of the computations.
+    pub(crate) processor: B::Processor<'a>,
 }
 
 impl<B: Backend + 'static> Block<B> {
@@ -46,6 +64,8 @@ impl<B: Backend + 'static> Block<B> {
             label: label.into(),
             computations: Vec::new(),
             probes: HashSet::new(),
+            update_every: 1,
+            node_warning: DEFAULT_NODE_WARNING,
         }
     }
 
@@ -57,7 +77,61 @@ impl<B: Backend + 'static> Block<B> {
         &self.label
     }
 
-    /// Adds a new computation to the block with the specified label and expression.
+    /// Sets the update rate of the block, so that it is only computed every `n`-th iteration of the
+    /// graph, starting with the first one, e.g. for the slow parts of multi-rate simulations. In the
+    /// other iterations, the block keeps its tensors and the links going out of it are not
+    /// propagated. Iterations are counted across all `compute` calls of the graph.
+    ///
+    /// # Parameters
+    /// - `n`: The number of iterations between the updates of the block.
+    ///
+    /// # Returns
+    /// A mutable reference to the `Block` instance for chaining with other block calls.
+    ///
+    /// # Panics
+    /// Panics if `n` is zero.
+    pub fn set_update_every(&mut self, n: usize) -> &mut Self {
+        assert!(n > 0, "update rate of block {} should be positive", self.label);
+        self.update_every = n;
+        self
+    }
+
+    /// Returns the number of iterations between the updates of the block.
+    ///
+    /// # Returns
+    /// The update rate of the block, 1 if it is computed every iteration.
+    pub fn update_every(&self) -> usize {
+        self.update_every
+    }
+
+    /// Sets the number of nodes of a single statement above which adding a computation to the block
+    /// logs a warning. On backends like WGPU, every statement is emitted as a single shader
+    /// expression, so very large expressions can exceed the shader size limits or take long to
+    /// compile. Materializing subexpressions splits them up.
+    ///
+    /// # Parameters
+    /// - `n`: The largest number of nodes added without a warning, 1000 by default.
+    ///
+    /// # Returns
+    /// A mutable reference to the `Block` instance for chaining with other block calls.
+    pub fn set_node_warning(&mut self, n: usize) -> &mut Self {
+        self.node_warning = n;
+        self
+    }
+
+    /// Checks whether the block is due to be computed in the specified iteration of the graph.
+    ///
+    /// # Parameters
+    /// - `iteration`: The zero-based iteration of the graph.
+    ///
+    /// # Returns
+    /// `true` if the block is computed in the iteration, `false` otherwise.
+    pub(crate) fn is_due(&self, iteration: usize) -> bool {
+        iteration % self.update_every == 0
+    }
+
+    /// Adds a new computation to the block with the specified label and expression, validating the
+    /// label first. Materialized subexpressions are added as separate computations before it.
     ///
     /// # Type Parameters
     /// - `T`: The storage type of the expression.
@@ -67,15 +141,303 @@ impl<B: Backend + 'static> Block<B> {
     /// - `expr`: The expression to be computed.
     ///
     /// # Returns
-    /// A mutable reference to the `Block` instance for chaining with other block calls.
-    pub fn add_computation<T: StorageType>(&mut self, label: impl Into<String>, expr: Expression<T, B>) -> &mut Self {
-        let output = self.tengu.tensor(expr.shape()).label(label).zero::<T>();
-        let computation = Computation::new(output, expr);
+    /// A result containing a mutable reference to the `Block` instance for chaining with other block
+    /// calls, `Error::InvalidLabel` if the label is not a valid identifier, `Error::DuplicateOutput` if another computation of the block
+    /// already writes to the label, `Error::SourceAlreadyExists` if the label is already used by
+    /// another source in the block or in the expression, `Error::MaterializedElsewhere` if the
+    /// expression reads a subexpression materialized by another block, or `Error::BackendError`
+    /// wrapping `OutOfMemory` if the output tensor cannot be allocated.
+    pub fn add_computation<T: StorageType>(
+        &mut self,
+        label: impl Into<String>,
+        expr: Expression<T, B>,
+    ) -> Result<&mut Self> {
+        let label = label.into();
+        if !tengu_backend_tensor::is_valid_label(&label) {
+            return Err(Error::InvalidLabel(label));
+        }
+        if self.computations.iter().any(|c| c.output_label() == label) {
+            return Err(Error::DuplicateOutput(label));
+        }
+        if self.source(&label).is_some() || expr.find(&label).is_some() {
+            return Err(Error::SourceAlreadyExists(label));
+        }
+        let materialized = {
+            let mut collector = Collector::all();
+            expr.collect(&mut collector);
+            let materialized = collector.into_materialized().collect::<Vec<_>>();
+            for materialized in &materialized {
+                if let Some(block) = materialized.block().filter(|block| *block != self.label) {
+                    let label = materialized.label().to_string();
+                    return Err(Error::MaterializedElsewhere(label, block.to_string()));
+                }
+            }
+            materialized
+                .into_iter()
+                .filter(|materialized| materialized.block().is_none())
+                .map(|materialized| materialized.computation(&self.tengu, &self.label))
+                .collect::<Result<Vec<_>>>()?
+        };
+        let computation = Computation::new(&self.tengu, label, expr)?;
+        for computation in materialized.iter().chain([&computation]) {
+            let nodes = computation.node_count();
+            if nodes > self.node_warning {
+                warn!(
+                    "Computation {} in block {} has {nodes} nodes, more than {}, and may exceed the shader size limits",
+                    computation.output_label(),
+                    self.label,
+                    self.node_warning
+                );
+            }
+        }
+        self.computations.extend(materialized);
         self.computations.push(computation);
-        self
+        Ok(self)
+    }
+
+    /// Adds a new computation to the block with the specified label and a type-erased expression,
+    /// e.g. the one produced by `Expression::cast_dtype`.
+    ///
+    /// # Parameters
+    /// - `label`: The label for the new computation.
+    /// - `expr`: The type-erased expression to be computed.
+    ///
+    /// # Returns
+    /// A result containing a mutable reference to the `Block` instance for chaining with other block
+    /// calls, or any of the errors returned by `add_computation`.
+    pub fn add_erased_computation(&mut self, label: impl Into<String>, expr: ErasedExpression<B>) -> Result<&mut Self> {
+        match expr {
+            ErasedExpression::Bool(expr) => self.add_computation(label, expr),
+            ErasedExpression::U32(expr) => self.add_computation(label, expr),
+            ErasedExpression::I32(expr) => self.add_computation(label, expr),
+            ErasedExpression::F32(expr) => self.add_computation(label, expr),
+            ErasedExpression::U16(expr) => self.add_computation(label, expr),
+            ErasedExpression::I16(expr) => self.add_computation(label, expr),
+        }
     }
 
-    /// Adds a new probe label to the block.
+    /// Renames the output of the computation with the specified label in place. The output keeps
+    /// its data, and if it is probed, the probe is moved to the new label, so probes obtained before
+    /// renaming keep receiving data. Links address tensors by path, so outputs with links should be
+    /// renamed with `Graph::rename_output` instead, which updates the links as well.
+    ///
+    /// # Parameters
+    /// - `old`: The current label of the computation output.
+    /// - `new`: The new label of the computation output.
+    ///
+    /// # Returns
+    /// A result indicating success, or an error if there is no computation with the old label, the
+    /// new label is not a valid identifier or is already used by another source in the block.
+    pub fn rename_output(&mut self, old: &str, new: impl Into<String>) -> Result<()> {
+        let new = new.into();
+        if !tengu_backend_tensor::is_valid_label(&new) {
+            return Err(Error::InvalidLabel(new));
+        }
+        if self.source(&new).is_some() {
+            return Err(Error::SourceAlreadyExists(new));
+        }
+        let computation = self
+            .computations
+            .iter_mut()
+            .find(|computation| computation.output_label() == old)
+            .ok_or_else(|| Error::SourceNotFound(old.to_string()))?;
+        computation.rename_output(new.clone());
+        if self.probes.remove(old) {
+            self.probes.insert(new);
+        }
+        Ok(())
+    }
+
+    /// Returns the number of distinct tensors used by the computations in the block, including
+    /// the computation outputs.
+    ///
+    /// # Returns
+    /// The number of tensors.
+    pub fn tensor_count(&self) -> usize {
+        let mut collector = Collector::all();
+        for computation in &self.computations {
+            computation.collect(&mut collector);
+        }
+        collector.count()
+    }
+
+    /// Returns the computations of the block in the order they were added.
+    ///
+    /// # Returns
+    /// A slice of the computations.
+    pub(crate) fn computations(&self) -> &[Computation<B>] {
+        &self.computations
+    }
+
+    /// Returns all tensors used by the computations in the block, including the computation
+    /// outputs, sorted by their labels.
+    ///
+    /// # Returns
+    /// The sources of the tensors.
+    pub(crate) fn tensors(&self) -> Vec<&dyn Source<B>> {
+        let mut collector = Collector::all();
+        for computation in &self.computations {
+            computation.collect(&mut collector);
+        }
+        collector
+            .into_sources()
+            .sorted_by(|a, b| a.label().cmp(b.label()))
+            .collect()
+    }
+
+    /// Returns the input tensors of the block, i.e. the tensors used by its computations which are
+    /// not produced by any of them.
+    ///
+    /// # Returns
+    /// An iterator over the input sources.
+    pub(crate) fn inputs(&self) -> impl Iterator<Item = &dyn Source<B>> {
+        let mut collector = Collector::all();
+        for computation in &self.computations {
+            computation.collect(&mut collector);
+        }
+        collector.into_sources().filter(|source| {
+            self.computations
+                .iter()
+                .all(|computation| !computation.produces(source.label()))
+        })
+    }
+
+    /// Checks that the tensors used by the computations of the block have consistent shapes, i.e.
+    /// that different tensors sharing a label, which the backends treat as the same tensor, have the
+    /// same shape.
+    ///
+    /// # Returns
+    /// A result indicating success, or `Error::ShapeConflict` naming the first computation using a
+    /// tensor with a shape different from the one used before.
+    pub(crate) fn validate_shapes(&self) -> Result<()> {
+        let mut collector = Collector::all();
+        for computation in &self.computations {
+            computation.collect(&mut collector);
+            if let Some((source, expected)) = collector.conflict() {
+                return Err(Error::ShapeConflict {
+                    block: self.label.clone(),
+                    computation: computation.output_label().to_string(),
+                    tensor: source.label().to_string(),
+                    shape: source.shape().to_vec(),
+                    expected: expected.shape().to_vec(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks that the block doesn't use more tensors than the backend allows in a single compute
+    /// stage. With splitting enabled, only each single computation has to fit within the limit.
+    ///
+    /// # Parameters
+    /// - `split`: Whether the block may be split into several compute stages.
+    ///
+    /// # Returns
+    /// A result indicating success, or `Error::TooManyTensors` if the limit is exceeded.
+    pub(crate) fn check_limits(&self, split: bool) -> Result<()> {
+        self.check_tensor_count(self.tengu.max_tensor_count(), split)
+    }
+
+    /// Checks that the block doesn't use more tensors than the specified limit in a single compute
+    /// stage. With splitting enabled, only each single computation has to fit within the limit.
+    ///
+    /// # Parameters
+    /// - `limit`: The maximum number of tensors in a single stage, or `None` if there is no limit.
+    /// - `split`: Whether the block may be split into several compute stages.
+    ///
+    /// # Returns
+    /// A result indicating success, or `Error::TooManyTensors` if the limit is exceeded.
+    fn check_tensor_count(&self, limit: Option<usize>, split: bool) -> Result<()> {
+        if split {
+            return self.stages(limit).map(|_| ());
+        }
+        let count = self.tensor_count();
+        match limit {
+            Some(limit) if count > limit => Err(Error::TooManyTensors(self.label.clone(), count, limit)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Checks the block against all specified limits, collecting every violation instead of
+    /// stopping at the first one.
+    ///
+    /// # Parameters
+    /// - `limits`: The limits of the backend.
+    /// - `split`: Whether the block may be split into several compute stages.
+    ///
+    /// # Returns
+    /// The violations of the limits, which is empty if the block fits within all of them.
+    pub(crate) fn violations(&self, limits: &impl Limits, split: bool) -> Vec<Violation> {
+        let mut violations = Vec::new();
+        if let Err(Error::TooManyTensors(block, count, limit)) =
+            self.check_tensor_count(limits.max_tensor_per_compute(), split)
+        {
+            violations.push(Violation::TooManyTensors { block, count, limit });
+        }
+        let tensors = self.tensors();
+        if let Some(limit) = limits.max_tensor_size() {
+            let large = tensors.iter().filter(|tensor| tensor.count() > limit);
+            violations.extend(large.map(|tensor| Violation::TensorTooLarge {
+                block: self.label.clone(),
+                tensor: tensor.label().to_string(),
+                count: tensor.count(),
+                limit,
+            }));
+        }
+        let count = tensors.iter().map(|tensor| tensor.count()).max().unwrap_or(0);
+        if let Some(limit) = limits.max_dispatch_size().filter(|&limit| count > limit) {
+            violations.push(Violation::DispatchTooLarge {
+                block: self.label.clone(),
+                count,
+                limit,
+            });
+        }
+        violations
+    }
+
+    /// Groups the computations of the block into consecutive stages, each using at most `limit`
+    /// distinct tensors. A new stage is also started before a computation reading the output of
+    /// another computation in the current stage, e.g. a materialized subexpression, so that the
+    /// output is complete before it is read. The computations keep their order, so running the
+    /// stages one after another is equivalent to running the whole block at once.
+    ///
+    /// # Parameters
+    /// - `limit`: The maximum number of tensors in a single stage, or `None` if there is no limit.
+    ///
+    /// # Returns
+    /// A result containing the stages, or `Error::TooManyTensors` if a single computation uses
+    /// more tensors than the limit.
+    fn stages(&self, limit: Option<usize>) -> Result<Vec<Vec<&Computation<B>>>> {
+        let mut stages = Vec::new();
+        let mut stage: Vec<&Computation<B>> = Vec::new();
+        let mut labels = HashSet::new();
+        for computation in &self.computations {
+            let mut collector = Collector::all();
+            computation.collect(&mut collector);
+            let sources: HashSet<_> = collector.into_sources().map(|source| source.label()).collect();
+            if let Some(limit) = limit.filter(|&limit| sources.len() > limit) {
+                return Err(Error::TooManyTensors(self.label.clone(), sources.len(), limit));
+            }
+            let exceeds = limit.is_some_and(|limit| labels.union(&sources).count() > limit);
+            let depends = stage
+                .iter()
+                .any(|other| sources.iter().any(|label| other.produces(label)));
+            if exceeds || depends {
+                stages.push(std::mem::take(&mut stage));
+                labels.clear();
+            }
+            labels.extend(sources);
+            stage.push(computation);
+        }
+        if !stage.is_empty() || stages.is_empty() {
+            stages.push(stage);
+        }
+        Ok(stages)
+    }
+
+    /// Adds a new probe label to the block. Only probed tensors are read out after each
+    /// computation, so tensors without probes (like intermediates used only by other computations)
+    /// never allocate staging buffers or take part in readouts.
     ///
     /// # Parameters
     /// - `label`: The label for the new probe.
@@ -83,54 +445,137 @@ impl<B: Backend + 'static> Block<B> {
         self.probes.insert(label.into());
     }
 
-    /// Executes the computations in the block using the provided compute object and processor.
+    /// Checks whether the tensor with the specified label is probed, i.e. read out after each
+    /// computation.
+    ///
+    /// # Parameters
+    /// - `label`: The label of the tensor.
+    ///
+    /// # Returns
+    /// `true` if the tensor has a probe, `false` otherwise.
+    pub(crate) fn has_probe(&self, label: &str) -> bool {
+        self.probes.contains(label)
+    }
+
+    /// Checks whether any tensor of the block is probed.
+    ///
+    /// # Returns
+    /// `true` if the block has at least one probe, `false` otherwise.
+    pub(crate) fn has_probes(&self) -> bool {
+        !self.probes.is_empty()
+    }
+
+    /// Removes all probe labels from the block, so none of its tensors are read out anymore.
+    /// Probes obtained before clearing stay valid, but they don't receive any new data. Staging
+    /// buffers already allocated for the previously probed tensors are kept.
+    pub fn clear_probes(&mut self) {
+        self.probes.clear();
+    }
+
+    /// Executes the computations of a stage of the block using the provided compute object.
     ///
     /// # Parameters
     /// - `compute`: A mutable reference to the compute object.
-    /// - `processor`: A reference to the processor.
+    /// - `stage`: A reference to the stage holding the processor of the computations.
     ///
     /// # Returns
-    /// A `Result` indicating whether the computation was successful or an error occurred.
-    pub(crate) fn compute(&self, compute: &mut B::Compute<'_>, processor: &B::Processor<'_>) -> Result<()> {
-        compute.run(processor).map_err(Error::BackendError)
+    /// A `Result` indicating whether the computation was successful, or `Error::ComputationError`
+    /// naming the computations of the stage and the block if the backend failed to run them.
+    pub(crate) fn compute(&self, compute: &mut B::Compute<'_>, stage: &Stage<'_, B>) -> Result<()> {
+        compute
+            .run(&stage.processor)
+            .map_err(|e| Error::ComputationError(stage.computations.clone(), self.label.clone(), e))
     }
 
-    /// Executes the tensor readout operation for all tensors in the block which have a probe
-    /// associated with them.
+    /// Executes the tensor readout operation for all tensors in a stage of the block which have a
+    /// probe associated with them.
     ///
     /// # Parameters
     /// - `readout`: A mutable reference to the stage object.
-    /// - `processor`: A reference to the processor.
-    pub(crate) fn readout(&self, readout: &mut B::Readout<'_>, processor: &B::Processor<'_>) {
-        readout.run(processor);
+    /// - `stage`: A reference to the stage holding the processor of the computations.
+    pub(crate) fn readout(&self, readout: &mut B::Readout<'_>, stage: &Stage<'_, B>) {
+        readout.run(&stage.processor);
     }
 
-    /// Executes the tensor retrieve operation for all tensors in the block which have a probe
-    /// associated with them.
+    /// Creates a processor specific for this block. Adding computations will invalidate the
+    /// processor. Since every computation is visited as a statement writing its output, the
+    /// processor knows which tensors are only read, e.g. so that the WGPU backend binds them
+    /// read-only.
+    ///
+    /// # Returns
+    /// A result containing a processor for the block, or an error if the backend cannot process
+    /// its computations.
+    pub fn processor(&self) -> Result<B::Processor<'_>> {
+        self.stage_processor(&self.computations.iter().collect::<Vec<_>>(), &self.probes)
+    }
+
+    /// Creates a processor for all computations of the block which reads out the specified tensors
+    /// instead of the probed ones. It is used for staging tensors outside of the regular readouts,
+    /// e.g. when saving the state of the graph.
     ///
     /// # Parameters
-    /// - `retrieve`: A mutable reference to the readout object.
-    /// - `processor`: A reference to the collector that provides soureces.
-    pub(crate) async fn retrieve(&self, collector: &Collector<'_, B>) -> Result<()> {
-        for source in collector.sources() {
-            source.retrieve().await?;
-        }
-        Ok(())
+    /// - `readouts`: The labels of the tensors to read out.
+    ///
+    /// # Returns
+    /// A result containing a processor for the block, or an error if the backend cannot process
+    /// its computations.
+    pub(crate) fn readout_processor<'a>(&'a self, readouts: &'a HashSet<String>) -> Result<B::Processor<'a>> {
+        self.stage_processor(&self.computations.iter().collect::<Vec<_>>(), readouts)
     }
 
-    /// Creates a processor specific for this block. Adding computations will invalidate the
-    /// processor.
+    /// Creates the processors for the block. Without splitting, there is a single processor for
+    /// all computations, unless some of them read the outputs of materialized subexpressions. With
+    /// splitting, the computations are grouped into stages which fit within the tensor limit of the
+    /// backend, and there is one processor (and one shader on backends like WGPU) per stage, to be
+    /// run in order.
+    ///
+    /// # Parameters
+    /// - `split`: Whether the block may be split into several compute stages.
     ///
     /// # Returns
-    /// A processor for the block.
-    pub fn processor(&self) -> B::Processor<'_> {
-        let mut processor = self.tengu.backend().processor(&self.probes);
+    /// A result containing the stages with their processors, or `Error::TooManyTensors` if the
+    /// block (or, with splitting, a single computation) uses more tensors than the backend allows,
+    /// or `Error::BackendError` if the backend cannot process the computations.
+    pub(crate) fn processors(&self, split: bool) -> Result<Vec<Stage<'_, B>>> {
+        let stages = if split {
+            self.stages(self.tengu.max_tensor_count())?
+        } else {
+            self.check_limits(false)?;
+            self.stages(None)?
+        };
+        let stages = stages.iter().map(|computations| {
+            Ok(Stage {
+                computations: computations
+                    .iter()
+                    .map(|computation| computation.output_label())
+                    .join(", "),
+                processor: self.stage_processor(computations, &self.probes)?,
+            })
+        });
+        stages.collect()
+    }
+
+    /// Creates a processor for the given computations of the block.
+    ///
+    /// # Parameters
+    /// - `computations`: The computations to process.
+    /// - `readouts`: The labels of the tensors read out after the computations.
+    ///
+    /// # Returns
+    /// A result containing a processor for the computations, or `Error::BackendError` if the
+    /// backend cannot process them, e.g. because a bincount is nested in another expression.
+    fn stage_processor<'a>(
+        &'a self,
+        computations: &[&'a Computation<B>],
+        readouts: &'a HashSet<String>,
+    ) -> Result<B::Processor<'a>> {
+        let mut processor = self.tengu.backend().processor(readouts);
         let mut statements = Vec::new();
-        for computation in &self.computations {
+        for computation in computations {
             statements.push(computation.visit(&mut processor));
         }
-        processor.block(statements.into_iter());
-        processor
+        processor.block(statements.into_iter())?;
+        Ok(processor)
     }
 
     /// Creates a collector specific for this block. Adding computations will invalidate the
@@ -168,7 +613,183 @@ mod tests {
         let mut graph = tengu.graph();
         let block = graph.add_block("main").unwrap();
         assert_eq!(block.computations.len(), 0);
-        block.add_computation("one", tengu.scalar(1));
+        block.add_computation("one", tengu.scalar(1)).unwrap();
+        assert_eq!(block.computations.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn computation_labels() {
+        let tengu = Tengu::cpu().await.unwrap();
+        let a = tengu.tensor([2]).label("a").zero::<f32>();
+        let mut graph = tengu.graph();
+        let block = graph.add_block("main").unwrap();
+        let result = block.add_computation("my tensor", a.clone().exp());
+        assert!(matches!(result, Err(Error::InvalidLabel(label)) if label == "my tensor"));
+        let result = block.add_computation("1b", a.clone().exp());
+        assert!(matches!(result, Err(Error::InvalidLabel(label)) if label == "1b"));
+        let result = block.add_computation("a", a.clone().exp());
+        assert!(matches!(result, Err(Error::SourceAlreadyExists(label)) if label == "a"));
+        block.add_computation("b", a.clone().exp()).unwrap();
+        let result = block.add_computation("b", a.log());
+        assert!(matches!(result, Err(Error::DuplicateOutput(label)) if label == "b"));
         assert_eq!(block.computations.len(), 1);
     }
+
+    #[tokio::test]
+    async fn duplicate_output() {
+        let tengu = Tengu::cpu().await.unwrap();
+        let a = tengu.tensor([2]).label("a").zero::<f32>();
+        let b = tengu.tensor([2]).label("b").zero::<f32>();
+        let mut graph = tengu.graph();
+        let block = graph.add_block("main").unwrap();
+        block.add_computation("c", a.clone() + b.clone()).unwrap();
+        let result = block.add_computation("c", a * b);
+        assert!(matches!(result, Err(Error::DuplicateOutput(label)) if label == "c"));
+        assert_eq!(block.computations.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn scalar_is_not_a_tensor() {
+        let tengu = Tengu::cpu().await.unwrap();
+        let a = tengu.tensor([4]).label("a").zero::<f32>();
+        let mut graph = tengu.graph();
+        let block = graph.add_block("main").unwrap();
+        block.add_computation("b", a + tengu.scalar(2.0)).unwrap();
+        assert_eq!(block.tensor_count(), 2);
+    }
+
+    #[tokio::test]
+    async fn clear_probes() {
+        let tengu = Tengu::cpu().await.unwrap();
+        let a = tengu.tensor([2]).label("a").init(&[1, 2]);
+        let mut graph = tengu.graph();
+        graph
+            .add_block("main")
+            .unwrap()
+            .add_computation("b", a.clone() + a)
+            .unwrap();
+        graph.add_probe::<i32>("main/a").unwrap();
+        graph.add_probe::<i32>("main/b").unwrap();
+        let block = graph.get_block_mut("main").unwrap();
+        assert_eq!(block.collector().sources().count(), 2);
+        block.clear_probes();
+        assert!(block.probes.is_empty());
+        assert_eq!(block.collector().sources().count(), 0);
+    }
+
+    #[tokio::test]
+    async fn update_every() {
+        let tengu = Tengu::cpu().await.unwrap();
+        let mut graph = tengu.graph();
+        let block = graph.add_block("main").unwrap();
+        assert!((0..3).all(|iteration| block.is_due(iteration)));
+        block.set_update_every(3);
+        assert_eq!(block.update_every(), 3);
+        assert_eq!(
+            (0..7).filter(|&iteration| block.is_due(iteration)).collect::<Vec<_>>(),
+            [0, 3, 6]
+        );
+    }
+
+    #[tokio::test]
+    #[should_panic]
+    async fn update_every_zero() {
+        let tengu = Tengu::cpu().await.unwrap();
+        let mut graph = tengu.graph();
+        graph.add_block("main").unwrap().set_update_every(0);
+    }
+
+    #[tokio::test]
+    async fn stages() {
+        let tengu = Tengu::cpu().await.unwrap();
+        let a = tengu.tensor([2]).label("a").zero::<f32>();
+        let b = tengu.tensor([2]).label("b").zero::<f32>();
+        let mut graph = tengu.graph();
+        let block = graph.add_block("main").unwrap();
+        block
+            .add_computation("c", a.clone() + b.clone())
+            .unwrap()
+            .add_computation("d", a.clone() * b)
+            .unwrap()
+            .add_computation("e", a.exp())
+            .unwrap();
+        let labels = |limit| {
+            let stages = block.stages(limit).unwrap();
+            stages
+                .iter()
+                .map(|stage| stage.iter().map(|c| c.output_label().to_string()).collect::<Vec<_>>())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(labels(None), [vec!["c", "d", "e"]]);
+        assert_eq!(labels(Some(4)), [vec!["c", "d"], vec!["e"]]);
+        assert_eq!(labels(Some(3)), [vec!["c"], vec!["d"], vec!["e"]]);
+        assert!(matches!(block.stages(Some(2)), Err(Error::TooManyTensors(_, 3, 2))));
+    }
+
+    #[tokio::test]
+    async fn materialize() {
+        let tengu = Tengu::cpu().await.unwrap();
+        let a = tengu.tensor([2]).label("a").zero::<f32>();
+        let b = tengu.tensor([2]).label("b").zero::<f32>();
+        let mut graph = tengu.graph();
+        let block = graph.add_block("main").unwrap();
+        block.add_computation("c", (a.clone() + b).materialize() * a).unwrap();
+        assert_eq!(block.computations.len(), 2);
+        let materialized = block.computations[0].output_label();
+        assert_eq!(block.computations[1].output_label(), "c");
+        let mut collector = Collector::all();
+        block.computations[1].collect(&mut collector);
+        let mut sources = collector
+            .into_sources()
+            .map(|source| source.label())
+            .collect::<Vec<_>>();
+        sources.sort();
+        let mut expected = vec!["a", "c", materialized];
+        expected.sort();
+        assert_eq!(sources, expected);
+        assert_eq!(block.stages(None).unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn materialize_reuse() {
+        let tengu = Tengu::cpu().await.unwrap();
+        let a = tengu.tensor([2]).label("a").zero::<f32>();
+        let shared = (a.clone() + a.clone()).materialize();
+        let mut graph = tengu.graph();
+        let block = graph.add_block("main").unwrap();
+        block
+            .add_computation("c", shared.clone() * a.clone())
+            .unwrap()
+            .add_computation("d", shared.clone() + a.clone())
+            .unwrap();
+        assert_eq!(block.computations.len(), 3);
+        let other = graph.add_block("other").unwrap();
+        let result = other.add_computation("e", shared * a);
+        assert!(matches!(result, Err(Error::MaterializedElsewhere(_, block)) if block == "main"));
+        assert!(other.computations.is_empty());
+    }
+
+    #[tokio::test]
+    async fn sources_of_dtype() {
+        let tengu = Tengu::cpu().await.unwrap();
+        let a = tengu.tensor([2]).label("a").zero::<f32>();
+        let b = tengu.tensor([2]).label("b").zero::<i32>();
+        let mut graph = tengu.graph();
+        let block = graph.add_block("main").unwrap();
+        block
+            .add_computation("c", a.clone() * a)
+            .unwrap()
+            .add_computation("d", b.clone() + b)
+            .unwrap();
+        let mut collector = Collector::all();
+        for computation in &block.computations {
+            computation.collect(&mut collector);
+        }
+        let mut floats = collector
+            .sources_of_dtype(tengu_backend_tensor::Type::F32)
+            .map(|source| source.label())
+            .collect::<Vec<_>>();
+        floats.sort();
+        assert_eq!(floats, ["a", "c"]);
+    }
 }
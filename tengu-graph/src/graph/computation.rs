@@ -5,37 +5,112 @@
 //! within a block. It has direct access to all other tensors used inside a block and create a new
 //! tensor as a result.
 
+use std::fmt;
+use std::rc::Rc;
+
+use as_any::Downcast;
 use tengu_backend::{Backend, Processor};
 use tengu_backend_tensor::StorageType;
+use tengu_graph_tensor::Tensor;
 
 use crate::collector::Collector;
 use crate::expression::Expression;
 use crate::node::Node;
 use crate::shape::Shape;
 use crate::source::Source;
+use crate::{Result, Tengu};
+
+/// A function building the statement of a computation from its current statement, relabeling its
+/// output tensors from the first label to the second one.
+type Relabeler<B> = Box<dyn Fn(&dyn Node<B>, &str, &str) -> Box<dyn Node<B>>>;
 
 /// A struct representing a computation in the Tengu framework.
 ///
 /// The `Computation` struct holds a computational statement and provides methods to visit and find sources within the statement.
 pub struct Computation<B> {
+    label: String,
+    masked: bool,
     statement: Box<dyn Node<B>>,
+    relabeler: Relabeler<B>,
 }
 
 impl<B: Backend + 'static> Computation<B> {
-    /// Creates a new `Computation` instance with the specified output and expression.
+    /// Creates a new `Computation` instance assigning the expression to a new output tensor with the
+    /// specified label. If the expression is a masked selection, its length is assigned to another
+    /// new tensor labelled `<label>_len`, which is attached to the output so that its probes only
+    /// receive the selected elements.
     ///
     /// # Type Parameters
     /// - `T`: The storage type of the expression.
     ///
     /// # Parameters
-    /// - `out`: The output expression.
+    /// - `tengu`: A reference-counted Tengu instance used to create the output tensor.
+    /// - `label`: The label of the output tensor.
     /// - `expr`: The input expression.
     ///
     /// # Returns
-    /// A new `Computation` instance.
-    pub fn new<T: StorageType>(out: Expression<T, B>, expr: Expression<T, B>) -> Self {
-        let statement = Box::new(Expression::statement(out, expr));
-        Self { statement }
+    /// A result wrapping the new `Computation` instance, or `Error::BackendError` wrapping
+    /// `OutOfMemory` if the output tensor cannot be allocated.
+    pub fn new<T: StorageType>(tengu: &Rc<Tengu<B>>, label: impl Into<String>, expr: Expression<T, B>) -> Result<Self> {
+        let label = label.into();
+        let masked = matches!(expr, Expression::MaskSelect(_));
+        let output = tengu.tensor(expr.shape()).label(&label).try_zero::<T>()?;
+        let length = match masked {
+            true => Some(tengu.tensor([1]).label(length_label(&label)).try_zero::<u32>()?),
+            false => None,
+        };
+        let statement = statement(output, length, expr.clone());
+        let relabeler = move |statement: &dyn Node<B>, old: &str, new: &str| {
+            let output = relabeled::<T, B>(statement, old, new);
+            let length = masked.then(|| relabeled::<u32, B>(statement, &length_label(old), &length_label(new)));
+            self::statement(output, length, expr.clone())
+        };
+        Ok(Self {
+            label,
+            masked,
+            statement,
+            relabeler: Box::new(relabeler),
+        })
+    }
+
+    /// Returns the label of the output tensor of the computation.
+    ///
+    /// # Returns
+    /// A reference to the label string.
+    pub fn output_label(&self) -> &str {
+        &self.label
+    }
+
+    /// Checks whether the tensor with the specified label is produced by the computation, i.e.
+    /// whether it is the output tensor or the length of a masked selection output.
+    ///
+    /// # Parameters
+    /// - `label`: The label of the tensor.
+    ///
+    /// # Returns
+    /// `true` if the tensor is produced by the computation, `false` otherwise.
+    pub(crate) fn produces(&self, label: &str) -> bool {
+        label == self.label || (self.masked && label == length_label(&self.label))
+    }
+
+    /// Returns the number of nodes of the statement assigning the expression to the output tensor.
+    ///
+    /// # Returns
+    /// The number of nodes, including the output tensor.
+    pub(crate) fn node_count(&self) -> usize {
+        self.statement.node_count()
+    }
+
+    /// Renames the output tensor of the computation, and the length tensor of a masked selection
+    /// output. The tensors keep their data and probes, so probes obtained for the old output keep
+    /// receiving data after renaming.
+    ///
+    /// # Parameters
+    /// - `label`: The new label of the output tensor.
+    pub(crate) fn rename_output(&mut self, label: impl Into<String>) {
+        let label = label.into();
+        self.statement = (self.relabeler)(self.statement.as_ref(), &self.label, &label);
+        self.label = label;
     }
 
     /// Visits the computation with a processor.
@@ -45,7 +120,7 @@ impl<B: Backend + 'static> Computation<B> {
     ///
     /// # Returns
     /// The inner representation used by the processor.
-    pub fn visit<'a>(&'a self, processor: &mut B::Processor<'a>) -> <B::Processor<'a> as Processor<B>>::Repr {
+    pub fn visit<'a>(&'a self, processor: &mut B::Processor<'a>) -> <B::Processor<'a> as Processor<'a, B>>::Repr {
         self.statement.visit(processor)
     }
 
@@ -69,6 +144,63 @@ impl<B: Backend + 'static> Computation<B> {
     }
 }
 
+/// Builds the statement assigning the expression to the output tensor. The length of a masked
+/// selection is attached both to the output, so that its probes only receive the selected
+/// elements, and to the selection writing it.
+///
+/// # Parameters
+/// - `output`: The output tensor.
+/// - `length`: The tensor receiving the length of a masked selection output.
+/// - `expr`: The assigned expression.
+///
+/// # Returns
+/// The statement node.
+fn statement<T: StorageType, B: Backend + 'static>(
+    output: Expression<T, B>,
+    length: Option<Expression<u32, B>>,
+    expr: Expression<T, B>,
+) -> Box<dyn Node<B>> {
+    match (length, &expr) {
+        (Some(length), Expression::MaskSelect(mask_select)) => {
+            let output = output.with_length(&length);
+            let expr = Expression::MaskSelect(mask_select.with_length(length));
+            Box::new(Expression::statement(output, expr))
+        }
+        _ => Box::new(Expression::statement(output, expr)),
+    }
+}
+
+/// Returns a copy of a tensor of the statement with another label, sharing its data and probes.
+///
+/// # Parameters
+/// - `statement`: The statement containing the tensor.
+/// - `old`: The current label of the tensor.
+/// - `new`: The label of the copy.
+///
+/// # Returns
+/// The relabeled tensor.
+///
+/// # Panics
+/// Panics if the statement contains no tensor of the expected type with the current label.
+fn relabeled<T: StorageType, B: Backend + 'static>(statement: &dyn Node<B>, old: &str, new: &str) -> Expression<T, B> {
+    let tensor = statement
+        .find(old)
+        .and_then(|source| source.downcast_ref::<Tensor<T, B>>())
+        .expect("statement should contain its output tensors");
+    Expression::Tensor(tensor.relabeled(new))
+}
+
+/// Returns the label of the tensor holding the length of a masked selection output.
+///
+/// # Parameters
+/// - `label`: The label of the output tensor.
+///
+/// # Returns
+/// The label of the length tensor.
+fn length_label(label: &str) -> String {
+    format!("{label}_len")
+}
+
 // NOTE: Shape trait implementation.
 
 impl<B> Shape for Computation<B> {
@@ -91,6 +223,16 @@ impl<B> Shape for Computation<B> {
 
 // Tests
 
+// NOTE: Debug implementation.
+
+impl<B: Backend + 'static> fmt::Debug for Computation<B> {
+    /// Formats the computation as the expression tree of its statement, e.g.
+    /// `Statement(Tensor("c"), Add(Tensor("a"), Tensor("b")))`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.statement.fmt_tree(f)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::Tengu;
@@ -103,9 +245,22 @@ mod tests {
         let tengu = Tengu::wgpu().await.unwrap();
         let a = tengu.tensor([2, 2]).init(&[1.0, 2.0, 3.0, 4.0]);
         let b = tengu.tensor([2, 2]).init(&[5.0, 6.0, 7.0, 8.0]);
-        let c = tengu.tensor([2, 2]).zero();
-        let computation = Computation::new(c, a + b);
+        let computation = Computation::new(&tengu, "c", a + b).unwrap();
         assert_eq!(computation.shape(), [2, 2]);
         assert_eq!(computation.count(), 4);
     }
+
+    #[tokio::test]
+    async fn rename_output() {
+        let tengu = Tengu::cpu().await.unwrap();
+        let a = tengu.tensor([2]).label("a").init(&[1, 2]);
+        let mut computation = Computation::new(&tengu, "c", a.clone() + a).unwrap();
+        assert_eq!(computation.output_label(), "c");
+        assert!(computation.source("c").is_some());
+        computation.rename_output("d");
+        assert_eq!(computation.output_label(), "d");
+        assert!(computation.source("c").is_none());
+        assert!(computation.source("d").is_some());
+        assert_eq!(computation.shape(), [2]);
+    }
 }
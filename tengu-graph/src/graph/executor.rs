@@ -2,86 +2,228 @@
 //! The executor does its jobby computing the blocks, reading out the results, and propagating the
 //! results through the links.
 
+use std::cell::{Cell, OnceCell, RefCell};
+use std::collections::HashSet;
+use std::time::Duration;
+
 use tengu_backend::Backend;
 
+use super::block::Stage;
 use super::link::RealizedLink;
 use super::Graph;
 use crate::graph::Block;
-use crate::{Error, Result};
+use crate::Result;
 
 /// The `Executor` struct is responsible for running the computational graph.
 pub struct Executor<'a, B: Backend> {
     backend: &'a B,
     blocks: Vec<&'a Block<B>>,
-    links: Vec<RealizedLink<'a, B>>,
-    processors: Vec<B::Processor<'a>>,
+    links: Vec<(usize, RealizedLink<'a, B>)>,
+    stages: Vec<Vec<Stage<'a, B>>>,
+    probed: bool,
+    computed: &'a RefCell<HashSet<String>>,
+    iteration: Option<&'a Cell<usize>>,
+    timer: OnceCell<B::Timer>,
 }
 
 impl<'a, B: Backend + 'static> Executor<'a, B> {
-    /// Creates a new `Executor` instance with the specified graph.
+    /// Creates a new `Executor` instance with the specified graph. The processors of all blocks are
+    /// built here once and reused by every step, so that backends can cache whatever they derive
+    /// from them (like compiled shaders). Since the graph is borrowed for the lifetime of the
+    /// executor, its computations cannot change in the meantime and the cache never gets stale.
+    /// The cache lives as long as the executor, so every new executor compiles its shaders again:
+    /// methods like `Graph::compute` create one per call, so repeated calls with few iterations
+    /// each pay for the compilation every time, while a single call with many iterations doesn't.
+    /// Each step advances the iteration counter of the graph, and blocks with an update rate are
+    /// only computed in the iterations they are due.
+    ///
+    /// # Parameters
+    /// - `graph`: A reference to the computational graph.
+    ///
+    /// # Returns
+    /// A new `Executor` instance, or `Error::TooManyTensors` if any block uses more tensors than
+    /// the backend allows and can't be split (see `Graph::auto_split`).
+    pub fn new(graph: &'a Graph<B>) -> Result<Self> {
+        let mut executor = Self::with_blocks(graph, graph.blocks.values().collect())?;
+        executor.iteration = Some(&graph.iteration);
+        Ok(executor)
+    }
+
+    /// Creates a new `Executor` instance which runs only the specified block of the graph. Only
+    /// the links going out of the block are propagated. The block is computed in every step
+    /// regardless of its update rate, and the iteration counter of the graph is left unchanged.
     ///
     /// # Parameters
     /// - `graph`: A reference to the computational graph.
+    /// - `block`: A reference to the block of the graph to run.
     ///
     /// # Returns
-    /// A new `Executor` instance.
-    pub fn new(graph: &'a Graph<B>) -> Self {
-        let blocks: Vec<_> = graph.blocks.values().collect();
-        let links: Vec<_> = graph.links.iter().map(|link| link.realize(graph)).collect();
-        let processors = blocks.iter().map(|block| block.processor()).collect();
-        Self {
+    /// A new `Executor` instance, or `Error::TooManyTensors` if the block uses more tensors than
+    /// the backend allows.
+    pub fn for_block(graph: &'a Graph<B>, block: &'a Block<B>) -> Result<Self> {
+        Self::with_blocks(graph, vec![block])
+    }
+
+    /// Creates a new `Executor` instance which runs the specified blocks of the graph and
+    /// propagates the links going out of them.
+    ///
+    /// # Parameters
+    /// - `graph`: A reference to the computational graph.
+    /// - `blocks`: The blocks of the graph to run.
+    ///
+    /// # Returns
+    /// A new `Executor` instance, `Error::TooManyTensors` if any block uses more tensors than the
+    /// backend allows, or `Error::DanglingLink` if a link going out of the blocks refers to a tensor
+    /// which no longer exists.
+    fn with_blocks(graph: &'a Graph<B>, blocks: Vec<&'a Block<B>>) -> Result<Self> {
+        let links: Vec<_> = graph
+            .links
+            .iter()
+            .filter_map(|link| {
+                let source = blocks.iter().position(|block| block.label() == link.source_block())?;
+                Some(link.realize(graph).map(|link| (source, link)))
+            })
+            .collect::<Result<_>>()?;
+        let stages = blocks
+            .iter()
+            .map(|block| block.processors(graph.auto_split))
+            .collect::<Result<_>>()?;
+        let probed = blocks.iter().any(|block| block.has_probes());
+        Ok(Self {
             backend: graph.tengu.backend(),
             blocks,
             links,
-            processors,
-        }
+            stages,
+            probed,
+            computed: &graph.computed,
+            iteration: None,
+            timer: OnceCell::new(),
+        })
     }
 
     /// Executes a single step of computation of the graph.
     ///
     /// # Returns
-    /// A result indicating success or failure.
+    /// A result indicating success or failure. If a block fails to compute, the error names the
+    /// block and its failed computations.
     pub fn step(&self) -> Result<()> {
-        self.compute()?;
-        self.propagate();
-        self.readout();
+        let due = self.due();
+        self.backend
+            .compute("compute", |mut compute| self.compute(&mut compute, &due))?;
+        self.finish_step(&due);
         Ok(())
     }
 
-    /// Computes the blocks in the graph.
+    /// Executes a single step of computation of the graph, measuring the time the device spends
+    /// computing the blocks. The timer of the backend is created by the first timed step and
+    /// reused by all the following ones, and the measured time is only read back by `elapsed`.
     ///
     /// # Returns
-    /// A result indicating success or failure.
-    fn compute(&self) -> Result<()> {
+    /// A result indicating success or failure, e.g. `Error::BackendError` wrapping
+    /// `TimestampQueryUnsupported` if the backend cannot measure the execution time.
+    pub fn step_timed(&self) -> Result<()> {
+        let timer = match self.timer.get() {
+            Some(timer) => timer,
+            None => {
+                let timer = self.backend.timer("compute")?;
+                self.timer.get_or_init(|| timer)
+            }
+        };
+        let due = self.due();
         self.backend
-            .compute("compute", |mut compute| {
-                for (block, processor) in self.blocks.iter().zip(&self.processors) {
-                    block.compute(&mut compute, processor)?;
-                }
-                Ok(())
-            })
-            .map_err(Error::BackendError)
+            .compute_timed("compute", timer, |mut compute| self.compute(&mut compute, &due))?;
+        self.finish_step(&due);
+        Ok(())
+    }
+
+    /// Waits for the timed steps to finish and returns the time the device spent computing them.
+    ///
+    /// # Returns
+    /// A result containing the total execution time of the timed steps, which is zero if there
+    /// were none.
+    pub fn elapsed(&self) -> Result<Duration> {
+        match self.timer.get() {
+            Some(timer) => Ok(self.backend.elapsed(timer)?),
+            None => Ok(Duration::ZERO),
+        }
+    }
+
+    /// Determines which blocks are computed in the current iteration of the graph.
+    ///
+    /// # Returns
+    /// A flag for each block of the executor, `true` if the block is due in the current iteration.
+    fn due(&self) -> Vec<bool> {
+        match self.iteration {
+            Some(iteration) => self.blocks.iter().map(|block| block.is_due(iteration.get())).collect(),
+            None => vec![true; self.blocks.len()],
+        }
+    }
+
+    /// Propagates the links and reads out the probes after the blocks have been computed, marks
+    /// the blocks as computed and advances the iteration counter. Each of these is a separate
+    /// submission to the backend (with its own command encoder on WGPU), so they are skipped when
+    /// there are no links or no probes, which keeps the per-iteration overhead of plain simulation
+    /// loops down to the compute submission.
+    ///
+    /// # Parameters
+    /// - `due`: The flags of the blocks computed in this iteration.
+    fn finish_step(&self, due: &[bool]) {
+        if self.links.iter().any(|(source, _)| due[*source]) {
+            self.propagate(due);
+        }
+        if self.probed {
+            self.readout();
+        }
+        let labels = self
+            .blocks
+            .iter()
+            .zip(due)
+            .filter(|(_, due)| **due)
+            .map(|(block, _)| block.label().to_string());
+        self.computed.borrow_mut().extend(labels);
+        if let Some(iteration) = self.iteration {
+            iteration.set(iteration.get() + 1);
+        }
+    }
+
+    /// Computes the blocks in the graph.
+    ///
+    /// # Parameters
+    /// - `compute`: The compute instance of the backend.
+    /// - `due`: The flags of the blocks computed in this iteration.
+    ///
+    /// # Returns
+    /// A result indicating success, or the error of the block which failed to compute, naming the
+    /// block and its failed computations.
+    fn compute(&self, compute: &mut B::Compute<'_>, due: &[bool]) -> Result<()> {
+        for ((block, stages), _) in self.blocks.iter().zip(&self.stages).zip(due).filter(|(_, due)| **due) {
+            for stage in stages {
+                block.compute(compute, stage)?;
+            }
+        }
+        Ok(())
     }
 
     /// Performs readout operation on blocks in the graph.
     fn readout(&self) {
-        self.backend.readout("readout", |mut stage| {
-            for (block, processor) in self.blocks.iter().zip(&self.processors) {
-                block.readout(&mut stage, processor);
+        self.backend.readout("readout", |mut readout| {
+            for (block, stages) in self.blocks.iter().zip(&self.stages) {
+                for stage in stages {
+                    block.readout(&mut readout, stage);
+                }
             }
         });
     }
 
-    /// Propagates information through the links in the graph.
+    /// Propagates information through the links going out of the blocks computed in this
+    /// iteration.
     ///
-    /// # Returns
-    /// A result indicating success or failure.
-    fn propagate(&self) {
+    /// # Parameters
+    /// - `due`: The flags of the blocks computed in this iteration.
+    fn propagate(&self, due: &[bool]) {
         self.backend.propagate(|mut linker| {
-            for link in &self.links {
-                link.from()
-                    .copy(link.to(), &mut linker)
-                    .expect("link endpoints should match");
+            for (_, link) in self.links.iter().filter(|(source, _)| due[*source]) {
+                link.propagate(&mut linker).expect("link endpoints should match");
             }
         });
     }
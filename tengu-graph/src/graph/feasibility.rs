@@ -0,0 +1,121 @@
+//! Module for checking a graph against the limits of its backend before running it.
+//!
+//! This module defines the `FeasibilityReport` struct returned by `Graph::feasibility` and the
+//! `Violation` enum describing a single exceeded limit. Unlike the errors returned when the graph
+//! is computed, which stop at the first problem, the report lists every violation in the graph.
+
+use std::fmt;
+
+/// A single limit of the backend exceeded by a block of the graph.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Violation {
+    /// The block (or, with automatic splitting, a single computation of it) uses more tensors than
+    /// the backend allows in a single compute stage.
+    TooManyTensors { block: String, count: usize, limit: usize },
+    /// A tensor of the block has more elements than the backend can store in a single tensor.
+    TensorTooLarge {
+        block: String,
+        tensor: String,
+        count: usize,
+        limit: usize,
+    },
+    /// The largest tensor of the block has more elements than a single dispatch of the backend
+    /// can compute.
+    DispatchTooLarge { block: String, count: usize, limit: usize },
+}
+
+impl Violation {
+    /// Returns the label of the block exceeding the limit.
+    ///
+    /// # Returns
+    /// A reference to the block label.
+    pub fn block(&self) -> &str {
+        match self {
+            Self::TooManyTensors { block, .. } => block,
+            Self::TensorTooLarge { block, .. } => block,
+            Self::DispatchTooLarge { block, .. } => block,
+        }
+    }
+}
+
+impl fmt::Display for Violation {
+    /// Describes the violation in the same way as the errors returned when the graph is computed.
+    ///
+    /// # Parameters
+    /// - `f`: The formatter to write the description to.
+    ///
+    /// # Returns
+    /// A result indicating whether the formatting succeeded.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooManyTensors { block, count, limit } => write!(
+                f,
+                "Block {block} uses {count} tensors, but the backend allows at most {limit} in a single compute stage"
+            ),
+            Self::TensorTooLarge {
+                block,
+                tensor,
+                count,
+                limit,
+            } => write!(
+                f,
+                "Tensor {tensor} in block {block} has {count} elements, but the backend allows at most {limit}"
+            ),
+            Self::DispatchTooLarge { block, count, limit } => write!(
+                f,
+                "Block {block} computes {count} elements, but the backend dispatches at most {limit} at once"
+            ),
+        }
+    }
+}
+
+/// The result of checking a graph against the limits of its backend.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FeasibilityReport {
+    violations: Vec<Violation>,
+}
+
+impl FeasibilityReport {
+    /// Creates a new report listing the specified violations.
+    ///
+    /// # Parameters
+    /// - `violations`: The violations found in the graph.
+    ///
+    /// # Returns
+    /// A new `FeasibilityReport` instance.
+    pub(crate) fn new(violations: Vec<Violation>) -> Self {
+        Self { violations }
+    }
+
+    /// Returns whether the graph fits within all limits of the backend.
+    ///
+    /// # Returns
+    /// `true` if there are no violations, `false` otherwise.
+    pub fn is_feasible(&self) -> bool {
+        self.violations.is_empty()
+    }
+
+    /// Returns the violations found in the graph, ordered by the block labels.
+    ///
+    /// # Returns
+    /// A slice of the violations.
+    pub fn violations(&self) -> &[Violation] {
+        &self.violations
+    }
+}
+
+impl fmt::Display for FeasibilityReport {
+    /// Describes the violations, one per line.
+    ///
+    /// # Parameters
+    /// - `f`: The formatter to write the description to.
+    ///
+    /// # Returns
+    /// A result indicating whether the formatting succeeded.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for violation in &self.violations {
+            writeln!(f, "{violation}")?;
+        }
+        Ok(())
+    }
+}
@@ -10,6 +10,17 @@ use super::Graph;
 use crate::source::Source;
 use crate::{Error, Result};
 
+/// The way a link propagates the data of the source tensor into the destination tensor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LinkMode {
+    /// The destination tensor is overwritten with the source tensor (`to = from`).
+    #[default]
+    Copy,
+    /// The source tensor is added to the destination tensor element-wise (`to += from`), which is
+    /// useful for accumulating partial results across iterations.
+    Add,
+}
+
 /// A struct representing a link between two tensors in a computational graph.
 ///
 /// The `Link` struct holds the identifiers of the source and destination tensors and provides
@@ -17,6 +28,8 @@ use crate::{Error, Result};
 pub struct Link {
     from: String,
     to: String,
+    mode: LinkMode,
+    reshape: bool,
 }
 
 impl Link {
@@ -29,21 +42,32 @@ impl Link {
     /// - `graph`: A reference to the computational graph.
     /// - `from`: The identifier of the source tensor, in "block/tensor" format.
     /// - `to`: The identifier of the destination tensor, in "block/tensor" format.
+    /// - `mode`: The way the data is propagated through the link.
+    /// - `reshape`: Whether the sources may have different shapes with the same number of
+    ///   elements, in which case the data is propagated as a flat array.
     ///
     /// # Returns
     /// A result containing the created `Link` instance or an error if the sources do not match.
     ///
     /// # Errors
-    /// Returns `Error::ShapeMismatch` if the shapes of the sources do not match.
-    pub fn new<B: Backend + 'static>(graph: &Graph<B>, from: impl Into<String>, to: impl Into<String>) -> Result<Self> {
+    /// Returns `Error::ShapeMismatch` if the shapes of the sources do not match, or, with
+    /// `reshape`, if their element counts do not match.
+    pub fn new<B: Backend + 'static>(
+        graph: &Graph<B>,
+        from: impl Into<String>,
+        to: impl Into<String>,
+        mode: LinkMode,
+        reshape: bool,
+    ) -> Result<Self> {
         let from = from.into();
         let to = to.into();
-        let from_source = graph.get_source(&from)?;
-        let to_source = graph.get_source(&to)?;
-        if !from_source.matches_to(to_source)? {
-            return Err(Error::ShapeMismatch);
-        }
-        Ok(Self { from, to })
+        check_sources(graph.get_source(&from)?, graph.get_source(&to)?, reshape)?;
+        Ok(Self {
+            from,
+            to,
+            mode,
+            reshape,
+        })
     }
 
     /// Returns the identifier of the source tensor, in "block/tensor" format.
@@ -62,6 +86,67 @@ impl Link {
         &self.to
     }
 
+    /// Returns the way the data is propagated through the link.
+    ///
+    /// # Returns
+    /// The mode of the link.
+    pub fn mode(&self) -> LinkMode {
+        self.mode
+    }
+
+    /// Returns whether the link propagates the data between tensors of different shapes with the
+    /// same number of elements.
+    ///
+    /// # Returns
+    /// `true` if the link reshapes the data, `false` if the shapes have to match.
+    pub fn reshape(&self) -> bool {
+        self.reshape
+    }
+
+    /// Returns the label of the block containing the source tensor.
+    ///
+    /// # Returns
+    /// A reference to the label of the source block.
+    pub(crate) fn source_block(&self) -> &str {
+        Self::block(&self.from)
+    }
+
+    /// Returns the label of the block containing the destination tensor.
+    ///
+    /// # Returns
+    /// A reference to the label of the destination block.
+    pub(crate) fn target_block(&self) -> &str {
+        Self::block(&self.to)
+    }
+
+    /// Replaces the path of a renamed tensor in the link, if the link goes to or from it.
+    ///
+    /// # Parameters
+    /// - `old`: The path of the tensor before renaming, in "block/tensor" format.
+    /// - `new`: The path of the tensor after renaming, in "block/tensor" format.
+    pub(crate) fn rename(&mut self, old: &str, new: &str) {
+        if self.from == old {
+            self.from = new.to_string();
+        }
+        if self.to == old {
+            self.to = new.to_string();
+        }
+    }
+
+    /// Extracts the block label from a path in "block/tensor" format. The path is validated when the
+    /// link is created, so it always contains the separator.
+    ///
+    /// # Parameters
+    /// - `path`: The path to the tensor.
+    ///
+    /// # Returns
+    /// A reference to the block label.
+    fn block(path: &str) -> &str {
+        path.split_once('/')
+            .map(|(block, _)| block)
+            .expect("link path should be valid")
+    }
+
     /// Realizes the link by retrieving the source and destination nodes corresponding to tensor
     /// labels from the graph.
     ///
@@ -72,14 +157,56 @@ impl Link {
     /// - `graph`: A reference to the computational graph.
     ///
     /// # Returns
-    /// A `RealizedLink` instance containing the source and destination nodes.
+    /// A result containing a `RealizedLink` instance with the source and destination nodes, or
+    /// `Error::DanglingLink` if either of them no longer exists in the graph, e.g. because its block
+    /// has been removed.
+    pub(crate) fn realize<'a, B: Backend + 'static>(&self, graph: &'a Graph<B>) -> Result<RealizedLink<'a, B>> {
+        let dangling = |_| Error::DanglingLink {
+            from: self.from.clone(),
+            to: self.to.clone(),
+        };
+        let from = graph.get_source(&self.from).map_err(dangling)?;
+        let to = graph.get_source(&self.to).map_err(dangling)?;
+        Ok(RealizedLink::new(from, to, self.mode))
+    }
+
+    /// Checks that the tensors of the link still exist and match each other like they did when the
+    /// link was created, which may no longer be the case after blocks have been replaced.
+    ///
+    /// # Type Parameters
+    /// - `B`: The backend type.
+    ///
+    /// # Parameters
+    /// - `graph`: A reference to the computational graph.
     ///
-    /// # Panics
-    /// Panics if the source or destination nodes do not exist in the graph.
-    pub(crate) fn realize<'a, B: Backend + 'static>(&self, graph: &'a Graph<B>) -> RealizedLink<'a, B> {
-        let from = graph.get_source(&self.from).expect("link from source should exist");
-        let to = graph.get_source(&self.to).expect("link to source should exist");
-        RealizedLink::new(from, to)
+    /// # Returns
+    /// A result indicating success, `Error::DanglingLink` if either tensor no longer exists, or the
+    /// errors of `Link::new` if the tensors don't match.
+    pub(crate) fn validate<B: Backend + 'static>(&self, graph: &Graph<B>) -> Result<()> {
+        let link = self.realize(graph)?;
+        check_sources(link.from, link.to, self.reshape)
+    }
+}
+
+/// Checks that the data of one source can be propagated to another.
+///
+/// # Parameters
+/// - `from`: The source of the data.
+/// - `to`: The destination of the data.
+/// - `reshape`: Whether the sources may have different shapes with the same number of elements.
+///
+/// # Returns
+/// A result indicating success, or `Error::ShapeMismatch` if the shapes (or, with `reshape`, the
+/// element counts) of the sources do not match.
+fn check_sources<B: Backend + 'static>(from: &dyn Source<B>, to: &dyn Source<B>, reshape: bool) -> Result<()> {
+    let matches = if reshape {
+        from.matches_count_to(to)?
+    } else {
+        from.matches_to(to)?
+    };
+    match matches {
+        true => Ok(()),
+        false => Err(Error::ShapeMismatch),
     }
 }
 
@@ -91,6 +218,7 @@ impl Link {
 pub struct RealizedLink<'a, B: Backend> {
     from: &'a dyn Source<B>,
     to: &'a dyn Source<B>,
+    mode: LinkMode,
 }
 
 impl<'a, B: Backend> RealizedLink<'a, B> {
@@ -99,11 +227,12 @@ impl<'a, B: Backend> RealizedLink<'a, B> {
     /// # Parameters
     /// - `from`: The source node of the link.
     /// - `to`: The destination node of the link.
+    /// - `mode`: The way the data is propagated through the link.
     ///
     /// # Returns
     /// A new `RealizedLink` instance.
-    pub fn new(from: &'a dyn Source<B>, to: &'a dyn Source<B>) -> Self {
-        Self { from, to }
+    pub fn new(from: &'a dyn Source<B>, to: &'a dyn Source<B>, mode: LinkMode) -> Self {
+        Self { from, to, mode }
     }
 
     /// Returns a reference to the source node of the link.
@@ -121,4 +250,19 @@ impl<'a, B: Backend> RealizedLink<'a, B> {
     pub fn to(&self) -> &dyn Source<B> {
         self.to
     }
+
+    /// Propagates the data of the source node into the destination node according to the mode
+    /// of the link.
+    ///
+    /// # Parameters
+    /// - `linker`: A mutable reference to the linker.
+    ///
+    /// # Returns
+    /// A result indicating success or failure.
+    pub fn propagate(&self, linker: &mut B::Linker<'_>) -> Result<()> {
+        match self.mode {
+            LinkMode::Copy => self.from.copy(self.to, linker),
+            LinkMode::Add => self.from.accumulate(self.to, linker),
+        }
+    }
 }
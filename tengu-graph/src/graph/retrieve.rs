@@ -1,16 +1,20 @@
 //! This module defines the `Retriever` struct and associated functionality for sending tensor data to their respective probes.
+//!
+//! Retrieves of all probed tensors across all blocks are issued together: every source requests
+//! its data first, then the backend is polled once, and only then all retrieves are awaited. This
+//! way the total latency of a readback is the latency of a single poll rather than a sum of them.
 
+use futures::future::try_join_all;
 use tengu_backend::Backend;
 
-use super::Graph;
+use super::{Block, Graph};
 use crate::collector::Collector;
-use crate::graph::Block;
 use crate::Result;
 
 /// The `Retriever` struct is responsible for sending tensor data to their respective probes.
 pub struct Retriever<'a, B: Backend> {
-    blocks: Vec<&'a Block<B>>,
-    collectors: Vec<Collector<'a, B>>,
+    backend: &'a B,
+    collectors: Vec<(&'a str, Collector<'a, B>)>,
 }
 
 impl<'a, B: Backend + 'static> Retriever<'a, B> {
@@ -22,9 +26,57 @@ impl<'a, B: Backend + 'static> Retriever<'a, B> {
     /// # Returns
     /// A new `Retriever` instance.
     pub fn new(graph: &'a Graph<B>) -> Self {
-        let blocks: Vec<_> = graph.blocks.values().collect();
-        let collectors = blocks.iter().map(|block| block.collector()).collect();
-        Self { blocks, collectors }
+        let collectors = graph
+            .blocks
+            .values()
+            .map(|block| (block.label(), block.collector()))
+            .collect();
+        Self {
+            backend: graph.tengu.backend(),
+            collectors,
+        }
+    }
+
+    /// Creates a new `Retriever` instance which retrieves only the probes of the specified block.
+    ///
+    /// # Parameters
+    /// - `graph`: A reference to the computational graph.
+    /// - `block`: A reference to the block of the graph whose probes are retrieved.
+    ///
+    /// # Returns
+    /// A new `Retriever` instance.
+    pub fn for_block(graph: &'a Graph<B>, block: &'a Block<B>) -> Self {
+        Self {
+            backend: graph.tengu.backend(),
+            collectors: vec![(block.label(), block.collector())],
+        }
+    }
+
+    /// Creates a new `Retriever` instance which reads all tensors of the graph, probed or not. Only
+    /// `dump` is meant to be used with it, after the tensors have been staged, since the tensors
+    /// without probes have no channel to send their data to.
+    ///
+    /// # Parameters
+    /// - `graph`: A reference to the computational graph.
+    ///
+    /// # Returns
+    /// A new `Retriever` instance.
+    pub fn all(graph: &'a Graph<B>) -> Self {
+        let collectors = graph
+            .blocks
+            .values()
+            .map(|block| {
+                let mut collector = Collector::all();
+                for source in block.tensors() {
+                    collector.add(source);
+                }
+                (block.label(), collector)
+            })
+            .collect();
+        Self {
+            backend: graph.tengu.backend(),
+            collectors,
+        }
     }
 
     /// Retrieves data from tesnors in the graph into the associated probes.
@@ -32,9 +84,40 @@ impl<'a, B: Backend + 'static> Retriever<'a, B> {
     /// # Returns
     /// A result indicating success or failure.
     pub async fn step(&self) -> Result<()> {
-        for (block, collector) in self.blocks.iter().zip(&self.collectors) {
-            block.retrieve(collector).await?;
-        }
+        let sources = self.collectors.iter().flat_map(|(_, collector)| collector.sources());
+        let retrieves = try_join_all(sources.map(|source| source.retrieve()));
+        // NOTE: The retrieves are polled first, so all of them are issued before the backend poll.
+        let poll = async {
+            self.backend.poll();
+            Ok(())
+        };
+        futures::try_join!(retrieves, poll)?;
         Ok(())
     }
+
+    /// Reads the data of all collected tensors in the graph, i.e. the probed ones unless the
+    /// retriever was created with `all`, and encodes it in the NumPy `.npy` format. The data is
+    /// read directly from the tensors, so the probes are not affected. Like with `step`, all reads
+    /// are issued before the backend is polled.
+    ///
+    /// # Returns
+    /// A result containing the paths of the tensors in the "block/tensor" format together with the
+    /// bytes of their `.npy` files.
+    pub async fn dump(&self) -> Result<Vec<(String, Vec<u8>)>> {
+        let sources = self
+            .collectors
+            .iter()
+            .flat_map(|(block, collector)| collector.sources().map(move |source| (*block, source)));
+        let dumps = try_join_all(sources.map(|(block, source)| async move {
+            let path = format!("{block}/{}", source.label());
+            source.retrieve_npy().await.map(|bytes| (path, bytes))
+        }));
+        // NOTE: The reads are polled first, so all of them are issued before the backend poll.
+        let poll = async {
+            self.backend.poll();
+            Ok(())
+        };
+        let (dumps, _) = futures::try_join!(dumps, poll)?;
+        Ok(dumps)
+    }
 }
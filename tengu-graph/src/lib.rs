@@ -18,6 +18,7 @@
 //!
 //! # Key Modules
 //!
+//! - `cost`: Defines the heuristic cost estimate of tensor expressions.
 //! - `error`: Defines error handling mechanisms and result types used throughout the crate.
 //! - `expression`: Contains the core structures and operations for tensor expressions, including
 //!   unary and binary operations, as well as relational and arithmetic operations.
@@ -26,6 +27,8 @@
 //! - `tengu`: The main entry point of the crate, providing high-level functionalities and integration
 //!   with different backends.
 //! - `tensor`: Defines tensor structures and associated methods for manipulation and computation.
+//! - `testing`: Provides a harness asserting that the CPU and WGPU backends agree on the same
+//!   computations. Only available with the `testing` feature.
 //!
 //! # Usage
 //!
@@ -54,7 +57,8 @@
 //!     graph
 //!         .add_block("main")
 //!         .unwrap()
-//!         .add_computation("addoe", a + 1.0);
+//!         .add_computation("addoe", a + 1.0)
+//!         .unwrap();
 //!
 //!     // Set up probe.
 //!     let mut add = graph.add_probe::<f32>("main/a").unwrap();
@@ -76,8 +80,10 @@
 //! shape unifications, and backend initializations, and the error types provide detailed information to
 //! help diagnose and resolve issues.
 
+mod bench;
 mod builder;
 mod collector;
+mod cost;
 mod error;
 mod expression;
 mod graph;
@@ -85,11 +91,18 @@ mod node;
 mod shape;
 mod source;
 mod tengu;
+#[cfg(feature = "testing")]
+pub mod testing;
 mod unify;
 
+pub use bench::BenchResult;
+pub use cost::CostEstimate;
 pub use error::{Error, Result};
+pub use expression::{ErasedExpression, NormalizeMode};
+pub use graph::{FeasibilityReport, LinkMode, Violation};
+pub use shape::Shape;
 pub use tengu::Tengu;
-pub use tengu_backend_tensor::{IOType, StorageType};
+pub use tengu_backend_tensor::{CastMode, IOType, StorageType, Type};
 
 pub use tengu_backend_cpu::Backend as CPU;
 pub use tengu_backend_wgpu::Backend as WGPU;
@@ -1,12 +1,16 @@
+use std::fmt;
+
 use tengu_backend::{Backend, Processor};
 
 use crate::collector::Collector;
+use crate::cost::CostEstimate;
 use crate::shape::Shape;
 use crate::source::Source;
 
 /// A trait for AST nodes in the Tengu framework. Any expression or a tensor is a node.
 ///
-/// The `Node` trait extends the `Shape` trait and defines methods for visiting, finding, and cloning nodes.
+/// The `Node` trait extends the `Shape` trait and defines methods for visiting, finding, cloning,
+/// costing, and rendering nodes.
 pub trait Node<B: Backend>: Shape {
     /// Visits the node with a processor.
     ///
@@ -37,4 +41,46 @@ pub trait Node<B: Backend>: Shape {
     /// # Returns
     /// A boxed trait object containing the cloned node.
     fn clone_box(&self) -> Box<dyn Node<B>>;
+
+    /// Estimates the computational cost of the expression tree rooted at the node.
+    ///
+    /// # Returns
+    /// The estimated cost.
+    fn cost(&self) -> CostEstimate;
+
+    /// Returns the direct subexpressions of the node, which are empty for tensors and scalars.
+    ///
+    /// # Returns
+    /// A vector holding the subexpressions.
+    fn children(&self) -> Vec<&dyn Node<B>>;
+
+    /// Returns the depth of the expression tree rooted at the node, i.e. the number of operations
+    /// on the longest path from the node to a tensor or a scalar, which have depth 0. For example,
+    /// `exp((a + b) * c)` has depth 3.
+    ///
+    /// # Returns
+    /// The depth of the expression tree.
+    fn depth(&self) -> usize {
+        self.children().iter().map(|child| child.depth() + 1).max().unwrap_or(0)
+    }
+
+    /// Returns the number of nodes in the expression tree rooted at the node, including the tensors
+    /// and scalars. Since all nodes of a statement end up in a single shader expression on
+    /// backends like WGPU, this is a measure of the size of the emitted code.
+    ///
+    /// # Returns
+    /// The number of nodes.
+    fn node_count(&self) -> usize {
+        1 + self.children().iter().map(|child| child.node_count()).sum::<usize>()
+    }
+
+    /// Renders the expression tree rooted at the node for debugging, e.g.
+    /// `Add(Mul(Tensor("a"), Scalar(2)), Tensor("b"))`.
+    ///
+    /// # Parameters
+    /// - `f`: The formatter to write the rendered expression tree to.
+    ///
+    /// # Returns
+    /// A result indicating whether the formatting succeeded.
+    fn fmt_tree(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result;
 }
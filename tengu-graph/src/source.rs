@@ -1,8 +1,10 @@
+use std::fmt;
+
 use as_any::{AsAny, Downcast};
 use async_trait::async_trait;
 
 use tengu_backend::{Backend, Linker};
-use tengu_backend_tensor::StorageType;
+use tengu_backend_tensor::{StorageType, Type};
 use tengu_graph_tensor::Tensor;
 
 use crate::shape::Shape;
@@ -10,15 +12,22 @@ use crate::{Error, Result};
 
 /// A trait for tensors to treat the uniformly irrespective of their underlying type.
 ///
-/// The `Source` trait defines methods for matching to and copying links between sources.
+/// The `Source` trait defines methods for matching to and copying links between sources. Since
+/// every source is a tensor, it also provides its shape.
 #[async_trait(?Send)]
-pub trait Source<B: Backend>: AsAny {
+pub trait Source<B: Backend>: AsAny + Shape {
     /// Retrieves the label of the source.
     ///
     /// # Returns
     /// The label of the source.
     fn label(&self) -> &str;
 
+    /// Returns the element type of the source.
+    ///
+    /// # Returns
+    /// The `Type` variant corresponding to the storage type of the source.
+    fn dtype(&self) -> Type;
+
     /// Checks if the source matches another source.
     ///
     /// # Parameters
@@ -28,6 +37,16 @@ pub trait Source<B: Backend>: AsAny {
     /// A result containing a boolean indicating whether the sources match.
     fn matches_to(&self, other: &dyn Source<B>) -> Result<bool>;
 
+    /// Checks if the source has the same number of elements as another source, regardless of
+    /// their shapes.
+    ///
+    /// # Parameters
+    /// - `other`: The other source to match against.
+    ///
+    /// # Returns
+    /// A result containing a boolean indicating whether the element counts match.
+    fn matches_count_to(&self, other: &dyn Source<B>) -> Result<bool>;
+
     /// Copies a source tensor to another source.
     ///
     /// # Parameters
@@ -38,11 +57,47 @@ pub trait Source<B: Backend>: AsAny {
     /// A result indicating success or failure.
     fn copy(&self, to: &dyn Source<B>, linker: &mut B::Linker<'_>) -> Result<()>;
 
+    /// Adds a source tensor to another source element-wise.
+    ///
+    /// # Parameters
+    /// - `to`: The destination source accumulating the tensor.
+    /// - `linker`: A mutable reference to the linker.
+    ///
+    /// # Returns
+    /// A result indicating success or failure.
+    fn accumulate(&self, to: &dyn Source<B>, linker: &mut B::Linker<'_>) -> Result<()>;
+
     /// Retrieves data from the source tensor and sends it to all associated probes.
     ///
     /// # Returns
     /// A result indicating success or failure.
     async fn retrieve(&self) -> Result<()>;
+
+    /// Retrieves data from the source tensor encoded in the NumPy `.npy` format, without sending
+    /// it to the probes.
+    ///
+    /// # Returns
+    /// A result containing the bytes of the `.npy` file.
+    async fn retrieve_npy(&self) -> Result<Vec<u8>>;
+
+    /// Overwrites the data of the source tensor with the contents of a `.npy` file.
+    ///
+    /// # Parameters
+    /// - `bytes`: The bytes of the `.npy` file.
+    ///
+    /// # Returns
+    /// A result indicating success or failure.
+    fn write_npy(&self, bytes: &[u8]) -> Result<()>;
+}
+
+// NOTE: Display implementation.
+
+impl<B: Backend> fmt::Display for dyn Source<B> + '_ {
+    /// Formats the source as a one-line summary of its label, shape and element type, e.g.
+    /// `Tensor("a", [2, 3], f32)`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Tensor({:?}, {:?}, {})", self.label(), self.shape(), self.dtype())
+    }
 }
 
 // NOTE: Tensor implementation.
@@ -57,6 +112,14 @@ impl<T: StorageType, B: Backend + 'static> Source<B> for Tensor<T, B> {
         self.label()
     }
 
+    /// Returns the element type of the tensor.
+    ///
+    /// # Returns
+    /// The `Type` variant corresponding to the storage type of the tensor.
+    fn dtype(&self) -> Type {
+        T::as_type()
+    }
+
     /// Checks if the tensor matches the shape of another tensor.
     ///
     /// # Parameters
@@ -69,6 +132,18 @@ impl<T: StorageType, B: Backend + 'static> Source<B> for Tensor<T, B> {
         Ok(self.shape() == other.shape())
     }
 
+    /// Checks if the tensor has the same number of elements as another tensor.
+    ///
+    /// # Parameters
+    /// - `other`: Another source to compare against.
+    ///
+    /// # Returns
+    /// A result indicating whether the element counts match.
+    fn matches_count_to(&self, other: &dyn Source<B>) -> Result<bool> {
+        let other = other.downcast_ref::<Self>().ok_or_else(|| Error::TypeMismatch)?;
+        Ok(self.count() == other.count())
+    }
+
     /// Copies the data from this tensor to another tensor using the provided linker.
     ///
     /// # Parameters
@@ -83,6 +158,20 @@ impl<T: StorageType, B: Backend + 'static> Source<B> for Tensor<T, B> {
         Ok(())
     }
 
+    /// Adds the data of this tensor to another tensor using the provided linker.
+    ///
+    /// # Parameters
+    /// - `to`: The target tensor to accumulate into.
+    /// - `linker`: The linker to use for the accumulation.
+    ///
+    /// # Returns
+    /// A result indicating the success of the operation.
+    fn accumulate(&self, to: &dyn Source<B>, linker: &mut B::Linker<'_>) -> Result<()> {
+        let to = to.downcast_ref::<Self>().ok_or_else(|| Error::TypeMismatch)?;
+        linker.add_link(self.raw(), to.raw());
+        Ok(())
+    }
+
     /// Reads the tensor data from the source and sends it to associated probes.
     /// If the channel is full then there is no point wasting time on reading the data out - the
     /// previous message hasn't been read out by the probe yet. In this case the method will return
@@ -93,4 +182,25 @@ impl<T: StorageType, B: Backend + 'static> Source<B> for Tensor<T, B> {
     async fn retrieve(&self) -> Result<()> {
         self.retrieve().await.map_err(Error::TensorError)
     }
+
+    /// Reads the tensor data and encodes it in the NumPy `.npy` format. The data is not sent to
+    /// the probes, so they still receive the data read out by `retrieve`.
+    ///
+    /// # Returns
+    /// A result containing the bytes of the `.npy` file.
+    async fn retrieve_npy(&self) -> Result<Vec<u8>> {
+        self.retrieve_npy().await.map_err(Error::TensorError)
+    }
+
+    /// Overwrites the tensor data with the contents of a `.npy` file, like the ones produced by
+    /// `retrieve_npy`.
+    ///
+    /// # Parameters
+    /// - `bytes`: The bytes of the `.npy` file.
+    ///
+    /// # Returns
+    /// A result indicating the success of the operation.
+    fn write_npy(&self, bytes: &[u8]) -> Result<()> {
+        self.write_npy(bytes).map_err(Error::TensorError)
+    }
 }
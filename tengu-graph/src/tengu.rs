@@ -4,9 +4,16 @@
 //! for working with tensors and scalars. This is what user sees first when they start interacting
 //! with Tengu.
 
+use std::cell::RefCell;
 use std::rc::Rc;
-use tengu_backend::Backend;
-use tengu_backend_tensor::IOType;
+use std::time::Duration;
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use tengu_backend::{Backend, Limits};
+use tengu_backend_tensor::{IOType, StorageType, Type};
+use tengu_backend_wgpu::{Device, Retry};
+use tracing::warn;
 
 use crate::builder::Builder;
 use crate::expression::Expression;
@@ -21,6 +28,7 @@ use crate::{CPU, WGPU};
 /// and computational graphs using a specified backend.
 pub struct Tengu<B> {
     backend: Rc<B>,
+    rng: Rc<RefCell<Option<StdRng>>>,
 }
 
 impl<B: Backend + 'static> Tengu<B> {
@@ -30,17 +38,51 @@ impl<B: Backend + 'static> Tengu<B> {
     /// A result containing a reference-counted `Tengu` instance or an error.
     pub async fn new() -> Result<Rc<Self>> {
         let backend = B::new().await?;
-        Ok(Rc::new(Self { backend }))
+        Ok(Rc::new(Self {
+            backend,
+            rng: Rc::default(),
+        }))
     }
 
-    /// Returns a reference to the backend used by this Tengu instance.
+    /// Returns a reference to the backend used by this Tengu instance. This is an escape hatch for
+    /// calling backend-specific methods which Tengu doesn't wrap, e.g. getting the WGPU device to
+    /// run custom GPU work alongside the computations. Resources created through the backend
+    /// directly aren't tracked by Tengu.
     ///
     /// # Returns
     /// A reference to the backend.
-    pub(crate) fn backend(self: &Rc<Self>) -> &Rc<B> {
+    pub fn backend(self: &Rc<Self>) -> &Rc<B> {
         &self.backend
     }
 
+    /// Installs a random number generator seeded with the given value. All random tensors created
+    /// by the builders of this instance afterwards draw their data from it, so building the same
+    /// graph under the same seed produces identical random tensors.
+    ///
+    /// # Parameters
+    /// - `seed`: The seed of the random number generator.
+    pub fn seed(self: &Rc<Self>, seed: u64) {
+        *self.rng.borrow_mut() = Some(StdRng::seed_from_u64(seed));
+    }
+
+    /// Returns the maximum number of tensors a single block can use, as reported by the backend.
+    /// Blocks using more tensors than that have to be split into several blocks.
+    ///
+    /// # Returns
+    /// The maximum number of tensors in a block, or `None` if the backend has no such limit.
+    pub fn max_tensor_count(&self) -> Option<usize> {
+        self.backend.limits().max_tensor_per_compute()
+    }
+
+    /// Returns the element types supported by the backend, e.g. to reject a dtype read from a
+    /// file before building expressions with it. Using other types panics when tensors are created.
+    ///
+    /// # Returns
+    /// The element types supported by the backend.
+    pub fn supported_dtypes(&self) -> Vec<Type> {
+        self.backend.limits().supported_types()
+    }
+
     /// Creates a new tensor builder with the specified shape.
     ///
     /// # Parameters
@@ -48,9 +90,41 @@ impl<B: Backend + 'static> Tengu<B> {
     ///
     /// # Returns
     /// A `Builder` instance for creating the tensor.
+    ///
+    /// # Panics
+    /// Panics if the shape is empty or contains a zero dimension. Use `try_tensor` to handle
+    /// invalid shapes gracefully.
     pub fn tensor(self: &Rc<Self>, shape: impl Into<Vec<usize>>) -> Builder<B> {
-        Builder::new(&self.backend, shape)
+        self.try_tensor(shape).unwrap_or_else(|e| panic!("{e}"))
     }
+
+    /// Creates a new tensor builder with the specified shape, validating it first.
+    ///
+    /// # Parameters
+    /// - `shape`: The shape of the tensor.
+    ///
+    /// # Returns
+    /// A result containing a `Builder` instance for creating the tensor, or `Error::InvalidShape`
+    /// if the shape is empty or contains a zero dimension.
+    pub fn try_tensor(self: &Rc<Self>, shape: impl Into<Vec<usize>>) -> Result<Builder<B>> {
+        Builder::new(&self.backend, &self.rng, shape)
+    }
+
+    /// Creates a new tensor builder for a 1-D tensor with the specified number of elements. This is
+    /// a shortcut for `tensor([count])`.
+    ///
+    /// # Parameters
+    /// - `count`: The number of elements of the tensor.
+    ///
+    /// # Returns
+    /// A `Builder` instance for creating the tensor.
+    ///
+    /// # Panics
+    /// Panics if the count is zero.
+    pub fn vector(self: &Rc<Self>, count: usize) -> Builder<B> {
+        self.tensor([count])
+    }
+
     /// Creates a new tensor builder with the same shape as the specified expression.
     ///
     /// # Parameters
@@ -59,10 +133,25 @@ impl<B: Backend + 'static> Tengu<B> {
     /// # Returns
     /// A `Builder` instance for creating the tensor.
     pub fn like<T: IOType>(self: &Rc<Self>, expr: &Expression<T, B>) -> Builder<B> {
-        Builder::new(&self.backend, expr.shape())
+        Builder::new(&self.backend, &self.rng, expr.shape()).expect("expression shape should be valid")
+    }
+
+    /// Creates a tensor with the same shape as the specified expression and every element set to
+    /// the specified value. Unlike a scalar, which is broadcast, this is a full tensor that can be
+    /// used e.g. as the initial state of a computation output.
+    ///
+    /// # Parameters
+    /// - `expr`: An expression to match the shape.
+    /// - `value`: The value of every element of the tensor.
+    ///
+    /// # Returns
+    /// An expression representing the tensor filled with the value.
+    pub fn full_like<T: IOType, S: IOType>(self: &Rc<Self>, expr: &Expression<S, B>, value: T) -> Expression<T, B> {
+        self.like(expr).full(value)
     }
 
-    /// Creates a scalar expression.
+    /// Creates a scalar expression. The type of the scalar can be given explicitly, e.g.
+    /// `tengu.scalar::<f32>(2.0)`, when the surrounding expression doesn't pin it down.
     ///
     /// # Parameters
     /// - `scalar`: The scalar value.
@@ -73,6 +162,55 @@ impl<B: Backend + 'static> Tengu<B> {
         Expression::Scalar(scalar)
     }
 
+    /// Creates an `f32` scalar expression.
+    ///
+    /// # Parameters
+    /// - `scalar`: The scalar value.
+    ///
+    /// # Returns
+    /// An `Expression` representing the scalar.
+    pub fn scalar_f32(self: &Rc<Self>, scalar: f32) -> Expression<f32, B> {
+        self.scalar(scalar)
+    }
+
+    /// Creates an `i32` scalar expression.
+    ///
+    /// # Parameters
+    /// - `scalar`: The scalar value.
+    ///
+    /// # Returns
+    /// An `Expression` representing the scalar.
+    pub fn scalar_i32(self: &Rc<Self>, scalar: i32) -> Expression<i32, B> {
+        self.scalar(scalar)
+    }
+
+    /// Creates a `u32` scalar expression.
+    ///
+    /// # Parameters
+    /// - `scalar`: The scalar value.
+    ///
+    /// # Returns
+    /// An `Expression` representing the scalar.
+    pub fn scalar_u32(self: &Rc<Self>, scalar: u32) -> Expression<u32, B> {
+        self.scalar(scalar)
+    }
+
+    /// Creates an expression joining expressions of the same shape along a new axis, e.g. stacking
+    /// three `[2]`-shaped expressions along axis 0 gives a `[3, 2]`-shaped expression.
+    ///
+    /// # Parameters
+    /// - `exprs`: The expressions to stack, in the order they appear along the new axis.
+    /// - `axis`: The position of the new axis, from 0 up to the rank of the expressions.
+    ///
+    /// # Returns
+    /// An `Expression` representing the stacked expressions.
+    ///
+    /// # Panics
+    /// Panics if there are no expressions, if their shapes differ, or if the axis is out of bounds.
+    pub fn stack<T: StorageType>(self: &Rc<Self>, exprs: &[Expression<T, B>], axis: usize) -> Expression<T, B> {
+        Expression::stack(exprs, axis)
+    }
+
     /// Creates a new computational graph.
     ///
     /// # Returns
@@ -90,6 +228,77 @@ impl Tengu<WGPU> {
     pub async fn wgpu() -> Result<Rc<Self>> {
         Tengu::new().await
     }
+
+    /// Creates a new instance of the Tengu framework with the WGPU backend, retrying failed
+    /// adapter and device requests. The delay between attempts starts at 100ms and doubles after
+    /// every failure. If all attempts fail, the error of the first one is returned.
+    ///
+    /// # Parameters
+    /// - `attempts`: The total number of attempts, including the first one.
+    ///
+    /// # Returns
+    /// A result containing a reference-counted `Tengu` instance or an error.
+    pub async fn wgpu_with_retries(attempts: usize) -> Result<Rc<Self>> {
+        let retry = Retry::new(attempts, Duration::from_millis(100));
+        let backend = WGPU::with_retry(retry).await?;
+        Ok(Rc::new(Self {
+            backend,
+            rng: Rc::default(),
+        }))
+    }
+
+    /// Creates a new instance of the Tengu framework with the WGPU backend, falling back to a
+    /// software adapter if no hardware adapter is available, e.g. llvmpipe on headless Linux CI
+    /// runners. Computations on a software adapter run on the CPU and are much slower than on a
+    /// GPU, often slower than the CPU backend, so this is meant for exercising the WGPU backend in
+    /// tests rather than for real workloads.
+    ///
+    /// # Returns
+    /// A result containing a reference-counted `Tengu` instance, or the error of the fallback
+    /// request if neither a hardware nor a software adapter is available.
+    pub async fn wgpu_allow_fallback() -> Result<Rc<Self>> {
+        let backend = match WGPU::new().await {
+            Ok(backend) => backend,
+            Err(e) => {
+                warn!("No hardware adapter available ({e}), falling back to a software adapter");
+                WGPU::fallback().await?
+            }
+        };
+        Ok(Rc::new(Self {
+            backend,
+            rng: Rc::default(),
+        }))
+    }
+
+    /// Creates a new instance of the Tengu framework with the WGPU backend on a software fallback
+    /// adapter, even if a hardware adapter is available. See `wgpu_allow_fallback` for the
+    /// performance caveats.
+    ///
+    /// # Returns
+    /// A result containing a reference-counted `Tengu` instance or an error if there is no
+    /// software adapter.
+    pub async fn wgpu_fallback() -> Result<Rc<Self>> {
+        Ok(Rc::new(Self {
+            backend: WGPU::fallback().await?,
+            rng: Rc::default(),
+        }))
+    }
+
+    /// Creates a new instance of the Tengu framework backed by an existing WGPU device. This allows
+    /// an application to share a single device between its renderer, e.g. one created together with
+    /// a surface, and the tensor computations, so that buffers don't need to cross devices.
+    ///
+    /// # Parameters
+    /// - `device`: The shared device to run the computations on.
+    ///
+    /// # Returns
+    /// A reference-counted `Tengu` instance.
+    pub fn from_device(device: Rc<Device>) -> Rc<Self> {
+        Rc::new(Self {
+            backend: WGPU::from_device(device),
+            rng: Rc::default(),
+        })
+    }
 }
 
 impl Tengu<CPU> {
@@ -104,9 +313,37 @@ impl Tengu<CPU> {
 
 #[cfg(test)]
 mod tests {
+    use std::rc::Rc;
+
     use crate::shape::Shape;
-    use crate::Tengu;
+    use crate::{Error, Tengu};
     use pretty_assertions::assert_eq;
+    use tengu_backend::{Backend, Limits};
+
+    #[tokio::test]
+    async fn max_tensor_count_cpu() {
+        let tengu = Tengu::cpu().await.unwrap();
+        assert_eq!(tengu.max_tensor_count(), None);
+    }
+
+    #[tokio::test]
+    async fn max_tensor_count() {
+        let tengu = Tengu::wgpu().await.unwrap();
+        let limit = tengu.backend().limits().max_tensor_per_compute();
+        assert!(limit.is_some());
+        assert_eq!(tengu.max_tensor_count(), limit);
+    }
+
+    #[tokio::test]
+    async fn backend_device() {
+        let tengu = Tengu::wgpu().await.unwrap();
+        let limits = tengu.backend().device().limits();
+        assert!(limits.max_buffer_size > 0);
+        assert_eq!(
+            tengu.max_tensor_count(),
+            Some(limits.max_storage_buffers_per_shader_stage as usize)
+        );
+    }
 
     #[tokio::test]
     async fn tensor_shape() {
@@ -116,6 +353,127 @@ mod tests {
         assert_eq!(tensor.shape(), &[3, 3, 3]);
     }
 
+    #[tokio::test]
+    async fn vector_shape() {
+        let tengu = Tengu::cpu().await.unwrap();
+        let vector = tengu.vector(4).zero::<f32>();
+        assert_eq!(vector.shape(), &[4]);
+    }
+
+    #[tokio::test]
+    async fn invalid_shapes() {
+        let tengu = Tengu::cpu().await.unwrap();
+        let empty: [usize; 0] = [];
+        assert!(matches!(tengu.try_tensor(empty), Err(Error::InvalidShape(shape)) if shape.is_empty()));
+        assert!(matches!(tengu.try_tensor([0]), Err(Error::InvalidShape(shape)) if shape == [0]));
+        assert!(matches!(tengu.try_tensor([2, 0, 3]), Err(Error::InvalidShape(shape)) if shape == [2, 0, 3]));
+        assert!(tengu.try_tensor([2, 1, 3]).is_ok());
+    }
+
+    #[tokio::test]
+    async fn invalid_labels() {
+        let tengu = Tengu::cpu().await.unwrap();
+        let result = tengu.tensor([2]).try_label("my tensor");
+        assert!(matches!(result, Err(Error::InvalidLabel(label)) if label == "my tensor"));
+        let result = tengu.tensor([2]).try_label("2d");
+        assert!(matches!(result, Err(Error::InvalidLabel(label)) if label == "2d"));
+        assert!(tengu.tensor([2]).try_label("tensor_2d").is_ok());
+    }
+
+    #[tokio::test]
+    #[should_panic]
+    async fn tensor_invalid_label() {
+        let tengu = Tengu::cpu().await.unwrap();
+        tengu.tensor([2]).label("my tensor").zero::<f32>();
+    }
+
+    #[tokio::test]
+    #[should_panic]
+    async fn tensor_empty_shape() {
+        let tengu = Tengu::cpu().await.unwrap();
+        tengu.tensor(Vec::new()).zero::<f32>();
+    }
+
+    #[tokio::test]
+    async fn full_like() {
+        let tengu = Tengu::cpu().await.unwrap();
+        let a = tengu.tensor([2, 3]).zero::<i32>();
+        let full = tengu.full_like(&a, 5.0f32);
+        assert_eq!(full.shape(), &[2, 3]);
+        let mut graph = tengu.graph();
+        graph
+            .add_block("main")
+            .unwrap()
+            .add_computation("out", full * 1.0)
+            .unwrap();
+        let probe = graph.add_probe::<f32>("main/out").unwrap();
+        graph.compute(1).await.unwrap();
+        assert_eq!(probe.retrieve().await.unwrap(), [5.0; 6]);
+    }
+
+    #[tokio::test]
+    async fn from_device() {
+        let device = Rc::new(tengu_wgpu::WGPU::default_context().await.unwrap());
+        let tengu = Tengu::from_device(Rc::clone(&device));
+        assert_eq!(Rc::strong_count(&device), 2);
+        let a = tengu.tensor([4]).init(&[1.0f32, 2.0, 3.0, 4.0]);
+        let mut graph = tengu.graph();
+        graph
+            .add_block("main")
+            .unwrap()
+            .add_computation("out", a.clone() + a)
+            .unwrap();
+        let probe = graph.add_probe::<f32>("main/out").unwrap();
+        graph.compute(1).await.unwrap();
+        assert_eq!(probe.retrieve().await.unwrap(), [2.0, 4.0, 6.0, 8.0]);
+    }
+
+    #[tokio::test]
+    async fn wgpu_fallback() {
+        let tengu = Tengu::wgpu_fallback().await.unwrap();
+        let a = tengu.tensor([4]).init(&[1.0f32, 2.0, 3.0, 4.0]);
+        let mut graph = tengu.graph();
+        graph
+            .add_block("main")
+            .unwrap()
+            .add_computation("out", a.clone() * a)
+            .unwrap();
+        let probe = graph.add_probe::<f32>("main/out").unwrap();
+        graph.compute(1).await.unwrap();
+        assert_eq!(probe.retrieve().await.unwrap(), [1.0, 4.0, 9.0, 16.0]);
+    }
+
+    #[tokio::test]
+    async fn seed() {
+        let tengu = Tengu::cpu().await.unwrap();
+        let mut probes = Vec::new();
+        for _ in 0..2 {
+            tengu.seed(42);
+            let a = tengu.tensor([8]).uniform::<f32>(0.0, 1.0);
+            let b = tengu.tensor([8]).normal::<f32>(0.0, 1.0).unwrap();
+            let mut graph = tengu.graph();
+            graph.add_block("main").unwrap().add_computation("out", a + b).unwrap();
+            let probe = graph.add_probe::<f32>("main/out").unwrap();
+            graph.compute(1).await.unwrap();
+            probes.push(probe.retrieve().await.unwrap());
+        }
+        assert_eq!(probes[0], probes[1]);
+    }
+
+    #[tokio::test]
+    async fn typed_scalar() {
+        let tengu = Tengu::cpu().await.unwrap();
+        let a = tengu.tensor([2, 3]).zero::<f32>();
+        let expr = tengu.scalar::<f32>(2.0) * a.clone();
+        assert_eq!(expr.shape(), &[2, 3]);
+        let expr = tengu.scalar_f32(2.0) * a;
+        assert_eq!(expr.shape(), &[2, 3]);
+        let b = tengu.tensor([4]).zero::<u32>();
+        assert_eq!((tengu.scalar_u32(1) + b).count(), 4);
+        let c = tengu.tensor([4]).zero::<i32>();
+        assert_eq!((tengu.scalar_i32(-1) + c).count(), 4);
+    }
+
     #[tokio::test]
     async fn tensor_label() {
         let tengu = Tengu::wgpu().await.unwrap();
@@ -0,0 +1,165 @@
+//! This module provides a golden test harness asserting that the CPU and WGPU backends agree on the
+//! results of the same computations. It is only available with the `testing` feature enabled.
+//!
+//! Since a closure can't be generic over the backend, the computations are described by
+//! implementing the `Golden` trait, which builds the outputs for any backend. The harness then
+//! computes the outputs on both backends and compares them elementwise within a tolerance.
+
+use std::any::Any;
+use std::rc::Rc;
+
+use tengu_backend::Backend;
+use tengu_backend_tensor::{StorageType, Type};
+use tengu_graph_tensor::Probe;
+
+use crate::graph::Graph;
+use crate::{ErasedExpression, Error, Result, Tengu, CPU, WGPU};
+
+/// The label of the block holding the golden outputs.
+const GOLDEN_BLOCK: &str = "golden";
+
+/// A trait for describing computations that should produce the same results on every backend.
+pub trait Golden {
+    /// Builds the outputs of the computation with the given Tengu instance.
+    ///
+    /// # Parameters
+    /// - `tengu`: The Tengu instance used to create the input tensors.
+    ///
+    /// # Returns
+    /// A vector of labeled output expressions.
+    fn build<B: Backend + 'static>(&self, tengu: &Rc<Tengu<B>>) -> Vec<(String, ErasedExpression<B>)>;
+}
+
+/// Computes the outputs of the golden computation on the CPU and WGPU backends and asserts that
+/// they agree.
+///
+/// # Parameters
+/// - `golden`: The computation to run on both backends.
+/// - `tolerance`: The maximum absolute difference allowed between corresponding elements.
+///
+/// # Panics
+/// Panics if the computation fails on either backend, or if any output differs between the
+/// backends in shape or by more than the tolerance.
+pub async fn assert_backends_agree(golden: impl Golden, tolerance: f64) {
+    let cpu = outputs::<CPU>(&golden)
+        .await
+        .expect("computation should succeed on CPU");
+    let wgpu = outputs::<WGPU>(&golden)
+        .await
+        .expect("computation should succeed on WGPU");
+    for ((label, cpu), (_, wgpu)) in cpu.into_iter().zip(wgpu) {
+        assert_eq!(cpu.len(), wgpu.len(), "output '{label}' differs in length");
+        for (i, (c, w)) in cpu.into_iter().zip(wgpu).enumerate() {
+            assert!(
+                (c - w).abs() <= tolerance,
+                "output '{label}' differs at index {i}: CPU {c}, WGPU {w}"
+            );
+        }
+    }
+}
+
+/// Computes the outputs of the golden computation on the given backend.
+///
+/// # Parameters
+/// - `golden`: The computation to run.
+///
+/// # Returns
+/// A result containing the labeled outputs converted to `f64`, or an error if the computation
+/// fails.
+async fn outputs<B: Backend + 'static>(golden: &impl Golden) -> Result<Vec<(String, Vec<f64>)>> {
+    let tengu = Tengu::<B>::new().await?;
+    let mut graph = tengu.graph();
+    let exprs = golden.build(&tengu);
+    let dtypes = exprs
+        .iter()
+        .map(|(label, expr)| (label.clone(), expr.dtype()))
+        .collect::<Vec<_>>();
+    let block = graph.add_block(GOLDEN_BLOCK)?;
+    for (label, expr) in exprs {
+        block.add_erased_computation(label, expr)?;
+    }
+    let mut probes = Vec::new();
+    for (label, dtype) in dtypes {
+        let path = format!("{GOLDEN_BLOCK}/{label}");
+        let probe = match dtype {
+            Type::Bool => Output::probe::<bool, B>(&mut graph, &path)?,
+            Type::U32 => Output::probe::<u32, B>(&mut graph, &path)?,
+            Type::I32 => Output::probe::<i32, B>(&mut graph, &path)?,
+            Type::F32 => Output::probe::<f32, B>(&mut graph, &path)?,
+            Type::U16 => Output::probe::<u16, B>(&mut graph, &path)?,
+            Type::I16 => Output::probe::<i16, B>(&mut graph, &path)?,
+        };
+        probes.push((label, probe));
+    }
+    graph.compute(1).await?;
+    let mut outputs = Vec::new();
+    for (label, probe) in probes {
+        outputs.push((label, probe.retrieve().await?));
+    }
+    Ok(outputs)
+}
+
+/// A probe of an output converting the retrieved values to `f64` for comparison.
+enum Output {
+    Bool(Probe<bool>),
+    U32(Probe<u32>),
+    I32(Probe<i32>),
+    F32(Probe<f32>),
+    U16(Probe<u16>),
+    I16(Probe<i16>),
+}
+
+impl Output {
+    /// Adds a probe for the output at the given path.
+    ///
+    /// # Parameters
+    /// - `graph`: The graph holding the output.
+    /// - `path`: The path to the output in the format "block/tensor".
+    ///
+    /// # Returns
+    /// A result containing the output probe, or an error if the probe can't be added.
+    fn probe<T: StorageType, B: Backend + 'static>(graph: &mut Graph<B>, path: &str) -> Result<Self> {
+        let probe = graph.add_probe::<T>(path)?;
+        let probe: Box<dyn Any> = Box::new(probe);
+        let output = match T::as_type() {
+            Type::Bool => Self::Bool(*probe.downcast().expect("type should be bool")),
+            Type::U32 => Self::U32(*probe.downcast().expect("type should be u32")),
+            Type::I32 => Self::I32(*probe.downcast().expect("type should be i32")),
+            Type::F32 => Self::F32(*probe.downcast().expect("type should be f32")),
+            Type::U16 => Self::U16(*probe.downcast().expect("type should be u16")),
+            Type::I16 => Self::I16(*probe.downcast().expect("type should be i16")),
+        };
+        Ok(output)
+    }
+
+    /// Retrieves the output values converted to `f64`.
+    ///
+    /// # Returns
+    /// A result containing the retrieved values, or an error if the retrieval fails.
+    async fn retrieve(&self) -> Result<Vec<f64>> {
+        match self {
+            Self::Bool(probe) => retrieve(probe).await,
+            Self::U32(probe) => retrieve(probe).await,
+            Self::I32(probe) => retrieve(probe).await,
+            Self::F32(probe) => retrieve(probe).await,
+            Self::U16(probe) => retrieve(probe).await,
+            Self::I16(probe) => retrieve(probe).await,
+        }
+    }
+}
+
+/// Retrieves the values of a probe converted to `f64`.
+///
+/// # Parameters
+/// - `probe`: The probe to retrieve.
+///
+/// # Returns
+/// A result containing the retrieved values, or an error if the retrieval fails.
+async fn retrieve<T>(probe: &Probe<T>) -> Result<Vec<f64>>
+where
+    T: StorageType,
+    T::IOType: Into<f64>,
+{
+    let values = probe.retrieve().await.map_err(Error::TensorError)?;
+    Ok(values.into_iter().map(Into::into).collect())
+}
@@ -0,0 +1,95 @@
+use std::rc::Rc;
+
+use anyhow::Result;
+use pretty_assertions::assert_eq;
+
+use tengu_backend::Backend;
+use tengu_graph::{LinkMode, Tengu, CPU, WGPU};
+
+#[tokio::test]
+async fn wgpu() {
+    assert_eq!(
+        run::<WGPU>().await.unwrap(),
+        [[0.0, 0.0], [1.0, 2.0], [3.0, 6.0], [6.0, 12.0]]
+    );
+}
+
+#[tokio::test]
+async fn cpu() {
+    assert_eq!(
+        run::<CPU>().await.unwrap(),
+        [[0.0, 0.0], [1.0, 2.0], [3.0, 6.0], [6.0, 12.0]]
+    );
+}
+
+#[tokio::test]
+async fn init_destination_wgpu() {
+    let tengu = Tengu::<WGPU>::new().await.unwrap();
+    let stats = tengu.backend().stats();
+    let mut pipelines = Vec::new();
+    let results = init_destination(&tengu, || pipelines.push(stats.pipelines()))
+        .await
+        .unwrap();
+    assert_eq!(results, [[10.0, 20.0], [11.0, 22.0], [12.0, 24.0]]);
+
+    // The accumulation pipeline is built on the first propagation and reused afterwards.
+    assert!(pipelines.windows(2).all(|w| w[0] == w[1]));
+}
+
+#[tokio::test]
+async fn init_destination_cpu() {
+    let tengu = Tengu::<CPU>::new().await.unwrap();
+    let results = init_destination(&tengu, || {}).await.unwrap();
+    assert_eq!(results, [[10.0, 20.0], [11.0, 22.0], [12.0, 24.0]]);
+}
+
+/// Accumulates into an initialized input tensor, which is only read by the computations.
+async fn init_destination<B: Backend + 'static>(tengu: &Rc<Tengu<B>>, mut step: impl FnMut()) -> Result<Vec<Vec<f32>>> {
+    let addend = tengu.tensor([2]).label("addend").init(&[1.0, 2.0]);
+    let acc = tengu.tensor([2]).label("acc").init(&[10.0, 20.0]);
+    let mut graph = tengu.graph();
+    graph.add_block("fst")?.add_computation("next", addend * 1.0)?;
+    graph.add_block("snd")?.add_computation("out", acc * 1.0)?;
+    graph.add_link_with_mode("fst/next", "snd/acc", LinkMode::Add)?;
+    let probe = graph.add_probe::<f32>("snd/out")?;
+    let mut results = Vec::new();
+    for _ in 0..3 {
+        graph.compute(1).await?;
+        step();
+        results.push(probe.retrieve().await?);
+    }
+    Ok(results)
+}
+
+async fn run<B: Backend + 'static>() -> Result<Vec<Vec<f32>>> {
+    // Initialize input tensors.
+    let tengu = Tengu::<B>::new().await?;
+    let step = tengu.tensor([2]).label("step").init(&[1.0, 2.0]);
+    let counter = tengu.tensor([2]).label("counter").zero::<f32>();
+    let acc = tengu.tensor([2]).label("acc").zero::<f32>();
+
+    // Create computation graph. The source of the accumulating link grows on every iteration,
+    // so that the destination holds the sum of all sources propagated so far.
+    let mut graph = tengu.graph();
+    graph.add_block("fst")?.add_computation("next", counter + step)?;
+    graph.add_block("snd")?.add_computation("out", acc * 1.0)?;
+    graph.add_link("fst/next", "fst/counter")?;
+    let link = graph.add_link_with_mode("fst/next", "snd/acc", LinkMode::Add)?;
+    assert_eq!(link.mode(), LinkMode::Add);
+
+    // Set up probes.
+    let probe = graph.add_probe::<f32>("snd/out")?;
+
+    // Run the computation three times. The output of every iteration sees the accumulated value
+    // before the propagation of that iteration.
+    let mut results = Vec::new();
+    for _ in 0..3 {
+        graph.compute(1).await?;
+        results.push(probe.retrieve().await?);
+    }
+
+    // Compute the second block once more to observe the sum of all three sources.
+    graph.compute_block("snd", 1).await?;
+    results.push(probe.retrieve().await?);
+    Ok(results)
+}
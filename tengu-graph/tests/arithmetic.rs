@@ -15,8 +15,11 @@ async fn main() {
         .add_block("main")
         .unwrap()
         .add_computation("addmul", a.clone() * b.clone() + 1.0)
+        .unwrap()
         .add_computation("subdiv", tengu.scalar(2.0) * b.clone() - a.clone() / c)
-        .add_computation("explog", (a.exp() + b.log()).cast::<u32>());
+        .unwrap()
+        .add_computation("explog", (a.exp() + b.log()).cast::<u32>())
+        .unwrap();
 
     // Set up probes.
     let add = graph.add_probe::<f32>("main/addmul").unwrap();
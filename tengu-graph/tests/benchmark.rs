@@ -0,0 +1,27 @@
+use anyhow::Result;
+use pretty_assertions::assert_eq;
+
+use tengu_backend::Backend;
+use tengu_graph::{Tengu, CPU, WGPU};
+
+#[tokio::test]
+async fn benchmark_cpu() {
+    run::<CPU>().await.unwrap();
+}
+
+#[tokio::test]
+async fn benchmark_wgpu() {
+    run::<WGPU>().await.unwrap();
+}
+
+async fn run<B: Backend + 'static>() -> Result<()> {
+    let tengu = Tengu::<B>::new().await?;
+    let a = tengu.tensor([1024]).label("a").init(&vec![1.0; 1024]);
+    let mut graph = tengu.graph();
+    graph.add_block("main")?.add_computation("b", a.exp())?;
+    let result = graph.benchmark(2, 5).await?;
+    assert_eq!(result.samples().len(), 5);
+    assert!(result.min() <= result.mean());
+    assert!(result.mean() <= result.max());
+    Ok(())
+}
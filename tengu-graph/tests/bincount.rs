@@ -0,0 +1,50 @@
+use pretty_assertions::assert_eq;
+use tengu_backend::Error as BackendError;
+use tengu_graph::{Error, Tengu};
+
+#[tokio::test]
+async fn main() {
+    // Initialize input tensors.
+    let tengu = Tengu::cpu().await.unwrap();
+    let a = tengu
+        .tensor([2, 5])
+        .label("a")
+        .init(&[0.1, 0.4, 0.6, 1.2, 1.7, 1.9, 2.5, 3.99, -0.5, 4.0]);
+
+    // Create computation graph.
+    let mut graph = tengu.graph();
+    graph
+        .add_block("main")
+        .unwrap()
+        .add_computation("counts", a.bincount(4, 0.0, 4.0))
+        .unwrap();
+
+    // Set up probes.
+    let probe = graph.add_probe::<u32>("main/counts").unwrap();
+
+    // Run one step of computation.
+    graph.compute(1).await.unwrap();
+
+    // Retrieve result and assert. Values outside of [0, 4) are ignored.
+    let data = probe.retrieve().await.unwrap();
+    assert_eq!(data, [3, 3, 1, 1]);
+}
+
+#[tokio::test]
+async fn nested_wgpu() {
+    let tengu = Tengu::wgpu().await.unwrap();
+    let a = tengu.tensor([4]).label("a").init(&[0.5, 1.5, 1.7, 3.2]);
+    let mut graph = tengu.graph();
+    graph
+        .add_block("main")
+        .unwrap()
+        .add_computation("counts", a.bincount(4, 0.0, 4.0) + 1u32)
+        .unwrap();
+
+    // The bins can't be incremented atomically inside another expression.
+    let result = graph.compute(1).await;
+    assert!(matches!(
+        result,
+        Err(Error::BackendError(BackendError::UnsupportedExpression(_)))
+    ));
+}
@@ -0,0 +1,34 @@
+use anyhow::Result;
+use pretty_assertions::assert_eq;
+
+use tengu_backend::Backend;
+use tengu_graph::{Tengu, CPU, WGPU};
+
+#[tokio::test]
+async fn bool_io_cpu() {
+    let (mask, gt) = run::<CPU>().await.unwrap();
+    assert_eq!(mask, vec![true, false, true, false]);
+    assert_eq!(gt, vec![false, false, true, true]);
+}
+
+#[tokio::test]
+async fn bool_io_wgpu() {
+    let (mask, gt) = run::<WGPU>().await.unwrap();
+    assert_eq!(mask, vec![true, false, true, false]);
+    assert_eq!(gt, vec![false, false, true, true]);
+}
+
+async fn run<B: Backend + 'static>() -> Result<(Vec<bool>, Vec<bool>)> {
+    let tengu = Tengu::<B>::new().await?;
+    let a = tengu.tensor([4]).label("a").init::<bool>(&[true, false, true, false]);
+    let x = tengu.tensor([4]).label("x").init(&[1.0, 2.0, 3.0, 4.0]);
+    let mut graph = tengu.graph();
+    graph
+        .add_block("main")?
+        .add_computation("mask", a)?
+        .add_computation("gt", x.gt(tengu.scalar(2.5)))?;
+    let mask = graph.add_probe::<bool>("main/mask")?;
+    let gt = graph.add_probe::<bool>("main/gt")?;
+    graph.compute(1).await?;
+    Ok((mask.retrieve().await?, gt.retrieve().await?))
+}
@@ -0,0 +1,36 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+
+use pretty_assertions::assert_eq;
+use tengu_graph::Tengu;
+
+#[tokio::test]
+async fn main() {
+    // Initialize input tensors.
+    let tengu = Tengu::cpu().await.unwrap();
+    let a = tengu.tensor([2, 2]).label("a").init(&[1, 2, 3, 4]);
+    let b = tengu.tensor([2, 2]).label("b").init(&[5, 6, 7, 8]);
+
+    // Create computation graph.
+    let mut graph = tengu.graph();
+    graph.add_block("main").unwrap().add_computation("out", a + b).unwrap();
+
+    // Set up probe.
+    let probe = graph.add_probe::<i32>("main/out").unwrap();
+
+    // Cancel the computation from another thread as soon as the first iteration is retrieved.
+    let token = Arc::new(AtomicBool::new(false));
+    let canceller = {
+        let token = Arc::clone(&token);
+        thread::spawn(move || {
+            let data = pollster::block_on(probe.retrieve()).unwrap();
+            token.store(true, Ordering::Relaxed);
+            data
+        })
+    };
+
+    // Without cancellation this would never return.
+    graph.compute_cancellable(usize::MAX, &token).await.unwrap();
+    assert_eq!(canceller.join().unwrap(), [6, 8, 10, 12]);
+}
@@ -0,0 +1,30 @@
+use anyhow::Result;
+use pretty_assertions::assert_eq;
+
+use tengu_backend::Backend;
+use tengu_graph::{Tengu, CPU, WGPU};
+
+#[tokio::test]
+async fn cast_init_cpu() {
+    assert_eq!(run::<CPU>().await.unwrap(), (vec![1.0, 2.0], vec![1, -2]));
+}
+
+#[tokio::test]
+async fn cast_init_wgpu() {
+    assert_eq!(run::<WGPU>().await.unwrap(), (vec![1.0, 2.0], vec![1, -2]));
+}
+
+async fn run<B: Backend + 'static>() -> Result<(Vec<f32>, Vec<i32>)> {
+    let tengu = Tengu::<B>::new().await?;
+    let a = tengu.tensor([2]).label("a").cast_init::<f64, f32>(&[1.0, 2.0]);
+    let b = tengu.tensor([2]).label("b").cast_init::<f64, i32>(&[1.7, -2.5]);
+    let mut graph = tengu.graph();
+    graph
+        .add_block("main")?
+        .add_computation("a_out", a)?
+        .add_computation("b_out", b)?;
+    let a = graph.add_probe::<f32>("main/a_out")?;
+    let b = graph.add_probe::<i32>("main/b_out")?;
+    graph.compute(1).await?;
+    Ok((a.retrieve().await?, b.retrieve().await?))
+}
@@ -0,0 +1,73 @@
+use anyhow::Result;
+use pretty_assertions::assert_eq;
+
+use tengu_backend::Backend;
+use tengu_graph::{CastMode, Tengu, CPU, WGPU};
+
+const SATURATED: [u32; 4] = [300, 4294967295, 0, 0];
+const WRAPPED: [u32; 4] = [300, 705032704, 4294967291, 4294967295];
+
+#[tokio::test]
+async fn saturate_cpu() {
+    assert_eq!(run::<CPU>(CastMode::Saturate).await.unwrap(), SATURATED);
+}
+
+#[tokio::test]
+async fn saturate_wgpu() {
+    assert_eq!(run::<WGPU>(CastMode::Saturate).await.unwrap(), SATURATED);
+}
+
+#[tokio::test]
+async fn wrap_cpu() {
+    assert_eq!(run::<CPU>(CastMode::Wrap).await.unwrap(), WRAPPED);
+}
+
+#[tokio::test]
+async fn wrap_wgpu() {
+    assert_eq!(run::<WGPU>(CastMode::Wrap).await.unwrap(), WRAPPED);
+}
+
+#[tokio::test]
+async fn default_cpu() {
+    assert_eq!(run_default::<CPU>().await.unwrap(), [300, 4294967295, 0, 4294967295]);
+}
+
+#[tokio::test]
+async fn default_wgpu() {
+    assert_eq!(run_default::<WGPU>().await.unwrap(), [300, 4294967295, 0, 4294967295]);
+}
+
+/// Casts without a mode, so floats saturate while integers wrap.
+async fn run_default<B: Backend + 'static>() -> Result<Vec<u32>> {
+    let tengu = Tengu::<B>::new().await?;
+    let a = tengu.tensor([3]).label("a").init(&[300.0, 5e9, -5.0]);
+    let b = tengu.tensor([1]).label("b").init(&[-1]);
+    let mut graph = tengu.graph();
+    graph
+        .add_block("main")?
+        .add_computation("floats", a.cast::<u32>())?
+        .add_computation("ints", b.cast::<u32>())?;
+    let floats = graph.add_probe::<u32>("main/floats")?;
+    let ints = graph.add_probe::<u32>("main/ints")?;
+    graph.compute(1).await?;
+    let mut values = floats.retrieve().await?;
+    values.extend(ints.retrieve().await?);
+    Ok(values)
+}
+
+async fn run<B: Backend + 'static>(mode: CastMode) -> Result<Vec<u32>> {
+    let tengu = Tengu::<B>::new().await?;
+    let a = tengu.tensor([3]).label("a").init(&[300.0, 5e9, -5.0]);
+    let b = tengu.tensor([1]).label("b").init(&[-1]);
+    let mut graph = tengu.graph();
+    graph
+        .add_block("main")?
+        .add_computation("floats", a.cast_with_mode::<u32>(mode))?
+        .add_computation("ints", b.cast_with_mode::<u32>(mode))?;
+    let floats = graph.add_probe::<u32>("main/floats")?;
+    let ints = graph.add_probe::<u32>("main/ints")?;
+    graph.compute(1).await?;
+    let mut values = floats.retrieve().await?;
+    values.extend(ints.retrieve().await?);
+    Ok(values)
+}
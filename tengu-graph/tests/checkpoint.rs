@@ -0,0 +1,94 @@
+use std::fs;
+use std::path::Path;
+
+use anyhow::Result;
+use pretty_assertions::assert_eq;
+
+use tengu_backend::Backend;
+use tengu_graph::{Error, Tengu, CPU, WGPU};
+
+#[tokio::test]
+async fn checkpoint_cpu() {
+    run::<CPU>("cpu").await.unwrap();
+}
+
+#[tokio::test]
+async fn checkpoint_wgpu() {
+    run::<WGPU>("wgpu").await.unwrap();
+}
+
+#[tokio::test]
+async fn state_mismatch() {
+    let dir = std::env::temp_dir().join(format!("tengu_state_mismatch_{}", std::process::id()));
+    let tengu = Tengu::cpu().await.unwrap();
+    let x = tengu.tensor([3]).label("x").zero::<f32>();
+    let mut graph = tengu.graph();
+    graph
+        .add_block("main")
+        .unwrap()
+        .add_computation("out", x * 2.0)
+        .unwrap();
+    graph.save_state(&dir).await.unwrap();
+
+    // The graphs have the same tensors, but they compute different expressions.
+    let x = tengu.tensor([3]).label("x").zero::<f32>();
+    let mut other = tengu.graph();
+    other
+        .add_block("main")
+        .unwrap()
+        .add_computation("out", x + 1.0)
+        .unwrap();
+    let result = other.load_state(&dir);
+    assert!(matches!(result, Err(Error::StateMismatch(_))));
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+async fn run<B: Backend + 'static>(name: &str) -> Result<()> {
+    let dir = std::env::temp_dir().join(format!("tengu_checkpoint_{name}_{}", std::process::id()));
+
+    // Create the iteration x <- 2x + 1, labeling the tensors so that a fresh graph has the same
+    // structure as the saved one.
+    let tengu = Tengu::<B>::new().await?;
+    let build = || -> Result<_> {
+        let x = tengu.tensor([3]).label("x").init(&[1.0, 3.0, 5.0]);
+        let mut graph = tengu.graph();
+        graph.add_block("main")?.add_computation("out", x * 2.0 + 1.0)?;
+        graph.add_link("main/out", "main/x")?;
+        Ok(graph)
+    };
+
+    // Run it five times without interruption.
+    let graph = build()?;
+    graph.compute(5).await?;
+    graph.save_state(dir.join("uninterrupted")).await?;
+
+    // Run it three times, save the state and continue in a fresh graph for the remaining two.
+    let graph = build()?;
+    graph.compute(3).await?;
+    graph.save_state(dir.join("checkpoint")).await?;
+    let mut resumed = build()?;
+    resumed.load_state(dir.join("checkpoint"))?;
+    resumed.compute(2).await?;
+    resumed.save_state(dir.join("resumed")).await?;
+
+    // The resumed run ends in the same state as the uninterrupted one.
+    for file in ["graph.txt", "main/x.npy", "main/out.npy"] {
+        assert_eq!(
+            fs::read(dir.join("resumed").join(file))?,
+            fs::read(dir.join("uninterrupted").join(file))?
+        );
+    }
+    assert_eq!(read_f32(&dir.join("resumed/main/out.npy"))?, [63.0, 127.0, 191.0]);
+    fs::remove_dir_all(&dir)?;
+    Ok(())
+}
+
+fn read_f32(path: &Path) -> Result<Vec<f32>> {
+    let bytes = fs::read(path)?;
+    let header_len = u16::from_le_bytes([bytes[8], bytes[9]]) as usize;
+    let data = bytes[10 + header_len..]
+        .chunks_exact(4)
+        .map(|chunk| f32::from_le_bytes(chunk.try_into().unwrap()))
+        .collect();
+    Ok(data)
+}
@@ -0,0 +1,38 @@
+use anyhow::Result;
+use pretty_assertions::assert_eq;
+
+use tengu_backend::Backend;
+use tengu_graph::{Tengu, CPU, WGPU};
+
+#[tokio::test]
+async fn cpu() {
+    run::<CPU>().await.unwrap();
+}
+
+#[tokio::test]
+async fn wgpu() {
+    run::<WGPU>().await.unwrap();
+}
+
+async fn run<B: Backend + 'static>() -> Result<()> {
+    // Initialize an input tensor with values of mixed signs.
+    let tengu = Tengu::<B>::new().await?;
+    let a = tengu.tensor([6]).label("a").init(&[-3.0, -0.5, 0.0, 0.5, 2.0, 4.0]);
+
+    // Create computation graph.
+    let mut graph = tengu.graph();
+    graph
+        .add_block("main")?
+        .add_computation("floor", a.clone().clamp_min(0.0))?
+        .add_computation("ceiling", a.clamp_max(1.0))?;
+
+    // Set up probes.
+    let floor = graph.add_probe::<f32>("main/floor")?;
+    let ceiling = graph.add_probe::<f32>("main/ceiling")?;
+
+    // Run the computation and check the results.
+    graph.compute(1).await?;
+    assert_eq!(floor.retrieve().await?, vec![0.0, 0.0, 0.0, 0.5, 2.0, 4.0]);
+    assert_eq!(ceiling.retrieve().await?, vec![-3.0, -0.5, 0.0, 0.5, 1.0, 1.0]);
+    Ok(())
+}
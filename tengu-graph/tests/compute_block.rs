@@ -0,0 +1,61 @@
+use futures::FutureExt;
+use pretty_assertions::assert_eq;
+
+use tengu_graph::{Error, Tengu};
+
+#[tokio::test]
+async fn single_block() {
+    let tengu = Tengu::cpu().await.unwrap();
+    let mut graph = tengu.graph();
+    let a = tengu.tensor([3]).label("a").init(&[1.0, 2.0, 3.0]);
+    let b = tengu.tensor([3]).label("b").init(&[4.0, 5.0, 6.0]);
+    graph.add_block("first").unwrap().add_computation("out", a + b).unwrap();
+    let c = tengu.tensor([3]).label("c").init(&[1.0, 1.0, 1.0]);
+    let d = tengu.tensor([3]).label("d").init(&[2.0, 2.0, 2.0]);
+    graph
+        .add_block("second")
+        .unwrap()
+        .add_computation("out", c * d)
+        .unwrap();
+    let first = graph.add_probe::<f32>("first/out").unwrap();
+    let second = graph.add_probe::<f32>("second/out").unwrap();
+
+    graph.compute_block("first", 1).await.unwrap();
+    assert_eq!(first.retrieve().await.unwrap(), vec![5.0, 7.0, 9.0]);
+    assert!(second.retrieve().now_or_never().is_none());
+}
+
+#[tokio::test]
+async fn dependency_not_computed() {
+    let tengu = Tengu::cpu().await.unwrap();
+    let mut graph = tengu.graph();
+    let a = tengu.tensor([3]).label("a").init(&[1.0, 2.0, 3.0]);
+    let b = tengu.tensor([3]).label("b").init(&[4.0, 5.0, 6.0]);
+    graph.add_block("first").unwrap().add_computation("out", a + b).unwrap();
+    let c = tengu.tensor([3]).label("c").zero::<f32>();
+    let d = tengu.tensor([3]).label("d").init(&[2.0, 2.0, 2.0]);
+    graph
+        .add_block("second")
+        .unwrap()
+        .add_computation("out", c * d)
+        .unwrap();
+    graph.add_link("first/out", "second/c").unwrap();
+    let second = graph.add_probe::<f32>("second/out").unwrap();
+
+    let result = graph.compute_block("second", 1).await;
+    assert!(
+        matches!(result, Err(Error::DependencyNotComputed(block, dependency)) if block == "second" && dependency == "first")
+    );
+
+    graph.compute_block("first", 1).await.unwrap();
+    graph.compute_block("second", 1).await.unwrap();
+    assert_eq!(second.retrieve().await.unwrap(), vec![10.0, 14.0, 18.0]);
+}
+
+#[tokio::test]
+async fn unknown_block() {
+    let tengu = Tengu::cpu().await.unwrap();
+    let graph = tengu.graph();
+    let result = graph.compute_block("missing", 1).await;
+    assert!(matches!(result, Err(Error::BlockNotFound(label)) if label == "missing"));
+}
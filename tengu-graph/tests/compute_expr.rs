@@ -0,0 +1,35 @@
+use pretty_assertions::assert_eq;
+
+use tengu_graph::Tengu;
+
+#[tokio::test]
+async fn default_block() {
+    let tengu = Tengu::cpu().await.unwrap();
+    let mut graph = tengu.graph();
+    let a = tengu.tensor([3]).label("a").init(&[1.0, 2.0, 3.0]);
+    let b = tengu.tensor([3]).label("b").init(&[4.0, 5.0, 6.0]);
+    let path = graph.compute_expr("total", a.clone() + b.clone()).unwrap();
+    assert_eq!(path, "default/total");
+    graph.compute_expr("product", a * b).unwrap();
+    let sum = graph.add_probe::<f32>("default/total").unwrap();
+    let product = graph.add_probe::<f32>("default/product").unwrap();
+    graph.compute(1).await.unwrap();
+    assert_eq!(sum.retrieve().await.unwrap(), vec![5.0, 7.0, 9.0]);
+    assert_eq!(product.retrieve().await.unwrap(), vec![4.0, 10.0, 18.0]);
+}
+
+#[tokio::test]
+async fn with_explicit_blocks() {
+    let tengu = Tengu::cpu().await.unwrap();
+    let mut graph = tengu.graph();
+    let a = tengu.tensor([2]).label("a").init(&[1, 2]);
+    let b = tengu.tensor([2]).label("b").init(&[3, 4]);
+    graph.add_block("main").unwrap().add_computation("out", a + b).unwrap();
+    let c = tengu.tensor([2]).label("c").init(&[5, 6]);
+    graph.compute_expr("out", c.clone() * c).unwrap();
+    let main = graph.add_probe::<i32>("main/out").unwrap();
+    let default = graph.add_probe::<i32>("default/out").unwrap();
+    graph.compute(1).await.unwrap();
+    assert_eq!(main.retrieve().await.unwrap(), vec![4, 6]);
+    assert_eq!(default.retrieve().await.unwrap(), vec![25, 36]);
+}
@@ -0,0 +1,39 @@
+use tengu_backend::Error as BackendError;
+use tengu_graph::{Error, Tengu};
+
+#[tokio::test]
+async fn wgpu() {
+    // Initialize input tensors.
+    let tengu = Tengu::wgpu().await.unwrap();
+    let a = tengu.tensor([1024]).label("a").init(&vec![1.0; 1024]);
+    let b = tengu.tensor([1024]).label("b").init(&vec![2.0; 1024]);
+
+    // Create computation graph.
+    let mut graph = tengu.graph();
+    graph
+        .add_block("main")
+        .unwrap()
+        .add_computation("c", (a * b).exp())
+        .unwrap();
+
+    // Run the computation, skipping the test on devices without timestamp queries. There are more
+    // iterations than the timer has slots, so its timestamps are read back in the meantime too.
+    match graph.compute_timed(300).await {
+        Ok(elapsed) => assert!(!elapsed.is_zero()),
+        Err(Error::BackendError(BackendError::TimestampQueryUnsupported)) => {}
+        Err(e) => panic!("unexpected error: {e}"),
+    }
+}
+
+#[tokio::test]
+async fn cpu() {
+    let tengu = Tengu::cpu().await.unwrap();
+    let a = tengu.tensor([4]).label("a").init(&[1.0, 2.0, 3.0, 4.0]);
+    let mut graph = tengu.graph();
+    graph.add_block("main").unwrap().add_computation("b", a.exp()).unwrap();
+    let result = graph.compute_timed(1).await;
+    assert!(matches!(
+        result,
+        Err(Error::BackendError(BackendError::TimestampQueryUnsupported))
+    ));
+}
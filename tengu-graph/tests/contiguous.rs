@@ -0,0 +1,18 @@
+use pretty_assertions::assert_eq;
+
+use tengu_graph::Tengu;
+
+#[tokio::test]
+async fn contiguous_data() {
+    let tengu = Tengu::cpu().await.unwrap();
+    let a = tengu.tensor([2, 3]).label("a").init(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    let mut graph = tengu.graph();
+    graph
+        .add_block("main")
+        .unwrap()
+        .add_computation("out", (a.clone() + a).contiguous())
+        .unwrap();
+    let out = graph.add_probe::<f32>("main/out").unwrap();
+    graph.compute(1).await.unwrap();
+    assert_eq!(out.retrieve().await.unwrap(), vec![2.0, 4.0, 6.0, 8.0, 10.0, 12.0]);
+}
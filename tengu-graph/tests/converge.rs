@@ -0,0 +1,89 @@
+use anyhow::Result;
+use pretty_assertions::assert_eq;
+
+use tengu_backend::Backend;
+use tengu_graph::{Error, Tengu, CPU, WGPU};
+
+#[tokio::test]
+async fn converge_cpu() {
+    assert_eq!(run::<CPU>().await.unwrap(), 11);
+}
+
+#[tokio::test]
+async fn converge_wgpu() {
+    assert_eq!(run::<WGPU>().await.unwrap(), 11);
+}
+
+#[tokio::test]
+async fn missing_probe() {
+    let tengu = Tengu::cpu().await.unwrap();
+    let a = tengu.tensor([2]).label("a").zero::<f32>();
+    let mut graph = tengu.graph();
+    graph
+        .add_block("main")
+        .unwrap()
+        .add_computation("out", a.clone() + a)
+        .unwrap();
+    let result = graph.compute_until_converged("main/out", 1e-3, 10).await;
+    assert!(matches!(result, Err(Error::ProbeNotFound(path)) if path == "main/out"));
+}
+
+#[tokio::test]
+async fn diverging() {
+    let tengu = Tengu::cpu().await.unwrap();
+    let x = tengu.tensor([2]).label("x").zero::<f32>();
+
+    // Dividing zero by itself gives NaN, which must never count as converged.
+    let mut graph = tengu.graph();
+    graph
+        .add_block("main")
+        .unwrap()
+        .add_computation("out", x.clone() / x)
+        .unwrap();
+    graph.add_link("main/out", "main/x").unwrap();
+    graph.add_probe::<f32>("main/out").unwrap();
+    let iterations = graph.compute_until_converged("main/out", 1e-3, 10).await.unwrap();
+    assert_eq!(iterations, 10);
+}
+
+#[tokio::test]
+async fn probe_untouched() {
+    let tengu = Tengu::cpu().await.unwrap();
+    let x = tengu.tensor([2]).label("x").zero::<f32>();
+    let half = tengu.tensor([2]).label("half").init(&[0.5, 0.5]);
+    let mut graph = tengu.graph();
+    graph
+        .add_block("main")
+        .unwrap()
+        .add_computation("out", x * half)
+        .unwrap();
+    graph.add_link("main/out", "main/x").unwrap();
+    let probe = graph.add_probe::<f32>("main/out").unwrap();
+
+    // The probe still receives the data read out by the last iteration.
+    let iterations = graph.compute_until_converged("main/out", 1e-3, 10).await.unwrap();
+    assert_eq!(iterations, 2);
+    assert!(probe.is_dirty());
+    assert_eq!(probe.retrieve().await.unwrap(), [0.0, 0.0]);
+}
+
+async fn run<B: Backend + 'static>() -> Result<usize> {
+    // Initialize input tensors.
+    let tengu = Tengu::<B>::new().await?;
+    let x = tengu.tensor([2]).label("x").zero::<f32>();
+    let half = tengu.tensor([2]).label("half").init(&[0.5, 0.5]);
+    let one = tengu.tensor([2]).label("one").init(&[1.0, 1.0]);
+
+    // Create the contracting iteration x <- x / 2 + 1, whose fixed point is 2. The difference
+    // between consecutive iterations halves every time, starting from 0.5 after the second one.
+    let mut graph = tengu.graph();
+    graph.add_block("main")?.add_computation("out", x * half + one)?;
+    graph.add_link("main/out", "main/x")?;
+    graph.add_probe::<f32>("main/out")?;
+
+    // Run until the difference falls below the tolerance.
+    let max_iters = 100;
+    let iterations = graph.compute_until_converged("main/out", 1e-3, max_iters).await?;
+    assert!(iterations < max_iters);
+    Ok(iterations)
+}
@@ -11,6 +11,14 @@ async fn main() {
     assert_eq!(result_wgpu, result_cpu);
 }
 
+#[tokio::test]
+async fn scalar_broadcast() {
+    let result_wgpu = run_scalar::<WGPU>().await.unwrap();
+    let result_cpu = run_scalar::<CPU>().await.unwrap();
+    assert_eq!(result_wgpu, result_cpu);
+    assert_eq!(result_cpu, (vec![2.0, 4.0, 6.0, 8.0], vec![2.0, 1.0, 0.0, -1.0]));
+}
+
 async fn run<B: Backend + 'static>() -> Result<Vec<i32>> {
     // Initialize input tensors.
     let tengu = Tengu::<B>::new().await?;
@@ -19,7 +27,7 @@ async fn run<B: Backend + 'static>() -> Result<Vec<i32>> {
 
     // Create computation graph.
     let mut graph = tengu.graph();
-    graph.add_block("main").unwrap().add_computation("add", a * b);
+    graph.add_block("main").unwrap().add_computation("add", a * b)?;
 
     // Set up probes.
     let probe = graph.add_probe::<i32>("main/add")?;
@@ -30,3 +38,24 @@ async fn run<B: Backend + 'static>() -> Result<Vec<i32>> {
     // Retrieve the results and assert.
     probe.retrieve().await.map_err(Into::into)
 }
+
+async fn run_scalar<B: Backend + 'static>() -> Result<(Vec<f32>, Vec<f32>)> {
+    // Initialize input tensors.
+    let tengu = Tengu::<B>::new().await?;
+    let a = tengu.tensor([4]).label("a").init(&[1.0, 2.0, 3.0, 4.0]);
+
+    // Create computation graph with scalars on either side of the tensor.
+    let mut graph = tengu.graph();
+    graph
+        .add_block("main")?
+        .add_computation("mul", a.clone() * tengu.scalar(2.0))?
+        .add_computation("sub", tengu.scalar(3.0) - a)?;
+
+    // Set up probes.
+    let mul = graph.add_probe::<f32>("main/mul")?;
+    let sub = graph.add_probe::<f32>("main/sub")?;
+
+    // Run the computation and retrieve the results.
+    graph.compute(1).await?;
+    Ok((mul.retrieve().await?, sub.retrieve().await?))
+}
@@ -0,0 +1,37 @@
+use anyhow::Result;
+use pretty_assertions::assert_eq;
+
+use tengu_backend::Backend;
+use tengu_graph::{Tengu, CPU, WGPU};
+
+#[tokio::test]
+async fn degenerate_cpu() {
+    run::<CPU>().await.unwrap();
+}
+
+#[tokio::test]
+async fn degenerate_wgpu() {
+    run::<WGPU>().await.unwrap();
+}
+
+async fn run<B: Backend + 'static>() -> Result<()> {
+    // Reduce along axes of length 1, which leaves the values unchanged.
+    let tengu = Tengu::<B>::new().await?;
+    let a = tengu.tensor([3, 1]).label("a").init(&[1.5, -2.0, 4.0]);
+    let b = tengu.tensor([1]).label("b").init(&[7.0]);
+    let mut graph = tengu.graph();
+    graph
+        .add_block("main")?
+        .add_computation("rows", a.clone().mean_axis(1, false))?
+        .add_computation("single", b.mean_axis(0, false))?
+        .add_computation("softmax", a.softmax(1))?;
+    let rows = graph.add_probe_shaped::<f32>("main/rows", &[3])?;
+    let single = graph.add_probe_shaped::<f32>("main/single", &[1])?;
+    let softmax = graph.add_probe_shaped::<f32>("main/softmax", &[3, 1])?;
+    graph.compute(1).await?;
+
+    assert_eq!(rows.retrieve().await?, [1.5, -2.0, 4.0]);
+    assert_eq!(single.retrieve().await?, [7.0]);
+    assert_eq!(softmax.retrieve().await?, [1.0; 3]);
+    Ok(())
+}
@@ -0,0 +1,27 @@
+use anyhow::Result;
+use pretty_assertions::assert_eq;
+
+use tengu_backend::Backend;
+use tengu_graph::{Shape, Tengu, CPU, WGPU};
+
+#[tokio::test]
+async fn diff_cpu() {
+    assert_eq!(run::<CPU>().await.unwrap(), vec![2.0, 3.0, 4.0]);
+}
+
+#[tokio::test]
+async fn diff_wgpu() {
+    assert_eq!(run::<WGPU>().await.unwrap(), vec![2.0, 3.0, 4.0]);
+}
+
+async fn run<B: Backend + 'static>() -> Result<Vec<f32>> {
+    let tengu = Tengu::<B>::new().await?;
+    let a = tengu.tensor([4]).label("a").init(&[1.0, 3.0, 6.0, 10.0]);
+    let diff = a.diff(0);
+    assert_eq!(diff.shape(), &[3]);
+    let mut graph = tengu.graph();
+    graph.add_block("main")?.add_computation("diff", diff)?;
+    let diff = graph.add_probe::<f32>("main/diff")?;
+    graph.compute(1).await?;
+    Ok(diff.retrieve().await?)
+}
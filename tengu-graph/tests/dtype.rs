@@ -0,0 +1,35 @@
+use pretty_assertions::assert_eq;
+
+use tengu_graph::{Shape, Tengu, Type};
+
+#[tokio::test]
+async fn runtime_cast() {
+    let tengu = Tengu::cpu().await.unwrap();
+    let a = tengu.tensor([4]).label("a").init(&[1, -2, 3, -4]);
+    let ty: Type = Type::F32;
+    let erased = a.cast_dtype(ty);
+    assert_eq!(erased.dtype(), Type::F32);
+    assert_eq!(erased.shape(), &[4]);
+    let mut graph = tengu.graph();
+    graph
+        .add_block("main")
+        .unwrap()
+        .add_erased_computation("out", erased)
+        .unwrap();
+    let probe = graph.add_probe::<f32>("main/out").unwrap();
+    graph.compute(1).await.unwrap();
+    assert_eq!(probe.retrieve().await.unwrap(), vec![1.0, -2.0, 3.0, -4.0]);
+}
+
+#[tokio::test]
+async fn supported_dtypes() {
+    // The 32-bit types are supported everywhere, 16-bit integers only on the CPU.
+    let cpu = Tengu::cpu().await.unwrap().supported_dtypes();
+    let wgpu = Tengu::wgpu().await.unwrap().supported_dtypes();
+    for ty in [Type::F32, Type::I32, Type::U32] {
+        assert!(cpu.contains(&ty));
+        assert!(wgpu.contains(&ty));
+    }
+    assert!(cpu.contains(&Type::I16));
+    assert!(!wgpu.contains(&Type::I16));
+}
@@ -0,0 +1,63 @@
+use std::fs;
+
+use anyhow::Result;
+use pretty_assertions::assert_eq;
+
+use tengu_backend::Backend;
+use tengu_graph::{Tengu, CPU, WGPU};
+
+#[tokio::test]
+async fn dump_cpu() {
+    run::<CPU>("cpu").await.unwrap();
+}
+
+#[tokio::test]
+async fn dump_wgpu() {
+    run::<WGPU>("wgpu").await.unwrap();
+}
+
+async fn run<B: Backend + 'static>(name: &str) -> Result<()> {
+    let dir = std::env::temp_dir().join(format!("tengu_dump_{name}_{}", std::process::id()));
+    let tengu = Tengu::<B>::new().await?;
+    let a = tengu.tensor([3]).label("a").init(&[1.0, 2.0, 3.0]);
+    let b = tengu.tensor([3]).label("b").init(&[4.0, 5.0, 6.0]);
+    let mut graph = tengu.graph();
+    graph
+        .add_block("main")?
+        .add_computation("total", a.clone() + b.clone())?
+        .add_computation("product", a * b)?;
+    graph.add_probe::<f32>("main/total")?;
+    graph.add_probe::<f32>("main/product")?;
+    graph.compute_with_dump(1, &dir).await?;
+
+    let mut files: Vec<_> = fs::read_dir(&dir)?
+        .map(|entry| entry.map(|entry| entry.file_name().into_string().unwrap()))
+        .collect::<std::io::Result<_>>()?;
+    files.sort();
+    assert_eq!(files, vec!["0_main_product.npy", "0_main_total.npy"]);
+    assert_eq!(
+        read_npy(&dir.join("0_main_total.npy"))?,
+        (String::from("'shape': (3,)"), vec![5.0, 7.0, 9.0])
+    );
+    assert_eq!(
+        read_npy(&dir.join("0_main_product.npy"))?,
+        (String::from("'shape': (3,)"), vec![4.0, 10.0, 18.0])
+    );
+    fs::remove_dir_all(&dir)?;
+    Ok(())
+}
+
+fn read_npy(path: &std::path::Path) -> Result<(String, Vec<f32>)> {
+    let bytes = fs::read(path)?;
+    assert_eq!(&bytes[..8], b"\x93NUMPY\x01\x00");
+    let header_len = u16::from_le_bytes([bytes[8], bytes[9]]) as usize;
+    assert_eq!((10 + header_len) % 64, 0);
+    let header = std::str::from_utf8(&bytes[10..10 + header_len])?;
+    assert!(header.contains("'descr': '<f4'"));
+    let shape = header[header.find("'shape'").unwrap()..header.find(')').unwrap() + 1].to_string();
+    let data = bytes[10 + header_len..]
+        .chunks_exact(4)
+        .map(|chunk| f32::from_le_bytes(chunk.try_into().unwrap()))
+        .collect();
+    Ok((shape, data))
+}
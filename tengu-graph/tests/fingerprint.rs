@@ -0,0 +1,33 @@
+use anyhow::Result;
+use pretty_assertions::{assert_eq, assert_ne};
+use tengu_graph::Tengu;
+
+#[tokio::test]
+async fn identical_graphs() {
+    run().await.unwrap();
+}
+
+async fn run() -> Result<()> {
+    // Build graphs with the same structure, labeling the tensors so that their paths match.
+    let tengu = Tengu::cpu().await?;
+    let build = |data: &[f32]| -> Result<_> {
+        let a = tengu.tensor([2, 2]).label("a").init(data);
+        let b = tengu.tensor([2, 2]).label("b").init(&[0.5, 1.0, -1.5, 2.0]);
+        let mut graph = tengu.graph();
+        graph
+            .add_block("main")?
+            .add_computation("total", a.clone() + b.clone())?
+            .add_computation("product", a * b)?;
+        graph.add_probe::<f32>("main/total")?;
+        graph.add_probe::<f32>("main/product")?;
+        Ok(graph)
+    };
+
+    // Identical graphs produce the same fingerprint, and a change of the results changes it.
+    let fst = build(&[1.0, -2.0, 3.5, -4.5])?.fingerprint().await?;
+    let snd = build(&[1.0, -2.0, 3.5, -4.5])?.fingerprint().await?;
+    let changed = build(&[1.0, -2.0, 3.5, 4.5])?.fingerprint().await?;
+    assert_eq!(fst, snd);
+    assert_ne!(fst, changed);
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use anyhow::Result;
+use pretty_assertions::assert_eq;
+
+use tengu_backend::Backend;
+use tengu_graph::{Shape, Tengu, CPU, WGPU};
+
+#[tokio::test]
+async fn flip_cpu() {
+    assert_eq!(
+        run::<CPU>().await.unwrap(),
+        (vec![3.0, 2.0, 1.0], vec![6, 5, 4, 3, 2, 1])
+    );
+}
+
+#[tokio::test]
+async fn flip_wgpu() {
+    assert_eq!(
+        run::<WGPU>().await.unwrap(),
+        (vec![3.0, 2.0, 1.0], vec![6, 5, 4, 3, 2, 1])
+    );
+}
+
+async fn run<B: Backend + 'static>() -> Result<(Vec<f32>, Vec<i32>)> {
+    let tengu = Tengu::<B>::new().await?;
+    let a = tengu.tensor([3]).label("a").init(&[1.0, 2.0, 3.0]);
+    let b = tengu.tensor([2, 3]).label("b").init(&[1, 2, 3, 4, 5, 6]);
+    let flip_all = b.flip_all(&[0, 1]);
+    assert_eq!(flip_all.shape(), &[2, 3]);
+    let mut graph = tengu.graph();
+    graph
+        .add_block("main")?
+        .add_computation("flip", a.flip(0))?
+        .add_computation("flip_all", flip_all)?;
+    let flip = graph.add_probe::<f32>("main/flip")?;
+    let flip_all = graph.add_probe::<i32>("main/flip_all")?;
+    graph.compute(1).await?;
+    Ok((flip.retrieve().await?, flip_all.retrieve().await?))
+}
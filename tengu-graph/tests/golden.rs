@@ -0,0 +1,28 @@
+use std::rc::Rc;
+
+use tengu_backend::Backend;
+use tengu_graph::testing::{assert_backends_agree, Golden};
+use tengu_graph::{ErasedExpression, Tengu};
+
+struct Mixed;
+
+impl Golden for Mixed {
+    fn build<B: Backend + 'static>(&self, tengu: &Rc<Tengu<B>>) -> Vec<(String, ErasedExpression<B>)> {
+        let a = tengu.tensor([2, 2]).label("a").init(&[1.0, -2.0, 3.5, -4.5]);
+        let b = tengu.tensor([2, 2]).label("b").init(&[0.5, 1.0, -1.5, 2.0]);
+        let c = tengu.tensor([2, 2]).label("c").init(&[1, -2, 3, -4]);
+        vec![
+            (
+                "arithmetic".into(),
+                ErasedExpression::new(a.clone() * b.clone() + a.clone()),
+            ),
+            ("unary".into(), ErasedExpression::new(b.exp() + a.clone().sign())),
+            ("cast".into(), ErasedExpression::new(a.cast::<i32>() + c)),
+        ]
+    }
+}
+
+#[tokio::test]
+async fn arithmetic_unary_cast() {
+    assert_backends_agree(Mixed, 1e-4).await;
+}
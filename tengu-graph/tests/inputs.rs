@@ -0,0 +1,45 @@
+use pretty_assertions::assert_eq;
+
+use tengu_graph::Tengu;
+
+#[tokio::test]
+async fn inputs() {
+    let tengu = Tengu::cpu().await.unwrap();
+    let a = tengu.tensor([2, 3]).label("a").zero::<f32>();
+    let b = tengu.tensor([2, 3]).label("b").zero::<f32>();
+    let mut graph = tengu.graph();
+    graph
+        .add_block("main")
+        .unwrap()
+        .add_computation("c", a.clone() + b)
+        .unwrap()
+        .add_computation("d", a.exp())
+        .unwrap();
+    assert_eq!(
+        graph.inputs(),
+        vec![("main/a".to_string(), vec![2, 3]), ("main/b".to_string(), vec![2, 3])]
+    );
+}
+
+#[tokio::test]
+async fn inputs_across_blocks() {
+    let tengu = Tengu::cpu().await.unwrap();
+    let a = tengu.tensor([4]).label("a").zero::<u32>();
+    let b = tengu.tensor([4]).label("b").zero::<u32>();
+    let mut graph = tengu.graph();
+    graph
+        .add_block("first")
+        .unwrap()
+        .add_computation("out", a.clone() * a)
+        .unwrap();
+    graph
+        .add_block("second")
+        .unwrap()
+        .add_computation("out", b.clone() + b)
+        .unwrap();
+    graph.add_link("first/out", "second/b").unwrap();
+    assert_eq!(
+        graph.inputs(),
+        vec![("first/a".to_string(), vec![4]), ("second/b".to_string(), vec![4])]
+    );
+}
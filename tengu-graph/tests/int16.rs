@@ -0,0 +1,51 @@
+use pretty_assertions::assert_eq;
+use tengu_backend::Error as BackendError;
+use tengu_graph::{Error, Tengu, Type};
+
+#[tokio::test]
+async fn main() {
+    // Initialize input tensors.
+    let tengu = Tengu::cpu().await.unwrap();
+    let a = tengu.tensor([2, 2]).label("a").init(&[-1000i16, -1, 1, 1000]);
+    let b = tengu.tensor([2, 2]).label("b").init(&[10i16, 20, 30, 40]);
+
+    // Create computation graph.
+    let mut graph = tengu.graph();
+    graph
+        .add_block("main")
+        .unwrap()
+        .add_computation("total", a.clone() + b)
+        .unwrap()
+        .add_computation(
+            "scaled",
+            a.cast::<f32>() * tengu.tensor([2, 2]).label("c").init(&[0.5; 4]),
+        )
+        .unwrap();
+
+    // Set up probes.
+    let sum = graph.add_probe::<i16>("main/total").unwrap();
+    let scaled = graph.add_probe::<f32>("main/scaled").unwrap();
+
+    // Run one step of computation.
+    graph.compute(1).await.unwrap();
+
+    // Retrieve results and assert.
+    assert_eq!(sum.retrieve().await.unwrap(), [-990, 19, 31, 1040]);
+    assert_eq!(scaled.retrieve().await.unwrap(), [-500.0, -0.5, 0.5, 500.0]);
+}
+
+#[tokio::test]
+async fn unsupported_wgpu() {
+    // WGSL has no 16-bit integers, so the WGPU backend refuses to create such tensors.
+    let tengu = Tengu::wgpu().await.unwrap();
+    let result = tengu.tensor([2]).label("a").try_init(&[1i16, 2]);
+    assert!(matches!(
+        result,
+        Err(Error::BackendError(BackendError::UnsupportedType(Type::I16, _)))
+    ));
+    let result = tengu.tensor([2]).label("b").try_zero::<u16>();
+    assert!(matches!(
+        result,
+        Err(Error::BackendError(BackendError::UnsupportedType(Type::U16, _)))
+    ));
+}
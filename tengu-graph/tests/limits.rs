@@ -0,0 +1,55 @@
+use pretty_assertions::assert_eq;
+
+use tengu_graph::{Error, Tengu};
+
+#[tokio::test]
+async fn too_many_tensors() {
+    let tengu = Tengu::wgpu().await.unwrap();
+    let limit = tengu.max_tensor_count().unwrap();
+    let mut graph = tengu.graph();
+    let sum = (0..limit)
+        .map(|i| tengu.tensor([4]).label(format!("t{i}")).zero::<f32>())
+        .reduce(|acc, tensor| acc + tensor)
+        .unwrap();
+    graph.add_block("main").unwrap().add_computation("total", sum).unwrap();
+    assert_eq!(graph.get_block("main").unwrap().tensor_count(), limit + 1);
+    let result = graph.compute(1).await;
+    assert!(
+        matches!(result, Err(Error::TooManyTensors(block, count, max)) if block == "main" && count == limit + 1 && max == limit)
+    );
+    let result = graph.add_probe::<f32>("main/total");
+    assert!(matches!(result, Err(Error::TooManyTensors(..))));
+}
+
+#[tokio::test]
+async fn tensor_count() {
+    let tengu = Tengu::cpu().await.unwrap();
+    let a = tengu.tensor([4]).label("a").zero::<f32>();
+    let b = tengu.tensor([4]).label("b").zero::<f32>();
+    let mut graph = tengu.graph();
+    let block = graph.add_block("main").unwrap();
+    block.add_computation("c", a.clone() + b.clone() * a).unwrap();
+    assert_eq!(block.tensor_count(), 3);
+}
+
+#[tokio::test]
+async fn auto_split() {
+    let tengu = Tengu::wgpu().await.unwrap();
+    let limit = tengu.max_tensor_count().unwrap();
+    let mut graph = tengu.graph();
+    let block = graph.add_block("main").unwrap();
+    for i in 0..limit {
+        let a = tengu.tensor([4]).label(format!("a{i}")).init(&[i as f32; 4]);
+        block.add_computation(format!("b{i}"), a + 1.0).unwrap();
+    }
+    assert_eq!(graph.get_block("main").unwrap().tensor_count(), 2 * limit);
+    assert!(matches!(graph.compute(1).await, Err(Error::TooManyTensors(..))));
+    graph.auto_split(true);
+    let probes = (0..limit)
+        .map(|i| graph.add_probe::<f32>(&format!("main/b{i}")).unwrap())
+        .collect::<Vec<_>>();
+    graph.compute(1).await.unwrap();
+    for (i, probe) in probes.iter().enumerate() {
+        assert_eq!(probe.retrieve().await.unwrap(), vec![i as f32 + 1.0; 4]);
+    }
+}
@@ -0,0 +1,38 @@
+use anyhow::Result;
+use pretty_assertions::assert_eq;
+
+use tengu_backend::Backend;
+use tengu_graph::{Tengu, CPU, WGPU};
+
+#[tokio::test]
+async fn cpu() {
+    run::<CPU>().await.unwrap();
+}
+
+#[tokio::test]
+async fn wgpu() {
+    run::<WGPU>().await.unwrap();
+}
+
+async fn run<B: Backend + 'static>() -> Result<()> {
+    // Initialize input tensors.
+    let tengu = Tengu::<B>::new().await?;
+    let a = tengu.tensor([3]).label("a").init(&[1.0, 2.0, 3.0]);
+
+    // Create computation graph mixing the tensor with unannotated float and integer literals.
+    let mut graph = tengu.graph();
+    graph
+        .add_block("main")?
+        .add_computation("float", a.clone() + 1.0)?
+        .add_computation("int", a * 2 - 1)?;
+
+    // Set up probes.
+    let float = graph.add_probe::<f32>("main/float")?;
+    let int = graph.add_probe::<f32>("main/int")?;
+
+    // Run the computation and check the results.
+    graph.compute(1).await?;
+    assert_eq!(float.retrieve().await?, vec![2.0, 3.0, 4.0]);
+    assert_eq!(int.retrieve().await?, vec![1.0, 3.0, 5.0]);
+    Ok(())
+}
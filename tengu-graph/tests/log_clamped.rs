@@ -0,0 +1,26 @@
+use anyhow::Result;
+
+use tengu_backend::Backend;
+use tengu_graph::{Tengu, CPU, WGPU};
+
+const EPS: f32 = 1e-7;
+
+#[tokio::test]
+async fn log_clamped() {
+    let cpu = run::<CPU>().await.unwrap();
+    let wgpu = run::<WGPU>().await.unwrap();
+    for (cpu, wgpu) in cpu.into_iter().zip(wgpu) {
+        assert!(cpu.is_finite() && wgpu.is_finite());
+        assert!((cpu - wgpu).abs() <= 1e-5 * cpu.abs().max(1.0), "{cpu} != {wgpu}");
+    }
+}
+
+async fn run<B: Backend + 'static>() -> Result<Vec<f32>> {
+    let tengu = Tengu::<B>::new().await?;
+    let a = tengu.tensor([4]).label("a").init(&[0.0, -0.0, -1.0, 1.0]);
+    let mut graph = tengu.graph();
+    graph.add_block("main")?.add_computation("out", a.log_clamped(EPS))?;
+    let out = graph.add_probe::<f32>("main/out")?;
+    graph.compute(1).await?;
+    Ok(out.retrieve().await?)
+}
@@ -0,0 +1,101 @@
+use anyhow::Result;
+use pretty_assertions::assert_eq;
+
+use tengu_backend::{Backend, Error as BackendError};
+use tengu_graph::{Error, Tengu, CPU, WGPU};
+
+#[tokio::test]
+async fn wgpu() {
+    assert_eq!(run::<WGPU>().await.unwrap(), [1.0, 3.0]);
+}
+
+#[tokio::test]
+async fn cpu() {
+    assert_eq!(run::<CPU>().await.unwrap(), [1.0, 3.0]);
+}
+
+#[tokio::test]
+async fn chunks_wgpu() {
+    assert_eq!(chunks::<WGPU>().await.unwrap(), expected_chunks());
+}
+
+#[tokio::test]
+async fn chunks_cpu() {
+    assert_eq!(chunks::<CPU>().await.unwrap(), expected_chunks());
+}
+
+#[tokio::test]
+async fn nested_wgpu() {
+    let tengu = Tengu::wgpu().await.unwrap();
+    let a = tengu.tensor([4]).label("a").init(&[1.0, 2.0, 3.0, 4.0]);
+    let mask = tengu.tensor([4]).label("mask").bools(&[true, false, true, false]);
+    let mut graph = tengu.graph();
+    graph
+        .add_block("main")
+        .unwrap()
+        .add_computation("out", a.mask_select(mask).exp())
+        .unwrap();
+
+    // The selected elements can't be packed inside another expression.
+    let result = graph.compute(1).await;
+    assert!(matches!(
+        result,
+        Err(Error::BackendError(BackendError::UnsupportedExpression(_)))
+    ));
+}
+
+const COUNT: usize = 200;
+
+fn expected_chunks() -> Vec<u32> {
+    (0..COUNT as u32).filter(|i| i % 3 == 0).collect()
+}
+
+async fn run<B: Backend + 'static>() -> Result<Vec<f32>> {
+    // Initialize input tensors.
+    let tengu = Tengu::<B>::new().await?;
+    let a = tengu.tensor([4]).label("a").init(&[1.0, 2.0, 3.0, 4.0]);
+    let mask = tengu.tensor([4]).label("mask").bools(&[true, false, true, false]);
+
+    // Create computation graph.
+    let mut graph = tengu.graph();
+    graph
+        .add_block("main")?
+        .add_computation("picked", a.mask_select(mask.cast::<bool>()))?;
+
+    // The length of the selection is an internal output, not an input of the block.
+    assert_eq!(
+        graph.inputs(),
+        vec![("main/a".to_string(), vec![4]), ("main/mask".to_string(), vec![4])]
+    );
+
+    // Set up probes.
+    let probe = graph.add_probe::<f32>("main/picked")?;
+
+    // Run one step of computation. The probe of the selection only receives the selected elements.
+    graph.compute(1).await?;
+    Ok(probe.retrieve().await?)
+}
+
+/// Selects every third element of a tensor spanning several workgroups, so that the selected
+/// positions are carried over from one chunk of the mask to the next.
+async fn chunks<B: Backend + 'static>() -> Result<Vec<u32>> {
+    // Initialize input tensors.
+    let tengu = Tengu::<B>::new().await?;
+    let values = (0..COUNT as u32).collect::<Vec<_>>();
+    let mask = values.iter().map(|i| i % 3 == 0).collect::<Vec<_>>();
+    let a = tengu.tensor([COUNT]).label("a").init(&values);
+    let mask = tengu.tensor([COUNT]).label("mask").bools(&mask);
+
+    // Create computation graph.
+    let mut graph = tengu.graph();
+    graph
+        .add_block("main")?
+        .add_computation("picked", a.mask_select(mask.cast::<bool>()))?;
+
+    // Set up probes.
+    let probe = graph.add_probe::<u32>("main/picked")?;
+
+    // Run one step of computation.
+    graph.compute(1).await?;
+    Ok(probe.retrieve().await?)
+}
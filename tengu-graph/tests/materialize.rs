@@ -0,0 +1,27 @@
+use anyhow::Result;
+use pretty_assertions::assert_eq;
+
+use tengu_backend::Backend;
+use tengu_graph::{Tengu, CPU, WGPU};
+
+#[tokio::test]
+async fn materialize_cpu() {
+    assert_eq!(run::<CPU>().await.unwrap(), vec![4.0, 12.0, 24.0]);
+}
+
+#[tokio::test]
+async fn materialize_wgpu() {
+    assert_eq!(run::<WGPU>().await.unwrap(), vec![4.0, 12.0, 24.0]);
+}
+
+async fn run<B: Backend + 'static>() -> Result<Vec<f32>> {
+    let tengu = Tengu::<B>::new().await?;
+    let a = tengu.tensor([3]).label("a").init(&[1.0, 2.0, 3.0]);
+    let b = tengu.tensor([3]).label("b").init(&[3.0, 4.0, 5.0]);
+    let sum = (a.clone() + b).materialize();
+    let mut graph = tengu.graph();
+    graph.add_block("main")?.add_computation("out", sum * a.clone() + a)?;
+    let probe = graph.add_probe::<f32>("main/out")?;
+    graph.compute(1).await?;
+    Ok(probe.retrieve().await?)
+}
@@ -0,0 +1,30 @@
+use pretty_assertions::assert_eq;
+use tengu_graph::Tengu;
+
+#[tokio::test]
+async fn main() {
+    // Initialize input tensors.
+    let tengu = Tengu::cpu().await.unwrap();
+    let a = tengu.tensor([2, 3]).label("a").init(&[1.0, 2.0, 3.0, 4.0, 5.0, 9.0]);
+
+    // Create computation graph.
+    let mut graph = tengu.graph();
+    graph
+        .add_block("main")
+        .unwrap()
+        .add_computation("rows", a.clone().mean_axis(1, true))
+        .unwrap()
+        .add_computation("cols", a.mean_axis(0, false))
+        .unwrap();
+
+    // Set up probes.
+    let rows = graph.add_probe::<f32>("main/rows").unwrap();
+    let cols = graph.add_probe::<f32>("main/cols").unwrap();
+
+    // Run one step of computation.
+    graph.compute(1).await.unwrap();
+
+    // Retrieve results and assert.
+    assert_eq!(rows.retrieve().await.unwrap(), [2.0, 6.0]);
+    assert_eq!(cols.retrieve().await.unwrap(), [2.5, 3.5, 6.0]);
+}
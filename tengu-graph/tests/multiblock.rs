@@ -10,8 +10,8 @@ async fn main() {
 
     // Create computation graph.
     let mut graph = tengu.graph();
-    graph.add_block("fst").unwrap().add_computation("out", a + 1.0);
-    graph.add_block("snd").unwrap().add_computation("out", b + 1.0);
+    graph.add_block("fst").unwrap().add_computation("out", a + 1.0).unwrap();
+    graph.add_block("snd").unwrap().add_computation("out", b + 1.0).unwrap();
     graph.add_link("fst/out", "snd/b").unwrap();
 
     // Set up probes.
@@ -30,3 +30,43 @@ async fn main() {
         .await
         .unwrap();
 }
+
+#[tokio::test]
+async fn batched_pass() {
+    // Initialize input tensors.
+    let tengu = Tengu::wgpu().await.unwrap();
+    let a = tengu.tensor([4]).label("a").init(&[1.0, 2.0, 3.0, 4.0]);
+    let b = tengu.tensor([4]).label("b").init(&[5.0, 6.0, 7.0, 8.0]);
+
+    // Create computation graph with independent blocks.
+    let mut graph = tengu.graph();
+    graph
+        .add_block("sum")
+        .unwrap()
+        .add_computation("out", a.clone() + b.clone())
+        .unwrap();
+    graph
+        .add_block("product")
+        .unwrap()
+        .add_computation("out", a.clone() * b.clone())
+        .unwrap();
+    graph.add_block("exp").unwrap().add_computation("out", a.exp()).unwrap();
+    let probes = ["sum/out", "product/out", "exp/out"].map(|path| graph.add_probe::<f32>(path).unwrap());
+
+    // Compute all blocks in a single pass and each block in a pass of its own.
+    graph.compute(1).await.unwrap();
+    let mut batched = Vec::new();
+    for probe in &probes {
+        batched.push(probe.retrieve().await.unwrap());
+    }
+    let mut separate = Vec::new();
+    for (label, probe) in ["sum", "product", "exp"].iter().zip(&probes) {
+        graph.compute_block(label, 1).await.unwrap();
+        separate.push(probe.retrieve().await.unwrap());
+    }
+
+    // Assert that batching the dispatches doesn't change the results.
+    assert_eq!(batched, separate);
+    assert_eq!(batched[0], [6.0, 8.0, 10.0, 12.0]);
+    assert_eq!(batched[1], [5.0, 12.0, 21.0, 32.0]);
+}
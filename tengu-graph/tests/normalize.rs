@@ -0,0 +1,50 @@
+use anyhow::Result;
+
+use tengu_backend::Backend;
+use tengu_graph::{NormalizeMode, Tengu, CPU, WGPU};
+
+const ROWS: [f32; 9] = [3.0, 4.0, 0.0, -1.0, 2.0, 2.0, 0.0, 0.0, 0.0];
+
+#[tokio::test]
+async fn normalize_cpu() {
+    run::<CPU>().await.unwrap();
+}
+
+#[tokio::test]
+async fn normalize_wgpu() {
+    run::<WGPU>().await.unwrap();
+}
+
+async fn run<B: Backend + 'static>() -> Result<()> {
+    let tengu = Tengu::<B>::new().await?;
+    let a = tengu.tensor([3, 3]).label("a").init(&ROWS);
+    let mut graph = tengu.graph();
+    let block = graph.add_block("main")?;
+    block.add_computation("l2", a.clone().normalize(1, NormalizeMode::L2))?;
+    block.add_computation("std", a.normalize(1, NormalizeMode::Standardize))?;
+    let l2 = graph.add_probe::<f32>("main/l2")?;
+    let std = graph.add_probe::<f32>("main/std")?;
+    graph.compute(1).await?;
+
+    // Nonzero rows have unit norm, the all-zero row stays zero instead of becoming NaN.
+    let l2 = l2.retrieve().await?;
+    for row in l2[..6].chunks(3) {
+        let norm = row.iter().map(|x| x * x).sum::<f32>().sqrt();
+        assert!((norm - 1.0).abs() < 1e-4, "{row:?} has norm {norm}");
+    }
+    assert_eq!(&l2[6..], [0.0; 3]);
+    for (actual, expected) in l2.iter().zip([0.6, 0.8, 0.0]) {
+        assert!((actual - expected).abs() < 1e-4, "{actual} != {expected}");
+    }
+
+    // Standardized rows have zero mean and unit variance.
+    let std = std.retrieve().await?;
+    for row in std[..6].chunks(3) {
+        let mean = row.iter().sum::<f32>() / 3.0;
+        let variance = row.iter().map(|x| (x - mean) * (x - mean)).sum::<f32>() / 3.0;
+        assert!(mean.abs() < 1e-4, "{row:?} has mean {mean}");
+        assert!((variance - 1.0).abs() < 1e-3, "{row:?} has variance {variance}");
+    }
+    assert_eq!(&std[6..], [0.0; 3]);
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use anyhow::Result;
+use pretty_assertions::assert_eq;
+
+use tengu_backend::Backend;
+use tengu_graph::{Tengu, CPU, WGPU};
+
+#[tokio::test]
+async fn cpu() {
+    run::<CPU>().await.unwrap();
+}
+
+#[tokio::test]
+async fn wgpu() {
+    run::<WGPU>().await.unwrap();
+}
+
+async fn run<B: Backend + 'static>() -> Result<()> {
+    // Initialize input tensors. The last index of `b` is out of range.
+    let tengu = Tengu::<B>::new().await?;
+    let a = tengu.tensor([3]).label("a").init(&[0u32, 2, 1]);
+    let b = tengu.tensor([2]).label("b").init(&[1u32, 3]);
+
+    // Create computation graph.
+    let mut graph = tengu.graph();
+    graph
+        .add_block("main")?
+        .add_computation("a_hot", a.one_hot(3))?
+        .add_computation("b_hot", b.one_hot(3))?;
+
+    // Set up probes.
+    let a_hot = graph.add_probe::<f32>("main/a_hot")?;
+    let b_hot = graph.add_probe::<f32>("main/b_hot")?;
+
+    // Run the computation and check the results.
+    graph.compute(1).await?;
+    assert_eq!(
+        a_hot.retrieve().await?,
+        vec![1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 1.0, 0.0]
+    );
+    assert_eq!(b_hot.retrieve().await?, vec![0.0, 1.0, 0.0, 0.0, 0.0, 0.0]);
+    Ok(())
+}
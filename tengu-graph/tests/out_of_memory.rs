@@ -0,0 +1,34 @@
+use tengu_backend::Error as BackendError;
+use tengu_graph::{Error, Tengu};
+
+/// The number of `f32` elements in a 4 GiB tensor, which exceeds the buffer size limit of any device.
+const HUGE: usize = 1 << 30;
+
+#[tokio::test]
+async fn tensor() {
+    let tengu = Tengu::wgpu().await.unwrap();
+    let result = tengu.tensor([HUGE]).label("huge").try_zero::<f32>();
+    assert!(matches!(
+        result,
+        Err(Error::BackendError(BackendError::OutOfMemory { .. }))
+    ));
+}
+
+#[tokio::test]
+async fn computation() {
+    let tengu = Tengu::wgpu().await.unwrap();
+    let a = tengu.tensor([1]).label("a").init(&[1.0f32]);
+    let mut graph = tengu.graph();
+    let block = graph.add_block("main").unwrap();
+    let result = block.add_computation("huge", a.clone().repeat(&[HUGE]));
+    assert!(matches!(
+        result,
+        Err(Error::BackendError(BackendError::OutOfMemory { .. }))
+    ));
+
+    // The graph stays usable with a smaller computation.
+    block.add_computation("small", a.repeat(&[4])).unwrap();
+    let small = graph.add_probe::<f32>("main/small").unwrap();
+    graph.compute(1).await.unwrap();
+    assert_eq!(small.retrieve().await.unwrap(), [1.0; 4]);
+}
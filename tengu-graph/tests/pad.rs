@@ -0,0 +1,33 @@
+use anyhow::Result;
+use pretty_assertions::assert_eq;
+
+use tengu_backend::Backend;
+use tengu_graph::{Shape, Tengu, CPU, WGPU};
+
+#[tokio::test]
+async fn pad_rows_cpu() {
+    assert_eq!(
+        run::<CPU>().await.unwrap(),
+        vec![-1.0, -1.0, 1.0, 2.0, 3.0, 4.0, -1.0, -1.0]
+    );
+}
+
+#[tokio::test]
+async fn pad_rows_wgpu() {
+    assert_eq!(
+        run::<WGPU>().await.unwrap(),
+        vec![-1.0, -1.0, 1.0, 2.0, 3.0, 4.0, -1.0, -1.0]
+    );
+}
+
+async fn run<B: Backend + 'static>() -> Result<Vec<f32>> {
+    let tengu = Tengu::<B>::new().await?;
+    let a = tengu.tensor([2, 2]).label("a").init(&[1.0, 2.0, 3.0, 4.0]);
+    let padded = a.pad(&[(1, 1), (0, 0)], -1.0);
+    assert_eq!(padded.shape(), &[4, 2]);
+    let mut graph = tengu.graph();
+    graph.add_block("main")?.add_computation("padded", padded)?;
+    let probe = graph.add_probe::<f32>("main/padded")?;
+    graph.compute(1).await?;
+    Ok(probe.retrieve().await?)
+}
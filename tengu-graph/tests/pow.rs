@@ -0,0 +1,69 @@
+use anyhow::Result;
+use pretty_assertions::assert_eq;
+
+use tengu_backend::Backend;
+use tengu_graph::{Tengu, CPU, WGPU};
+
+type Powers = (Vec<f32>, Vec<f32>, Vec<f32>, Vec<i32>);
+
+#[tokio::test]
+async fn pow_cpu() {
+    assert_eq!(run::<CPU>().await.unwrap(), expected());
+}
+
+#[tokio::test]
+async fn pow_wgpu() {
+    assert_eq!(run::<WGPU>().await.unwrap(), expected());
+}
+
+#[tokio::test]
+async fn emitted_shader() {
+    let tengu = Tengu::wgpu().await.unwrap();
+    let a = tengu.tensor([4]).label("a").init(&[1.0, 2.0, 4.0, 0.25]);
+    let mut graph = tengu.graph();
+    let block = graph
+        .add_block("main")
+        .unwrap()
+        .add_computation("square", a.powf(2.0))
+        .unwrap();
+    let processor = block.processor().unwrap();
+    assert!(processor.shader().contains("(a[idx] * a[idx])"));
+    assert!(!processor.shader().contains("pow"));
+}
+
+fn expected() -> Powers {
+    (
+        vec![1.0, 4.0, 16.0, 0.0625],
+        vec![1.0, 0.5, 0.25, 4.0],
+        vec![1.0, 1.0, 1.0],
+        vec![-8, 27],
+    )
+}
+
+async fn run<B: Backend + 'static>() -> Result<Powers> {
+    let tengu = Tengu::<B>::new().await?;
+    let a = tengu.tensor([4]).label("a").init(&[1.0, 2.0, 4.0, 0.25]);
+    let b = tengu.tensor([2]).label("b").init(&[-2, 3]);
+    let c = tengu.tensor([3]).label("c").init(&[-2.0, 0.0, 3.0]);
+
+    // Integer exponents are lowered to multiplications, which are exact for these values, and a
+    // zero exponent gives ones even for bases without a logarithm.
+    let mut graph = tengu.graph();
+    graph
+        .add_block("main")?
+        .add_computation("square", a.clone().powf(2.0))?
+        .add_computation("inverse", a.powf(-1.0))?
+        .add_computation("zero", c.powf(0.0))?
+        .add_computation("cube", b.powi(3))?;
+    let square = graph.add_probe::<f32>("main/square")?;
+    let inverse = graph.add_probe::<f32>("main/inverse")?;
+    let zero = graph.add_probe::<f32>("main/zero")?;
+    let cube = graph.add_probe::<i32>("main/cube")?;
+    graph.compute(1).await?;
+    Ok((
+        square.retrieve().await?,
+        inverse.retrieve().await?,
+        zero.retrieve().await?,
+        cube.retrieve().await?,
+    ))
+}
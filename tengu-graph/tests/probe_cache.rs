@@ -0,0 +1,99 @@
+use anyhow::Result;
+use pretty_assertions::assert_eq;
+
+use tengu_backend::Backend;
+use tengu_graph::{Tengu, CPU, WGPU};
+
+#[tokio::test]
+async fn probe_cache_cpu() {
+    assert_eq!(run::<CPU>().await.unwrap(), [[1.0, 2.0], [1.0, 2.0], [2.0, 4.0]]);
+}
+
+#[tokio::test]
+async fn probe_cache_wgpu() {
+    assert_eq!(run::<WGPU>().await.unwrap(), [[1.0, 2.0], [1.0, 2.0], [2.0, 4.0]]);
+}
+
+#[tokio::test]
+async fn mask_select_cpu() {
+    assert_eq!(mask_select::<CPU>().await.unwrap(), [[1.0, 3.0], [1.0, 3.0]]);
+}
+
+#[tokio::test]
+async fn mask_select_wgpu() {
+    assert_eq!(mask_select::<WGPU>().await.unwrap(), [[1.0, 3.0], [1.0, 3.0]]);
+}
+
+#[tokio::test]
+async fn single_map() {
+    // Initialize input tensors.
+    let tengu = Tengu::<WGPU>::new().await.unwrap();
+    let a = tengu.tensor([2]).label("a").init(&[1.0, 2.0]);
+
+    // Create computation graph.
+    let mut graph = tengu.graph();
+    graph
+        .add_block("main")
+        .unwrap()
+        .add_computation("out", a * 2.0)
+        .unwrap();
+    let probe = graph.add_probe::<f32>("main/out").unwrap();
+
+    // Retrieving twice after a single computation maps the staging buffer only once.
+    let stats = tengu.backend().stats();
+    let maps = stats.maps();
+    graph.compute(1).await.unwrap();
+    let first = probe.retrieve().await.unwrap();
+    let second = probe.retrieve().await.unwrap();
+    assert_eq!(first, [2.0, 4.0]);
+    assert_eq!(second, first);
+    assert_eq!(stats.maps() - maps, 1);
+}
+
+async fn run<B: Backend + 'static>() -> Result<Vec<Vec<f32>>> {
+    // Initialize input tensors.
+    let tengu = Tengu::<B>::new().await?;
+    let step = tengu.tensor([2]).label("step").init(&[1.0, 2.0]);
+    let counter = tengu.tensor([2]).label("counter").zero::<f32>();
+
+    // Create computation graph. The output grows on every iteration.
+    let mut graph = tengu.graph();
+    graph.add_block("main")?.add_computation("out", counter + step)?;
+    graph.add_link("main/out", "main/counter")?;
+    let probe = graph.add_probe::<f32>("main/out")?;
+    assert!(!probe.is_dirty());
+
+    // Retrieve twice after a single computation. Only the first retrieval receives the data read
+    // out by the computation, while the second one returns the cached copy.
+    let mut results = Vec::new();
+    graph.compute(1).await?;
+    assert!(probe.is_dirty());
+    results.push(probe.retrieve().await?);
+    assert!(!probe.is_dirty());
+    results.push(probe.retrieve().await?);
+    assert!(!probe.is_dirty());
+
+    // The next computation dirties the probe again.
+    graph.compute(1).await?;
+    assert!(probe.is_dirty());
+    results.push(probe.retrieve().await?);
+    Ok(results)
+}
+
+async fn mask_select<B: Backend + 'static>() -> Result<Vec<Vec<f32>>> {
+    // Initialize input tensors.
+    let tengu = Tengu::<B>::new().await?;
+    let a = tengu.tensor([4]).label("a").init(&[1.0, 2.0, 3.0, 4.0]);
+    let mask = tengu.tensor([4]).label("mask").init(&[true, false, true, false]);
+
+    // Create computation graph.
+    let mut graph = tengu.graph();
+    graph
+        .add_block("main")?
+        .add_computation("picked", a.mask_select(mask))?;
+    let probe = graph.add_probe::<f32>("main/picked")?;
+
+    // The selection holds fewer elements than the tensor, both when received and when cached.
+    graph.compute(1).await?;
+    Ok(vec![probe.retrieve().await?, probe.retrieve().await?])
+}
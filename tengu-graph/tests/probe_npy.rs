@@ -0,0 +1,56 @@
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+
+use futures::FutureExt;
+use tengu_graph::Tengu;
+
+#[tokio::test]
+async fn matches_reference() {
+    let path = reference("matches");
+    let tengu = Tengu::cpu().await.unwrap();
+    let a = tengu.tensor([2, 2]).label("a").init(&[1.0, 2.0, 3.0, 4.0]);
+    let mut graph = tengu.graph();
+    graph
+        .add_block("main")
+        .unwrap()
+        .add_computation("out", a * 2.0)
+        .unwrap();
+    let probe = graph.add_probe::<f32>("main/out").unwrap();
+    graph.compute(1).await.unwrap();
+    fs::write(&path, probe.retrieve_npy().await.unwrap()).unwrap();
+    probe.assert_matches_npy(&path, 0.0).await;
+    fs::remove_file(&path).unwrap();
+}
+
+#[tokio::test]
+#[should_panic(expected = "the worst at index 2: expected 7, got 6")]
+async fn perturbed_reference() {
+    let path = reference("perturbed");
+    let tengu = Tengu::cpu().await.unwrap();
+    let a = tengu.tensor([2, 2]).label("a").init(&[1.0, 2.0, 3.0, 4.0]);
+    let b = tengu.tensor([2, 2]).label("b").init(&[1.0, 2.0, 3.5, 4.0]);
+    let mut graph = tengu.graph();
+    graph
+        .add_block("main")
+        .unwrap()
+        .add_computation("out", a * 2.0)
+        .unwrap()
+        .add_computation("perturbed", b * 2.0)
+        .unwrap();
+    let out = graph.add_probe::<f32>("main/out").unwrap();
+    let perturbed = graph.add_probe::<f32>("main/perturbed").unwrap();
+    graph.compute(1).await.unwrap();
+    fs::write(&path, perturbed.retrieve_npy().await.unwrap()).unwrap();
+
+    // Remove the reference before propagating the expected panic.
+    let result = AssertUnwindSafe(out.assert_matches_npy(&path, 0.5))
+        .catch_unwind()
+        .await;
+    fs::remove_file(&path).unwrap();
+    panic::resume_unwind(result.unwrap_err());
+}
+
+fn reference(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("tengu_probe_npy_{name}_{}.npy", std::process::id()))
+}
@@ -0,0 +1,33 @@
+use std::thread;
+
+use pretty_assertions::assert_eq;
+use tengu_graph::Tengu;
+
+fn assert_send_sync<T: Send + Sync + Clone>(_: &T) {}
+
+#[tokio::test]
+async fn main() {
+    // Initialize input tensors.
+    let tengu = Tengu::cpu().await.unwrap();
+    let a = tengu.tensor([4]).label("a").init(&[1.0, 2.0, 3.0, 4.0]);
+
+    // Create computation graph.
+    let mut graph = tengu.graph();
+    graph
+        .add_block("main")
+        .unwrap()
+        .add_computation("out", a.clone() * a)
+        .unwrap();
+
+    // Set up probe and hand a clone of it to another thread.
+    let probe = graph.add_probe::<f32>("main/out").unwrap();
+    assert_send_sync(&probe);
+    let reader = {
+        let probe = probe.clone();
+        thread::spawn(move || pollster::block_on(probe.retrieve()).unwrap())
+    };
+
+    // Run one step of computation and check the data received by the other thread.
+    graph.compute(1).await.unwrap();
+    assert_eq!(reader.join().unwrap(), [1.0, 4.0, 9.0, 16.0]);
+}
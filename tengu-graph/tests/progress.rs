@@ -0,0 +1,18 @@
+use std::cell::RefCell;
+
+use pretty_assertions::assert_eq;
+use tengu_graph::Tengu;
+
+#[tokio::test]
+async fn reports_every_iteration() {
+    let tengu = Tengu::cpu().await.unwrap();
+    let a = tengu.tensor([2]).label("a").init(&[1, 2]);
+    let mut graph = tengu.graph();
+    graph.compute_expr("out", a.clone() + a).unwrap();
+    let reports = RefCell::new(Vec::new());
+    graph
+        .compute_with_progress(3, |current, total| reports.borrow_mut().push((current, total)))
+        .await
+        .unwrap();
+    assert_eq!(reports.into_inner(), [(1, 3), (2, 3), (3, 3)]);
+}
@@ -0,0 +1,38 @@
+use pretty_assertions::assert_eq;
+use tengu_graph::Tengu;
+
+const ITERATIONS: usize = 32;
+
+#[tokio::test]
+async fn main() {
+    // Initialize input tensors.
+    let tengu = Tengu::wgpu().await.unwrap();
+    let a = tengu.tensor([1024]).label("a").init(&[1.0; 1024]);
+    let b = tengu.tensor([1024]).label("b").init(&[2.0; 1024]);
+
+    // Create computation graph.
+    let mut graph = tengu.graph();
+    graph
+        .add_block("main")
+        .unwrap()
+        .add_computation("out", (a.clone() * b.clone() + a).exp() - b)
+        .unwrap();
+
+    // Set up probe.
+    let probe = graph.add_probe::<f32>("main/out").unwrap();
+
+    // Record the number of pipelines built by the end of every iteration.
+    let stats = tengu.backend().stats();
+    let start = stats.pipelines();
+    let mut built = Vec::with_capacity(ITERATIONS);
+    graph
+        .process_async(ITERATIONS, |_| {
+            built.push(stats.pipelines() - start);
+            probe.retrieve()
+        })
+        .await
+        .unwrap();
+
+    // The shader is compiled only on the first iteration and the pipeline is reused afterwards.
+    assert_eq!(built, vec![1; ITERATIONS]);
+}
@@ -13,7 +13,8 @@ async fn main() {
     graph
         .add_block("main")
         .unwrap()
-        .add_computation("rel", a.eq(b).cast::<u32>());
+        .add_computation("rel", a.eq(b).cast::<u32>())
+        .unwrap();
 
     // Set up probes.
     let probe = graph.add_probe::<u32>("main/rel").unwrap();
@@ -0,0 +1,61 @@
+use pretty_assertions::assert_eq;
+use tengu_graph::Tengu;
+
+#[tokio::test]
+async fn main() {
+    // Initialize input tensors.
+    let tengu = Tengu::cpu().await.unwrap();
+    let a = tengu.tensor([2, 2]).label("a").init(&[1, 2, 3, 4]);
+    let b = tengu.tensor([2, 2]).label("b").init(&[5, 6, 7, 8]);
+
+    // Create computation graph and rename the output.
+    let mut graph = tengu.graph();
+    let block = graph.add_block("main").unwrap();
+    block.add_computation("out", a.clone() + b.clone()).unwrap();
+    block.rename_output("out", "total").unwrap();
+    assert!(block.rename_output("out", "other").is_err());
+    assert!(block.rename_output("total", "a").is_err());
+
+    // Set up probes.
+    assert!(graph.add_probe::<i32>("main/out").is_err());
+    let probe = graph.add_probe::<i32>("main/total").unwrap();
+
+    // Run one step of computation.
+    graph.compute(1).await.unwrap();
+
+    // Retrieve result and assert.
+    assert_eq!(probe.retrieve().await.unwrap(), [6, 8, 10, 12]);
+}
+
+#[tokio::test]
+async fn probes_and_links() {
+    // Initialize input tensors.
+    let tengu = Tengu::cpu().await.unwrap();
+    let a = tengu.tensor([2]).label("a").init(&[1, 2]);
+    let b = tengu.tensor([2]).label("b").zero::<i32>();
+
+    // Create computation graph with a probe and a link on the output before renaming it.
+    let mut graph = tengu.graph();
+    graph
+        .add_block("fst")
+        .unwrap()
+        .add_computation("out", a.clone() + a)
+        .unwrap();
+    graph
+        .add_block("snd")
+        .unwrap()
+        .add_computation("copy", b.clone() + b)
+        .unwrap();
+    graph.add_link("fst/out", "snd/b").unwrap();
+    let probe = graph.add_probe::<i32>("fst/out").unwrap();
+    graph.rename_output("fst/out", "total").unwrap();
+    graph.validate_shapes().unwrap();
+
+    // Run two steps of computation, so that the linked output reaches the second block.
+    let copy = graph.add_probe::<i32>("snd/copy").unwrap();
+    graph.compute(2).await.unwrap();
+
+    // Retrieve results and assert.
+    assert_eq!(probe.retrieve().await.unwrap(), [2, 4]);
+    assert_eq!(copy.retrieve().await.unwrap(), [4, 8]);
+}
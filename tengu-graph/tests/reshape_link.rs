@@ -0,0 +1,69 @@
+use anyhow::Result;
+use pretty_assertions::assert_eq;
+
+use tengu_backend::Backend;
+use tengu_graph::{Error, LinkMode, Tengu, CPU, WGPU};
+
+#[tokio::test]
+async fn wgpu() {
+    assert_eq!(run::<WGPU>().await.unwrap(), [4.0, 8.0, 12.0, 16.0, 20.0, 24.0]);
+}
+
+#[tokio::test]
+async fn cpu() {
+    assert_eq!(run::<CPU>().await.unwrap(), [4.0, 8.0, 12.0, 16.0, 20.0, 24.0]);
+}
+
+#[tokio::test]
+async fn shape_mismatch() {
+    let tengu = Tengu::cpu().await.unwrap();
+    let a = tengu.tensor([6]).label("a").zero::<f32>();
+    let b = tengu.tensor([2, 3]).label("b").zero::<f32>();
+    let c = tengu.tensor([5]).label("c").zero::<f32>();
+    let mut graph = tengu.graph();
+    graph
+        .add_block("fst")
+        .unwrap()
+        .add_computation("flat", a.clone() + a)
+        .unwrap();
+    graph
+        .add_block("snd")
+        .unwrap()
+        .add_computation("out", b.clone() + b)
+        .unwrap();
+    graph
+        .add_block("trd")
+        .unwrap()
+        .add_computation("short", c.clone() + c)
+        .unwrap();
+    assert!(matches!(graph.add_link("fst/flat", "snd/b"), Err(Error::ShapeMismatch)));
+    assert!(matches!(
+        graph.add_link_with_options("fst/flat", "trd/c", LinkMode::Copy, true),
+        Err(Error::ShapeMismatch)
+    ));
+}
+
+async fn run<B: Backend + 'static>() -> Result<Vec<f32>> {
+    // Initialize input tensors.
+    let tengu = Tengu::<B>::new().await?;
+    let a = tengu.tensor([6]).label("a").init(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    let b = tengu.tensor([2, 3]).label("b").zero::<f32>();
+
+    // Create computation graph, linking the flat output of the first block to the matrix input of
+    // the second one.
+    let mut graph = tengu.graph();
+    graph.add_block("fst")?.add_computation("flat", a.clone() + a)?;
+    graph.add_block("snd")?.add_computation("out", b.clone() + b)?;
+    let link = graph.add_link_with_options("fst/flat", "snd/b", LinkMode::Copy, true)?;
+    assert!(link.reshape());
+
+    // Set up probes.
+    let probe = graph.add_probe::<f32>("snd/out")?;
+
+    // The link is propagated after the first iteration, so the second block sees the flat
+    // output only when it is computed again.
+    graph.compute(1).await?;
+    assert_eq!(probe.retrieve().await?, [0.0; 6]);
+    graph.compute_block("snd", 1).await?;
+    Ok(probe.retrieve().await?)
+}
@@ -0,0 +1,55 @@
+use std::rc::Rc;
+
+use anyhow::Result;
+use pretty_assertions::assert_eq;
+
+use tengu_backend::Backend;
+use tengu_graph::{Tengu, CPU, WGPU};
+
+const BLOCKS: usize = 8;
+
+#[tokio::test]
+async fn all_probes() {
+    let tengu = Tengu::<CPU>::new().await.unwrap();
+    assert!(run(&tengu).await.unwrap());
+}
+
+#[tokio::test]
+async fn single_poll() {
+    let tengu = Tengu::<WGPU>::new().await.unwrap();
+    let stats = tengu.backend().stats();
+    let (polls, maps) = (stats.polls(), stats.maps());
+
+    // All the staging buffers are mapped before the device is polled once for all of them.
+    assert!(run(&tengu).await.unwrap());
+    assert_eq!(stats.polls() - polls, 1);
+    assert_eq!(stats.maps() - maps, BLOCKS);
+}
+
+/// Runs a single iteration of a graph with several independent probed blocks and checks that every
+/// probe received the output of its block.
+///
+/// # Returns
+/// Whether all probes had data waiting once the computation returned.
+async fn run<B: Backend + 'static>(tengu: &Rc<Tengu<B>>) -> Result<bool> {
+    // Create computation graph.
+    let mut graph = tengu.graph();
+    for i in 0..BLOCKS {
+        let a = tengu.tensor([1024]).label(format!("a{i}")).init(&[i as f32; 1024]);
+        let b = tengu.tensor([1024]).label(format!("b{i}")).init(&[1.0; 1024]);
+        graph.add_block(format!("block{i}"))?.add_computation("out", a + b)?;
+    }
+
+    // Set up probes.
+    let probes = (0..BLOCKS)
+        .map(|i| graph.add_probe::<f32>(&format!("block{i}/out")))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Run the computation and check the results.
+    graph.compute(1).await?;
+    let dirty = probes.iter().all(|probe| probe.is_dirty());
+    for (i, probe) in probes.iter().enumerate() {
+        assert_eq!(probe.retrieve().await?, vec![i as f32 + 1.0; 1024]);
+    }
+    Ok(dirty)
+}
@@ -0,0 +1,34 @@
+use anyhow::Result;
+use pretty_assertions::assert_eq;
+
+use tengu_backend::Backend;
+use tengu_graph::{Tengu, Type, CPU, WGPU};
+
+#[tokio::test]
+async fn retrieve_bytes_cpu() {
+    run::<CPU>().await.unwrap();
+}
+
+#[tokio::test]
+async fn retrieve_bytes_wgpu() {
+    run::<WGPU>().await.unwrap();
+}
+
+async fn run<B: Backend + 'static>() -> Result<()> {
+    let tengu = Tengu::<B>::new().await?;
+    let mut graph = tengu.graph();
+    let a = tengu.tensor([2, 3]).label("a").init(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    graph.compute_expr("out", a.clone() + a)?;
+    let probe = graph.add_probe::<f32>("default/out")?;
+    graph.compute(1).await?;
+    let bytes = probe.retrieve_bytes().await?;
+    assert_eq!(probe.dtype(), Type::F32);
+    assert_eq!(probe.shape(), &[2, 3]);
+    assert_eq!(bytes.len(), 6 * std::mem::size_of::<f32>());
+    let values = bytes
+        .chunks_exact(4)
+        .map(|chunk| f32::from_ne_bytes(chunk.try_into().unwrap()))
+        .collect::<Vec<_>>();
+    assert_eq!(values, [2.0, 4.0, 6.0, 8.0, 10.0, 12.0]);
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use anyhow::Result;
+use pretty_assertions::assert_eq;
+
+use tengu_backend::Backend;
+use tengu_graph::{Tengu, CPU, WGPU};
+
+#[tokio::test]
+async fn retrieve_range_cpu() {
+    run::<CPU>().await.unwrap();
+}
+
+#[tokio::test]
+async fn retrieve_range_wgpu() {
+    run::<WGPU>().await.unwrap();
+}
+
+#[tokio::test]
+async fn out_of_bounds() {
+    let tengu = Tengu::cpu().await.unwrap();
+    let mut graph = tengu.graph();
+    let a = tengu.tensor([4]).label("a").init(&[1.0, 2.0, 3.0, 4.0]);
+    graph.compute_expr("out", a.clone() + a).unwrap();
+    let probe = graph.add_probe::<f32>("default/out").unwrap();
+    assert!(probe.retrieve_range(2..5).await.is_err());
+}
+
+#[tokio::test]
+async fn empty() {
+    let tengu = Tengu::cpu().await.unwrap();
+    let mut graph = tengu.graph();
+    let a = tengu.tensor([4]).label("a").init(&[1.0, 2.0, 3.0, 4.0]);
+    graph.compute_expr("out", a.clone() + a).unwrap();
+    let probe = graph.add_probe::<f32>("default/out").unwrap();
+    assert!(probe.retrieve_range(2..2).await.is_err());
+}
+
+async fn run<B: Backend + 'static>() -> Result<()> {
+    let tengu = Tengu::<B>::new().await?;
+    let mut graph = tengu.graph();
+    let data = (0..10).map(|v| v as f32).collect::<Vec<_>>();
+    let a = tengu.tensor([10]).label("a").init(&data);
+    let b = tengu.tensor([10]).label("b").init(&[1.0; 10]);
+    graph.compute_expr("out", a + b)?;
+    let probe = graph.add_probe::<f32>("default/out")?;
+
+    // Read out the whole tensor first.
+    graph.compute(1).await?;
+    let full = probe.retrieve().await?;
+
+    // Request the window before the next readout, so that only the window is read out.
+    let (partial, computed) = tokio::join!(probe.retrieve_range(2..5), graph.compute(1));
+    computed?;
+    assert_eq!(partial?, full[2..5]);
+    Ok(())
+}
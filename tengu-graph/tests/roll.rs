@@ -0,0 +1,38 @@
+use anyhow::Result;
+use pretty_assertions::assert_eq;
+
+use tengu_backend::Backend;
+use tengu_graph::{Tengu, CPU, WGPU};
+
+#[tokio::test]
+async fn roll_cpu() {
+    assert_eq!(
+        run::<CPU>().await.unwrap(),
+        (vec![4.0, 1.0, 2.0, 3.0], vec![4, 5, 6, 1, 2, 3], vec![2, 3, 1, 5, 6, 4])
+    );
+}
+
+#[tokio::test]
+async fn roll_wgpu() {
+    assert_eq!(
+        run::<WGPU>().await.unwrap(),
+        (vec![4.0, 1.0, 2.0, 3.0], vec![4, 5, 6, 1, 2, 3], vec![2, 3, 1, 5, 6, 4])
+    );
+}
+
+async fn run<B: Backend + 'static>() -> Result<(Vec<f32>, Vec<i32>, Vec<i32>)> {
+    let tengu = Tengu::<B>::new().await?;
+    let a = tengu.tensor([4]).label("a").init(&[1.0, 2.0, 3.0, 4.0]);
+    let b = tengu.tensor([2, 3]).label("b").init(&[1, 2, 3, 4, 5, 6]);
+    let mut graph = tengu.graph();
+    graph
+        .add_block("main")?
+        .add_computation("roll", a.roll(1, 0))?
+        .add_computation("rows", b.clone().roll(3, 0))?
+        .add_computation("back", b.roll(-1, 1))?;
+    let roll = graph.add_probe::<f32>("main/roll")?;
+    let rows = graph.add_probe::<i32>("main/rows")?;
+    let back = graph.add_probe::<i32>("main/back")?;
+    graph.compute(1).await?;
+    Ok((roll.retrieve().await?, rows.retrieve().await?, back.retrieve().await?))
+}
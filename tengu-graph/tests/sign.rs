@@ -0,0 +1,77 @@
+use anyhow::Result;
+use pretty_assertions::assert_eq;
+
+use tengu_backend::Backend;
+use tengu_graph::{Tengu, CPU, WGPU};
+
+#[tokio::test]
+async fn cpu() {
+    run::<CPU>().await.unwrap();
+}
+
+#[tokio::test]
+async fn wgpu() {
+    run::<WGPU>().await.unwrap();
+}
+
+#[tokio::test]
+async fn integers_cpu() {
+    integers::<CPU>().await.unwrap();
+}
+
+#[tokio::test]
+async fn integers_wgpu() {
+    integers::<WGPU>().await.unwrap();
+}
+
+async fn run<B: Backend + 'static>() -> Result<()> {
+    // Initialize input tensors covering positive, negative, and zero values.
+    let tengu = Tengu::<B>::new().await?;
+    let a = tengu.tensor([6]).label("a").init(&[3.0, -2.0, 0.0, -0.0, 1.5, -4.0]);
+    let b = tengu.tensor([6]).label("b").init(&[-1.0, 5.0, -2.0, 3.0, 0.0, -0.0]);
+
+    // Create computation graph.
+    let mut graph = tengu.graph();
+    graph
+        .add_block("main")?
+        .add_computation("sign", a.clone().sign())?
+        .add_computation("copysign", a.copysign(b))?;
+
+    // Set up probes.
+    let sign = graph.add_probe::<f32>("main/sign")?;
+    let copysign = graph.add_probe::<f32>("main/copysign")?;
+
+    // Run the computation and check the results. Zeros have no sign, and zero signs are positive.
+    graph.compute(1).await?;
+    assert_eq!(sign.retrieve().await?, vec![1.0, -1.0, 0.0, 0.0, 1.0, -1.0]);
+    assert_eq!(copysign.retrieve().await?, vec![-3.0, 2.0, 0.0, 0.0, 1.5, 4.0]);
+    Ok(())
+}
+
+async fn integers<B: Backend + 'static>() -> Result<()> {
+    // Initialize signed and unsigned input tensors.
+    let tengu = Tengu::<B>::new().await?;
+    let a = tengu.tensor([4]).label("a").init(&[3i32, -2, 0, -7]);
+    let b = tengu.tensor([4]).label("b").init(&[-1i32, 5, -2, 0]);
+    let c = tengu.tensor([3]).label("c").init(&[0u32, 1, 9]);
+
+    // Create computation graph.
+    let mut graph = tengu.graph();
+    graph
+        .add_block("main")?
+        .add_computation("sign", a.clone().sign())?
+        .add_computation("copysign", a.copysign(b))?
+        .add_computation("unsigned_sign", c.sign())?;
+
+    // Set up probes.
+    let sign = graph.add_probe::<i32>("main/sign")?;
+    let copysign = graph.add_probe::<i32>("main/copysign")?;
+    let unsigned_sign = graph.add_probe::<u32>("main/unsigned_sign")?;
+
+    // Run the computation and check the results. Unsigned signs are either zero or one.
+    graph.compute(1).await?;
+    assert_eq!(sign.retrieve().await?, vec![1, -1, 0, -1]);
+    assert_eq!(copysign.retrieve().await?, vec![-3, 2, 0, 7]);
+    assert_eq!(unsigned_sign.retrieve().await?, vec![0, 1, 1]);
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use anyhow::Result;
+
+use tengu_backend::Backend;
+use tengu_graph::{Tengu, CPU, WGPU};
+
+#[tokio::test]
+async fn cpu() {
+    run::<CPU>().await.unwrap();
+}
+
+#[tokio::test]
+async fn wgpu() {
+    run::<WGPU>().await.unwrap();
+}
+
+async fn run<B: Backend + 'static>() -> Result<()> {
+    // Initialize input tensors. The last row would overflow without the max-subtraction.
+    let tengu = Tengu::<B>::new().await?;
+    let a = tengu
+        .tensor([3, 4])
+        .label("a")
+        .init(&[1.0, 2.0, 3.0, 4.0, -5.0, 0.0, 5.0, 0.5, 1000.0, 999.0, 998.0, 1000.0]);
+
+    // Create computation graph.
+    let mut graph = tengu.graph();
+    graph.add_block("main")?.add_computation("softmax", a.softmax(1))?;
+
+    // Set up probes.
+    let probe = graph.add_probe::<f32>("main/softmax")?;
+
+    // Run the computation.
+    graph.compute(1).await?;
+
+    // Retrieve the results and assert that every row sums to one.
+    let data = probe.retrieve().await?;
+    for row in data.chunks(4) {
+        assert!(row.iter().all(|value| value.is_finite() && *value > 0.0));
+        let sum: f32 = row.iter().sum();
+        assert!((sum - 1.0).abs() < 1e-5, "row {row:?} sums to {sum}");
+    }
+    assert!(data[3] > data[2] && data[2] > data[1] && data[1] > data[0]);
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use anyhow::Result;
+use pretty_assertions::assert_eq;
+
+use tengu_backend::Backend;
+use tengu_graph::{Shape, Tengu, CPU, WGPU};
+
+#[tokio::test]
+async fn split_cpu() {
+    let (head, tail) = run::<CPU>().await.unwrap();
+    assert_eq!(head, vec![1.0, 2.0]);
+    assert_eq!(tail, vec![3.0, 4.0, 5.0, 6.0]);
+}
+
+#[tokio::test]
+async fn split_wgpu() {
+    let (head, tail) = run::<WGPU>().await.unwrap();
+    assert_eq!(head, vec![1.0, 2.0]);
+    assert_eq!(tail, vec![3.0, 4.0, 5.0, 6.0]);
+}
+
+async fn run<B: Backend + 'static>() -> Result<(Vec<f32>, Vec<f32>)> {
+    let tengu = Tengu::<B>::new().await?;
+    let a = tengu.tensor([6]).label("a").init(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    let mut chunks = a.split(0, &[2, 4]).into_iter();
+    let (head, tail) = (chunks.next().unwrap(), chunks.next().unwrap());
+    assert_eq!(head.shape(), &[2]);
+    assert_eq!(tail.shape(), &[4]);
+    let mut graph = tengu.graph();
+    graph
+        .add_block("main")?
+        .add_computation("head", head)?
+        .add_computation("tail", tail)?;
+    let head = graph.add_probe::<f32>("main/head")?;
+    let tail = graph.add_probe::<f32>("main/tail")?;
+    graph.compute(1).await?;
+    Ok((head.retrieve().await?, tail.retrieve().await?))
+}
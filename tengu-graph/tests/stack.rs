@@ -0,0 +1,43 @@
+use anyhow::Result;
+use pretty_assertions::assert_eq;
+
+use tengu_backend::Backend;
+use tengu_graph::{Shape, Tengu, CPU, WGPU};
+
+#[tokio::test]
+async fn stack_cpu() {
+    assert_eq!(
+        run::<CPU>().await.unwrap(),
+        (vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], vec![1, 3, 5, 2, 4, 6])
+    );
+}
+
+#[tokio::test]
+async fn stack_wgpu() {
+    assert_eq!(
+        run::<WGPU>().await.unwrap(),
+        (vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], vec![1, 3, 5, 2, 4, 6])
+    );
+}
+
+async fn run<B: Backend + 'static>() -> Result<(Vec<f32>, Vec<i32>)> {
+    let tengu = Tengu::<B>::new().await?;
+    let a = tengu.tensor([2]).label("a").init(&[1.0, 2.0]);
+    let b = tengu.tensor([2]).label("b").init(&[3.0, 4.0]);
+    let c = tengu.tensor([2]).label("c").init(&[5.0, 6.0]);
+
+    // Stacking along axis 0 makes the tensors the rows of the result, and along axis 1 its columns.
+    let rows = tengu.stack(&[a.clone(), b.clone(), c.clone()], 0);
+    assert_eq!(rows.shape(), &[3, 2]);
+    let columns = tengu.stack(&[a, b, c], 1).cast::<i32>();
+    assert_eq!(columns.shape(), &[2, 3]);
+    let mut graph = tengu.graph();
+    graph
+        .add_block("main")?
+        .add_computation("rows", rows)?
+        .add_computation("columns", columns)?;
+    let rows = graph.add_probe::<f32>("main/rows")?;
+    let columns = graph.add_probe::<i32>("main/columns")?;
+    graph.compute(1).await?;
+    Ok((rows.retrieve().await?, columns.retrieve().await?))
+}
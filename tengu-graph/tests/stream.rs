@@ -0,0 +1,37 @@
+use futures::{StreamExt, TryStreamExt};
+use pretty_assertions::assert_eq;
+use tengu_graph::Tengu;
+
+#[tokio::test]
+async fn iteration_indices() {
+    let tengu = Tengu::cpu().await.unwrap();
+    let a = tengu.tensor([2]).label("a").init(&[1, 2]);
+    let mut graph = tengu.graph();
+    graph.compute_expr("out", a.clone() + a).unwrap();
+    let iterations: Vec<usize> = graph.stream(3).try_collect().await.unwrap();
+    assert_eq!(iterations, [0, 1, 2]);
+}
+
+#[tokio::test]
+async fn driven_by_caller() {
+    // Accumulate into x on every iteration, so that the probe shows how many have been computed.
+    let tengu = Tengu::cpu().await.unwrap();
+    let x = tengu.tensor([2]).label("x").init(&[0.0, 10.0]);
+    let one = tengu.tensor([2]).label("one").init(&[1.0; 2]);
+    let mut graph = tengu.graph();
+    graph
+        .add_block("main")
+        .unwrap()
+        .add_computation("out", x + one)
+        .unwrap();
+    graph.add_link("main/out", "main/x").unwrap();
+    let probe = graph.add_probe::<f32>("main/out").unwrap();
+
+    // Only the polled iterations are computed.
+    let mut stream = Box::pin(graph.stream(usize::MAX));
+    for i in 0..2 {
+        assert_eq!(stream.next().await.unwrap().unwrap(), i);
+    }
+    drop(stream);
+    assert_eq!(probe.retrieve().await.unwrap(), [2.0, 12.0]);
+}
@@ -0,0 +1,26 @@
+use pretty_assertions::assert_eq;
+use tengu_graph::Tengu;
+
+const ITERATIONS: usize = 8;
+
+#[tokio::test]
+async fn main() {
+    let tengu = Tengu::wgpu().await.unwrap();
+    let stats = tengu.backend().stats();
+    for (link, probe) in [(false, false), (true, false), (true, true)] {
+        // Create the iteration x <- x + 1.
+        let x = tengu.tensor([64]).label("x").zero::<f32>();
+        let mut graph = tengu.graph();
+        graph.add_block("main").unwrap().add_computation("y", x + 1.0).unwrap();
+        if link {
+            graph.add_link("main/y", "main/x").unwrap();
+        }
+        let _probe = probe.then(|| graph.add_probe::<f32>("main/y").unwrap());
+
+        // Links and probes each add a submission per iteration, which is skipped without them.
+        let (propagations, readouts) = (stats.propagations(), stats.readouts());
+        graph.compute(ITERATIONS).await.unwrap();
+        assert_eq!(stats.propagations() - propagations, if link { ITERATIONS } else { 0 });
+        assert_eq!(stats.readouts() - readouts, if probe { ITERATIONS } else { 0 });
+    }
+}
@@ -0,0 +1,27 @@
+use anyhow::Result;
+use pretty_assertions::assert_eq;
+
+use tengu_backend::Backend;
+use tengu_graph::{Shape, Tengu, CPU, WGPU};
+
+#[tokio::test]
+async fn take_cpu() {
+    assert_eq!(run::<CPU>().await.unwrap(), vec![4.0, 2.0, 1.0]);
+}
+
+#[tokio::test]
+async fn take_wgpu() {
+    assert_eq!(run::<WGPU>().await.unwrap(), vec![4.0, 2.0, 1.0]);
+}
+
+async fn run<B: Backend + 'static>() -> Result<Vec<f32>> {
+    let tengu = Tengu::<B>::new().await?;
+    let a = tengu.tensor([4]).label("a").init(&[1.0, 2.0, 3.0, 4.0]);
+    let taken = a.take(&[3, 1, 0]);
+    assert_eq!(taken.shape(), &[3]);
+    let mut graph = tengu.graph();
+    graph.add_block("main")?.add_computation("taken", taken)?;
+    let probe = graph.add_probe::<f32>("main/taken")?;
+    graph.compute(1).await?;
+    Ok(probe.retrieve().await?)
+}
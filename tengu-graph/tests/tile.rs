@@ -0,0 +1,27 @@
+use anyhow::Result;
+use pretty_assertions::assert_eq;
+
+use tengu_backend::Backend;
+use tengu_graph::{Shape, Tengu, CPU, WGPU};
+
+#[tokio::test]
+async fn repeat_rows_cpu() {
+    assert_eq!(run::<CPU>().await.unwrap(), vec![1.0, 2.0, 3.0, 1.0, 2.0, 3.0]);
+}
+
+#[tokio::test]
+async fn repeat_rows_wgpu() {
+    assert_eq!(run::<WGPU>().await.unwrap(), vec![1.0, 2.0, 3.0, 1.0, 2.0, 3.0]);
+}
+
+async fn run<B: Backend + 'static>() -> Result<Vec<f32>> {
+    let tengu = Tengu::<B>::new().await?;
+    let a = tengu.tensor([1, 3]).label("a").init(&[1.0, 2.0, 3.0]);
+    let tiled = a.repeat(&[2, 1]);
+    assert_eq!(tiled.shape(), &[2, 3]);
+    let mut graph = tengu.graph();
+    graph.add_block("main")?.add_computation("tiled", tiled)?;
+    let probe = graph.add_probe::<f32>("main/tiled")?;
+    graph.compute(1).await?;
+    Ok(probe.retrieve().await?)
+}
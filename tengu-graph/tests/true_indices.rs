@@ -0,0 +1,29 @@
+use anyhow::Result;
+use pretty_assertions::assert_eq;
+
+use tengu_backend::Backend;
+use tengu_graph::{Tengu, CPU, WGPU};
+
+#[tokio::test]
+async fn true_indices_cpu() {
+    assert_eq!(run::<CPU>().await.unwrap(), (vec![1, 3], vec![1, 3]));
+}
+
+#[tokio::test]
+async fn true_indices_wgpu() {
+    assert_eq!(run::<WGPU>().await.unwrap(), (vec![1, 3], vec![1, 3]));
+}
+
+async fn run<B: Backend + 'static>() -> Result<(Vec<u32>, Vec<u32>)> {
+    let tengu = Tengu::<B>::new().await?;
+    let a = tengu.tensor([4]).label("a").init(&[0u32, 1, 0, 1]);
+    let mut graph = tengu.graph();
+    graph
+        .add_block("main")?
+        .add_computation("mask", a.clone() * a.clone())?
+        .add_computation("bools", a.cast::<bool>())?;
+    let mask = graph.add_probe::<u32>("main/mask")?;
+    let bools = graph.add_probe::<bool>("main/bools")?;
+    graph.compute(1).await?;
+    Ok((mask.true_indices().await?, bools.true_indices().await?))
+}
@@ -0,0 +1,37 @@
+use anyhow::Result;
+use pretty_assertions::assert_eq;
+
+use tengu_backend::Backend;
+use tengu_graph::{Tengu, CPU, WGPU};
+
+#[tokio::test]
+async fn update_every_cpu() {
+    assert_eq!(run::<CPU>(&[4]).await.unwrap(), (vec![4.0], vec![2.0]));
+    assert_eq!(run::<CPU>(&[1, 1, 1, 1, 1]).await.unwrap(), (vec![5.0], vec![3.0]));
+}
+
+#[tokio::test]
+async fn update_every_wgpu() {
+    assert_eq!(run::<WGPU>(&[4]).await.unwrap(), (vec![4.0], vec![2.0]));
+    assert_eq!(run::<WGPU>(&[1, 1, 1, 1, 1]).await.unwrap(), (vec![5.0], vec![3.0]));
+}
+
+/// Runs two counters, one of which is only updated every second iteration, over several `compute`
+/// calls with the given numbers of iterations, and returns the final values of both counters.
+async fn run<B: Backend + 'static>(calls: &[usize]) -> Result<(Vec<f32>, Vec<f32>)> {
+    let tengu = Tengu::<B>::new().await?;
+    let mut graph = tengu.graph();
+    for label in ["fast", "slow"] {
+        let x = tengu.tensor([1]).label("x").zero::<f32>();
+        let one = tengu.tensor([1]).label("one").init(&[1.0]);
+        graph.add_block(label)?.add_computation("out", x + one)?;
+        graph.add_link(format!("{label}/out"), format!("{label}/x"))?;
+    }
+    graph.get_block_mut("slow")?.set_update_every(2);
+    let fast = graph.add_probe::<f32>("fast/out")?;
+    let slow = graph.add_probe::<f32>("slow/out")?;
+    for &times in calls {
+        graph.compute(times).await?;
+    }
+    Ok((fast.retrieve().await?, slow.retrieve().await?))
+}
@@ -0,0 +1,31 @@
+use anyhow::Result;
+use pretty_assertions::assert_eq;
+
+use tengu_backend::Backend;
+use tengu_graph::{Tengu, CPU, WGPU};
+
+#[tokio::test]
+async fn view_cpu() {
+    run::<CPU>().await.unwrap();
+}
+
+#[tokio::test]
+async fn view_wgpu() {
+    run::<WGPU>().await.unwrap();
+}
+
+async fn run<B: Backend + 'static>() -> Result<()> {
+    let tengu = Tengu::<B>::new().await?;
+    let a = tengu.tensor([2, 3]).label("a").init(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    let mut graph = tengu.graph();
+    graph.add_block("main")?.add_computation("out", a.clone() + a)?;
+    let probe = graph.add_probe::<f32>("main/out")?;
+    graph.compute(1).await?;
+    let view = probe.view().await?;
+    assert_eq!(view.shape(), &[2, 3]);
+    assert_eq!(view.get(&[1, 2]), Some(&12.0));
+    assert_eq!(view[[0, 1]], 4.0);
+    assert_eq!(view.get(&[2, 0]), None);
+    assert_eq!(view.get(&[1]), None);
+    Ok(())
+}
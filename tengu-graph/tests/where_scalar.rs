@@ -0,0 +1,40 @@
+use anyhow::Result;
+use pretty_assertions::assert_eq;
+
+use tengu_backend::Backend;
+use tengu_graph::{Tengu, CPU, WGPU};
+
+#[tokio::test]
+async fn where_scalar_cpu() {
+    assert_eq!(
+        run::<CPU>().await.unwrap(),
+        (vec![0.0, 2.5, 0.0, 4.0], vec![-1, 2, -1, 4])
+    );
+}
+
+#[tokio::test]
+async fn where_scalar_wgpu() {
+    assert_eq!(
+        run::<WGPU>().await.unwrap(),
+        (vec![0.0, 2.5, 0.0, 4.0], vec![-1, 2, -1, 4])
+    );
+}
+
+async fn run<B: Backend + 'static>() -> Result<(Vec<f32>, Vec<i32>)> {
+    let tengu = Tengu::<B>::new().await?;
+    let a = tengu.tensor([4]).label("a").init(&[-1.0, 2.5, -3.0, 4.0]);
+    let b = tengu.tensor([2, 2]).label("b").init(&[1, 2, 3, 4]);
+    let odd = tengu.tensor([2, 2]).label("odd").init(&[true, false, true, false]);
+
+    // Mask out the negative values with a `0 > a` comparison.
+    let negative = tengu.scalar(0.0).gt(a.clone());
+    let mut graph = tengu.graph();
+    graph
+        .add_block("main")?
+        .add_computation("relu", negative.where_scalar(a, 0.0))?
+        .add_computation("filled", odd.where_scalar(b, -1))?;
+    let relu = graph.add_probe::<f32>("main/relu")?;
+    let filled = graph.add_probe::<i32>("main/filled")?;
+    graph.compute(1).await?;
+    Ok((relu.retrieve().await?, filled.retrieve().await?))
+}
@@ -0,0 +1,29 @@
+use anyhow::Result;
+use pretty_assertions::assert_eq;
+
+use tengu_backend::Backend;
+use tengu_graph::{Tengu, CPU, WGPU};
+
+#[tokio::test]
+async fn zeros_cpu() {
+    run::<CPU>().await.unwrap();
+}
+
+#[tokio::test]
+async fn zeros_wgpu() {
+    run::<WGPU>().await.unwrap();
+}
+
+async fn run<B: Backend + 'static>() -> Result<()> {
+    let tengu = Tengu::<B>::new().await?;
+    let mut graph = tengu.graph();
+    let block = graph.add_block("main")?;
+    block.add_computation("f32", tengu.tensor([4]).zeros_f32() + 1.5)?;
+    block.add_computation("i32", tengu.tensor([4]).zeros_i32() - 2)?;
+    let f32_probe = graph.add_probe::<f32>("main/f32")?;
+    let i32_probe = graph.add_probe::<i32>("main/i32")?;
+    graph.compute(1).await?;
+    assert_eq!(f32_probe.retrieve().await?, [1.5; 4]);
+    assert_eq!(i32_probe.retrieve().await?, [-2; 4]);
+    Ok(())
+}
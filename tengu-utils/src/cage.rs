@@ -1,7 +1,7 @@
-/// This module defined the `Cage` enum that works similarly to `Cow`, but holds either a borrowed
-/// reference to `dyn Any` or or boxed version of it. It is useful when you need to hold an object
-/// of any type but sometimes you just need to borrow it and somethimes you want to place it on the
-/// heap.
+//! This module defined the `Cage` enum that works similarly to `Cow`, but holds either a borrowed
+//! reference to `dyn Any` or or boxed version of it. It is useful when you need to hold an object
+//! of any type but sometimes you just need to borrow it and somethimes you want to place it on the
+//! heap.
 use std::any::Any;
 
 /// The `Cage` enum holds either a borrowed reference to `dyn Any` or a boxed version of it.
@@ -20,13 +20,15 @@
 //! - `AdapterBuilder`: Provides a builder pattern for requesting adapters from a WGPU instance.
 //!   - `AdapterBuilder::new`: Creates a new `AdapterBuilder` for the specified instance.
 //!   - `AdapterBuilder::with_surface`: Sets the surface for the adapter to be compatible with.
+//!   - `AdapterBuilder::with_retry`: Sets the policy for retrying failed adapter requests.
+//!   - `AdapterBuilder::with_fallback`: Requests a software adapter instead of a hardware one.
 //!   - `AdapterBuilder::request`: Requests an adapter asynchronously and returns an `Adapter` if successful.
 
 use std::ops::Deref;
 
 use tracing::trace;
 
-use crate::{device::DeviceBuilder, Error, Surface};
+use crate::{device::DeviceBuilder, Error, Retry, Surface};
 
 // NOTE: Adapter implementation.
 
@@ -57,6 +59,18 @@ impl Adapter {
     pub fn device(self) -> DeviceBuilder {
         DeviceBuilder::new(self.adapter)
     }
+
+    /// Creates a `DeviceBuilder` from the adapter for building a GPU device, which retries failed
+    /// device requests according to the given policy.
+    ///
+    /// # Parameters
+    /// - `retry`: The policy for retrying failed device requests.
+    ///
+    /// # Returns
+    /// A `DeviceBuilder` instance.
+    pub fn device_with_retry(self, retry: Retry) -> DeviceBuilder {
+        DeviceBuilder::new(self.adapter).with_retry(retry)
+    }
 }
 
 impl Deref for Adapter {
@@ -75,6 +89,7 @@ impl Deref for Adapter {
 pub struct AdapterBuilder<'surface, 'window> {
     instance: wgpu::Instance,
     request_adapter_options: wgpu::RequestAdapterOptions<'surface, 'window>,
+    retry: Retry,
 }
 
 impl<'surface, 'window> AdapterBuilder<'surface, 'window> {
@@ -89,6 +104,7 @@ impl<'surface, 'window> AdapterBuilder<'surface, 'window> {
         Self {
             instance,
             request_adapter_options: wgpu::RequestAdapterOptions::default(),
+            retry: Retry::none(),
         }
     }
 
@@ -104,16 +120,45 @@ impl<'surface, 'window> AdapterBuilder<'surface, 'window> {
         self
     }
 
-    /// Requests an adapter asynchronously and returns an `Adapter` if successful.
+    /// Requests a software fallback adapter, such as llvmpipe or lavapipe on Linux and WARP on
+    /// Windows, instead of a hardware one. This allows running on machines without a GPU, e.g.
+    /// headless CI runners, at the cost of being much slower than any hardware adapter.
+    ///
+    /// # Returns
+    /// The updated `AdapterBuilder`.
+    pub fn with_fallback(mut self) -> Self {
+        self.request_adapter_options.force_fallback_adapter = true;
+        self
+    }
+
+    /// Sets the policy for retrying failed adapter requests. By default, the adapter is requested
+    /// only once.
+    ///
+    /// # Parameters
+    /// - `retry`: The retry policy.
+    ///
+    /// # Returns
+    /// The updated `AdapterBuilder`.
+    pub fn with_retry(mut self, retry: Retry) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Requests an adapter asynchronously and returns an `Adapter` if successful. Failed requests
+    /// are retried according to the retry policy.
     ///
     /// # Returns
     /// A `Result` containing an `Adapter` or an `Error` if the adapter creation fails.
     pub async fn request(self) -> Result<Adapter, Error> {
         let adapter = self
-            .instance
-            .request_adapter(&self.request_adapter_options)
-            .await
-            .ok_or(Error::CreateAdapterError)?;
+            .retry
+            .run(|| async {
+                self.instance
+                    .request_adapter(&self.request_adapter_options)
+                    .await
+                    .ok_or(Error::CreateAdapterError)
+            })
+            .await?;
         trace!("Requested new adapter");
         Ok(Adapter::new(adapter))
     }
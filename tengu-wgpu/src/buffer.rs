@@ -17,12 +17,14 @@
 //! The `BufferUsage` enum defines different usage types for buffers, corresponding to WGPU-defined usages:
 //! - `Staging`: Used for staging data to be transferred to the GPU. Corresponds to `MAP_READ | COPY_DST`. These buffers are typically
 //!   used for uploading data to the GPU or reading data back from the GPU.
-//! - `Read`: Used for reading data from the GPU. Corresponds to `STORAGE | COPY_SRC`. These buffers are mainly used when the data will
-//!   be read by shaders.
+//! - `Read`: Used for reading data from the GPU. Corresponds to `STORAGE | COPY_SRC | COPY_DST`. These buffers are mainly used when
+//!   the data will be read by shaders, but they can still be overwritten from the CPU, e.g. when restoring a saved state.
 //! - `Write`: Used for writing data to the GPU. Corresponds to `STORAGE | COPY_DST`. These buffers are used when the GPU will write
 //!   data that the CPU will read.
 //! - `ReadWrite`: Used for both reading and writing data to/from the GPU. Corresponds to `STORAGE | COPY_SRC | COPY_DST`. These
 //!   buffers offer the most flexibility as they can be used for both read and write operations.
+//! - `Upload`: Used for uploading data from the CPU through a mapped write. Corresponds to `MAP_WRITE | COPY_SRC`. These buffers
+//!   are written directly by the CPU and then copied into storage buffers.
 //!
 //! ## Staging Buffers
 //!
@@ -30,11 +32,17 @@
 //! access to GPU memory is often restricted or inefficient. By using staging buffers, data can be efficiently transferred to and from
 //! the GPU. This is particularly useful for initializing buffers with data or reading back results from computations.
 
+use std::borrow::Cow;
 use std::ops::Deref;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
 use tracing::trace;
 use wgpu::util::DeviceExt;
 
-use crate::Device;
+use crate::{Device, Error, Result};
+
+/// Counter used to number the generated labels of buffers created without one.
+static UNLABELED_BUFFERS: AtomicUsize = AtomicUsize::new(0);
 
 /// Enumerates the different usages for a buffer.
 #[derive(Copy, Clone, Debug)]
@@ -47,6 +55,8 @@ pub enum BufferUsage {
     ReadWrite,
     /// Buffer used for writing.
     Write,
+    /// Buffer used for uploading data through a mapped write.
+    Upload,
 }
 
 impl BufferUsage {
@@ -58,9 +68,24 @@ impl BufferUsage {
         use wgpu::BufferUsages as Usage;
         match self {
             Self::Staging => Usage::MAP_READ | Usage::COPY_DST,
-            Self::Read => Usage::STORAGE | Usage::COPY_SRC,
+            Self::Read => Usage::STORAGE | Usage::COPY_SRC | Usage::COPY_DST,
             Self::Write => Usage::STORAGE | Usage::COPY_DST,
             Self::ReadWrite => Usage::STORAGE | Usage::COPY_SRC | Usage::COPY_DST,
+            Self::Upload => Usage::MAP_WRITE | Usage::COPY_SRC,
+        }
+    }
+
+    /// Returns the name of the usage, used to generate labels for unlabeled buffers.
+    ///
+    /// # Returns
+    /// The name of the usage in snake case.
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Staging => "staging",
+            Self::Read => "read",
+            Self::Write => "write",
+            Self::ReadWrite => "read_write",
+            Self::Upload => "upload",
         }
     }
 }
@@ -68,6 +93,7 @@ impl BufferUsage {
 /// Represents a GPU buffer in the WGPU backend.
 pub struct Buffer {
     buffer: wgpu::Buffer,
+    label: String,
     usage: BufferUsage,
 }
 
@@ -76,12 +102,21 @@ impl Buffer {
     ///
     /// # Parameters
     /// - `buffer`: The WGPU buffer.
+    /// - `label`: The label the buffer was created with.
     /// - `usage`: The usage type of the buffer.
     ///
     /// # Returns
     /// A new `Buffer` instance.
-    fn new(buffer: wgpu::Buffer, usage: BufferUsage) -> Self {
-        Self { buffer, usage }
+    fn new(buffer: wgpu::Buffer, label: String, usage: BufferUsage) -> Self {
+        Self { buffer, label, usage }
+    }
+
+    /// Returns the label of the buffer, which identifies it in validation errors and GPU captures.
+    ///
+    /// # Returns
+    /// A reference to the label string.
+    pub fn label(&self) -> &str {
+        &self.label
     }
 
     /// Returns the usage type of the buffer.
@@ -91,6 +126,37 @@ impl Buffer {
     pub fn usage(&self) -> BufferUsage {
         self.usage
     }
+
+    /// Writes data into the buffer by mapping it, copying the data into the mapped memory and
+    /// unmapping it again. This avoids the intermediate staging copy of `wgpu::Queue::write_buffer`,
+    /// but blocks until the buffer is mapped and only works for buffers with the `Upload` usage.
+    ///
+    /// # Parameters
+    /// - `device`: The device owning the buffer, polled until the buffer is mapped.
+    /// - `data`: The data to write at the beginning of the buffer. Its size has to match the size
+    ///   the buffer was created with, which may be smaller than the size of the buffer itself.
+    ///
+    /// # Returns
+    /// A `Result` indicating success, or `Error::MapError` if the buffer couldn't be mapped.
+    ///
+    /// # Panics
+    /// Panics if the buffer doesn't have the `Upload` usage or the data doesn't fit into it.
+    pub fn write_mapped<T: bytemuck::Pod>(&self, device: &Device, data: &[T]) -> Result<()> {
+        assert!(
+            matches!(self.usage, BufferUsage::Upload),
+            "only upload buffers can be written through a mapping"
+        );
+        let slice = self.buffer.slice(..);
+        let (sender, receiver) = mpsc::channel();
+        slice.map_async(wgpu::MapMode::Write, move |v| sender.send(v).unwrap());
+        device.poll(wgpu::Maintain::wait()).panic_on_timeout();
+        receiver.recv().expect("map callback should be called after polling")?;
+        let bytes: &[u8] = bytemuck::cast_slice(data);
+        slice.get_mapped_range_mut()[..bytes.len()].copy_from_slice(bytes);
+        self.buffer.unmap();
+        trace!("Wrote {} bytes through a mapped buffer", std::mem::size_of_val(data));
+        Ok(())
+    }
 }
 
 impl Deref for Buffer {
@@ -106,58 +172,210 @@ impl Deref for Buffer {
 /// Builder for creating and configuring a GPU buffer.
 pub struct BufferBuilder<'a, 'device> {
     device: &'device Device,
-    label: &'a str,
+    label: Cow<'a, str>,
     usage: BufferUsage,
 }
 
 impl<'a, 'device> BufferBuilder<'a, 'device> {
-    /// Creates a new `BufferBuilder` instance.
+    /// Creates a new `BufferBuilder` instance. Buffers without a label are given one made of their
+    /// usage and a sequence number, e.g. `read_write_3`, so that every buffer can be told apart in
+    /// validation errors and GPU captures.
     ///
     /// # Parameters
     /// - `device`: The device to use for creating the buffer.
+    /// - `label`: The label of the buffer, or an empty string to generate one.
     /// - `usage`: The usage type of the buffer.
     ///
     /// # Returns
     /// A new `BufferBuilder` instance.
     pub fn new(device: &'device Device, label: &'a str, usage: BufferUsage) -> Self {
+        let label = match label {
+            "" => {
+                let number = UNLABELED_BUFFERS.fetch_add(1, Ordering::Relaxed);
+                Cow::Owned(format!("{}_{number}", usage.name()))
+            }
+            label => Cow::Borrowed(label),
+        };
         Self { device, label, usage }
     }
 
-    /// Creates an empty buffer with the specified size.
+    /// Creates an empty buffer with the specified size. Buffer copies have to cover a multiple of
+    /// `wgpu::COPY_BUFFER_ALIGNMENT` bytes, so the size is rounded up to it, e.g. a buffer for three
+    /// `u16` values takes 8 bytes instead of 6. This way, copying whole buffers works for any
+    /// element type and count.
     ///
     /// # Parameters
     /// - `size`: The size of the buffer in bytes.
     ///
     /// # Returns
     /// A `Buffer` instance.
+    ///
+    /// # Panics
+    /// Panics if the buffer cannot be allocated. Use `try_empty` to handle allocation failures
+    /// gracefully.
     pub fn empty(self, size: usize) -> Buffer {
-        let buffer = self.device.create_buffer(&wgpu::BufferDescriptor {
-            label: Some(self.label),
-            size: size as u64,
-            usage: self.usage.usage(),
-            mapped_at_creation: false,
-        });
+        self.try_empty(size).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Creates an empty buffer with the specified size, rounded up like for `empty`, returning
+    /// allocation failures instead of panicking.
+    ///
+    /// # Parameters
+    /// - `size`: The size of the buffer in bytes.
+    ///
+    /// # Returns
+    /// A `Result` containing the `Buffer` instance, or `Error::OutOfMemory` if the buffer cannot
+    /// be allocated.
+    pub fn try_empty(self, size: usize) -> Result<Buffer> {
+        let size = (size as u64).next_multiple_of(wgpu::COPY_BUFFER_ALIGNMENT);
+        let buffer = self.allocate(size, || {
+            self.device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some(&self.label),
+                size,
+                usage: self.usage.usage(),
+                mapped_at_creation: false,
+            })
+        })?;
         trace!("Created buffer with label '{}'", self.label);
-        Buffer::new(buffer, self.usage)
+        Ok(Buffer::new(buffer, self.label.into_owned(), self.usage))
     }
 
-    /// Creates a buffer initialized with the specified data.
+    /// Creates a buffer initialized with the specified data. Like for `empty`, the size of the
+    /// buffer is rounded up to `wgpu::COPY_BUFFER_ALIGNMENT`, and the padding is zeroed.
     ///
     /// # Parameters
     /// - `data`: A slice of data to initialize the buffer with.
     ///
     /// # Returns
     /// A `Buffer` instance.
+    ///
+    /// # Panics
+    /// Panics if the buffer cannot be allocated. Use `try_with_data` to handle allocation failures
+    /// gracefully.
     pub fn with_data<T>(self, data: &'a [T]) -> Buffer
     where
         T: bytemuck::Pod,
     {
-        let buffer = self.device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
-            label: Some(self.label),
-            contents: bytemuck::cast_slice(data),
-            usage: self.usage.usage(),
-        });
+        self.try_with_data(data).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Creates a buffer initialized with the specified data, returning allocation failures instead
+    /// of panicking.
+    ///
+    /// # Parameters
+    /// - `data`: A slice of data to initialize the buffer with.
+    ///
+    /// # Returns
+    /// A `Result` containing the `Buffer` instance, or `Error::OutOfMemory` if the buffer cannot
+    /// be allocated.
+    pub fn try_with_data<T>(self, data: &'a [T]) -> Result<Buffer>
+    where
+        T: bytemuck::Pod,
+    {
+        let size = (std::mem::size_of_val(data) as u64).next_multiple_of(wgpu::COPY_BUFFER_ALIGNMENT);
+        let buffer = self.allocate(size, || {
+            self.device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some(&self.label),
+                contents: bytemuck::cast_slice(data),
+                usage: self.usage.usage(),
+            })
+        })?;
         trace!("Created initialized buffer with label '{}'", self.label);
-        Buffer::new(buffer, self.usage)
+        Ok(Buffer::new(buffer, self.label.into_owned(), self.usage))
+    }
+
+    /// Allocates a buffer capturing out-of-memory errors, so that allocation failures are returned
+    /// to the caller instead of causing a panic. Sizes above the maximum buffer size of the device can never be allocated, so they
+    /// are reported as out of memory as well, before any allocation is attempted.
+    ///
+    /// # Parameters
+    /// - `size`: The size of the buffer in bytes.
+    /// - `create`: The closure creating the buffer.
+    ///
+    /// # Returns
+    /// A `Result` containing the created `wgpu::Buffer`, or `Error::OutOfMemory` if it cannot be
+    /// allocated.
+    fn allocate(&self, size: u64, create: impl FnOnce() -> wgpu::Buffer) -> Result<wgpu::Buffer> {
+        if size > self.device.limits().max_buffer_size {
+            return Err(Error::OutOfMemory { requested_bytes: size });
+        }
+        match self.device.capture(wgpu::ErrorFilter::OutOfMemory, create) {
+            (_, Some(_)) => Err(Error::OutOfMemory { requested_bytes: size }),
+            (buffer, None) => Ok(buffer),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::device::MAPPED_WRITE_THRESHOLD;
+    use crate::WGPU;
+
+    #[test]
+    fn write_mapped() {
+        let device = pollster::block_on(WGPU::default_context()).unwrap();
+        let data = [1.0f32, 2.0, 3.0, 4.0];
+        let upload = device.buffer::<f32>("upload", BufferUsage::Upload).empty(16);
+        upload.write_mapped(&device, &data).unwrap();
+        let staging = device.buffer::<f32>("staging", BufferUsage::Staging).empty(16);
+        device.submit(device.compute("copy", |encoder| encoder.copy_buffer(&upload, &staging)));
+        let slice = staging.slice(..);
+        slice.map_async(wgpu::MapMode::Read, |v| v.unwrap());
+        device.poll(wgpu::Maintain::wait()).panic_on_timeout();
+        let result: Vec<f32> = bytemuck::cast_slice(&slice.get_mapped_range()).to_vec();
+        assert_eq!(result, data);
+    }
+
+    #[test]
+    fn write_large() {
+        let device = pollster::block_on(WGPU::default_context()).unwrap();
+        let data = (0..MAPPED_WRITE_THRESHOLD as u32).collect::<Vec<_>>();
+        let size = std::mem::size_of_val(data.as_slice());
+        let buffer = device.buffer::<u32>("buffer", BufferUsage::ReadWrite).empty(size);
+        device.write(&buffer, &data).unwrap();
+        let staging = device.buffer::<u32>("staging", BufferUsage::Staging).empty(size);
+        device.submit(device.compute("copy", |encoder| encoder.copy_buffer(&buffer, &staging)));
+        let slice = staging.slice(..);
+        slice.map_async(wgpu::MapMode::Read, |v| v.unwrap());
+        device.poll(wgpu::Maintain::wait()).panic_on_timeout();
+        let result: Vec<u32> = bytemuck::cast_slice(&slice.get_mapped_range()).to_vec();
+        assert_eq!(result, data);
+    }
+
+    #[test]
+    fn unaligned_size() {
+        let device = pollster::block_on(WGPU::default_context()).unwrap();
+        let data = [1u16, 2, 3];
+        let size = std::mem::size_of_val(&data);
+        let buffer = device.buffer::<u16>("buffer", BufferUsage::ReadWrite).empty(size);
+        assert_eq!(buffer.size(), 8);
+        device.write(&buffer, &data).unwrap();
+        let staging = device.buffer::<u16>("staging", BufferUsage::Staging).empty(size);
+        device.submit(device.compute("copy", |encoder| encoder.copy_buffer(&buffer, &staging)));
+        let slice = staging.slice(..);
+        slice.map_async(wgpu::MapMode::Read, |v| v.unwrap());
+        device.poll(wgpu::Maintain::wait()).panic_on_timeout();
+        let result: Vec<u16> = bytemuck::cast_slice(&slice.get_mapped_range()).to_vec();
+        assert_eq!(result, [1, 2, 3, 0]);
+    }
+
+    #[test]
+    fn generated_label() {
+        let device = pollster::block_on(WGPU::default_context()).unwrap();
+        let buffer = device.buffer::<f32>("buffer", BufferUsage::ReadWrite).empty(16);
+        assert_eq!(buffer.label(), "buffer");
+        let first = device.buffer::<f32>("", BufferUsage::ReadWrite).empty(16);
+        let second = device.buffer::<f32>("", BufferUsage::ReadWrite).empty(16);
+        assert!(first.label().starts_with("read_write_"));
+        assert_ne!(first.label(), second.label());
+    }
+
+    #[test]
+    fn out_of_memory() {
+        let device = pollster::block_on(WGPU::default_context()).unwrap();
+        let size = device.limits().max_buffer_size as usize + 1;
+        let result = device.buffer::<u8>("buffer", BufferUsage::ReadWrite).try_empty(size);
+        assert!(matches!(result, Err(Error::OutOfMemory { .. })));
     }
 }
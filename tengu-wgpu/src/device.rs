@@ -18,32 +18,50 @@
 //!   - `Device::compute`: Encodes compute commands using a provided closure.
 //!   - `Device::buffer`: Creates a buffer builder for creating GPU buffers.
 //!   - `Device::shader`: Creates a shader module from WGSL source code.
+//!   - `Device::write`: Writes data into a buffer, choosing between a queue write and a mapped upload.
 //!   - `Device::submit`: Submits a command buffer to the GPU queue for execution.
+//!   - `Device::validate`: Runs a closure capturing its validation errors, returning them to the caller.
 //!   - `Device::layout`: Creates a layout builder for creating bind group layouts and pipelines.
+//!   - `Device::timer`: Creates a timer measuring the GPU execution time of compute passes.
 //!
 //! - `DeviceBuilder`: Provides a builder pattern for requesting devices from a WGPU adapter.
 //!   - `DeviceBuilder::new`: Creates a new `DeviceBuilder` for the specified adapter.
 //!   - `DeviceBuilder::request`: Requests a device asynchronously and returns a `Device` if successful.
 //!   - `DeviceBuilder::with_features`: Sets the required features for the device.
+//!   - `DeviceBuilder::with_optional_features`: Enables the features for the device if the adapter supports them.
 //!   - `DeviceBuilder::with_limits`: Sets the required limits for the device.
 //!   - `DeviceBuilder::with_webgl_limits`: Sets the WebGL-compatible limits for the device.
 
+use std::borrow::Cow;
 use std::ops::Deref;
+use std::sync::{Arc, Mutex};
 
 use tracing::trace;
 
 use crate::buffer::BufferBuilder;
 use crate::pipeline::LayoutBuilder;
-use crate::{BufferUsage, Encoder, Error};
+use crate::{Buffer, BufferUsage, Encoder, Error, Retry, Timer};
+
+/// The size in bytes from which `Device::write` uploads data through a mapped buffer instead of
+/// `wgpu::Queue::write_buffer`. Below that, the cost of creating and mapping a dedicated upload
+/// buffer outweighs the saved staging copy.
+pub(crate) const MAPPED_WRITE_THRESHOLD: usize = 1 << 20;
+
+/// The error scopes opened by `Device::capture`: the filter of each scope and the first error it
+/// captured.
+type ErrorScopes = Arc<Mutex<Vec<(wgpu::ErrorFilter, Option<wgpu::Error>)>>>;
 
 /// Represents a WGPU device and its associated queue.
 pub struct Device {
     device: wgpu::Device,
     queue: wgpu::Queue,
+    scopes: ErrorScopes,
 }
 
 impl Device {
-    /// Creates a new `Device` instance.
+    /// Creates a new `Device` instance. The uncaptured error handler of the device is replaced
+    /// with one delivering errors to the scopes opened by `capture`, and panicking like the default
+    /// handler for errors outside of them.
     ///
     /// # Parameters
     /// - `device`: The WGPU device.
@@ -52,7 +70,18 @@ impl Device {
     /// # Returns
     /// A new `Device` instance.
     pub fn new(device: wgpu::Device, queue: wgpu::Queue) -> Device {
-        Self { device, queue }
+        let scopes = ErrorScopes::default();
+        let handler_scopes = scopes.clone();
+        device.on_uncaptured_error(Box::new(move |error| {
+            let mut scopes = handler_scopes.lock().expect("error scopes should not be poisoned");
+            if let Some((_, captured)) = scopes.iter_mut().rev().find(|(filter, _)| is_filtered(*filter, &error)) {
+                captured.get_or_insert(error);
+                return;
+            }
+            drop(scopes);
+            panic!("wgpu error: {error}");
+        }));
+        Self { device, queue, scopes }
     }
 
     /// Creates a new command encoder with the specified label.
@@ -93,20 +122,66 @@ impl Device {
         BufferBuilder::new(self, label, buffer_kind)
     }
 
-    /// Creates a new shader module from the specified WGSL source code.
+    /// Creates a new shader module from the specified WGSL source code. Validation errors are
+    /// captured while the shader is created, so that compilation failures are returned to the
+    /// caller instead of causing a panic.
     ///
     /// # Parameters
     /// - `label`: A label for the shader module.
     /// - `source`: The WGSL source code for the shader.
     ///
     /// # Returns
-    /// A `wgpu::ShaderModule` instance.
-    pub fn shader(&self, label: &str, source: &str) -> wgpu::ShaderModule {
+    /// A `Result` containing the `wgpu::ShaderModule` instance, or `Error::ShaderCompilation`
+    /// with the shader source and the compilation message if the shader is invalid.
+    pub fn shader(&self, label: &str, source: &str) -> Result<wgpu::ShaderModule, Error> {
         trace!("Creating shader module '{label}'...");
-        self.device.create_shader_module(wgpu::ShaderModuleDescriptor {
-            label: Some(label),
-            source: wgpu::ShaderSource::Wgsl(source.into()),
-        })
+        let (shader, error) = self.capture(wgpu::ErrorFilter::Validation, || {
+            self.device.create_shader_module(wgpu::ShaderModuleDescriptor {
+                label: Some(label),
+                source: wgpu::ShaderSource::Wgsl(source.into()),
+            })
+        });
+        match error {
+            Some(error) => Err(Error::ShaderCompilation {
+                shader: source.to_string(),
+                message: error.to_string(),
+            }),
+            None => Ok(shader),
+        }
+    }
+
+    /// Writes data into a buffer with the `Read`, `Write` or `ReadWrite` usage. Small writes go through
+    /// `wgpu::Queue::write_buffer`, while large ones are written into a mapped upload buffer which
+    /// is then copied into the destination, avoiding the intermediate staging copy. Data whose size
+    /// isn't a multiple of `wgpu::COPY_BUFFER_ALIGNMENT` is padded with zeros, which fit into the
+    /// buffer since its size is rounded up the same way.
+    ///
+    /// # Parameters
+    /// - `buffer`: The destination buffer.
+    /// - `data`: The data to write, starting at the beginning of the buffer.
+    ///
+    /// # Returns
+    /// A `Result` indicating success, `Error::OutOfMemory` if the upload buffer couldn't be
+    /// allocated, or `Error::MapError` if it couldn't be mapped.
+    pub fn write<T: bytemuck::Pod>(&self, buffer: &Buffer, data: &[T]) -> Result<(), Error> {
+        let size = std::mem::size_of_val(data);
+        if size < MAPPED_WRITE_THRESHOLD {
+            trace!("Writing {size} bytes through the queue");
+            let mut bytes = Cow::Borrowed(bytemuck::cast_slice::<T, u8>(data));
+            let aligned = size.next_multiple_of(wgpu::COPY_BUFFER_ALIGNMENT as usize);
+            if aligned != size {
+                bytes.to_mut().resize(aligned, 0);
+            }
+            self.queue.write_buffer(buffer, 0, &bytes);
+            return Ok(());
+        }
+        trace!("Writing {size} bytes through a mapped upload buffer");
+        let label = format!("{}_upload", buffer.label());
+        let upload = self.buffer::<T>(&label, BufferUsage::Upload).try_empty(size)?;
+        upload.write_mapped(self, data)?;
+        let commands = self.compute("upload", |encoder| encoder.copy_buffer(&upload, buffer));
+        self.submit(commands);
+        Ok(())
     }
 
     /// Submits a command buffer to the queue for execution.
@@ -118,11 +193,56 @@ impl Device {
         self.queue.submit(std::iter::once(commands));
     }
 
+    /// Runs the provided closure capturing validation errors, so that validation errors raised by
+    /// the GPU operations it issues (e.g. encoding and submitting commands) are returned to the
+    /// caller instead of causing a panic.
+    ///
+    /// # Parameters
+    /// - `call`: The closure issuing the GPU operations to validate.
+    ///
+    /// # Returns
+    /// A `Result` containing the result of the closure, the error returned by the closure, or
+    /// `Error::ValidationError` with the message of the captured validation error.
+    pub fn validate<T>(&self, call: impl FnOnce() -> Result<T, Error>) -> Result<T, Error> {
+        let (result, error) = self.capture(wgpu::ErrorFilter::Validation, call);
+        match error {
+            Some(error) if result.is_ok() => Err(Error::ValidationError(error.to_string())),
+            _ => result,
+        }
+    }
+
+    /// Runs the provided closure inside an error scope capturing the errors of the given kind.
+    /// Unlike `wgpu::Device::pop_error_scope`, which returns a future, the scope is resolved
+    /// without waiting: the uncaptured error handler of the device delivers errors as they are
+    /// raised by the operations of the closure, which happens synchronously on native platforms.
+    /// Scopes can be nested, and an error is captured by the innermost scope of its kind.
+    ///
+    /// # Parameters
+    /// - `filter`: The kind of errors to capture.
+    /// - `call`: The closure issuing the GPU operations.
+    ///
+    /// # Returns
+    /// The result of the closure and the first error captured while it ran, if any.
+    pub(crate) fn capture<T>(&self, filter: wgpu::ErrorFilter, call: impl FnOnce() -> T) -> (T, Option<wgpu::Error>) {
+        self.lock_scopes().push((filter, None));
+        let result = call();
+        let (_, error) = self.lock_scopes().pop().expect("error scope should be open");
+        (result, error)
+    }
+
+    /// Locks the error scopes opened by `capture`.
+    ///
+    /// # Returns
+    /// The guard of the error scopes.
+    fn lock_scopes(&self) -> std::sync::MutexGuard<'_, Vec<(wgpu::ErrorFilter, Option<wgpu::Error>)>> {
+        self.scopes.lock().expect("error scopes should not be poisoned")
+    }
+
     /// Creates a new layout builder for configuring pipeline layouts.
     ///
     /// # Returns
     /// A `LayoutBuilder` instance.
-    pub fn layout(&self) -> LayoutBuilder {
+    pub fn layout(&self) -> LayoutBuilder<'_, '_> {
         LayoutBuilder::new(self)
     }
 
@@ -133,6 +253,38 @@ impl Device {
     pub fn limits(&self) -> wgpu::Limits {
         self.device.limits()
     }
+
+    /// Creates a new timer measuring the GPU execution time of compute passes.
+    ///
+    /// # Parameters
+    /// - `label`: A label for the timer resources.
+    ///
+    /// # Returns
+    /// A `Result` containing the `Timer`, or `Error::TimestampQueryUnsupported` if the device
+    /// doesn't have the `TIMESTAMP_QUERY` feature enabled.
+    pub fn timer(&self, label: &str) -> Result<Timer, Error> {
+        if !self.device.features().contains(wgpu::Features::TIMESTAMP_QUERY) {
+            return Err(Error::TimestampQueryUnsupported);
+        }
+        Ok(Timer::new(&self.device, label, self.queue.get_timestamp_period()))
+    }
+}
+
+/// Checks whether an error is of the kind captured by an error filter.
+///
+/// # Parameters
+/// - `filter`: The error filter.
+/// - `error`: The error to check.
+///
+/// # Returns
+/// `true` if the filter captures the error.
+fn is_filtered(filter: wgpu::ErrorFilter, error: &wgpu::Error) -> bool {
+    matches!(
+        (filter, error),
+        (wgpu::ErrorFilter::Validation, wgpu::Error::Validation { .. })
+            | (wgpu::ErrorFilter::OutOfMemory, wgpu::Error::OutOfMemory { .. })
+            | (wgpu::ErrorFilter::Internal, wgpu::Error::Internal { .. })
+    )
 }
 
 impl Deref for Device {
@@ -149,6 +301,7 @@ pub struct DeviceBuilder {
     adapter: wgpu::Adapter,
     features: wgpu::Features,
     limits: wgpu::Limits,
+    retry: Retry,
 }
 
 impl DeviceBuilder {
@@ -168,25 +321,25 @@ impl DeviceBuilder {
                 max_storage_buffers_per_shader_stage,
                 ..Default::default()
             },
+            retry: Retry::none(),
         }
     }
 
-    /// Requests a new `Device` instance asynchronously.
+    /// Requests a new `Device` instance asynchronously. Failed requests are retried according to
+    /// the retry policy.
     ///
     /// # Returns
     /// A `Result` containing the created `Device` or an error.
     pub async fn request(self) -> Result<Device, Error> {
+        let descriptor = wgpu::DeviceDescriptor {
+            label: None,
+            required_features: self.features,
+            required_limits: self.limits,
+            memory_hints: wgpu::MemoryHints::default(),
+        };
         let (device, queue) = self
-            .adapter
-            .request_device(
-                &wgpu::DeviceDescriptor {
-                    label: None,
-                    required_features: self.features,
-                    required_limits: self.limits,
-                    memory_hints: wgpu::MemoryHints::default(),
-                },
-                None, // Trace path
-            )
+            .retry
+            .run(|| self.adapter.request_device(&descriptor, None)) // No trace path
             .await?;
         trace!("Requested device and queue");
         Ok(Device::new(device, queue))
@@ -204,6 +357,19 @@ impl DeviceBuilder {
         self
     }
 
+    /// Adds additional features to the device if the adapter supports them. Unsupported features
+    /// are silently skipped, so the availability of each of them should be checked on the device.
+    ///
+    /// # Parameters
+    /// - `features`: The features to add.
+    ///
+    /// # Returns
+    /// The updated `DeviceBuilder`.
+    pub fn with_optional_features(mut self, features: wgpu::Features) -> Self {
+        self.features |= features & self.adapter.features();
+        self
+    }
+
     /// Sets the resource limits for the device.
     ///
     /// # Parameters
@@ -216,6 +382,19 @@ impl DeviceBuilder {
         self
     }
 
+    /// Sets the policy for retrying failed device requests. By default, the device is requested
+    /// only once.
+    ///
+    /// # Parameters
+    /// - `retry`: The retry policy.
+    ///
+    /// # Returns
+    /// The updated `DeviceBuilder`.
+    pub fn with_retry(mut self, retry: Retry) -> Self {
+        self.retry = retry;
+        self
+    }
+
     /// Sets the resource limits for the device to WebGL2 defaults.
     ///
     /// # Returns
@@ -226,3 +405,71 @@ impl DeviceBuilder {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, WGPU};
+
+    #[test]
+    fn shader() {
+        let device = pollster::block_on(WGPU::default_context()).unwrap();
+        let source = "@compute @workgroup_size(64) fn main() {}";
+        assert!(device.shader("valid", source).is_ok());
+    }
+
+    #[test]
+    fn shader_compilation_error() {
+        let device = pollster::block_on(WGPU::default_context()).unwrap();
+        let source = "@compute @workgroup_size(64) fn main() { let x: u32 = ; }";
+        let Err(Error::ShaderCompilation { shader, message }) = device.shader("malformed", source) else {
+            panic!("malformed shader should not compile");
+        };
+        assert_eq!(shader, source);
+        assert!(!message.is_empty());
+    }
+
+    #[test]
+    fn validation_error() {
+        let device = pollster::block_on(WGPU::default_context()).unwrap();
+        let result = device.validate(|| {
+            device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some("invalid"),
+                size: 4,
+                usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::MAP_WRITE,
+                mapped_at_creation: false,
+            });
+            Ok(())
+        });
+        assert!(matches!(result, Err(Error::ValidationError(message)) if !message.is_empty()));
+        assert!(device.validate(|| Ok(())).is_ok());
+    }
+
+    #[test]
+    fn nested_capture() {
+        let device = pollster::block_on(WGPU::default_context()).unwrap();
+        let invalid = || {
+            device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some("invalid"),
+                size: 4,
+                usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::MAP_WRITE,
+                mapped_at_creation: false,
+            })
+        };
+        let ((_, inner), outer) = device.capture(wgpu::ErrorFilter::Validation, || {
+            device.capture(wgpu::ErrorFilter::OutOfMemory, invalid)
+        });
+        assert!(inner.is_none());
+        assert!(matches!(outer, Some(wgpu::Error::Validation { .. })));
+    }
+
+    #[test]
+    fn timer_availability() {
+        let device = pollster::block_on(WGPU::default_context()).unwrap();
+        let supported = device.features().contains(wgpu::Features::TIMESTAMP_QUERY);
+        match device.timer("timer") {
+            Ok(_) => assert!(supported),
+            Err(Error::TimestampQueryUnsupported) => assert!(!supported),
+            Err(e) => panic!("unexpected error: {e}"),
+        }
+    }
+}
@@ -21,13 +21,15 @@
 //!   command buffers.
 //!   - `new`: Creates a new command encoder with a specified label.
 //!   - `pass`: Begins a new compute pass with a specified label and executes a provided closure with the compute pass.
+//!   - `timed_pass`: Begins a new compute pass like `pass`, measuring its GPU execution time with a `Timer`.
 //!   - `stage`: Executes a provided closure with a mutable reference to the encoder.
+//!   - `dispatch`: Runs a single compute pass dispatching a pipeline.
 //!   - `copy_buffer`: Copies data from a source buffer to a destination buffer.
 //!   - `finish`: Finalizes the command buffer and returns it for submission to the GPU.
 
 use tracing::trace;
 
-use crate::{Buffer, Device, Error, Result};
+use crate::{Buffer, Device, Error, Pipeline, Result, Timer};
 
 /// Represents a command encoder in the WGPU backend.
 pub struct Encoder {
@@ -70,6 +72,30 @@ impl Encoder {
         Ok(self)
     }
 
+    /// Begins a compute pass writing timestamps at its beginning and at its end into the first
+    /// unused slot of the timer, and executes the provided callback. The slot has to be marked as
+    /// used with `Timer::record` once the command buffer has been submitted.
+    ///
+    /// # Parameters
+    /// - `label`: A label for the compute pass.
+    /// - `timer`: The timer receiving the timestamps of the pass.
+    /// - `call`: A callback function to execute within the compute pass.
+    ///
+    /// # Returns
+    /// The updated `Encoder` instance. If an error occurs during the compute pass, it is returned as an `Error`.
+    pub fn timed_pass<F>(mut self, label: &str, timer: &Timer, call: F) -> Result<Self>
+    where
+        F: FnOnce(wgpu::ComputePass) -> anyhow::Result<()>,
+    {
+        let compute_pass = self.encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+            label: Some(label),
+            timestamp_writes: Some(timer.timestamp_writes()),
+        });
+        trace!("Executing timed compute pass");
+        call(compute_pass).map_err(Error::ComputeError)?;
+        Ok(self)
+    }
+
     /// Executes the provided callback for staging operations.
     ///
     /// # Parameters
@@ -83,6 +109,23 @@ impl Encoder {
         self
     }
 
+    /// Runs a single compute pass dispatching the given pipeline.
+    ///
+    /// # Parameters
+    /// - `label`: A label for the compute pass.
+    /// - `pipeline`: The pipeline to dispatch, together with its bind group.
+    /// - `workgroup_count`: The number of workgroups to dispatch along the first dimension.
+    pub fn dispatch(&mut self, label: &str, pipeline: &Pipeline, workgroup_count: u32) {
+        let mut compute_pass = self.encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+            label: Some(label),
+            timestamp_writes: None,
+        });
+        compute_pass.set_pipeline(pipeline);
+        compute_pass.set_bind_group(0, pipeline.bind_group(), &[]);
+        compute_pass.dispatch_workgroups(workgroup_count, 1, 1);
+        trace!("Dispatched compute pass '{label}'");
+    }
+
     /// Copies the contents of one buffer to another.
     ///
     /// # Parameters
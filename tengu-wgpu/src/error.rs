@@ -1,5 +1,7 @@
 use thiserror::Error;
 
+use crate::BufferUsage;
+
 #[derive(Debug, Error)]
 pub enum Error {
     #[error("Readout error: {0}")]
@@ -12,6 +14,18 @@ pub enum Error {
     CreateAdapterError,
     #[error("cannot create device: {0}")]
     RequestDeviceError(#[from] wgpu::RequestDeviceError),
+    #[error("cannot map buffer: {0}")]
+    MapError(#[from] wgpu::BufferAsyncError),
+    #[error("timestamp queries are not supported by the device")]
+    TimestampQueryUnsupported,
+    #[error("buffer with usage {0:?} cannot be bound to a pipeline")]
+    InvalidBufferUsage(BufferUsage),
+    #[error("validation error: {0}")]
+    ValidationError(String),
+    #[error("cannot compile shader: {message}\n{shader}")]
+    ShaderCompilation { shader: String, message: String },
+    #[error("out of memory: cannot allocate a buffer of {requested_bytes} bytes")]
+    OutOfMemory { requested_bytes: u64 },
 }
 
 pub type Result<T> = std::result::Result<T, self::Error>;
@@ -17,8 +17,10 @@
 //! - `encoder`: Manages command encoders for recording commands.
 //! - `error`: Defines error types and results used across the crate.
 //! - `pipeline`: Provides utilities for creating and managing GPU pipelines.
+//! - `retry`: Defines the `Retry` policy for retrying adapter and device requests that fail transiently.
 //! - `size`: Contains utilities for working with sizes and byte sizes.
 //! - `surface`: Manages GPU surfaces and their configurations.
+//! - `timer`: Measures the GPU execution time of compute passes with timestamp queries.
 //! - `wgpu`: Contains the main entry point for creating WGPU instances and requesting default contexts.
 //!
 //! ## Integration with WGPU
@@ -53,9 +55,9 @@
 //!         // Compute shader code
 //!     }
 //!     "#;
-//!     let shader = device.shader("compute_shader", shader_source);
+//!     let shader = device.shader("compute_shader", shader_source).unwrap();
 //!     let buffer = device.buffer::<u32>("buf", BufferUsage::ReadWrite).with_data(&[0; 64]);
-//!     let pipeline = device.layout().add_entry(&buffer).pipeline("compute_pipeline").build(shader);
+//!     let pipeline = device.layout().add_entry(&buffer).unwrap().pipeline("compute_pipeline").build(shader);
 //!     let command_buffer = device
 //!         .encoder("encoder")
 //!         .pass("pass", |mut pass| {
@@ -80,8 +82,10 @@ mod device;
 mod encoder;
 mod error;
 mod pipeline;
+mod retry;
 mod size;
 mod surface;
+mod timer;
 mod wgpu;
 
 pub use adapter::Adapter;
@@ -90,7 +94,9 @@ pub use device::Device;
 pub use encoder::Encoder;
 pub use error::{Error, Result};
 pub use pipeline::Pipeline;
+pub use retry::Retry;
 pub use size::ByteSize;
 pub use surface::BoundSurface;
 pub use surface::Surface;
+pub use timer::Timer;
 pub use wgpu::WGPU;
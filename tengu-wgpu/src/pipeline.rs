@@ -18,6 +18,8 @@
 //! groups.
 //! - `LayoutBuilder::new`: Creates a new layout builder for the specified device.
 //! - `LayoutBuilder::add_entry`: Adds a single buffer entry to the layout and bind group.
+//! - `LayoutBuilder::add_entry_with_usage`: Adds a single buffer entry bound with a different usage than the buffer's own,
+//!   e.g. a read-write buffer which a shader only reads.
 //! - `LayoutBuilder::add_entries`: Adds multiple buffer entries to the layout and bind group.
 //! - `LayoutBuilder::pipeline`: Finalizes the layout and bind group, and returns a `PipelineBuilder` for creating a compute pipeline.
 //!
@@ -42,7 +44,7 @@ use std::ops::Deref;
 
 use tracing::trace;
 
-use crate::{Buffer, BufferUsage, Device};
+use crate::{Buffer, BufferUsage, Device, Error, Result};
 
 const ENTRY: &str = "main";
 
@@ -118,13 +120,30 @@ impl<'a, 'device> LayoutBuilder<'a, 'device> {
     /// - `buffer`: The buffer to add.
     ///
     /// # Returns
-    /// The updated `LayoutBuilder`.
-    pub fn add_entry(mut self, buffer: &'a Buffer) -> Self {
-        self.layout_entries.push(create_layout_entry(buffer, self.counter));
+    /// The updated `LayoutBuilder`, or `Error::InvalidBufferUsage` if the buffer is a staging or
+    /// upload buffer, which cannot be bound to a pipeline.
+    pub fn add_entry(self, buffer: &'a Buffer) -> Result<Self> {
+        self.add_entry_with_usage(buffer, buffer.usage())
+    }
+
+    /// Adds a buffer entry to the layout and bind group, overriding the usage with which it is
+    /// bound. This allows binding a read-write buffer as read-only to a shader that never writes
+    /// it.
+    ///
+    /// # Parameters
+    /// - `buffer`: The buffer to add.
+    /// - `usage`: The usage with which the buffer is bound.
+    ///
+    /// # Returns
+    /// The updated `LayoutBuilder`, or `Error::InvalidBufferUsage` if the buffer is a staging or
+    /// upload buffer, or if it is bound as one, neither of which can be bound to a pipeline.
+    pub fn add_entry_with_usage(mut self, buffer: &'a Buffer, usage: BufferUsage) -> Result<Self> {
+        let layout_entry = create_layout_entry(buffer, usage, self.counter)?;
+        self.layout_entries.push(layout_entry);
         self.bind_entries.push(create_bind_entry(buffer, self.counter));
         self.buffers.push(buffer);
         self.counter += 1;
-        self
+        Ok(self)
     }
 
     /// Adds multiple buffer entries to the layout and bind group.
@@ -133,15 +152,13 @@ impl<'a, 'device> LayoutBuilder<'a, 'device> {
     /// - `buffers`: An iterator over the buffers to add.
     ///
     /// # Returns
-    /// The updated `LayoutBuilder`.
-    pub fn add_entries(mut self, buffers: impl IntoIterator<Item = &'a Buffer>) -> Self {
+    /// The updated `LayoutBuilder`, or `Error::InvalidBufferUsage` if any of the buffers is a
+    /// staging or upload buffer, which cannot be bound to a pipeline.
+    pub fn add_entries(mut self, buffers: impl IntoIterator<Item = &'a Buffer>) -> Result<Self> {
         for buffer in buffers.into_iter() {
-            self.layout_entries.push(create_layout_entry(buffer, self.counter));
-            self.bind_entries.push(create_bind_entry(buffer, self.counter));
-            self.buffers.push(buffer);
-            self.counter += 1;
+            self = self.add_entry(buffer)?;
         }
-        self
+        Ok(self)
     }
     /// Creates a `PipelineBuilder` for further configuring and building the compute pipeline.
     ///
@@ -170,18 +187,23 @@ impl<'a, 'device> LayoutBuilder<'a, 'device> {
 ///
 /// # Parameters
 /// - `buffer`: The buffer to create the layout entry for.
+/// - `usage`: The usage with which the buffer is bound.
 /// - `idx`: The binding index.
 ///
 /// # Returns
-/// A `wgpu::BindGroupLayoutEntry`.
-fn create_layout_entry(buffer: &Buffer, idx: usize) -> wgpu::BindGroupLayoutEntry {
-    let read_only = match buffer.usage() {
+/// A `wgpu::BindGroupLayoutEntry`, or `Error::InvalidBufferUsage` if the buffer is a staging or
+/// upload buffer, or is bound as one, which should not belong to a bind group.
+fn create_layout_entry(buffer: &Buffer, usage: BufferUsage, idx: usize) -> Result<wgpu::BindGroupLayoutEntry> {
+    if let usage @ (BufferUsage::Staging | BufferUsage::Upload) = buffer.usage() {
+        return Err(Error::InvalidBufferUsage(usage));
+    }
+    let read_only = match usage {
         BufferUsage::Read => true,
         BufferUsage::Write => false,
         BufferUsage::ReadWrite => false,
-        BufferUsage::Staging => panic!("staging buffers should not belong to a bind group"),
+        usage @ (BufferUsage::Staging | BufferUsage::Upload) => return Err(Error::InvalidBufferUsage(usage)),
     };
-    wgpu::BindGroupLayoutEntry {
+    Ok(wgpu::BindGroupLayoutEntry {
         binding: idx as u32,
         visibility: wgpu::ShaderStages::COMPUTE,
         ty: wgpu::BindingType::Buffer {
@@ -190,7 +212,7 @@ fn create_layout_entry(buffer: &Buffer, idx: usize) -> wgpu::BindGroupLayoutEntr
             min_binding_size: None,
         },
         count: None,
-    }
+    })
 }
 /// Creates a bind group entry for a buffer.
 ///
@@ -200,7 +222,7 @@ fn create_layout_entry(buffer: &Buffer, idx: usize) -> wgpu::BindGroupLayoutEntr
 ///
 /// # Returns
 /// A `wgpu::BindGroupEntry`.
-fn create_bind_entry(buffer: &Buffer, idx: usize) -> wgpu::BindGroupEntry {
+fn create_bind_entry(buffer: &Buffer, idx: usize) -> wgpu::BindGroupEntry<'_> {
     wgpu::BindGroupEntry {
         binding: idx as u32,
         resource: buffer.as_entire_binding(),
@@ -267,3 +289,30 @@ impl<'a, 'device> PipelineBuilder<'a, 'device> {
         Pipeline::new(pipeline, self.bind_group)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{BufferUsage, Error, WGPU};
+
+    #[test]
+    fn staging_entry() {
+        let device = pollster::block_on(WGPU::default_context()).unwrap();
+        let buffer = device.buffer::<f32>("buffer", BufferUsage::ReadWrite).empty(16);
+        let staging = device.buffer::<f32>("staging", BufferUsage::Staging).empty(16);
+        assert!(device.layout().add_entry(&buffer).is_ok());
+        let result = device.layout().add_entries([&buffer, &staging]);
+        assert!(matches!(result, Err(Error::InvalidBufferUsage(BufferUsage::Staging))));
+    }
+
+    #[test]
+    fn usage_override() {
+        let device = pollster::block_on(WGPU::default_context()).unwrap();
+        let buffer = device.buffer::<f32>("buffer", BufferUsage::ReadWrite).empty(16);
+        let staging = device.buffer::<f32>("staging", BufferUsage::Staging).empty(16);
+        assert!(device.layout().add_entry_with_usage(&buffer, BufferUsage::Read).is_ok());
+        let result = device.layout().add_entry_with_usage(&buffer, BufferUsage::Upload);
+        assert!(matches!(result, Err(Error::InvalidBufferUsage(BufferUsage::Upload))));
+        let result = device.layout().add_entry_with_usage(&staging, BufferUsage::Read);
+        assert!(matches!(result, Err(Error::InvalidBufferUsage(BufferUsage::Staging))));
+    }
+}
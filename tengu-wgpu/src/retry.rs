@@ -0,0 +1,142 @@
+//! This module provides the `Retry` policy used for retrying requests that can fail transiently,
+//! such as adapter and device requests on runners where the GPU is under contention.
+//!
+//! The policy consists of the number of attempts and the initial backoff, which is doubled after
+//! every failed attempt. If all attempts fail, the error of the first attempt is returned, since it
+//! is usually the most descriptive one.
+
+use std::future::Future;
+use std::time::Duration;
+
+use futures_timer::Delay;
+use tracing::trace;
+
+/// A policy for retrying fallible asynchronous requests with exponential backoff.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Retry {
+    attempts: usize,
+    backoff: Duration,
+}
+
+impl Retry {
+    /// Creates a new `Retry` policy.
+    ///
+    /// # Parameters
+    /// - `attempts`: The total number of attempts. Zero is treated as a single attempt.
+    /// - `backoff`: The delay before the second attempt. Subsequent delays are doubled.
+    ///
+    /// # Returns
+    /// A new `Retry` instance.
+    pub fn new(attempts: usize, backoff: Duration) -> Self {
+        Self {
+            attempts: attempts.max(1),
+            backoff,
+        }
+    }
+
+    /// Creates a policy which makes a single attempt without any retries.
+    ///
+    /// # Returns
+    /// A new `Retry` instance.
+    pub fn none() -> Self {
+        Self::new(1, Duration::ZERO)
+    }
+
+    /// Returns the total number of attempts.
+    ///
+    /// # Returns
+    /// The number of attempts.
+    pub fn attempts(&self) -> usize {
+        self.attempts
+    }
+
+    /// Runs the request until it succeeds or the attempts are exhausted. The backoff delay is an
+    /// asynchronous timer, so it doesn't block the executor running the request.
+    ///
+    /// # Parameters
+    /// - `request`: A function creating the request future for every attempt.
+    ///
+    /// # Returns
+    /// The result of the first successful attempt, or the error of the first attempt if all of them
+    /// have failed.
+    pub async fn run<T, E, F, Fut>(&self, mut request: F) -> Result<T, E>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+    {
+        let mut first_error = None;
+        let mut backoff = self.backoff;
+        for attempt in 1..=self.attempts {
+            match request().await {
+                Ok(value) => return Ok(value),
+                Err(error) => {
+                    trace!("Attempt {attempt} of {} failed", self.attempts);
+                    first_error.get_or_insert(error);
+                }
+            }
+            if attempt < self.attempts && !backoff.is_zero() {
+                Delay::new(backoff).await;
+                backoff *= 2;
+            }
+        }
+        Err(first_error.expect("there should be at least one attempt"))
+    }
+}
+
+impl Default for Retry {
+    /// Creates a policy which makes a single attempt without any retries.
+    fn default() -> Self {
+        Self::none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::time::Duration;
+
+    use super::Retry;
+
+    #[test]
+    fn succeeds_on_second_attempt() {
+        let calls = Cell::new(0);
+        let factory = || {
+            calls.set(calls.get() + 1);
+            let attempt = calls.get();
+            async move {
+                match attempt {
+                    1 => Err("no suitable adapter found"),
+                    _ => Ok("adapter"),
+                }
+            }
+        };
+        let result = pollster::block_on(Retry::new(3, Duration::from_millis(1)).run(factory));
+        assert_eq!(result, Ok("adapter"));
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[test]
+    fn returns_first_error() {
+        let calls = Cell::new(0);
+        let factory = || {
+            calls.set(calls.get() + 1);
+            let attempt = calls.get();
+            async move { Err::<(), _>(attempt) }
+        };
+        let result = pollster::block_on(Retry::new(3, Duration::ZERO).run(factory));
+        assert_eq!(result, Err(1));
+        assert_eq!(calls.get(), 3);
+    }
+
+    #[test]
+    fn single_attempt() {
+        let calls = Cell::new(0);
+        let factory = || {
+            calls.set(calls.get() + 1);
+            async { Err::<(), _>(()) }
+        };
+        let result = pollster::block_on(Retry::none().run(factory));
+        assert_eq!(result, Err(()));
+        assert_eq!(calls.get(), 1);
+    }
+}
@@ -0,0 +1,172 @@
+//! This module provides functionality for measuring the time the GPU spends executing compute passes.
+//!
+//! ## Timestamp Queries in WGPU
+//!
+//! When the device has the `TIMESTAMP_QUERY` feature enabled, compute passes can write GPU timestamps into a query
+//! set at their beginning and at their end. The query set is then resolved into a buffer, which is copied into a
+//! mappable buffer and read back on the CPU. Timestamps are measured in ticks, which are converted to nanoseconds
+//! using the timestamp period of the queue. Unlike wall-clock timing, this excludes any CPU overhead of encoding and
+//! submitting the commands.
+//!
+//! A timer measures many passes: each of them writes its timestamps into its own slot of the query set, and the
+//! timestamps are only read back once all slots are used or the total time is requested. The device is therefore
+//! waited for once per batch of passes rather than after every pass.
+//!
+//! ## Module Structs and Methods
+//!
+//! - `Timer`: Holds the query set and the buffers used to measure the duration of compute passes.
+//!   - `record`: Marks the slot of the last measured pass as used once its commands have been submitted.
+//!   - `is_full`: Checks whether all slots are used, so that the timestamps have to be read back first.
+//!   - `flush`: Reads back the timestamps of the measured passes, adding their time to the total.
+//!   - `elapsed`: Reads back any pending timestamps and returns the total time of all measured passes.
+
+use std::cell::Cell;
+use std::sync::mpsc;
+use std::time::Duration;
+
+use tracing::trace;
+
+use crate::{Device, Result};
+
+/// The number of timestamps written by a measured pass: one at its beginning and one at its end.
+const TIMESTAMPS_PER_PASS: u32 = 2;
+
+/// The number of passes measured before their timestamps have to be read back.
+const PASS_CAPACITY: u32 = 256;
+
+/// The size in bytes of the resolved timestamps of a single pass.
+const PASS_SIZE: u64 = TIMESTAMPS_PER_PASS as u64 * std::mem::size_of::<u64>() as u64;
+
+/// Measures the time the GPU spends executing compute passes using timestamp queries.
+pub struct Timer {
+    query_set: wgpu::QuerySet,
+    resolve_buffer: wgpu::Buffer,
+    readback_buffer: wgpu::Buffer,
+    period: f32,
+    pending: Cell<u32>,
+    elapsed: Cell<Duration>,
+}
+
+impl Timer {
+    /// Creates a new `Timer` instance. The device must have the `TIMESTAMP_QUERY` feature enabled.
+    ///
+    /// # Parameters
+    /// - `device`: The WGPU device used to create the query set and the buffers.
+    /// - `label`: A label for the query set and the buffers.
+    /// - `period`: The number of nanoseconds per timestamp tick.
+    ///
+    /// # Returns
+    /// A new `Timer` instance.
+    pub(crate) fn new(device: &wgpu::Device, label: &str, period: f32) -> Self {
+        let query_set = device.create_query_set(&wgpu::QuerySetDescriptor {
+            label: Some(label),
+            ty: wgpu::QueryType::Timestamp,
+            count: PASS_CAPACITY * TIMESTAMPS_PER_PASS,
+        });
+        let resolve_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some(label),
+            size: PASS_CAPACITY as u64 * PASS_SIZE,
+            usage: wgpu::BufferUsages::QUERY_RESOLVE | wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        });
+        let readback_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some(label),
+            size: PASS_CAPACITY as u64 * PASS_SIZE,
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        trace!("Created timer '{label}'");
+        Self {
+            query_set,
+            resolve_buffer,
+            readback_buffer,
+            period,
+            pending: Cell::new(0),
+            elapsed: Cell::new(Duration::ZERO),
+        }
+    }
+
+    /// Returns the timestamp writes to be attached to the measured compute pass. They go into the
+    /// first unused slot of the query set, which is only marked as used by `record`.
+    ///
+    /// # Returns
+    /// The timestamp writes at the beginning and at the end of the pass.
+    ///
+    /// # Panics
+    /// Panics if all slots are used, in which case the timer has to be flushed first.
+    pub(crate) fn timestamp_writes(&self) -> wgpu::ComputePassTimestampWrites<'_> {
+        assert!(!self.is_full(), "timer should be flushed before measuring more passes");
+        let index = self.pending.get() * TIMESTAMPS_PER_PASS;
+        wgpu::ComputePassTimestampWrites {
+            query_set: &self.query_set,
+            beginning_of_pass_write_index: Some(index),
+            end_of_pass_write_index: Some(index + 1),
+        }
+    }
+
+    /// Marks the slot of the last measured pass as used. It has to be called once the commands of
+    /// the pass have been submitted, so that passes which are never submitted don't take a slot.
+    pub fn record(&self) {
+        self.pending.set(self.pending.get() + 1);
+    }
+
+    /// Checks whether all slots of the timer are used.
+    ///
+    /// # Returns
+    /// `true` if the timer has to be flushed before it can measure more passes.
+    pub fn is_full(&self) -> bool {
+        self.pending.get() == PASS_CAPACITY
+    }
+
+    /// Reads back the timestamps of the passes measured since the last flush and adds their time to
+    /// the total. The device is polled until their commands have been executed, so this method
+    /// should only be called after they have been submitted.
+    ///
+    /// # Parameters
+    /// - `device`: The device the measured passes were submitted to.
+    ///
+    /// # Returns
+    /// A `Result` indicating success, or `Error::MapError` if the readback buffer couldn't be mapped.
+    pub fn flush(&self, device: &Device) -> Result<()> {
+        let pending = self.pending.get();
+        if pending == 0 {
+            return Ok(());
+        }
+        let size = pending as u64 * PASS_SIZE;
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor { label: Some("timer") });
+        let queries = 0..pending * TIMESTAMPS_PER_PASS;
+        encoder.resolve_query_set(&self.query_set, queries, &self.resolve_buffer, 0);
+        encoder.copy_buffer_to_buffer(&self.resolve_buffer, 0, &self.readback_buffer, 0, size);
+        device.submit(encoder.finish());
+        let slice = self.readback_buffer.slice(..size);
+        let (sender, receiver) = mpsc::channel();
+        slice.map_async(wgpu::MapMode::Read, move |v| sender.send(v).unwrap());
+        device.poll(wgpu::Maintain::wait()).panic_on_timeout();
+        receiver.recv().expect("map callback should be called after polling")?;
+        let timestamps: Vec<u64> = bytemuck::cast_slice(&slice.get_mapped_range()).to_vec();
+        self.readback_buffer.unmap();
+        let ticks: u64 = timestamps
+            .chunks_exact(TIMESTAMPS_PER_PASS as usize)
+            .map(|pass| pass[1].saturating_sub(pass[0]))
+            .sum();
+        let duration = Duration::from_nanos((ticks as f64 * self.period as f64) as u64);
+        trace!("Measured GPU time of {duration:?} over {pending} passes");
+        self.elapsed.set(self.elapsed.get() + duration);
+        self.pending.set(0);
+        Ok(())
+    }
+
+    /// Reads back any pending timestamps and returns the total time of all passes measured by the
+    /// timer.
+    ///
+    /// # Parameters
+    /// - `device`: The device the measured passes were submitted to.
+    ///
+    /// # Returns
+    /// A `Result` containing the total GPU time, or `Error::MapError` if the readback buffer
+    /// couldn't be mapped.
+    pub fn elapsed(&self, device: &Device) -> Result<Duration> {
+        self.flush(device)?;
+        Ok(self.elapsed.get())
+    }
+}
@@ -6,7 +6,7 @@ use bon::bon;
 use tracing::trace;
 
 use crate::adapter::AdapterBuilder;
-use crate::{Device, Result, Surface};
+use crate::{Device, Result, Retry, Surface};
 
 /// The `WGPU` struct represents an instance of the WGPU backend. It is responsible for creating GPU instances, surfaces, and adapters.
 pub struct WGPU {
@@ -58,7 +58,46 @@ impl WGPU {
     /// # Returns
     /// A `Result` containing the created `Device` or an error.
     pub async fn default_context() -> Result<Device> {
+        Self::default_context_with_retry(Retry::none()).await
+    }
+
+    /// Creates a default GPU context using the primary backend, retrying failed adapter and device
+    /// requests according to the given policy. Timestamp queries are enabled if the adapter
+    /// supports them.
+    ///
+    /// # Parameters
+    /// - `retry`: The policy for retrying failed requests.
+    ///
+    /// # Returns
+    /// A `Result` containing the created `Device` or an error.
+    pub async fn default_context_with_retry(retry: Retry) -> Result<Device> {
         let instance = Self::builder().backends(wgpu::Backends::PRIMARY).build();
-        instance.adapter().request().await?.device().request().await
+        let adapter = instance.adapter().with_retry(retry).request().await?;
+        adapter
+            .device_with_retry(retry)
+            .with_optional_features(wgpu::Features::TIMESTAMP_QUERY)
+            .request()
+            .await
+    }
+
+    /// Creates a GPU context on a software fallback adapter from any of the backends, including GL,
+    /// e.g. llvmpipe on headless Linux machines without a GPU. Software adapters may not reach the
+    /// default limits, so the device is requested with the limits of the adapter. Computations run
+    /// on the CPU and are much slower than on hardware adapters, so this is meant for testing.
+    ///
+    /// # Returns
+    /// A `Result` containing the created `Device`, or `Error::CreateAdapterError` if there is no
+    /// fallback adapter.
+    pub async fn fallback_context() -> Result<Device> {
+        let instance = Self::builder().backends(wgpu::Backends::all()).build();
+        let adapter = instance.adapter().with_fallback().request().await?;
+        trace!("Requested fallback adapter {:?}", adapter.get_info());
+        let limits = adapter.limits();
+        adapter
+            .device()
+            .with_limits(limits)
+            .with_optional_features(wgpu::Features::TIMESTAMP_QUERY)
+            .request()
+            .await
     }
 }